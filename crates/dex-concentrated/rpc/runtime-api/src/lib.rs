@@ -0,0 +1,40 @@
+//! Runtime API definition for concentrated amm.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+use codec::Codec;
+pub use dex_concentrated::PositionId;
+use sp_runtime::Permill;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait DexConcentratedApi<CurrencyId, Balance, AccountId, PoolId> where
+        Balance: Codec,
+        CurrencyId: Codec,
+        AccountId: Codec,
+        PoolId: Codec,
+    {
+        fn get_currencies(pool_id: PoolId) -> Vec<CurrencyId>;
+
+        fn get_swap_fee(pool_id: PoolId) -> Permill;
+
+        fn get_tick(pool_id: PoolId) -> Option<i32>;
+
+        fn get_liquidity(pool_id: PoolId) -> Balance;
+
+        fn get_spot_price(pool_id: PoolId) -> Option<Balance>;
+
+        fn get_positions(who: AccountId) -> Vec<PositionId>;
+
+        fn calculate_position_amounts(who: AccountId, position_id: PositionId) -> Option<Vec<Balance>>;
+
+        fn calculate_swap(pool_id: PoolId, in_currency: CurrencyId, in_amount: Balance) -> Option<Balance>;
+
+        fn calculate_swap_for_exact(pool_id: PoolId, in_currency: CurrencyId, out_amount: Balance) -> Option<Balance>;
+
+        fn calculate_add_liquidity(pool_id: PoolId, tick_lower: i32, tick_upper: i32, amount_0: Balance, amount_1: Balance) -> Option<Balance>;
+    }
+}
@@ -0,0 +1,269 @@
+//! RPC interface for the concentrated amm pallet.
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+    traits::{Block as BlockT, MaybeDisplay},
+    Permill,
+};
+use std::sync::Arc;
+
+pub use dex_concentrated_rpc_runtime_api::{DexConcentratedApi as DexConcentratedRuntimeApi, PositionId};
+
+#[rpc(client, server)]
+pub trait DexConcentratedApi<BlockHash, CurrencyId, Balance, AccountId, PoolId> {
+    #[method(name = "dexConcentrated_getCurrencies")]
+    fn get_currencies(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Vec<CurrencyId>>;
+
+    #[method(name = "dexConcentrated_getSwapFee")]
+    fn get_swap_fee(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Permill>;
+
+    #[method(name = "dexConcentrated_getTick")]
+    fn get_tick(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<i32>;
+
+    #[method(name = "dexConcentrated_getLiquidity")]
+    fn get_liquidity(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexConcentrated_getSpotPrice")]
+    fn get_spot_price(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexConcentrated_getPositions")]
+    fn get_positions(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<PositionId>>;
+
+    #[method(name = "dexConcentrated_calculatePositionAmounts")]
+    fn calculate_position_amounts(
+        &self,
+        who: AccountId,
+        position_id: PositionId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NumberOrHex>>;
+
+    #[method(name = "dexConcentrated_calculateSwap")]
+    fn calculate_swap(
+        &self,
+        pool_id: PoolId,
+        in_currency: CurrencyId,
+        in_amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexConcentrated_calculateSwapForExact")]
+    fn calculate_swap_for_exact(
+        &self,
+        pool_id: PoolId,
+        in_currency: CurrencyId,
+        out_amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexConcentrated_calculateAddLiquidity")]
+    fn calculate_add_liquidity(
+        &self,
+        pool_id: PoolId,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0: Balance,
+        amount_1: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+}
+
+pub struct DexConcentrated<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> DexConcentrated<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, CurrencyId, Balance, AccountId, PoolId>
+    DexConcentratedApiServer<<Block as BlockT>::Hash, CurrencyId, Balance, AccountId, PoolId>
+    for DexConcentrated<C, Block>
+where
+    Block: BlockT,
+    CurrencyId: Codec,
+    Balance: Codec + TryInto<NumberOrHex> + std::fmt::Debug + MaybeDisplay + Copy,
+    AccountId: Codec,
+    PoolId: Codec,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: DexConcentratedRuntimeApi<Block, CurrencyId, Balance, AccountId, PoolId>,
+{
+    fn get_currencies(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<CurrencyId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_currencies(at, pool_id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_swap_fee(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Permill> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_swap_fee(at, pool_id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_tick(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<i32> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_tick(at, pool_id).map_or_else(
+            |e| Err(runtime_error_into_rpc_err(e)),
+            |v| v.ok_or(runtime_error_into_rpc_err("invalid pool")),
+        )
+    }
+
+    fn get_liquidity(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api.get_liquidity(at, pool_id).map_err(runtime_error_into_rpc_err)?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn get_spot_price(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .get_spot_price(at, pool_id)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("invalid pool"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn get_positions(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<PositionId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_positions(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn calculate_position_amounts(
+        &self,
+        who: AccountId,
+        position_id: PositionId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.calculate_position_amounts(at, who, position_id)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("invalid position"))?
+            .iter()
+            .map(|b| try_into_rpc_balance(*b))
+            .collect()
+    }
+
+    fn calculate_swap(
+        &self,
+        pool_id: PoolId,
+        in_currency: CurrencyId,
+        in_amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_swap(at, pool_id, in_currency, in_amount)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("swap not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn calculate_swap_for_exact(
+        &self,
+        pool_id: PoolId,
+        in_currency: CurrencyId,
+        out_amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_swap_for_exact(at, pool_id, in_currency, out_amount)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("swap not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn calculate_add_liquidity(
+        &self,
+        pool_id: PoolId,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0: Balance,
+        amount_1: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_add_liquidity(at, pool_id, tick_lower, tick_upper, amount_0, amount_1)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("deposit not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+}
+
+fn try_into_rpc_balance<Balance: Codec + TryInto<NumberOrHex> + MaybeDisplay + Copy + std::fmt::Debug>(
+    value: Balance,
+) -> RpcResult<NumberOrHex> {
+    value.try_into().map_err(|_| {
+        CallError::Custom(ErrorObject::owned(
+            Error::RuntimeError.into(),
+            "error in concentrated amm pallet",
+            Some("transfer into rpc balance".to_string()),
+        ))
+        .into()
+    })
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Display) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "error in concentrated pallet",
+        Some(err.to_string()),
+    ))
+    .into()
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as ConcentratedPallet;
+
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+const UNIT: Balance = 1_000_000_000_000;
+const SWAP_FEE: Permill = Permill::from_parts(3_000);
+const TICK_SPACING: u32 = 10;
+
+fn pool_currencies<T: Config>() -> (T::CurrencyId, T::CurrencyId)
+where
+    T::CurrencyId: From<u32>,
+{
+    let (currency_a, currency_b) = (T::CurrencyId::from(0), T::CurrencyId::from(1));
+    (currency_a.min(currency_b), currency_a.max(currency_b))
+}
+
+/// Creates a pool at tick zero with `positions` adjacent positions of `caller` above the price,
+/// so that swapping currency 1 crosses the tick between each of them.
+fn setup_pool_and_positions<T: Config>(caller: T::AccountId, positions: u32) -> T::PoolId
+where
+    T::CurrencyId: From<u32>,
+{
+    let pool_id = ConcentratedPallet::<T>::next_pool_id();
+    let (currency_0, currency_1) = pool_currencies::<T>();
+
+    assert_ok!(ConcentratedPallet::<T>::create_pool(
+        RawOrigin::Root.into(),
+        currency_0,
+        currency_1,
+        SWAP_FEE,
+        TICK_SPACING,
+        0,
+    ));
+
+    assert_ok!(T::MultiCurrency::deposit(currency_0, &caller, 1000 * UNIT));
+    assert_ok!(T::MultiCurrency::deposit(currency_1, &caller, 1000 * UNIT));
+    // the deposits of the positions, and of one more opened by the benchmark
+    assert_ok!(T::MultiCurrency::deposit(
+        T::NativeCurrencyId::get(),
+        &caller,
+        T::PositionDeposit::get() * (positions + 1) as Balance
+    ));
+
+    for i in 0..positions as i32 {
+        let tick_lower = i * TICK_SPACING as i32;
+        assert_ok!(ConcentratedPallet::<T>::create_position(
+            RawOrigin::Signed(caller.clone()).into(),
+            pool_id,
+            tick_lower,
+            tick_lower + TICK_SPACING as i32,
+            UNIT,
+            UNIT,
+            0,
+            0,
+            1000u32.into()
+        ));
+    }
+
+    pool_id
+}
+
+/// Swaps currency 1 for currency 0, moving the price across the range of the first position.
+fn swap_through_first_position<T: Config>(caller: T::AccountId, pool_id: T::PoolId)
+where
+    T::CurrencyId: From<u32>,
+{
+    let (_, currency_1) = pool_currencies::<T>();
+    assert_ok!(ConcentratedPallet::<T>::swap(
+        RawOrigin::Signed(caller.clone()).into(),
+        pool_id,
+        currency_1,
+        UNIT / 2,
+        0,
+        caller,
+        MAX_TICK_CROSSINGS,
+        1000u32.into()
+    ));
+}
+
+#[benchmarks(
+    where
+        T: Config,
+        T::CurrencyId: From<u32>,
+)]
+pub mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    pub fn create_pool() {
+        let (currency_0, currency_1) = pool_currencies::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, currency_0, currency_1, MAX_SWAP_FEE, TICK_SPACING, 0);
+
+        assert!(ConcentratedPallet::<T>::pools(T::PoolId::zero()).is_some());
+    }
+
+    #[benchmark]
+    pub fn create_position() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_positions::<T>(caller.clone(), 1);
+
+        // a range around the price, which initializes two ticks and takes both currencies
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            -100,
+            100,
+            10 * UNIT,
+            10 * UNIT,
+            0,
+            0,
+            1000u32.into(),
+        );
+
+        assert!(ConcentratedPallet::<T>::positions(&caller, 1).is_some());
+    }
+
+    #[benchmark]
+    pub fn add_liquidity() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_positions::<T>(caller.clone(), 1);
+        swap_through_first_position::<T>(caller.clone(), pool_id);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            0,
+            10 * UNIT,
+            10 * UNIT,
+            0,
+            0,
+            1000u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn remove_liquidity() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_positions::<T>(caller.clone(), 1);
+        swap_through_first_position::<T>(caller.clone(), pool_id);
+        let liquidity = ConcentratedPallet::<T>::positions(&caller, 0).unwrap().liquidity;
+
+        // removing all the liquidity clears both ticks
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, liquidity, 0, 0, 1000u32.into());
+    }
+
+    #[benchmark]
+    pub fn collect() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_positions::<T>(caller.clone(), 1);
+        swap_through_first_position::<T>(caller.clone(), pool_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, caller.clone());
+    }
+
+    #[benchmark]
+    pub fn swap(c: Linear<1, MAX_TICK_CROSSINGS>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_positions::<T>(caller.clone(), c + 1);
+        let (_, currency_1) = pool_currencies::<T>();
+
+        // buying out a position takes more than a unit, so this crosses the ticks between almost
+        // `c` positions
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            currency_1,
+            c as Balance * UNIT,
+            0,
+            caller.clone(),
+            c,
+            1000u32.into(),
+        );
+    }
+
+    impl_benchmark_test_suite!(ConcentratedPallet, crate::mock::ExtBuilder::build(), crate::mock::Test);
+}
@@ -0,0 +1,1067 @@
+//! # Concentrated Liquidity AMM Pallet
+//!
+//! Based on the Uniswap V3 concentrated liquidity architecture.
+//!
+//! A pool trades two currencies. Liquidity providers choose the price range of their positions,
+//! and a position only backs swaps while the price is inside its range, so correlated currencies
+//! can be traded with far less liquidity than a constant product pair over the full range needs.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality for:
+//!
+//! - Creating pools
+//! - Opening positions over a price range and adding / removing their liquidity
+//! - Collecting the fees earned by positions
+//! - Swapping currencies
+//!
+//! ### Terminology
+//!
+//! - **Tick:** The price `1.0001^i` of currency 0 in currency 1 at tick `i`. The ranges of positions are bounded by
+//!   ticks which are multiples of the tick spacing of their pool.
+//!
+//! - **Liquidity:** The square root of the product of the amounts a position would hold if its range covered all
+//!   prices. The liquidity of the positions whose range contains the current price is traded against.
+//!
+//! - **Position:** A range and its liquidity, owned by an account and identified by a `PositionId` among the positions
+//!   of that account.
+//!
+//! - **Swap Fee:** The fee taken from the input currency, which is shared by the liquidity in range.
+//!
+//! - **Position Deposit:** The native currency reserved from the owner of a position until it is closed, since the
+//!   ticks bounding it take up room in the limited set of initialized ticks of its pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+#![allow(clippy::too_many_arguments)]
+
+pub mod math;
+pub mod rpc;
+pub mod traits;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod default_weights;
+mod primitives;
+
+use frame_support::{
+    dispatch::{Codec, DispatchResult, DispatchResultWithPostInfo},
+    pallet_prelude::*,
+    transactional, PalletId,
+};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_arithmetic::Rounding;
+use sp_core::U256;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Zero},
+    Permill,
+};
+use sp_std::vec::Vec;
+
+pub use default_weights::WeightInfo;
+pub use pallet::*;
+pub use primitives::*;
+
+#[allow(type_alias_bounds)]
+type AccountIdOf<T: Config> = <T as frame_system::Config>::AccountId;
+
+#[allow(type_alias_bounds)]
+type PoolOf<T: Config> = ConcentratedPool<T::CurrencyId, AccountIdOf<T>>;
+
+#[allow(type_alias_bounds)]
+type PositionOf<T: Config> = Position<T::PoolId>;
+
+/// Number of times the input computed for an exact output is topped up, in case rounding at the
+/// crossed ticks leaves the output of swapping it just short.
+const AMOUNT_IN_CORRECTIONS: usize = 4;
+
+/// The state of a pool after a simulated swap, which `inner_swap` applies.
+pub(crate) struct SwapResult {
+    pub amount_in: Balance,
+    pub amount_out: Balance,
+    pub sqrt_price: U256,
+    pub tick: i32,
+    pub liquidity: u128,
+    /// The fee growth of the input currency.
+    pub fee_growth_global: U256,
+    /// The initialized ticks crossed, with the fee growth of the input currency when crossing them.
+    pub crossed_ticks: Vec<(i32, U256)>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency ID type
+        type CurrencyId: Parameter + Member + Copy + MaybeSerializeDeserialize + Ord + TypeInfo + MaxEncodedLen;
+
+        /// The trait control all currencies
+        type MultiCurrency: MultiReservableCurrency<AccountIdOf<Self>, CurrencyId = Self::CurrencyId, Balance = Balance>;
+
+        /// The currency in which position deposits are reserved.
+        #[pallet::constant]
+        type NativeCurrencyId: Get<Self::CurrencyId>;
+
+        /// The deposit reserved for every open position.
+        #[pallet::constant]
+        type PositionDeposit: Get<Balance>;
+
+        /// The pool ID type
+        type PoolId: Parameter + Codec + Copy + Ord + AtLeast32BitUnsigned + Zero + One + Default + MaxEncodedLen;
+
+        /// The maximum number of initialized ticks of a pool.
+        #[pallet::constant]
+        type MaxTicks: Get<u32>;
+
+        /// This pallet ID.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The id of next pool
+    #[pallet::storage]
+    #[pallet::getter(fn next_pool_id)]
+    pub type NextPoolId<T: Config> = StorageValue<_, T::PoolId, ValueQuery>;
+
+    /// Info of a pool.
+    #[pallet::storage]
+    #[pallet::getter(fn pools)]
+    pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, PoolOf<T>>;
+
+    /// The initialized ticks of a pool, which bound the range of at least one position.
+    #[pallet::storage]
+    #[pallet::getter(fn ticks)]
+    pub type Ticks<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::PoolId, Twox64Concat, i32, TickInfo>;
+
+    /// The initialized ticks of a pool in ascending order.
+    #[pallet::storage]
+    #[pallet::getter(fn initialized_ticks)]
+    pub type InitializedTicks<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::PoolId, BoundedVec<i32, T::MaxTicks>, ValueQuery>;
+
+    /// The positions of an account.
+    #[pallet::storage]
+    #[pallet::getter(fn positions)]
+    pub type Positions<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, PositionId, PositionOf<T>>;
+
+    /// The id of the next position of an account.
+    #[pallet::storage]
+    #[pallet::getter(fn next_position_id)]
+    pub type NextPositionId<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, PositionId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A pool was created.
+        CreatePool {
+            pool_id: T::PoolId,
+            currency_0: T::CurrencyId,
+            currency_1: T::CurrencyId,
+            swap_fee: Permill,
+            tick_spacing: u32,
+            tick: i32,
+            account: T::AccountId,
+        },
+        /// A position was opened.
+        CreatePosition {
+            who: T::AccountId,
+            position_id: PositionId,
+            pool_id: T::PoolId,
+            tick_lower: i32,
+            tick_upper: i32,
+        },
+        /// Supply some liquidity to a position.
+        AddLiquidity {
+            who: T::AccountId,
+            position_id: PositionId,
+            pool_id: T::PoolId,
+            liquidity: u128,
+            amount_0: Balance,
+            amount_1: Balance,
+        },
+        /// Remove some liquidity from a position, the amounts are left to collect.
+        RemoveLiquidity {
+            who: T::AccountId,
+            position_id: PositionId,
+            pool_id: T::PoolId,
+            liquidity: u128,
+            amount_0: Balance,
+            amount_1: Balance,
+        },
+        /// The fees and removed liquidity of a position were collected.
+        Collect {
+            who: T::AccountId,
+            position_id: PositionId,
+            pool_id: T::PoolId,
+            to: T::AccountId,
+            amount_0: Balance,
+            amount_1: Balance,
+        },
+        /// A position without liquidity was closed after collecting everything it was owed.
+        ClosePosition { who: T::AccountId, position_id: PositionId },
+        /// Swap a amounts of currency to get other.
+        CurrencyExchange {
+            pool_id: T::PoolId,
+            who: T::AccountId,
+            to: T::AccountId,
+            in_currency: T::CurrencyId,
+            in_amount: Balance,
+            out_currency: T::CurrencyId,
+            out_amount: Balance,
+            tick: i32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The currencies of a pool must be distinct and sorted.
+        InvalidPooledCurrency,
+        /// The currency is not traded by the pool.
+        CurrencyNotInPool,
+        /// The fee parameter exceeds MAX_SWAP_FEE when creating the pool.
+        ExceedMaxFee,
+        /// The tick spacing must be between one and MAX_TICK_SPACING.
+        InvalidTickSpacing,
+        /// The tick is out of range or not a multiple of the tick spacing of the pool, or the
+        /// lower tick of a range is not below its upper tick.
+        InvalidTick,
+        /// The pool id is invalid.
+        InvalidPoolId,
+        /// The position id is invalid.
+        InvalidPositionId,
+        /// The error generate by some arithmetic function.
+        Arithmetic,
+        /// The call already expired.
+        Deadline,
+        /// Slippage is too large.
+        AmountSlippage,
+        /// The caller does not have enough currencies.
+        InsufficientSupply,
+        /// The caller can not reserve the deposit of a position.
+        InsufficientDeposit,
+        /// The pool does not have enough currencies.
+        InsufficientReserve,
+        /// The position has not enough liquidity, or the pool not enough liquidity in range to
+        /// fill the swap.
+        InsufficientLiquidity,
+        /// The liquidity of a tick exceeds the maximum of its pool.
+        ExceedMaxLiquidityPerTick,
+        /// The pool has MaxTicks initialized ticks.
+        TooManyTicks,
+        /// The swap crosses more initialized ticks than its limit.
+        TooManyTickCrossings,
+        /// The transaction change nothing.
+        InvalidTransaction,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a concentrated liquidity pool.
+        ///
+        /// Only admin can create pool.
+        ///
+        /// # Argument
+        ///
+        /// - `currency_0`: The lower of the two currencies of the pool.
+        /// - `currency_1`: The higher of the two currencies of the pool.
+        /// - `fee`: The swap fee of created pool.
+        /// - `tick_spacing`: The tick spacing of created pool.
+        /// - `tick`: The tick of the initial price of `currency_0` in `currency_1`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_pool())]
+        #[transactional]
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            currency_0: T::CurrencyId,
+            currency_1: T::CurrencyId,
+            fee: Permill,
+            tick_spacing: u32,
+            tick: i32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(currency_0 < currency_1, Error::<T>::InvalidPooledCurrency);
+            ensure!(fee <= MAX_SWAP_FEE, Error::<T>::ExceedMaxFee);
+            ensure!(
+                (1..=MAX_TICK_SPACING).contains(&tick_spacing),
+                Error::<T>::InvalidTickSpacing
+            );
+            ensure!(tick > MIN_TICK && tick < MAX_TICK, Error::<T>::InvalidTick);
+            let sqrt_price = math::get_sqrt_ratio_at_tick(tick).ok_or(Error::<T>::Arithmetic)?;
+
+            let pool_id = Self::next_pool_id();
+            let account = Self::pool_account_id(&pool_id);
+            frame_system::Pallet::<T>::inc_providers(&account);
+
+            Pools::<T>::insert(
+                pool_id,
+                ConcentratedPool {
+                    currency_0,
+                    currency_1,
+                    fee,
+                    tick_spacing,
+                    sqrt_price,
+                    tick,
+                    liquidity: Zero::zero(),
+                    fee_growth_global_0: U256::zero(),
+                    fee_growth_global_1: U256::zero(),
+                    account: account.clone(),
+                },
+            );
+            NextPoolId::<T>::put(pool_id.checked_add(&One::one()).ok_or(Error::<T>::Arithmetic)?);
+
+            Self::deposit_event(Event::CreatePool {
+                pool_id,
+                currency_0,
+                currency_1,
+                swap_fee: fee,
+                tick_spacing,
+                tick,
+                account,
+            });
+
+            Ok(())
+        }
+
+        /// Open a position over `[tick_lower, tick_upper)` and supply it with the largest
+        /// liquidity the desired amounts pay for at the current price.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `tick_lower`: The lower tick of the range of the position.
+        /// - `tick_upper`: The upper tick of the range of the position.
+        /// - `amount_0_desired`: The maximum amount of currency 0 to supply.
+        /// - `amount_1_desired`: The maximum amount of currency 1 to supply.
+        /// - `amount_0_min`: The min amount of currency 0 to supply.
+        /// - `amount_1_min`: The min amount of currency 1 to supply.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::create_position())]
+        #[transactional]
+        pub fn create_position(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            tick_lower: i32,
+            tick_upper: i32,
+            amount_0_desired: Balance,
+            amount_1_desired: Balance,
+            amount_0_min: Balance,
+            amount_1_min: Balance,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            let position_id = Self::inner_create_position(&who, pool_id, tick_lower, tick_upper)?;
+            Self::inner_add_liquidity(
+                &who,
+                position_id,
+                amount_0_desired,
+                amount_1_desired,
+                amount_0_min,
+                amount_1_min,
+            )?;
+
+            Ok(())
+        }
+
+        /// Supply liquidity to a position.
+        ///
+        /// # Argument
+        ///
+        /// - `position_id`: The id of the position of the caller.
+        /// - `amount_0_desired`: The maximum amount of currency 0 to supply.
+        /// - `amount_1_desired`: The maximum amount of currency 1 to supply.
+        /// - `amount_0_min`: The min amount of currency 0 to supply.
+        /// - `amount_1_min`: The min amount of currency 1 to supply.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::add_liquidity())]
+        #[transactional]
+        pub fn add_liquidity(
+            origin: OriginFor<T>,
+            position_id: PositionId,
+            amount_0_desired: Balance,
+            amount_1_desired: Balance,
+            amount_0_min: Balance,
+            amount_1_min: Balance,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_add_liquidity(
+                &who,
+                position_id,
+                amount_0_desired,
+                amount_1_desired,
+                amount_0_min,
+                amount_1_min,
+            )?;
+
+            Ok(())
+        }
+
+        /// Remove liquidity from a position. The amounts it held are left to `collect`.
+        ///
+        /// # Argument
+        ///
+        /// - `position_id`: The id of the position of the caller.
+        /// - `liquidity`: The liquidity to remove.
+        /// - `amount_0_min`: The min amount of currency 0 to get.
+        /// - `amount_1_min`: The min amount of currency 1 to get.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::remove_liquidity())]
+        #[transactional]
+        pub fn remove_liquidity(
+            origin: OriginFor<T>,
+            position_id: PositionId,
+            liquidity: u128,
+            amount_0_min: Balance,
+            amount_1_min: Balance,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_remove_liquidity(&who, position_id, liquidity, amount_0_min, amount_1_min)?;
+
+            Ok(())
+        }
+
+        /// Collect the fees earned and the liquidity removed by a position. A position without
+        /// liquidity is closed afterwards.
+        ///
+        /// # Argument
+        ///
+        /// - `position_id`: The id of the position of the caller.
+        /// - `to`: The account which receives the currencies.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::collect())]
+        #[transactional]
+        pub fn collect(origin: OriginFor<T>, position_id: PositionId, to: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::inner_collect(&who, position_id, &to)?;
+
+            Ok(())
+        }
+
+        /// Swap a amounts of currencies to get other.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `in_currency`: The currency to swap.
+        /// - `in_amount`: The amounts of currencies swap.
+        /// - `min_out_amount`: The min amounts of the other currency get.
+        /// - `to`: The account which receives the other currency.
+        /// - `max_tick_crossings`: The max number of initialized ticks the swap may cross, which the weight is charged
+        ///   for up front and refunded down to the ticks crossed.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::swap(*max_tick_crossings))]
+        #[transactional]
+        pub fn swap(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            in_currency: T::CurrencyId,
+            in_amount: Balance,
+            min_out_amount: Balance,
+            to: T::AccountId,
+            max_tick_crossings: u32,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            let (_, tick_crossings) = Self::inner_swap(
+                &who,
+                pool_id,
+                in_currency,
+                in_amount,
+                min_out_amount,
+                &to,
+                max_tick_crossings,
+            )?;
+
+            Ok(Some(T::WeightInfo::swap(tick_crossings)).into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// only use two byte prefix to support 16 byte account id (used by test)
+    /// "modl" ++ "dex/conc" is 12 bytes, and 4 bytes remaining for PoolId
+    pub fn pool_account_id(pool_id: &T::PoolId) -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(pool_id)
+    }
+
+    fn inner_create_position(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> Result<PositionId, DispatchError> {
+        let pool = Self::pools(pool_id).ok_or(Error::<T>::InvalidPoolId)?;
+        let tick_spacing = pool.tick_spacing as i32;
+        ensure!(
+            tick_lower < tick_upper
+                && tick_lower >= MIN_TICK
+                && tick_upper <= MAX_TICK
+                && tick_lower % tick_spacing == 0
+                && tick_upper % tick_spacing == 0,
+            Error::<T>::InvalidTick
+        );
+
+        let deposit = T::PositionDeposit::get();
+        T::MultiCurrency::reserve(T::NativeCurrencyId::get(), who, deposit)
+            .map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+        let position_id = Self::next_position_id(who);
+        Positions::<T>::insert(
+            who,
+            position_id,
+            Position {
+                pool_id,
+                tick_lower,
+                tick_upper,
+                liquidity: Zero::zero(),
+                fee_growth_inside_0_last: U256::zero(),
+                fee_growth_inside_1_last: U256::zero(),
+                tokens_owed_0: Zero::zero(),
+                tokens_owed_1: Zero::zero(),
+                deposit,
+            },
+        );
+        NextPositionId::<T>::insert(who, position_id.checked_add(1).ok_or(Error::<T>::Arithmetic)?);
+
+        Self::deposit_event(Event::CreatePosition {
+            who: who.clone(),
+            position_id,
+            pool_id,
+            tick_lower,
+            tick_upper,
+        });
+        Ok(position_id)
+    }
+
+    fn inner_add_liquidity(
+        who: &T::AccountId,
+        position_id: PositionId,
+        amount_0_desired: Balance,
+        amount_1_desired: Balance,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> Result<u128, DispatchError> {
+        let position = Self::positions(who, position_id).ok_or(Error::<T>::InvalidPositionId)?;
+        let pool = Self::pools(position.pool_id).ok_or(Error::<T>::InvalidPoolId)?;
+
+        let liquidity = math::get_liquidity_for_amounts(
+            pool.sqrt_price,
+            math::get_sqrt_ratio_at_tick(position.tick_lower).ok_or(Error::<T>::InvalidTick)?,
+            math::get_sqrt_ratio_at_tick(position.tick_upper).ok_or(Error::<T>::InvalidTick)?,
+            amount_0_desired,
+            amount_1_desired,
+        )
+        .ok_or(Error::<T>::Arithmetic)?;
+        ensure!(!liquidity.is_zero(), Error::<T>::InvalidTransaction);
+
+        let liquidity_delta = i128::try_from(liquidity).map_err(|_| Error::<T>::ExceedMaxLiquidityPerTick)?;
+        let (amount_0, amount_1) = Self::modify_position(who, position_id, liquidity_delta)?;
+        ensure!(
+            amount_0 >= amount_0_min && amount_1 >= amount_1_min,
+            Error::<T>::AmountSlippage
+        );
+
+        T::MultiCurrency::transfer(pool.currency_0, who, &pool.account, amount_0)
+            .map_err(|_| Error::<T>::InsufficientSupply)?;
+        T::MultiCurrency::transfer(pool.currency_1, who, &pool.account, amount_1)
+            .map_err(|_| Error::<T>::InsufficientSupply)?;
+
+        Self::deposit_event(Event::AddLiquidity {
+            who: who.clone(),
+            position_id,
+            pool_id: position.pool_id,
+            liquidity,
+            amount_0,
+            amount_1,
+        });
+        Ok(liquidity)
+    }
+
+    fn inner_remove_liquidity(
+        who: &T::AccountId,
+        position_id: PositionId,
+        liquidity: u128,
+        amount_0_min: Balance,
+        amount_1_min: Balance,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        ensure!(!liquidity.is_zero(), Error::<T>::InvalidTransaction);
+        let position = Self::positions(who, position_id).ok_or(Error::<T>::InvalidPositionId)?;
+        ensure!(liquidity <= position.liquidity, Error::<T>::InsufficientLiquidity);
+
+        let liquidity_delta = i128::try_from(liquidity).map_err(|_| Error::<T>::Arithmetic)?;
+        let (amount_0, amount_1) = Self::modify_position(who, position_id, -liquidity_delta)?;
+        ensure!(
+            amount_0 >= amount_0_min && amount_1 >= amount_1_min,
+            Error::<T>::AmountSlippage
+        );
+
+        // the amounts stay in the pool account until they are collected
+        Positions::<T>::try_mutate(who, position_id, |maybe_position| -> DispatchResult {
+            let position = maybe_position.as_mut().ok_or(Error::<T>::InvalidPositionId)?;
+            position.tokens_owed_0 = position.tokens_owed_0.saturating_add(amount_0);
+            position.tokens_owed_1 = position.tokens_owed_1.saturating_add(amount_1);
+            Ok(())
+        })?;
+
+        Self::deposit_event(Event::RemoveLiquidity {
+            who: who.clone(),
+            position_id,
+            pool_id: position.pool_id,
+            liquidity,
+            amount_0,
+            amount_1,
+        });
+        Ok((amount_0, amount_1))
+    }
+
+    fn inner_collect(
+        who: &T::AccountId,
+        position_id: PositionId,
+        to: &T::AccountId,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        let position = Self::positions(who, position_id).ok_or(Error::<T>::InvalidPositionId)?;
+        if !position.liquidity.is_zero() {
+            // credit the fees earned since the last update of the position
+            Self::modify_position(who, position_id, 0)?;
+        }
+
+        let position = Self::positions(who, position_id).ok_or(Error::<T>::InvalidPositionId)?;
+        let pool = Self::pools(position.pool_id).ok_or(Error::<T>::InvalidPoolId)?;
+        let (amount_0, amount_1) = (position.tokens_owed_0, position.tokens_owed_1);
+
+        T::MultiCurrency::transfer(pool.currency_0, &pool.account, to, amount_0)
+            .map_err(|_| Error::<T>::InsufficientReserve)?;
+        T::MultiCurrency::transfer(pool.currency_1, &pool.account, to, amount_1)
+            .map_err(|_| Error::<T>::InsufficientReserve)?;
+
+        Self::deposit_event(Event::Collect {
+            who: who.clone(),
+            position_id,
+            pool_id: position.pool_id,
+            to: to.clone(),
+            amount_0,
+            amount_1,
+        });
+
+        if position.liquidity.is_zero() {
+            Positions::<T>::remove(who, position_id);
+            T::MultiCurrency::unreserve(T::NativeCurrencyId::get(), who, position.deposit);
+            Self::deposit_event(Event::ClosePosition {
+                who: who.clone(),
+                position_id,
+            });
+        } else {
+            Positions::<T>::insert(
+                who,
+                position_id,
+                Position {
+                    tokens_owed_0: Zero::zero(),
+                    tokens_owed_1: Zero::zero(),
+                    ..position
+                },
+            );
+        }
+        Ok((amount_0, amount_1))
+    }
+
+    /// Add `liquidity_delta` to a position, its ticks and the pool, crediting the fees the
+    /// position earned so far. Returns the amounts the liquidity delta holds at the current price,
+    /// rounded up for additions and down for removals.
+    fn modify_position(
+        who: &T::AccountId,
+        position_id: PositionId,
+        liquidity_delta: i128,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        Positions::<T>::try_mutate(who, position_id, |maybe_position| -> Result<_, DispatchError> {
+            let position = maybe_position.as_mut().ok_or(Error::<T>::InvalidPositionId)?;
+            let pool_id = position.pool_id;
+            let (tick_lower, tick_upper) = (position.tick_lower, position.tick_upper);
+
+            Pools::<T>::try_mutate(pool_id, |maybe_pool| -> Result<_, DispatchError> {
+                let pool = maybe_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+
+                let flipped_lower = Self::update_tick(pool_id, pool, tick_lower, liquidity_delta, false)?;
+                let flipped_upper = Self::update_tick(pool_id, pool, tick_upper, liquidity_delta, true)?;
+
+                let (fee_growth_inside_0, fee_growth_inside_1) = Self::fee_growth_inside(pool_id, pool, position);
+                position.tokens_owed_0 = position.tokens_owed_0.saturating_add(math::fees_owed(
+                    fee_growth_inside_0,
+                    position.fee_growth_inside_0_last,
+                    position.liquidity,
+                )?);
+                position.tokens_owed_1 = position.tokens_owed_1.saturating_add(math::fees_owed(
+                    fee_growth_inside_1,
+                    position.fee_growth_inside_1_last,
+                    position.liquidity,
+                )?);
+                position.fee_growth_inside_0_last = fee_growth_inside_0;
+                position.fee_growth_inside_1_last = fee_growth_inside_1;
+                position.liquidity =
+                    math::add_delta(position.liquidity, liquidity_delta).ok_or(Error::<T>::InsufficientLiquidity)?;
+
+                // ticks no longer bounding any position are cleared
+                if liquidity_delta < 0 {
+                    if flipped_lower {
+                        Self::clear_tick(pool_id, tick_lower);
+                    }
+                    if flipped_upper {
+                        Self::clear_tick(pool_id, tick_upper);
+                    }
+                }
+
+                if pool.tick >= tick_lower && pool.tick < tick_upper {
+                    pool.liquidity = math::add_delta(pool.liquidity, liquidity_delta).ok_or(Error::<T>::Arithmetic)?;
+                }
+
+                let rounding = if liquidity_delta < 0 {
+                    Rounding::Down
+                } else {
+                    Rounding::Up
+                };
+                math::get_amounts_for_liquidity(
+                    pool.sqrt_price,
+                    pool.tick,
+                    tick_lower,
+                    tick_upper,
+                    liquidity_delta.unsigned_abs(),
+                    rounding,
+                )
+                .ok_or_else(|| Error::<T>::Arithmetic.into())
+            })
+        })
+    }
+
+    /// Add `liquidity_delta` to a tick bounding a range from below or, if `upper`, from above.
+    /// Returns whether the tick was initialized or cleared by it.
+    fn update_tick(
+        pool_id: T::PoolId,
+        pool: &PoolOf<T>,
+        tick: i32,
+        liquidity_delta: i128,
+        upper: bool,
+    ) -> Result<bool, DispatchError> {
+        let mut info = Self::ticks(pool_id, tick).unwrap_or_default();
+        let liquidity_gross_before = info.liquidity_gross;
+        let liquidity_gross_after =
+            math::add_delta(liquidity_gross_before, liquidity_delta).ok_or(Error::<T>::InsufficientLiquidity)?;
+        ensure!(
+            liquidity_gross_after <= math::max_liquidity_per_tick(pool.tick_spacing),
+            Error::<T>::ExceedMaxLiquidityPerTick
+        );
+
+        if liquidity_gross_before.is_zero() && !liquidity_gross_after.is_zero() {
+            // all fee growth before the tick was initialized is assumed to be below it
+            if tick <= pool.tick {
+                info.fee_growth_outside_0 = pool.fee_growth_global_0;
+                info.fee_growth_outside_1 = pool.fee_growth_global_1;
+            }
+            InitializedTicks::<T>::try_mutate(pool_id, |ticks| -> DispatchResult {
+                if let Err(index) = ticks.binary_search(&tick) {
+                    ticks.try_insert(index, tick).map_err(|_| Error::<T>::TooManyTicks)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        info.liquidity_gross = liquidity_gross_after;
+        info.liquidity_net = if upper {
+            info.liquidity_net.checked_sub(liquidity_delta)
+        } else {
+            info.liquidity_net.checked_add(liquidity_delta)
+        }
+        .ok_or(Error::<T>::Arithmetic)?;
+        Ticks::<T>::insert(pool_id, tick, info);
+
+        Ok(liquidity_gross_before.is_zero() != liquidity_gross_after.is_zero())
+    }
+
+    fn clear_tick(pool_id: T::PoolId, tick: i32) {
+        Ticks::<T>::remove(pool_id, tick);
+        InitializedTicks::<T>::mutate(pool_id, |ticks| ticks.retain(|t| *t != tick));
+    }
+
+    /// The fee growth of both currencies inside the range of `position`.
+    fn fee_growth_inside(pool_id: T::PoolId, pool: &PoolOf<T>, position: &PositionOf<T>) -> (U256, U256) {
+        let lower = Self::ticks(pool_id, position.tick_lower).unwrap_or_default();
+        let upper = Self::ticks(pool_id, position.tick_upper).unwrap_or_default();
+        (
+            math::fee_growth_inside(
+                pool.fee_growth_global_0,
+                pool.tick,
+                position.tick_lower,
+                lower.fee_growth_outside_0,
+                position.tick_upper,
+                upper.fee_growth_outside_0,
+            ),
+            math::fee_growth_inside(
+                pool.fee_growth_global_1,
+                pool.tick,
+                position.tick_lower,
+                lower.fee_growth_outside_1,
+                position.tick_upper,
+                upper.fee_growth_outside_1,
+            ),
+        )
+    }
+
+    /// Whether `in_currency` is currency 0 of the pool, so that the swap lowers the price.
+    fn zero_for_one(pool: &PoolOf<T>, in_currency: T::CurrencyId) -> Result<bool, DispatchError> {
+        if in_currency == pool.currency_0 {
+            Ok(true)
+        } else if in_currency == pool.currency_1 {
+            Ok(false)
+        } else {
+            Err(Error::<T>::CurrencyNotInPool.into())
+        }
+    }
+
+    /// Returns the output and the number of initialized ticks crossed.
+    fn inner_swap(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        in_currency: T::CurrencyId,
+        in_amount: Balance,
+        min_out_amount: Balance,
+        to: &T::AccountId,
+        max_tick_crossings: u32,
+    ) -> Result<(Balance, u32), DispatchError> {
+        ensure!(!in_amount.is_zero(), Error::<T>::InvalidTransaction);
+
+        Pools::<T>::try_mutate_exists(pool_id, |optioned_pool| -> Result<(Balance, u32), DispatchError> {
+            let pool = optioned_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+            let zero_for_one = Self::zero_for_one(pool, in_currency)?;
+            let out_currency = if zero_for_one { pool.currency_1 } else { pool.currency_0 };
+
+            let result = Self::compute_swap(pool_id, pool, zero_for_one, in_amount, true, max_tick_crossings)?;
+            ensure!(!result.amount_out.is_zero(), Error::<T>::InvalidTransaction);
+            ensure!(result.amount_out >= min_out_amount, Error::<T>::AmountSlippage);
+
+            // the fee growth outside of a crossed tick flips to the other side of it
+            for (tick, fee_growth_global) in result.crossed_ticks.iter() {
+                Ticks::<T>::mutate(pool_id, tick, |maybe_info| {
+                    if let Some(info) = maybe_info {
+                        let (fee_growth_global_0, fee_growth_global_1) = if zero_for_one {
+                            (*fee_growth_global, pool.fee_growth_global_1)
+                        } else {
+                            (pool.fee_growth_global_0, *fee_growth_global)
+                        };
+                        info.fee_growth_outside_0 = fee_growth_global_0.overflowing_sub(info.fee_growth_outside_0).0;
+                        info.fee_growth_outside_1 = fee_growth_global_1.overflowing_sub(info.fee_growth_outside_1).0;
+                    }
+                });
+            }
+
+            pool.sqrt_price = result.sqrt_price;
+            pool.tick = result.tick;
+            pool.liquidity = result.liquidity;
+            if zero_for_one {
+                pool.fee_growth_global_0 = result.fee_growth_global;
+            } else {
+                pool.fee_growth_global_1 = result.fee_growth_global;
+            }
+
+            T::MultiCurrency::transfer(in_currency, who, &pool.account, in_amount)
+                .map_err(|_| Error::<T>::InsufficientSupply)?;
+            T::MultiCurrency::transfer(out_currency, &pool.account, to, result.amount_out)
+                .map_err(|_| Error::<T>::InsufficientReserve)?;
+
+            Self::deposit_event(Event::CurrencyExchange {
+                pool_id,
+                who: who.clone(),
+                to: to.clone(),
+                in_currency,
+                in_amount,
+                out_currency,
+                out_amount: result.amount_out,
+                tick: pool.tick,
+            });
+            Ok((result.amount_out, result.crossed_ticks.len() as u32))
+        })
+    }
+
+    /// Simulates swapping `amount` of input, or for `amount` of output if not `exact_in`, range by
+    /// range between the initialized ticks. Fails unless the liquidity in range fills the whole
+    /// amount without crossing more than `max_tick_crossings` initialized ticks.
+    pub(crate) fn compute_swap(
+        pool_id: T::PoolId,
+        pool: &PoolOf<T>,
+        zero_for_one: bool,
+        amount: Balance,
+        exact_in: bool,
+        max_tick_crossings: u32,
+    ) -> Result<SwapResult, DispatchError> {
+        let ticks = Self::initialized_ticks(pool_id);
+        let sqrt_price_limit = if zero_for_one {
+            MIN_SQRT_RATIO + 1
+        } else {
+            MAX_SQRT_RATIO - 1
+        };
+
+        let mut amount_remaining = U256::from(amount);
+        let mut amount_calculated = U256::zero();
+        let mut sqrt_price = pool.sqrt_price;
+        let mut tick = pool.tick;
+        let mut liquidity = pool.liquidity;
+        let mut fee_growth_global = if zero_for_one {
+            pool.fee_growth_global_0
+        } else {
+            pool.fee_growth_global_1
+        };
+        let mut crossed_ticks = Vec::new();
+
+        while !amount_remaining.is_zero() {
+            ensure!(sqrt_price != sqrt_price_limit, Error::<T>::InsufficientLiquidity);
+
+            // the next initialized tick in the direction of the swap, at or below the current
+            // tick when the price falls
+            let index = ticks.partition_point(|t| *t <= tick);
+            let next_tick = if zero_for_one {
+                index.checked_sub(1).map(|i| ticks[i]).unwrap_or(MIN_TICK)
+            } else {
+                ticks.get(index).copied().unwrap_or(MAX_TICK)
+            };
+            let next_sqrt_price = math::get_sqrt_ratio_at_tick(next_tick).ok_or(Error::<T>::Arithmetic)?;
+            let sqrt_price_target = if zero_for_one {
+                next_sqrt_price.max(sqrt_price_limit)
+            } else {
+                next_sqrt_price.min(sqrt_price_limit)
+            };
+
+            let step = math::compute_swap_step(
+                sqrt_price,
+                sqrt_price_target,
+                liquidity,
+                amount_remaining,
+                exact_in,
+                pool.fee,
+            )
+            .ok_or(Error::<T>::Arithmetic)?;
+            let sqrt_price_start = sqrt_price;
+            sqrt_price = step.sqrt_price_next;
+
+            let step_amount_in = step
+                .amount_in
+                .checked_add(step.fee_amount)
+                .ok_or(Error::<T>::Arithmetic)?;
+            let (step_amount_specified, step_amount_calculated) = if exact_in {
+                (step_amount_in, step.amount_out)
+            } else {
+                (step.amount_out, step_amount_in)
+            };
+            amount_remaining = amount_remaining
+                .checked_sub(step_amount_specified)
+                .ok_or(Error::<T>::Arithmetic)?;
+            amount_calculated = amount_calculated
+                .checked_add(step_amount_calculated)
+                .ok_or(Error::<T>::Arithmetic)?;
+
+            if !liquidity.is_zero() {
+                let fee_growth = math::fee_growth(step.fee_amount, liquidity).ok_or(Error::<T>::Arithmetic)?;
+                fee_growth_global = fee_growth_global.overflowing_add(fee_growth).0;
+            }
+
+            if sqrt_price == next_sqrt_price {
+                ensure!(
+                    crossed_ticks.len() < max_tick_crossings as usize,
+                    Error::<T>::TooManyTickCrossings
+                );
+                let liquidity_net = Self::ticks(pool_id, next_tick)
+                    .map(|info| info.liquidity_net)
+                    .unwrap_or_default();
+                let liquidity_delta = if zero_for_one {
+                    liquidity_net.checked_neg().ok_or(Error::<T>::Arithmetic)?
+                } else {
+                    liquidity_net
+                };
+                liquidity = math::add_delta(liquidity, liquidity_delta).ok_or(Error::<T>::Arithmetic)?;
+                crossed_ticks.push((next_tick, fee_growth_global));
+                tick = if zero_for_one { next_tick - 1 } else { next_tick };
+            } else if sqrt_price != sqrt_price_start {
+                tick = math::get_tick_at_sqrt_ratio(sqrt_price).ok_or(Error::<T>::Arithmetic)?;
+            }
+        }
+
+        let amount_calculated = math::to_balance(amount_calculated).ok_or(Error::<T>::Arithmetic)?;
+        let (amount_in, amount_out) = if exact_in {
+            (amount, amount_calculated)
+        } else {
+            (amount_calculated, amount)
+        };
+        Ok(SwapResult {
+            amount_in,
+            amount_out,
+            sqrt_price,
+            tick,
+            liquidity,
+            fee_growth_global,
+            crossed_ticks,
+        })
+    }
+
+    /// The input for which swapping on `pool_id` returns at least `out_amount`.
+    pub(crate) fn calculate_swap_amount_in(
+        pool_id: T::PoolId,
+        pool: &PoolOf<T>,
+        zero_for_one: bool,
+        out_amount: Balance,
+        max_tick_crossings: u32,
+    ) -> Option<Balance> {
+        let mut in_amount = Self::compute_swap(pool_id, pool, zero_for_one, out_amount, false, max_tick_crossings)
+            .ok()?
+            .amount_in;
+
+        for _ in 0..AMOUNT_IN_CORRECTIONS {
+            let quoted = Self::compute_swap(pool_id, pool, zero_for_one, in_amount, true, max_tick_crossings)
+                .ok()?
+                .amount_out;
+            if quoted >= out_amount {
+                return Some(in_amount);
+            }
+            let shortfall =
+                multiply_by_rational_with_rounding(in_amount, out_amount - quoted, quoted.max(1), Rounding::Up)?;
+            in_amount = in_amount.checked_add(shortfall.max(1))?;
+        }
+        None
+    }
+}
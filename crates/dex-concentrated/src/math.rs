@@ -0,0 +1,435 @@
+//! Fixed point math of concentrated liquidity pools, following Uniswap V3.
+//!
+//! Square root prices are Q64.96 and fee growth is Q128.128. Amounts paid to a pool are rounded
+//! up and amounts paid by it down, so that rounding never drains a pool.
+
+use crate::primitives::*;
+use sp_arithmetic::Rounding;
+use sp_core::{U256, U512};
+use sp_runtime::{ArithmeticError, PerThing, Permill};
+
+/// `1.0001^(-2^i / 2)` as Q128.128, for the bits `i` of the absolute value of a tick.
+const TICK_RATIOS: [u128; 20] = [
+    0xfffcb933bd6fad37aa2d162d1a594001,
+    0xfff97272373d413259a46990580e213a,
+    0xfff2e50f5f656932ef12357cf3c7fdcc,
+    0xffe5caca7e10e4e61c3624eaa0941cd0,
+    0xffcb9843d60f6159c9db58835c926644,
+    0xff973b41fa98c081472e6896dfb254c0,
+    0xff2ea16466c96a3843ec78b326b52861,
+    0xfe5dee046a99a2a811c461f1969c3053,
+    0xfcbe86c7900a88aedcffc83b479aa3a4,
+    0xf987a7253ac413176f2b074cf7815e54,
+    0xf3392b0822b70005940c7a398e4b70f3,
+    0xe7159475a2c29b7443b29c7fa6e889d9,
+    0xd097f3bdfd2022b8845ad8f792aa5825,
+    0xa9f746462d870fdf8a65dc1f90e061e5,
+    0x70d869a156d2a1b890bb3df62baf32f7,
+    0x31be135f97d08fd981231505542fcfa6,
+    0x9aa508b5b7a84e1c677de54f3e99bc9,
+    0x5d6af8dedb81196699c329225ee604,
+    0x2216e584f5fa1ea926041bedfe98,
+    0x48a170391f7dc42444e8fa2,
+];
+
+/// The result of swapping within a single range of constant liquidity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapStep {
+    pub sqrt_price_next: U256,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub fee_amount: U256,
+}
+
+/// `a * b / denominator` without overflow of the intermediate product.
+pub fn mul_div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Option<U256> {
+    if denominator.is_zero() {
+        return None;
+    }
+    let (mut result, remainder) = a.full_mul(b).div_mod(U512::from(denominator));
+    if matches!(rounding, Rounding::Up) && !remainder.is_zero() {
+        result += U512::one();
+    }
+    U256::try_from(result).ok()
+}
+
+fn div_rounding_up(a: U256, b: U256) -> Option<U256> {
+    if b.is_zero() {
+        return None;
+    }
+    let (result, remainder) = a.div_mod(b);
+    if remainder.is_zero() {
+        Some(result)
+    } else {
+        result.checked_add(U256::one())
+    }
+}
+
+pub fn to_balance(value: U256) -> Option<Balance> {
+    if value > U256::from(Balance::MAX) {
+        None
+    } else {
+        Some(value.low_u128())
+    }
+}
+
+/// `liquidity + delta`, or `None` on overflow or underflow.
+pub fn add_delta(liquidity: u128, delta: i128) -> Option<u128> {
+    if delta < 0 {
+        liquidity.checked_sub(delta.unsigned_abs())
+    } else {
+        liquidity.checked_add(delta as u128)
+    }
+}
+
+/// The square root price of `tick` as Q64.96.
+pub fn get_sqrt_ratio_at_tick(tick: i32) -> Option<U256> {
+    let abs_tick = tick.unsigned_abs();
+    if abs_tick > MAX_TICK as u32 {
+        return None;
+    }
+
+    // the product of the ratios of the set bits is 1.0001^(-|tick| / 2) as Q128.128
+    let mut ratio = U256::one() << 128;
+    for (i, tick_ratio) in TICK_RATIOS.iter().enumerate() {
+        if abs_tick & (1 << i) != 0 {
+            ratio = ratio.checked_mul(U256::from(*tick_ratio))? >> 128;
+        }
+    }
+    if tick > 0 {
+        ratio = U256::MAX / ratio;
+    }
+
+    // to Q64.96, rounding up so that get_tick_at_sqrt_ratio is consistent
+    let remainder = ratio.low_u32();
+    Some((ratio >> 32) + if remainder == 0 { U256::zero() } else { U256::one() })
+}
+
+/// The greatest tick whose square root price is at most `sqrt_price`.
+pub fn get_tick_at_sqrt_ratio(sqrt_price: U256) -> Option<i32> {
+    if sqrt_price < MIN_SQRT_RATIO || sqrt_price >= MAX_SQRT_RATIO {
+        return None;
+    }
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if get_sqrt_ratio_at_tick(middle)? <= sqrt_price {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Some(low)
+}
+
+/// The amount of currency 0 held by `liquidity` between two square root prices.
+pub fn get_amount_0_delta(sqrt_price_a: U256, sqrt_price_b: U256, liquidity: u128, rounding: Rounding) -> Option<U256> {
+    let (lower, upper) = (sqrt_price_a.min(sqrt_price_b), sqrt_price_a.max(sqrt_price_b));
+    if lower.is_zero() {
+        return None;
+    }
+    let numerator_1 = U256::from(liquidity) << 96;
+    let numerator_2 = upper - lower;
+    match rounding {
+        Rounding::Up => div_rounding_up(mul_div(numerator_1, numerator_2, upper, Rounding::Up)?, lower),
+        _ => Some(mul_div(numerator_1, numerator_2, upper, Rounding::Down)? / lower),
+    }
+}
+
+/// The amount of currency 1 held by `liquidity` between two square root prices.
+pub fn get_amount_1_delta(sqrt_price_a: U256, sqrt_price_b: U256, liquidity: u128, rounding: Rounding) -> Option<U256> {
+    let (lower, upper) = (sqrt_price_a.min(sqrt_price_b), sqrt_price_a.max(sqrt_price_b));
+    mul_div(U256::from(liquidity), upper - lower, Q96, rounding)
+}
+
+/// The square root price after adding or removing `amount` of currency 0, rounded up so that
+/// the price moves no further than the amount pays for.
+fn get_next_sqrt_price_from_amount_0_rounding_up(
+    sqrt_price: U256,
+    liquidity: u128,
+    amount: U256,
+    add: bool,
+) -> Option<U256> {
+    if amount.is_zero() {
+        return Some(sqrt_price);
+    }
+    let numerator_1 = U256::from(liquidity) << 96;
+    if add {
+        if let Some(denominator) = amount
+            .checked_mul(sqrt_price)
+            .and_then(|product| numerator_1.checked_add(product))
+        {
+            return mul_div(numerator_1, sqrt_price, denominator, Rounding::Up);
+        }
+        div_rounding_up(numerator_1, (numerator_1 / sqrt_price).checked_add(amount)?)
+    } else {
+        let product = amount.checked_mul(sqrt_price)?;
+        let denominator = numerator_1.checked_sub(product).filter(|d| !d.is_zero())?;
+        mul_div(numerator_1, sqrt_price, denominator, Rounding::Up)
+    }
+}
+
+/// The square root price after adding or removing `amount` of currency 1, rounded down so that
+/// the price moves no further than the amount pays for.
+fn get_next_sqrt_price_from_amount_1_rounding_down(
+    sqrt_price: U256,
+    liquidity: u128,
+    amount: U256,
+    add: bool,
+) -> Option<U256> {
+    if add {
+        let quotient = mul_div(amount, Q96, U256::from(liquidity), Rounding::Down)?;
+        sqrt_price.checked_add(quotient)
+    } else {
+        let quotient = mul_div(amount, Q96, U256::from(liquidity), Rounding::Up)?;
+        sqrt_price.checked_sub(quotient).filter(|p| !p.is_zero())
+    }
+}
+
+fn get_next_sqrt_price_from_input(
+    sqrt_price: U256,
+    liquidity: u128,
+    amount_in: U256,
+    zero_for_one: bool,
+) -> Option<U256> {
+    if sqrt_price.is_zero() || liquidity == 0 {
+        return None;
+    }
+    if zero_for_one {
+        get_next_sqrt_price_from_amount_0_rounding_up(sqrt_price, liquidity, amount_in, true)
+    } else {
+        get_next_sqrt_price_from_amount_1_rounding_down(sqrt_price, liquidity, amount_in, true)
+    }
+}
+
+fn get_next_sqrt_price_from_output(
+    sqrt_price: U256,
+    liquidity: u128,
+    amount_out: U256,
+    zero_for_one: bool,
+) -> Option<U256> {
+    if sqrt_price.is_zero() || liquidity == 0 {
+        return None;
+    }
+    if zero_for_one {
+        get_next_sqrt_price_from_amount_1_rounding_down(sqrt_price, liquidity, amount_out, false)
+    } else {
+        get_next_sqrt_price_from_amount_0_rounding_up(sqrt_price, liquidity, amount_out, false)
+    }
+}
+
+/// Swaps `amount_remaining` of input, or for `amount_remaining` of output if not `exact_in`,
+/// moving the price from `sqrt_price_current` at most to `sqrt_price_target`. The fee is
+/// charged on the input and not included in `amount_in`.
+pub fn compute_swap_step(
+    sqrt_price_current: U256,
+    sqrt_price_target: U256,
+    liquidity: u128,
+    amount_remaining: U256,
+    exact_in: bool,
+    fee: Permill,
+) -> Option<SwapStep> {
+    let zero_for_one = sqrt_price_current >= sqrt_price_target;
+    let fee_pips = U256::from(fee.deconstruct());
+    let fee_denominator = U256::from(Permill::ACCURACY);
+
+    let mut amount_in = U256::zero();
+    let mut amount_out = U256::zero();
+    let sqrt_price_next = if exact_in {
+        let amount_remaining_less_fee = mul_div(
+            amount_remaining,
+            fee_denominator - fee_pips,
+            fee_denominator,
+            Rounding::Down,
+        )?;
+        amount_in = if zero_for_one {
+            get_amount_0_delta(sqrt_price_target, sqrt_price_current, liquidity, Rounding::Up)?
+        } else {
+            get_amount_1_delta(sqrt_price_current, sqrt_price_target, liquidity, Rounding::Up)?
+        };
+        if amount_remaining_less_fee >= amount_in {
+            sqrt_price_target
+        } else {
+            get_next_sqrt_price_from_input(sqrt_price_current, liquidity, amount_remaining_less_fee, zero_for_one)?
+        }
+    } else {
+        amount_out = if zero_for_one {
+            get_amount_1_delta(sqrt_price_target, sqrt_price_current, liquidity, Rounding::Down)?
+        } else {
+            get_amount_0_delta(sqrt_price_current, sqrt_price_target, liquidity, Rounding::Down)?
+        };
+        if amount_remaining >= amount_out {
+            sqrt_price_target
+        } else {
+            get_next_sqrt_price_from_output(sqrt_price_current, liquidity, amount_remaining, zero_for_one)?
+        }
+    };
+
+    // the amounts to the target are already known, the others depend on the price reached
+    let reached_target = sqrt_price_next == sqrt_price_target;
+    if !(reached_target && exact_in) {
+        amount_in = if zero_for_one {
+            get_amount_0_delta(sqrt_price_next, sqrt_price_current, liquidity, Rounding::Up)?
+        } else {
+            get_amount_1_delta(sqrt_price_current, sqrt_price_next, liquidity, Rounding::Up)?
+        };
+    }
+    if !(reached_target && !exact_in) {
+        amount_out = if zero_for_one {
+            get_amount_1_delta(sqrt_price_next, sqrt_price_current, liquidity, Rounding::Down)?
+        } else {
+            get_amount_0_delta(sqrt_price_current, sqrt_price_next, liquidity, Rounding::Down)?
+        };
+    }
+    if !exact_in {
+        amount_out = amount_out.min(amount_remaining);
+    }
+
+    // if the input is used up before the target, the pool keeps what is left of it
+    let fee_amount = if exact_in && !reached_target {
+        amount_remaining.checked_sub(amount_in)?
+    } else {
+        mul_div(amount_in, fee_pips, fee_denominator - fee_pips, Rounding::Up)?
+    };
+
+    Some(SwapStep {
+        sqrt_price_next,
+        amount_in,
+        amount_out,
+        fee_amount,
+    })
+}
+
+fn get_liquidity_for_amount_0(sqrt_price_lower: U256, sqrt_price_upper: U256, amount_0: Balance) -> Option<U256> {
+    let intermediate = mul_div(sqrt_price_lower, sqrt_price_upper, Q96, Rounding::Down)?;
+    mul_div(
+        U256::from(amount_0),
+        intermediate,
+        sqrt_price_upper - sqrt_price_lower,
+        Rounding::Down,
+    )
+}
+
+fn get_liquidity_for_amount_1(sqrt_price_lower: U256, sqrt_price_upper: U256, amount_1: Balance) -> Option<U256> {
+    mul_div(
+        U256::from(amount_1),
+        Q96,
+        sqrt_price_upper - sqrt_price_lower,
+        Rounding::Down,
+    )
+}
+
+/// The largest liquidity of a range which holds at most `amount_0` and `amount_1` at the
+/// current price.
+pub fn get_liquidity_for_amounts(
+    sqrt_price: U256,
+    sqrt_price_lower: U256,
+    sqrt_price_upper: U256,
+    amount_0: Balance,
+    amount_1: Balance,
+) -> Option<u128> {
+    if sqrt_price_lower >= sqrt_price_upper {
+        return None;
+    }
+    let liquidity = if sqrt_price <= sqrt_price_lower {
+        get_liquidity_for_amount_0(sqrt_price_lower, sqrt_price_upper, amount_0)?
+    } else if sqrt_price < sqrt_price_upper {
+        get_liquidity_for_amount_0(sqrt_price, sqrt_price_upper, amount_0)?.min(get_liquidity_for_amount_1(
+            sqrt_price_lower,
+            sqrt_price,
+            amount_1,
+        )?)
+    } else {
+        get_liquidity_for_amount_1(sqrt_price_lower, sqrt_price_upper, amount_1)?
+    };
+    to_balance(liquidity)
+}
+
+/// The amounts held by `liquidity` in `[tick_lower, tick_upper)` at the current price.
+pub fn get_amounts_for_liquidity(
+    sqrt_price: U256,
+    tick: i32,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+    rounding: Rounding,
+) -> Option<(Balance, Balance)> {
+    let sqrt_price_lower = get_sqrt_ratio_at_tick(tick_lower)?;
+    let sqrt_price_upper = get_sqrt_ratio_at_tick(tick_upper)?;
+    let (amount_0, amount_1) = if tick < tick_lower {
+        (
+            get_amount_0_delta(sqrt_price_lower, sqrt_price_upper, liquidity, rounding)?,
+            U256::zero(),
+        )
+    } else if tick < tick_upper {
+        (
+            get_amount_0_delta(sqrt_price, sqrt_price_upper, liquidity, rounding)?,
+            get_amount_1_delta(sqrt_price_lower, sqrt_price, liquidity, rounding)?,
+        )
+    } else {
+        (
+            U256::zero(),
+            get_amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, rounding)?,
+        )
+    };
+    Some((to_balance(amount_0)?, to_balance(amount_1)?))
+}
+
+/// The liquidity a single tick can hold, such that the liquidity of all ticks fits in a `u128`.
+pub fn max_liquidity_per_tick(tick_spacing: u32) -> u128 {
+    let tick_spacing = tick_spacing.max(1) as i32;
+    let min_tick = MIN_TICK / tick_spacing * tick_spacing;
+    let max_tick = MAX_TICK / tick_spacing * tick_spacing;
+    let num_ticks = ((max_tick - min_tick) / tick_spacing) as u128 + 1;
+    u128::MAX / num_ticks
+}
+
+/// The fee growth per unit of liquidity of `fee_amount` earned by `liquidity`.
+pub fn fee_growth(fee_amount: U256, liquidity: u128) -> Option<U256> {
+    mul_div(fee_amount, Q128, U256::from(liquidity), Rounding::Down)
+}
+
+/// The fee growth between two ticks, from the fee growth of the pool and outside of the ticks.
+pub fn fee_growth_inside(
+    fee_growth_global: U256,
+    tick: i32,
+    tick_lower: i32,
+    fee_growth_outside_lower: U256,
+    tick_upper: i32,
+    fee_growth_outside_upper: U256,
+) -> U256 {
+    let fee_growth_below = if tick >= tick_lower {
+        fee_growth_outside_lower
+    } else {
+        fee_growth_global.overflowing_sub(fee_growth_outside_lower).0
+    };
+    let fee_growth_above = if tick < tick_upper {
+        fee_growth_outside_upper
+    } else {
+        fee_growth_global.overflowing_sub(fee_growth_outside_upper).0
+    };
+    fee_growth_global
+        .overflowing_sub(fee_growth_below)
+        .0
+        .overflowing_sub(fee_growth_above)
+        .0
+}
+
+/// The fees earned by `liquidity` since the fee growth inside its range was `fee_growth_inside_last`.
+/// The fee growth is allowed to wrap around, so only its delta is meaningful.
+pub fn fees_owed(
+    fee_growth_inside: U256,
+    fee_growth_inside_last: U256,
+    liquidity: u128,
+) -> Result<Balance, ArithmeticError> {
+    let fee_growth_delta = fee_growth_inside.overflowing_sub(fee_growth_inside_last).0;
+    mul_div(fee_growth_delta, U256::from(liquidity), Q128, Rounding::Down)
+        .and_then(to_balance)
+        .ok_or(ArithmeticError::Overflow)
+}
+
+/// The price of currency 0 in currency 1 with a precision of `ONE`.
+pub fn spot_price(sqrt_price: U256) -> Option<Balance> {
+    let price = mul_div(sqrt_price, sqrt_price, Q96, Rounding::Down)?;
+    to_balance(mul_div(price, U256::from(ONE), Q96, Rounding::Down)?)
+}
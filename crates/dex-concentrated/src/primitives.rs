@@ -0,0 +1,81 @@
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::*;
+use sp_core::U256;
+use sp_runtime::Permill;
+
+pub type Balance = u128;
+
+/// Identifies a position among the positions of its owner.
+pub type PositionId = u32;
+
+/// The precision of the spot prices reported by the pallet.
+pub const ONE: Balance = 1_000_000_000_000_000_000;
+
+/// The price at tick `i` is `1.0001^i`, ticks beyond these bounds overflow the square root price.
+pub const MIN_TICK: i32 = -887272;
+pub const MAX_TICK: i32 = -MIN_TICK;
+
+/// The square root price of `MIN_TICK` as Q64.96.
+pub const MIN_SQRT_RATIO: U256 = U256([4295128739, 0, 0, 0]);
+/// The square root price of `MAX_TICK` as Q64.96.
+pub const MAX_SQRT_RATIO: U256 = U256([0x5d951d5263988d26, 0xefd1fc6a50648849, 0xfffd8963, 0]);
+
+pub const Q96: U256 = U256([0, 1 << 32, 0, 0]);
+pub const Q128: U256 = U256([0, 0, 1, 0]);
+
+pub const MAX_TICK_SPACING: u32 = 16384;
+pub const MAX_SWAP_FEE: Permill = Permill::from_percent(10);
+
+/// Swaps through `ConcentratedAmmApi` may cross at most this many initialized ticks, which bounds
+/// the weight the router charges for them.
+pub const MAX_TICK_CROSSINGS: u32 = 32;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ConcentratedPool<CurrencyId, AccountId> {
+    // the currencies are sorted, prices are those of currency_0 in currency_1
+    pub currency_0: CurrencyId,
+    pub currency_1: CurrencyId,
+    // charged on the input of swaps and paid to the liquidity in range
+    pub fee: Permill,
+    // positions can only be bounded by multiples of the tick spacing
+    pub tick_spacing: u32,
+    // the square root of the current price as Q64.96
+    pub sqrt_price: U256,
+    // the greatest tick whose square root price is not above `sqrt_price`
+    pub tick: i32,
+    // the liquidity of the positions whose range contains the current tick
+    pub liquidity: u128,
+    // the fees earned per unit of liquidity over the life of the pool as Q128.128, wrapping on overflow
+    pub fee_growth_global_0: U256,
+    pub fee_growth_global_1: U256,
+    // the pool's account
+    pub account: AccountId,
+}
+
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TickInfo {
+    // the liquidity of all positions bounded by the tick
+    pub liquidity_gross: u128,
+    // the liquidity added to the pool when the price crosses the tick upwards
+    pub liquidity_net: i128,
+    // the fee growth on the other side of the tick than the current price
+    pub fee_growth_outside_0: U256,
+    pub fee_growth_outside_1: U256,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position<PoolId> {
+    pub pool_id: PoolId,
+    // the liquidity is active while the current tick is in [tick_lower, tick_upper)
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    // the fee growth inside the range when the fees owed were last updated
+    pub fee_growth_inside_0_last: U256,
+    pub fee_growth_inside_1_last: U256,
+    // fees earned and liquidity removed, which are left to collect
+    pub tokens_owed_0: Balance,
+    pub tokens_owed_1: Balance,
+    // reserved from the owner until the position is closed
+    pub deposit: Balance,
+}
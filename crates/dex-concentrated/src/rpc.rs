@@ -0,0 +1,100 @@
+use super::*;
+use sp_std::vec;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_currencies(pool_id: T::PoolId) -> Vec<T::CurrencyId> {
+        Self::pools(pool_id)
+            .map(|pool| vec![pool.currency_0, pool.currency_1])
+            .unwrap_or_default()
+    }
+
+    pub fn get_swap_fee(pool_id: T::PoolId) -> Permill {
+        Self::pools(pool_id).map(|pool| pool.fee).unwrap_or_default()
+    }
+
+    pub fn get_tick(pool_id: T::PoolId) -> Option<i32> {
+        Self::pools(pool_id).map(|pool| pool.tick)
+    }
+
+    /// The liquidity of the positions whose range contains the current price.
+    pub fn get_liquidity(pool_id: T::PoolId) -> Balance {
+        Self::pools(pool_id).map(|pool| pool.liquidity).unwrap_or_default()
+    }
+
+    /// The price of currency 0 in currency 1, with a precision of `ONE` and excluding the swap fee.
+    pub fn get_spot_price(pool_id: T::PoolId) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        math::spot_price(pool.sqrt_price)
+    }
+
+    pub fn get_positions(who: T::AccountId) -> Vec<PositionId> {
+        Positions::<T>::iter_key_prefix(who).collect()
+    }
+
+    /// The amounts of currency 0 and 1 the owner of a position gets by removing all its
+    /// liquidity and collecting, including the fees earned so far.
+    pub fn calculate_position_amounts(who: T::AccountId, position_id: PositionId) -> Option<Vec<Balance>> {
+        let position = Self::positions(&who, position_id)?;
+        let pool = Self::pools(position.pool_id)?;
+
+        let (amount_0, amount_1) = math::get_amounts_for_liquidity(
+            pool.sqrt_price,
+            pool.tick,
+            position.tick_lower,
+            position.tick_upper,
+            position.liquidity,
+            Rounding::Down,
+        )?;
+        let (fee_growth_inside_0, fee_growth_inside_1) = Self::fee_growth_inside(position.pool_id, &pool, &position);
+        let fees_0 = math::fees_owed(
+            fee_growth_inside_0,
+            position.fee_growth_inside_0_last,
+            position.liquidity,
+        )
+        .ok()?;
+        let fees_1 = math::fees_owed(
+            fee_growth_inside_1,
+            position.fee_growth_inside_1_last,
+            position.liquidity,
+        )
+        .ok()?;
+
+        Some(vec![
+            amount_0.saturating_add(fees_0).saturating_add(position.tokens_owed_0),
+            amount_1.saturating_add(fees_1).saturating_add(position.tokens_owed_1),
+        ])
+    }
+
+    pub fn calculate_swap(pool_id: T::PoolId, in_currency: T::CurrencyId, in_amount: Balance) -> Option<Balance> {
+        use crate::traits::ConcentratedAmmApi;
+        Self::quote_swap(pool_id, in_currency, in_amount)
+    }
+
+    pub fn calculate_swap_for_exact(
+        pool_id: T::PoolId,
+        in_currency: T::CurrencyId,
+        out_amount: Balance,
+    ) -> Option<Balance> {
+        use crate::traits::ConcentratedAmmApi;
+        Self::quote_swap_for_exact(pool_id, in_currency, out_amount)
+    }
+
+    /// The liquidity a new position over `[tick_lower, tick_upper)` gets for at most `amount_0`
+    /// and `amount_1`.
+    pub fn calculate_add_liquidity(
+        pool_id: T::PoolId,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0: Balance,
+        amount_1: Balance,
+    ) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        math::get_liquidity_for_amounts(
+            pool.sqrt_price,
+            math::get_sqrt_ratio_at_tick(tick_lower)?,
+            math::get_sqrt_ratio_at_tick(tick_upper)?,
+            amount_0,
+            amount_1,
+        )
+    }
+}
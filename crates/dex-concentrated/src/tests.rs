@@ -0,0 +1,627 @@
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError::BadOrigin;
+
+use super::{
+    mock::{CurrencyId::*, *},
+    traits::ConcentratedAmmApi,
+    *,
+};
+
+const SWAP_FEE: Permill = Permill::from_parts(3_000);
+const TICK_SPACING: u32 = 10;
+
+/// The liquidity of 100 token1 and 100 token2 over `[-1000, 1000)` at a price of one.
+const LIQUIDITY: u128 = 2_050_516_626_810_664_607_825;
+
+/// A pool of token1 and token2 at a price of one, with a position of ALICE over `[-1000, 1000)`
+/// holding 100 of each.
+fn setup_test_pool() -> PoolId {
+    let pool_id = ConcentratedAmm::next_pool_id();
+    assert_ok!(ConcentratedAmm::create_pool(
+        RawOrigin::Root.into(),
+        Token(TOKEN1_SYMBOL),
+        Token(TOKEN2_SYMBOL),
+        SWAP_FEE,
+        TICK_SPACING,
+        0,
+    ));
+    assert_ok!(ConcentratedAmm::create_position(
+        RawOrigin::Signed(ALICE).into(),
+        pool_id,
+        -1000,
+        1000,
+        100 * UNIT,
+        100 * UNIT,
+        0,
+        0,
+        u64::MAX,
+    ));
+    pool_id
+}
+
+#[test]
+fn create_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ConcentratedAmm::create_pool(
+            RawOrigin::Root.into(),
+            Token(TOKEN1_SYMBOL),
+            Token(TOKEN2_SYMBOL),
+            SWAP_FEE,
+            TICK_SPACING,
+            1000,
+        ));
+
+        let pool = ConcentratedAmm::pools(0).unwrap();
+        assert_eq!(pool.sqrt_price, math::get_sqrt_ratio_at_tick(1000).unwrap());
+        assert_eq!(pool.tick, 1000);
+        assert_eq!(pool.liquidity, 0);
+        assert_eq!(ConcentratedAmm::next_pool_id(), 1);
+        System::assert_last_event(RuntimeEvent::ConcentratedAMM(Event::CreatePool {
+            pool_id: 0,
+            currency_0: Token(TOKEN1_SYMBOL),
+            currency_1: Token(TOKEN2_SYMBOL),
+            swap_fee: SWAP_FEE,
+            tick_spacing: TICK_SPACING,
+            tick: 1000,
+            account: pool.account,
+        }));
+    });
+}
+
+#[test]
+fn create_pool_with_incorrect_parameter_should_not_work() {
+    new_test_ext().execute_with(|| {
+        let create_pool = |origin: RuntimeOrigin, currency_0, currency_1, fee, tick_spacing, tick| {
+            ConcentratedAmm::create_pool(origin, currency_0, currency_1, fee, tick_spacing, tick)
+        };
+        let (token1, token2) = (Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL));
+
+        assert_noop!(
+            create_pool(RawOrigin::Signed(ALICE).into(), token1, token2, SWAP_FEE, 10, 0),
+            BadOrigin
+        );
+        assert_noop!(
+            create_pool(RawOrigin::Root.into(), token2, token1, SWAP_FEE, 10, 0),
+            Error::<Test>::InvalidPooledCurrency
+        );
+        assert_noop!(
+            create_pool(RawOrigin::Root.into(), token1, token1, SWAP_FEE, 10, 0),
+            Error::<Test>::InvalidPooledCurrency
+        );
+        assert_noop!(
+            create_pool(RawOrigin::Root.into(), token1, token2, Permill::from_percent(11), 10, 0),
+            Error::<Test>::ExceedMaxFee
+        );
+        assert_noop!(
+            create_pool(RawOrigin::Root.into(), token1, token2, SWAP_FEE, 0, 0),
+            Error::<Test>::InvalidTickSpacing
+        );
+        assert_noop!(
+            create_pool(
+                RawOrigin::Root.into(),
+                token1,
+                token2,
+                SWAP_FEE,
+                MAX_TICK_SPACING + 1,
+                0
+            ),
+            Error::<Test>::InvalidTickSpacing
+        );
+        assert_noop!(
+            create_pool(RawOrigin::Root.into(), token1, token2, SWAP_FEE, 10, MAX_TICK),
+            Error::<Test>::InvalidTick
+        );
+    });
+}
+
+#[test]
+fn create_position_should_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        let pool = ConcentratedAmm::pools(pool_id).unwrap();
+
+        let position = ConcentratedAmm::positions(ALICE, 0).unwrap();
+        assert_eq!(position.liquidity, LIQUIDITY);
+        assert_eq!((position.tick_lower, position.tick_upper), (-1000, 1000));
+        assert_eq!(pool.liquidity, LIQUIDITY);
+        assert_eq!(ConcentratedAmm::initialized_ticks(pool_id).to_vec(), vec![-1000, 1000]);
+        assert_eq!(
+            ConcentratedAmm::ticks(pool_id, -1000).unwrap().liquidity_net,
+            LIQUIDITY as i128
+        );
+        assert_eq!(
+            ConcentratedAmm::ticks(pool_id, 1000).unwrap().liquidity_net,
+            -(LIQUIDITY as i128)
+        );
+
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &pool.account), 100 * UNIT);
+        assert_eq!(get_user_balance(Token(TOKEN2_SYMBOL), &pool.account), 100 * UNIT);
+        assert_eq!(ConcentratedAmm::next_position_id(ALICE), 1);
+        assert_eq!(ConcentratedAmm::get_positions(ALICE), vec![0]);
+        assert_eq!(ConcentratedAmm::get_spot_price(pool_id), Some(ONE));
+        System::assert_last_event(RuntimeEvent::ConcentratedAMM(Event::AddLiquidity {
+            who: ALICE,
+            position_id: 0,
+            pool_id,
+            liquidity: LIQUIDITY,
+            amount_0: 100 * UNIT,
+            amount_1: 100 * UNIT,
+        }));
+
+        // a range above the price only holds currency 0
+        assert_ok!(ConcentratedAmm::create_position(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            1000,
+            2000,
+            100 * UNIT,
+            100 * UNIT,
+            0,
+            0,
+            u64::MAX,
+        ));
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &BOB), 900 * UNIT);
+        assert_eq!(get_user_balance(Token(TOKEN2_SYMBOL), &BOB), 1000 * UNIT);
+        assert_eq!(ConcentratedAmm::pools(pool_id).unwrap().liquidity, LIQUIDITY);
+        assert_eq!(
+            ConcentratedAmm::initialized_ticks(pool_id).to_vec(),
+            vec![-1000, 1000, 2000]
+        );
+    });
+}
+
+#[test]
+fn create_position_with_incorrect_parameter_should_not_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        let create_position = |tick_lower, tick_upper| {
+            ConcentratedAmm::create_position(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                tick_lower,
+                tick_upper,
+                UNIT,
+                UNIT,
+                0,
+                0,
+                u64::MAX,
+            )
+        };
+
+        assert_noop!(create_position(1000, 1000), Error::<Test>::InvalidTick);
+        assert_noop!(create_position(1000, -1000), Error::<Test>::InvalidTick);
+        assert_noop!(create_position(-1005, 1000), Error::<Test>::InvalidTick);
+        assert_noop!(create_position(MIN_TICK - 8, 0), Error::<Test>::InvalidTick);
+        assert_noop!(
+            ConcentratedAmm::create_position(
+                RawOrigin::Signed(BOB).into(),
+                pool_id + 1,
+                -1000,
+                1000,
+                UNIT,
+                UNIT,
+                0,
+                0,
+                u64::MAX,
+            ),
+            Error::<Test>::InvalidPoolId
+        );
+        assert_noop!(
+            ConcentratedAmm::create_position(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                -1000,
+                1000,
+                UNIT,
+                UNIT,
+                UNIT + 1,
+                0,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_noop!(
+            ConcentratedAmm::create_position(RawOrigin::Signed(BOB).into(), pool_id, -1000, 1000, UNIT, UNIT, 0, 0, 1,),
+            Error::<Test>::Deadline
+        );
+    });
+}
+
+#[test]
+fn remove_liquidity_and_collect_should_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        let pool = ConcentratedAmm::pools(pool_id).unwrap();
+
+        assert_ok!(ConcentratedAmm::add_liquidity(
+            RawOrigin::Signed(ALICE).into(),
+            0,
+            100 * UNIT,
+            100 * UNIT,
+            0,
+            0,
+            u64::MAX,
+        ));
+        assert_eq!(ConcentratedAmm::positions(ALICE, 0).unwrap().liquidity, 2 * LIQUIDITY);
+
+        assert_noop!(
+            ConcentratedAmm::remove_liquidity(RawOrigin::Signed(ALICE).into(), 0, 2 * LIQUIDITY + 1, 0, 0, u64::MAX),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_noop!(
+            ConcentratedAmm::remove_liquidity(RawOrigin::Signed(BOB).into(), 0, LIQUIDITY, 0, 0, u64::MAX),
+            Error::<Test>::InvalidPositionId
+        );
+
+        assert_ok!(ConcentratedAmm::remove_liquidity(
+            RawOrigin::Signed(ALICE).into(),
+            0,
+            2 * LIQUIDITY,
+            0,
+            0,
+            u64::MAX,
+        ));
+        let position = ConcentratedAmm::positions(ALICE, 0).unwrap();
+        assert_eq!(position.liquidity, 0);
+        assert_eq!(position.tokens_owed_0, 199_999_999_999_999_999_999);
+        assert_eq!(position.tokens_owed_1, 199_999_999_999_999_999_999);
+        assert_eq!(ConcentratedAmm::pools(pool_id).unwrap().liquidity, 0);
+        assert!(ConcentratedAmm::initialized_ticks(pool_id).is_empty());
+        assert_eq!(ConcentratedAmm::ticks(pool_id, -1000), None);
+
+        // the removed liquidity stays in the pool until it is collected
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &pool.account), 200 * UNIT);
+        assert_ok!(ConcentratedAmm::collect(RawOrigin::Signed(ALICE).into(), 0, BOB));
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &pool.account), 1);
+        assert_eq!(
+            get_user_balance(Token(TOKEN2_SYMBOL), &BOB),
+            1000 * UNIT + 199_999_999_999_999_999_999
+        );
+        assert_eq!(ConcentratedAmm::positions(ALICE, 0), None);
+        assert!(ConcentratedAmm::get_positions(ALICE).is_empty());
+        System::assert_last_event(RuntimeEvent::ConcentratedAMM(Event::ClosePosition {
+            who: ALICE,
+            position_id: 0,
+        }));
+    });
+}
+
+#[test]
+fn positions_should_reserve_a_deposit_until_closed() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        let reserved = |who| <Test as Config>::MultiCurrency::reserved_balance(Token(TOKEN3_SYMBOL), &who);
+
+        assert_eq!(
+            ConcentratedAmm::positions(ALICE, 0).unwrap().deposit,
+            PositionDeposit::get()
+        );
+        assert_eq!(reserved(ALICE), PositionDeposit::get());
+
+        assert_ok!(ConcentratedAmm::remove_liquidity(
+            RawOrigin::Signed(ALICE).into(),
+            0,
+            LIQUIDITY,
+            0,
+            0,
+            u64::MAX,
+        ));
+        assert_eq!(reserved(ALICE), PositionDeposit::get());
+        assert_ok!(ConcentratedAmm::collect(RawOrigin::Signed(ALICE).into(), 0, ALICE));
+        assert_eq!(reserved(ALICE), 0);
+
+        assert_ok!(<Test as Config>::MultiCurrency::withdraw(
+            Token(TOKEN3_SYMBOL),
+            &BOB,
+            1000 * UNIT
+        ));
+        assert_noop!(
+            ConcentratedAmm::create_position(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                -1000,
+                1000,
+                UNIT,
+                UNIT,
+                0,
+                0,
+                u64::MAX,
+            ),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+#[test]
+fn swap_should_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        let pool = ConcentratedAmm::pools(pool_id).unwrap();
+        let expected_out = 996_515_475_311_979_617;
+
+        assert_eq!(
+            ConcentratedAmm::quote_swap(pool_id, Token(TOKEN1_SYMBOL), UNIT),
+            Some(expected_out)
+        );
+        assert_noop!(
+            ConcentratedAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                Token(TOKEN1_SYMBOL),
+                UNIT,
+                expected_out + 1,
+                BOB,
+                MAX_TICK_CROSSINGS,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_noop!(
+            ConcentratedAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                Token(TOKEN3_SYMBOL),
+                UNIT,
+                0,
+                BOB,
+                MAX_TICK_CROSSINGS,
+                u64::MAX,
+            ),
+            Error::<Test>::CurrencyNotInPool
+        );
+
+        assert_ok!(ConcentratedAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            Token(TOKEN1_SYMBOL),
+            UNIT,
+            expected_out,
+            BOB,
+            MAX_TICK_CROSSINGS,
+            u64::MAX,
+        ));
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &BOB), 999 * UNIT);
+        assert_eq!(get_user_balance(Token(TOKEN2_SYMBOL), &BOB), 1000 * UNIT + expected_out);
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &pool.account), 101 * UNIT);
+        assert_eq!(ConcentratedAmm::get_tick(pool_id), Some(-10));
+        System::assert_last_event(RuntimeEvent::ConcentratedAMM(Event::CurrencyExchange {
+            pool_id,
+            who: BOB,
+            to: BOB,
+            in_currency: Token(TOKEN1_SYMBOL),
+            in_amount: UNIT,
+            out_currency: Token(TOKEN2_SYMBOL),
+            out_amount: expected_out,
+            tick: -10,
+        }));
+    });
+}
+
+#[test]
+fn swap_fees_should_be_collected_by_positions_in_range() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        assert_ok!(ConcentratedAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            Token(TOKEN1_SYMBOL),
+            UNIT,
+            0,
+            BOB,
+            MAX_TICK_CROSSINGS,
+            u64::MAX,
+        ));
+
+        // 0.3% of the input, less rounding
+        let fees = 2_999_999_999_999_999;
+        assert_eq!(
+            ConcentratedAmm::calculate_position_amounts(ALICE, 0),
+            Some(vec![100_996_999_999_999_999_999 + fees, 99_003_484_524_688_020_382])
+        );
+
+        let balance = get_user_balance(Token(TOKEN1_SYMBOL), &ALICE);
+        assert_ok!(ConcentratedAmm::collect(RawOrigin::Signed(ALICE).into(), 0, ALICE));
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &ALICE), balance + fees);
+        System::assert_last_event(RuntimeEvent::ConcentratedAMM(Event::Collect {
+            who: ALICE,
+            position_id: 0,
+            pool_id,
+            to: ALICE,
+            amount_0: fees,
+            amount_1: 0,
+        }));
+
+        // the position stays open and owes nothing more until the next swap
+        let position = ConcentratedAmm::positions(ALICE, 0).unwrap();
+        assert_eq!((position.tokens_owed_0, position.tokens_owed_1), (0, 0));
+        assert_ok!(ConcentratedAmm::collect(RawOrigin::Signed(ALICE).into(), 0, ALICE));
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &ALICE), balance + fees);
+    });
+}
+
+#[test]
+fn swap_across_ticks_should_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        assert_ok!(ConcentratedAmm::create_position(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            1000,
+            2000,
+            100 * UNIT,
+            0,
+            0,
+            0,
+            u64::MAX,
+        ));
+        let upper_liquidity = 2_155_643_473_648_341_266_890;
+        let expected_out = 139_423_127_248_691_381_349;
+
+        assert_eq!(
+            ConcentratedAmm::quote_swap(pool_id, Token(TOKEN2_SYMBOL), 150 * UNIT),
+            Some(expected_out)
+        );
+        assert_ok!(ConcentratedAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            Token(TOKEN2_SYMBOL),
+            150 * UNIT,
+            expected_out,
+            BOB,
+            MAX_TICK_CROSSINGS,
+            u64::MAX,
+        ));
+
+        // only the upper position is in range after crossing tick 1000
+        let pool = ConcentratedAmm::pools(pool_id).unwrap();
+        assert_eq!(pool.tick, 1388);
+        assert_eq!(pool.liquidity, upper_liquidity);
+
+        // each position earned the fees of the part of the swap in its range
+        assert_eq!(
+            ConcentratedAmm::calculate_position_amounts(ALICE, 0),
+            Some(vec![0, 205_126_846_837_676_659_065 + 316_329_529_100_330_970])
+        );
+        assert_eq!(
+            ConcentratedAmm::calculate_position_amounts(BOB, 0),
+            Some(vec![
+                60_576_872_751_308_618_649,
+                44_423_153_162_323_340_932 + 133_670_470_899_669_029
+            ])
+        );
+    });
+}
+
+#[test]
+fn swap_should_not_cross_more_ticks_than_its_limit() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        assert_ok!(ConcentratedAmm::create_position(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            1000,
+            2000,
+            100 * UNIT,
+            0,
+            0,
+            0,
+            u64::MAX,
+        ));
+        let swap = |max_tick_crossings| {
+            ConcentratedAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                Token(TOKEN2_SYMBOL),
+                150 * UNIT,
+                0,
+                BOB,
+                max_tick_crossings,
+                u64::MAX,
+            )
+        };
+
+        assert_noop!(swap(0), Error::<Test>::TooManyTickCrossings);
+
+        // the weight of the crossings left unused is refunded
+        let post_info = swap(10).unwrap();
+        assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::swap(1)));
+    });
+}
+
+#[test]
+fn swap_for_exact_quote_should_be_sufficient() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        assert_ok!(ConcentratedAmm::create_position(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            1000,
+            2000,
+            100 * UNIT,
+            0,
+            0,
+            0,
+            u64::MAX,
+        ));
+
+        let in_amount = ConcentratedAmm::quote_swap_for_exact(pool_id, Token(TOKEN2_SYMBOL), 150 * UNIT).unwrap();
+        assert_eq!(in_amount, 162_252_975_378_086_593_695);
+        assert_eq!(
+            ConcentratedAmm::calculate_swap_for_exact(pool_id, Token(TOKEN2_SYMBOL), 150 * UNIT),
+            Some(in_amount)
+        );
+
+        assert_ok!(ConcentratedAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            Token(TOKEN2_SYMBOL),
+            in_amount,
+            150 * UNIT,
+            BOB,
+            MAX_TICK_CROSSINGS,
+            u64::MAX,
+        ));
+    });
+}
+
+#[test]
+fn swap_beyond_liquidity_should_not_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        assert_eq!(
+            ConcentratedAmm::quote_swap(pool_id, Token(TOKEN2_SYMBOL), 1000 * UNIT),
+            None
+        );
+        assert_eq!(
+            ConcentratedAmm::quote_swap_for_exact(pool_id, Token(TOKEN2_SYMBOL), 100 * UNIT),
+            None
+        );
+        assert_noop!(
+            ConcentratedAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                Token(TOKEN2_SYMBOL),
+                1000 * UNIT,
+                0,
+                BOB,
+                MAX_TICK_CROSSINGS,
+                u64::MAX,
+            ),
+            Error::<Test>::InsufficientLiquidity
+        );
+    });
+}
+
+#[test]
+fn tick_math_should_round_trip() {
+    assert_eq!(math::get_sqrt_ratio_at_tick(MIN_TICK), Some(MIN_SQRT_RATIO));
+    assert_eq!(math::get_sqrt_ratio_at_tick(MAX_TICK), Some(MAX_SQRT_RATIO));
+    assert_eq!(math::get_sqrt_ratio_at_tick(0), Some(Q96));
+    assert_eq!(math::get_sqrt_ratio_at_tick(MAX_TICK + 1), None);
+
+    assert_eq!(math::get_tick_at_sqrt_ratio(MIN_SQRT_RATIO), Some(MIN_TICK));
+    assert_eq!(math::get_tick_at_sqrt_ratio(MAX_SQRT_RATIO - 1), Some(MAX_TICK - 1));
+    assert_eq!(math::get_tick_at_sqrt_ratio(MAX_SQRT_RATIO), None);
+    for tick in [-500_000, -1000, -1, 1, 1000, 500_000] {
+        let sqrt_price = math::get_sqrt_ratio_at_tick(tick).unwrap();
+        assert_eq!(math::get_tick_at_sqrt_ratio(sqrt_price), Some(tick));
+        assert_eq!(math::get_tick_at_sqrt_ratio(sqrt_price - 1), Some(tick - 1));
+    }
+}
+
+#[test]
+fn fees_owed_should_wrap_fee_growth() {
+    // the fee growth wrapped around since it was last recorded
+    let fee_growth_inside_last = U256::MAX - Q128 + 1;
+    assert_eq!(math::fees_owed(Q128, fee_growth_inside_last, 10), Ok(20));
+    assert_eq!(math::fees_owed(Q128, Q128, 10), Ok(0));
+
+    // fees that can't be represented as a balance are an error rather than capped
+    assert_eq!(
+        math::fees_owed(U256::MAX, U256::zero(), u128::MAX),
+        Err(sp_runtime::ArithmeticError::Overflow)
+    );
+}
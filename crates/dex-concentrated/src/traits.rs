@@ -0,0 +1,66 @@
+use super::*;
+
+pub trait ConcentratedAmmApi<PoolId, CurrencyId, AccountId, Balance> {
+    fn pool_ids() -> Vec<PoolId>;
+
+    fn pool_currencies(pool_id: PoolId) -> Vec<CurrencyId>;
+
+    /// Output of `swap`, computed exactly as the swap would.
+    fn quote_swap(pool_id: PoolId, in_currency: CurrencyId, in_amount: Balance) -> Option<Balance>;
+
+    /// The smallest input known to return at least `out_amount` from `swap`.
+    fn quote_swap_for_exact(pool_id: PoolId, in_currency: CurrencyId, out_amount: Balance) -> Option<Balance>;
+
+    fn swap(
+        who: &AccountId,
+        pool_id: PoolId,
+        in_currency: CurrencyId,
+        in_amount: Balance,
+        min_out_amount: Balance,
+        to: &AccountId,
+    ) -> Result<Balance, DispatchError>;
+}
+
+impl<T: Config> ConcentratedAmmApi<T::PoolId, T::CurrencyId, T::AccountId, Balance> for Pallet<T> {
+    fn pool_ids() -> Vec<T::PoolId> {
+        Pools::<T>::iter_keys().collect()
+    }
+
+    fn pool_currencies(pool_id: T::PoolId) -> Vec<T::CurrencyId> {
+        Self::get_currencies(pool_id)
+    }
+
+    fn quote_swap(pool_id: T::PoolId, in_currency: T::CurrencyId, in_amount: Balance) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        let zero_for_one = Self::zero_for_one(&pool, in_currency).ok()?;
+        Self::compute_swap(pool_id, &pool, zero_for_one, in_amount, true, MAX_TICK_CROSSINGS)
+            .ok()
+            .map(|result| result.amount_out)
+    }
+
+    fn quote_swap_for_exact(pool_id: T::PoolId, in_currency: T::CurrencyId, out_amount: Balance) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        let zero_for_one = Self::zero_for_one(&pool, in_currency).ok()?;
+        Self::calculate_swap_amount_in(pool_id, &pool, zero_for_one, out_amount, MAX_TICK_CROSSINGS)
+    }
+
+    fn swap(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        in_currency: T::CurrencyId,
+        in_amount: Balance,
+        min_out_amount: Balance,
+        to: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        Self::inner_swap(
+            who,
+            pool_id,
+            in_currency,
+            in_amount,
+            min_out_amount,
+            to,
+            MAX_TICK_CROSSINGS,
+        )
+        .map(|(amount_out, _)| amount_out)
+    }
+}
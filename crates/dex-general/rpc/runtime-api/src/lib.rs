@@ -6,15 +6,17 @@
 #![allow(clippy::unnecessary_mut_passed)]
 
 use codec::Codec;
-use dex_general::{AssetBalance, PairInfo};
+use dex_general::{AssetBalance, PairFeeRate, PairInfo, PriceObservation};
+use sp_runtime::FixedU128;
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-     pub trait DexGeneralApi<AccountId, AssetId>
+     pub trait DexGeneralApi<AccountId, AssetId, BlockNumber>
      where
         AccountId: Codec,
         AssetBalance: Codec,
-        AssetId: Codec
+        AssetId: Codec,
+        BlockNumber: Codec
      {
         fn get_pair_by_asset_id(
             asset_0: AssetId,
@@ -41,5 +43,24 @@ sp_api::decl_runtime_apis! {
             asset_1: AssetId,
             amount: AssetBalance,
         ) -> Option<(AssetBalance, AssetBalance)>;
+
+        fn get_price_observation(
+            asset_0: AssetId,
+            asset_1: AssetId,
+        ) -> Option<PriceObservation<BlockNumber>>;
+
+        // time-weighted average price of asset_0 in asset_1
+        fn get_twap(
+            asset_0: AssetId,
+            asset_1: AssetId,
+            start: PriceObservation<BlockNumber>,
+            end: PriceObservation<BlockNumber>,
+        ) -> Option<FixedU128>;
+
+        // fee rate in effect for the pair and the inputs of a dynamic fee
+        fn get_fee_rate(
+            asset_0: AssetId,
+            asset_1: AssetId,
+        ) -> Option<PairFeeRate>;
     }
 }
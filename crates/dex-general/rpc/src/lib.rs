@@ -15,14 +15,14 @@ use jsonrpsee::{
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_rpc::number::NumberOrHex;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{traits::Block as BlockT, FixedU128};
 use std::sync::Arc;
 
-use dex_general::{AssetBalance, PairInfo};
+use dex_general::{AssetBalance, PairFeeRate, PairInfo, PriceObservation};
 pub use dex_general_rpc_runtime_api::DexGeneralApi as DexGeneralRuntimeApi;
 
 #[rpc(client, server)]
-pub trait DexGeneralApi<BlockHash, AccountId, AssetId> {
+pub trait DexGeneralApi<BlockHash, AccountId, AssetId, BlockNumber> {
     #[method(name = "dexGeneral_getPairByAssetId")]
     fn get_pair_by_asset_id(
         &self,
@@ -67,6 +67,28 @@ pub trait DexGeneralApi<BlockHash, AccountId, AssetId> {
         amount: AssetBalance,
         at: Option<BlockHash>,
     ) -> RpcResult<Option<(AssetBalance, AssetBalance)>>;
+
+    #[method(name = "dexGeneral_getPriceObservation")]
+    fn get_price_observation(
+        &self,
+        asset_0: AssetId,
+        asset_1: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<PriceObservation<BlockNumber>>>;
+
+    #[method(name = "dexGeneral_getTwap")]
+    fn get_twap(
+        &self,
+        asset_0: AssetId,
+        asset_1: AssetId,
+        start: PriceObservation<BlockNumber>,
+        end: PriceObservation<BlockNumber>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<FixedU128>>;
+
+    #[method(name = "dexGeneral_getFeeRate")]
+    fn get_fee_rate(&self, asset_0: AssetId, asset_1: AssetId, at: Option<BlockHash>)
+        -> RpcResult<Option<PairFeeRate>>;
 }
 
 pub struct DexGeneral<C, M> {
@@ -83,16 +105,17 @@ impl<C, M> DexGeneral<C, M> {
     }
 }
 
-impl<C, Block, AccountId, AssetId> DexGeneralApiServer<<Block as BlockT>::Hash, AccountId, AssetId>
-    for DexGeneral<C, Block>
+impl<C, Block, AccountId, AssetId, BlockNumber>
+    DexGeneralApiServer<<Block as BlockT>::Hash, AccountId, AssetId, BlockNumber> for DexGeneral<C, Block>
 where
     Block: BlockT,
     AccountId: Codec,
     AssetId: Codec,
+    BlockNumber: Codec,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
-    C::Api: DexGeneralRuntimeApi<Block, AccountId, AssetId>,
+    C::Api: DexGeneralRuntimeApi<Block, AccountId, AssetId, BlockNumber>,
 {
     // buy amount asset price
     fn get_amount_in_price(
@@ -189,6 +212,47 @@ where
         api.calculate_remove_liquidity(at, asset_0, asset_1, amount)
             .map_err(runtime_error_into_rpc_err)
     }
+
+    fn get_price_observation(
+        &self,
+        asset_0: AssetId,
+        asset_1: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<PriceObservation<BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_price_observation(at, asset_0, asset_1)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_twap(
+        &self,
+        asset_0: AssetId,
+        asset_1: AssetId,
+        start: PriceObservation<BlockNumber>,
+        end: PriceObservation<BlockNumber>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<FixedU128>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_twap(at, asset_0, asset_1, start, end)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_fee_rate(
+        &self,
+        asset_0: AssetId,
+        asset_1: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<PairFeeRate>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_fee_rate(at, asset_0, asset_1)
+            .map_err(runtime_error_into_rpc_err)
+    }
 }
 
 /// Error type of this RPC api.
@@ -0,0 +1,349 @@
+// Copyright 2021-2022 Zenlink.
+// Licensed under Apache 2.0.
+
+//! # Batch Module
+//!
+//! ## Overview
+//!
+//! Swaps queued through a pair during a block are cleared together at the end of the block,
+//! at a single price for all of them. Opposite swaps are matched with each other and only the
+//! remaining imbalance is swapped against the reserves of the pair, so the order of the swaps
+//! within the block can't be used to sandwich them. Immediate swaps through a pair can still
+//! trade ahead of its batch, unless the pair is made batch only with `set_batch_only`.
+
+use super::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Upper bound for the number of times the clearing price of a batch is searched, after
+/// refunding the orders which can't be filled at the previous one. The remaining orders are
+/// refunded if they still can't all be filled after the last search.
+pub const MAX_CLEARING_ROUNDS: u32 = 4;
+
+/// Amounts sold by the orders of a batch.
+#[derive(Default)]
+struct BatchTotals {
+    amount_0_in: AssetBalance,
+    amount_1_in: AssetBalance,
+}
+
+/// The price a batch is cleared at, as the price of the asset sold on net in the other asset.
+#[derive(Clone, Copy)]
+pub(crate) struct ClearingPrice<AssetId> {
+    asset_sold: AssetId,
+    price: FixedU128,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account escrowing the input of queued swaps until the end of the block.
+    pub fn batch_account_id() -> T::AccountId {
+        // "modl" ++ "dex/genr" ++ "batch" is truncated to 16 bytes for the account id used by tests
+        T::PalletId::get().into_sub_account_truncating("batch")
+    }
+
+    pub(crate) fn do_submit_batch_swap(
+        who: T::AccountId,
+        recipient: T::AccountId,
+        asset_in: T::AssetId,
+        asset_out: T::AssetId,
+        amount_in: AssetBalance,
+        amount_out_min: AssetBalance,
+    ) -> DispatchResult {
+        let pair = Self::sort_asset_id(asset_in, asset_out);
+        ensure!(matches!(Self::pair_status(pair), Trading(_)), Error::<T>::PairNotExists);
+
+        T::MultiCurrency::transfer(asset_in, &who, &Self::batch_account_id(), amount_in)?;
+
+        BatchOrders::<T>::try_mutate(pair, |orders| {
+            orders
+                .try_push(BatchOrder {
+                    owner: who.clone(),
+                    recipient: recipient.clone(),
+                    asset_in,
+                    amount_in,
+                    amount_out_min,
+                })
+                .map_err(|_| Error::<T>::TooManyBatchOrders)
+        })?;
+
+        Self::deposit_event(Event::BatchSwapSubmitted {
+            owner: who,
+            recipient,
+            asset_in,
+            asset_out,
+            amount_in,
+            amount_out_min,
+        });
+
+        Ok(())
+    }
+
+    /// Clears the swaps queued through a pair. The orders which can't get their minimum output
+    /// at the clearing price are refunded and the price is found again for the remaining ones,
+    /// until all remaining orders can be filled or `MAX_CLEARING_ROUNDS` is reached.
+    pub(crate) fn clear_batch(pair: (T::AssetId, T::AssetId), mut orders: Vec<BatchOrder<T::AccountId, T::AssetId>>) {
+        let pair_account = Self::pair_account_id(pair.0, pair.1);
+        let reserve_0 = T::MultiCurrency::free_balance(pair.0, &pair_account);
+        let reserve_1 = T::MultiCurrency::free_balance(pair.1, &pair_account);
+        let fee_rate = Self::pair_fee_rate(pair);
+
+        let mut refunds = Vec::new();
+        let mut clearing_price = None;
+        for _ in 0..MAX_CLEARING_ROUNDS {
+            if orders.is_empty() {
+                break;
+            }
+            let (price, filled, mut unfilled) =
+                Self::batch_clearing_round(pair, orders, reserve_0, reserve_1, fee_rate);
+            orders = filled;
+            let cleared = unfilled.is_empty();
+            refunds.append(&mut unfilled);
+            if cleared {
+                clearing_price = price;
+                break;
+            }
+        }
+        match clearing_price {
+            Some(price) => {
+                let settled = frame_support::storage::with_storage_layer(|| {
+                    Self::settle_batch(pair, &orders, price, reserve_0, reserve_1, fee_rate)
+                });
+                if let Err(err) = settled {
+                    log::error!(target: "dex-general", "failed to settle batch: {:?}", err);
+                    refunds.append(&mut orders);
+                }
+            }
+            // the remaining orders were only filled at a price which changed with the refunds
+            None => refunds.append(&mut orders),
+        }
+
+        for order in refunds {
+            match T::MultiCurrency::transfer(order.asset_in, &Self::batch_account_id(), &order.owner, order.amount_in) {
+                Ok(()) => Self::deposit_event(Event::BatchSwapRefunded {
+                    owner: order.owner,
+                    asset_in: order.asset_in,
+                    amount_in: order.amount_in,
+                }),
+                Err(err) => log::error!(target: "dex-general", "failed to refund batch swap: {:?}", err),
+            }
+        }
+    }
+
+    /// Finds the clearing price of `orders` and splits them into the orders which get their
+    /// minimum output at it and the ones which don't. All orders are unfilled if there is no
+    /// clearing price.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn batch_clearing_round(
+        pair: (T::AssetId, T::AssetId),
+        orders: Vec<BatchOrder<T::AccountId, T::AssetId>>,
+        reserve_0: AssetBalance,
+        reserve_1: AssetBalance,
+        fee_rate: AssetBalance,
+    ) -> (
+        Option<ClearingPrice<T::AssetId>>,
+        Vec<BatchOrder<T::AccountId, T::AssetId>>,
+        Vec<BatchOrder<T::AccountId, T::AssetId>>,
+    ) {
+        let totals = Self::batch_totals(pair, &orders);
+        let price = match Self::batch_clearing_price(pair, &totals, reserve_0, reserve_1, fee_rate) {
+            Some(price) => price,
+            None => return (None, Vec::new(), orders),
+        };
+        let (filled, unfilled) = orders.into_iter().partition(|order| {
+            Self::batch_amount_out(order, price).map_or(false, |amount_out| amount_out >= order.amount_out_min)
+        });
+        (Some(price), filled, unfilled)
+    }
+
+    fn batch_totals(pair: (T::AssetId, T::AssetId), orders: &[BatchOrder<T::AccountId, T::AssetId>]) -> BatchTotals {
+        orders.iter().fold(BatchTotals::default(), |mut totals, order| {
+            if order.asset_in == pair.0 {
+                totals.amount_0_in = totals.amount_0_in.saturating_add(order.amount_in);
+            } else {
+                totals.amount_1_in = totals.amount_1_in.saturating_add(order.amount_in);
+            }
+            totals
+        })
+    }
+
+    /// The price clearing a batch, or `None` if the pair can't absorb the imbalance of the
+    /// batch at any price.
+    fn batch_clearing_price(
+        pair: (T::AssetId, T::AssetId),
+        totals: &BatchTotals,
+        reserve_0: AssetBalance,
+        reserve_1: AssetBalance,
+        fee_rate: AssetBalance,
+    ) -> Option<ClearingPrice<T::AssetId>> {
+        // the pair takes the other side of the asset sold for more than the reserves would pay
+        if U256::from(totals.amount_0_in).saturating_mul(U256::from(reserve_1))
+            >= U256::from(totals.amount_1_in).saturating_mul(U256::from(reserve_0))
+        {
+            let price =
+                Self::net_clearing_price(totals.amount_0_in, totals.amount_1_in, reserve_0, reserve_1, fee_rate)?;
+            Some(ClearingPrice {
+                asset_sold: pair.0,
+                price,
+            })
+        } else {
+            let price =
+                Self::net_clearing_price(totals.amount_1_in, totals.amount_0_in, reserve_1, reserve_0, fee_rate)?;
+            Some(ClearingPrice {
+                asset_sold: pair.1,
+                price,
+            })
+        }
+    }
+
+    /// The highest price of the net sold asset, in the bought asset, at which the orders selling
+    /// it can be paid by the orders selling the bought asset and the pair. The amount the pair
+    /// has to pay out grows faster with the price than the amount it receives, so all lower
+    /// prices down to the price at which both sides match exactly are feasible too.
+    fn net_clearing_price(
+        amount_sold: AssetBalance,
+        amount_bought: AssetBalance,
+        reserve_sold: AssetBalance,
+        reserve_bought: AssetBalance,
+        fee_rate: AssetBalance,
+    ) -> Option<FixedU128> {
+        let is_feasible = |price: FixedU128| {
+            let paid_sold = match Self::divide_by_price(amount_bought, price) {
+                Some(paid_sold) if paid_sold <= amount_sold => paid_sold,
+                _ => return false,
+            };
+            let pair_amount_out = price.saturating_mul_int(amount_sold).saturating_sub(amount_bought);
+            pair_amount_out.is_zero()
+                || Self::get_amount_out(amount_sold - paid_sold, reserve_sold, reserve_bought, fee_rate)
+                    .map_or(false, |amount_out| amount_out >= pair_amount_out)
+        };
+
+        let spot_price = FixedU128::checked_from_rational(reserve_bought, reserve_sold)?;
+        // rounded up, so the orders selling the bought asset aren't paid more than was sold
+        let mut low = FixedU128::checked_from_rational(amount_bought, amount_sold)?
+            .into_inner()
+            .saturating_add(1)
+            .min(spot_price.into_inner());
+        if !is_feasible(FixedU128::from_inner(low)) {
+            return None;
+        }
+        let mut high = spot_price.into_inner();
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if is_feasible(FixedU128::from_inner(mid)) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        Some(FixedU128::from_inner(low))
+    }
+
+    /// The amount an order of a batch receives at the clearing price.
+    fn batch_amount_out(
+        order: &BatchOrder<T::AccountId, T::AssetId>,
+        clearing_price: ClearingPrice<T::AssetId>,
+    ) -> Option<AssetBalance> {
+        if order.asset_in == clearing_price.asset_sold {
+            Some(clearing_price.price.saturating_mul_int(order.amount_in))
+        } else {
+            Self::divide_by_price(order.amount_in, clearing_price.price)
+        }
+    }
+
+    fn divide_by_price(amount: AssetBalance, price: FixedU128) -> Option<AssetBalance> {
+        if amount.is_zero() {
+            return Some(Zero::zero());
+        }
+        U256::from(amount)
+            .saturating_mul(U256::from(FixedU128::DIV))
+            .checked_div(U256::from(price.into_inner()))
+            .and_then(|n| TryInto::<AssetBalance>::try_into(n).ok())
+    }
+
+    /// Swaps the imbalance of a batch through the pair and pays out all orders. Rounding
+    /// leftovers of the matched amounts go to the pair.
+    fn settle_batch(
+        pair: (T::AssetId, T::AssetId),
+        orders: &[BatchOrder<T::AccountId, T::AssetId>],
+        clearing_price: ClearingPrice<T::AssetId>,
+        reserve_0: AssetBalance,
+        reserve_1: AssetBalance,
+        fee_rate: AssetBalance,
+    ) -> DispatchResult {
+        let batch_account = Self::batch_account_id();
+        let pair_account = Self::pair_account_id(pair.0, pair.1);
+
+        let mut amounts_out = Vec::with_capacity(orders.len());
+        let mut totals_out = BatchTotals::default();
+        for order in orders {
+            let amount_out = Self::batch_amount_out(order, clearing_price).ok_or(Error::<T>::Overflow)?;
+            // the output of orders selling asset_0 is paid in asset_1 and vice versa
+            if order.asset_in == pair.0 {
+                totals_out.amount_1_in = totals_out.amount_1_in.saturating_add(amount_out);
+            } else {
+                totals_out.amount_0_in = totals_out.amount_0_in.saturating_add(amount_out);
+            }
+            amounts_out.push(amount_out);
+        }
+        let totals_in = Self::batch_totals(pair, orders);
+
+        let pair_amount_0_in = totals_in.amount_0_in.saturating_sub(totals_out.amount_0_in);
+        let pair_amount_1_in = totals_in.amount_1_in.saturating_sub(totals_out.amount_1_in);
+        let pair_amount_0_out = totals_out.amount_0_in.saturating_sub(totals_in.amount_0_in);
+        let pair_amount_1_out = totals_out.amount_1_in.saturating_sub(totals_in.amount_1_in);
+
+        if !pair_amount_0_out.is_zero() {
+            let amount_out = Self::get_amount_out(pair_amount_1_in, reserve_1, reserve_0, fee_rate)?;
+            ensure!(amount_out >= pair_amount_0_out, Error::<T>::InsufficientTargetAmount);
+        }
+        if !pair_amount_1_out.is_zero() {
+            let amount_out = Self::get_amount_out(pair_amount_0_in, reserve_0, reserve_1, fee_rate)?;
+            ensure!(amount_out >= pair_amount_1_out, Error::<T>::InsufficientTargetAmount);
+        }
+
+        if !pair_amount_0_in.is_zero() {
+            T::MultiCurrency::transfer(pair.0, &batch_account, &pair_account, pair_amount_0_in)?;
+        }
+        if !pair_amount_1_in.is_zero() {
+            T::MultiCurrency::transfer(pair.1, &batch_account, &pair_account, pair_amount_1_in)?;
+        }
+        Self::pair_swap(
+            pair.0,
+            pair.1,
+            &pair_account,
+            pair_amount_0_out,
+            pair_amount_1_out,
+            &batch_account,
+        )?;
+
+        for (order, amount_out) in orders.iter().zip(amounts_out) {
+            let asset_out = if order.asset_in == pair.0 { pair.1 } else { pair.0 };
+            if !amount_out.is_zero() {
+                T::MultiCurrency::transfer(asset_out, &batch_account, &order.recipient, amount_out)?;
+            }
+            Self::deposit_event(Event::BatchSwapFilled {
+                owner: order.owner.clone(),
+                recipient: order.recipient.clone(),
+                asset_in: order.asset_in,
+                asset_out,
+                amount_in: order.amount_in,
+                amount_out,
+            });
+        }
+
+        Self::deposit_event(Event::BatchCleared {
+            asset_0: pair.0,
+            asset_1: pair.1,
+            clearing_price: if clearing_price.asset_sold == pair.0 {
+                clearing_price.price
+            } else {
+                clearing_price.price.reciprocal().unwrap_or_default()
+            },
+            amount_0_in: totals_in.amount_0_in,
+            amount_1_in: totals_in.amount_1_in,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+// Copyright 2021-2022 Zenlink.
+// Licensed under Apache 2.0.
+
+use super::Error;
+use crate::{swap::mock::*, Event, DEFAULT_FEE_RATE};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+const DOT_ASSET_ID: CurrencyId = CurrencyId::Token(2);
+const BTC_ASSET_ID: CurrencyId = CurrencyId::Token(3);
+const ETH_ASSET_ID: CurrencyId = CurrencyId::Token(4);
+
+const ALICE: u128 = 1;
+const BOB: u128 = 2;
+const CHARLIE: u128 = 3;
+const DOT_UNIT: u128 = 1000_000_000_000_000;
+const BTC_UNIT: u128 = 1000_000_00;
+
+fn create_dot_btc_pair_at_block_1() {
+    System::set_block_number(1);
+    for (who, amount) in [(ALICE, 1000), (BOB, 10), (CHARLIE, 10)] {
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(
+            DOT_ASSET_ID,
+            &who,
+            amount * DOT_UNIT
+        ));
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(
+            BTC_ASSET_ID,
+            &who,
+            amount * BTC_UNIT
+        ));
+    }
+    assert_ok!(DexPallet::create_pair(
+        RawOrigin::Root.into(),
+        DOT_ASSET_ID,
+        BTC_ASSET_ID,
+        DEFAULT_FEE_RATE,
+    ));
+    // 1 DOT = 0.1 BTC
+    assert_ok!(DexPallet::inner_add_liquidity(
+        &ALICE,
+        DOT_ASSET_ID,
+        BTC_ASSET_ID,
+        10 * DOT_UNIT,
+        1 * BTC_UNIT,
+        0,
+        0
+    ));
+}
+
+fn submit(who: u128, asset_in: CurrencyId, asset_out: CurrencyId, amount_in: u128, amount_out_min: u128) {
+    assert_ok!(DexPallet::submit_batch_swap(
+        RawOrigin::Signed(who).into(),
+        asset_in,
+        asset_out,
+        amount_in,
+        amount_out_min,
+        who,
+    ));
+}
+
+fn balance(asset: CurrencyId, who: u128) -> u128 {
+    <Test as Config>::MultiCurrency::free_balance(asset, &who)
+}
+
+fn solo_amount_out(asset_in: CurrencyId, asset_out: CurrencyId, amount_in: u128) -> u128 {
+    *DexPallet::get_amount_out_by_path(amount_in, &[asset_in, asset_out])
+        .unwrap()
+        .last()
+        .unwrap()
+}
+
+fn clearing_price() -> Option<FixedU128> {
+    System::events().into_iter().find_map(|record| match record.event {
+        RuntimeEvent::DexGeneral(Event::BatchCleared { clearing_price, .. }) => Some(clearing_price),
+        _ => None,
+    })
+}
+
+#[test]
+fn submit_batch_swap_should_escrow_input_until_end_of_block() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+
+        submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, 0);
+
+        assert_eq!(balance(DOT_ASSET_ID, BOB), 9 * DOT_UNIT);
+        assert_eq!(balance(DOT_ASSET_ID, DexPallet::batch_account_id()), DOT_UNIT);
+        assert_eq!(DexPallet::batch_orders((DOT_ASSET_ID, BTC_ASSET_ID)).len(), 1);
+
+        DexPallet::on_finalize(1);
+
+        assert!(DexPallet::batch_orders((DOT_ASSET_ID, BTC_ASSET_ID)).is_empty());
+        assert_eq!(balance(DOT_ASSET_ID, DexPallet::batch_account_id()), 0);
+        assert_eq!(balance(BTC_ASSET_ID, DexPallet::batch_account_id()), 0);
+    });
+}
+
+#[test]
+fn submit_batch_swap_should_validate_order() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+
+        assert_noop!(
+            DexPallet::submit_batch_swap(
+                RawOrigin::Signed(BOB).into(),
+                DOT_ASSET_ID,
+                ETH_ASSET_ID,
+                DOT_UNIT,
+                0,
+                BOB
+            ),
+            Error::<Test>::PairNotExists
+        );
+        assert_noop!(
+            DexPallet::submit_batch_swap(
+                RawOrigin::Signed(BOB).into(),
+                DOT_ASSET_ID,
+                DOT_ASSET_ID,
+                DOT_UNIT,
+                0,
+                BOB
+            ),
+            Error::<Test>::InvalidPath
+        );
+        assert_noop!(
+            DexPallet::submit_batch_swap(RawOrigin::Signed(BOB).into(), DOT_ASSET_ID, BTC_ASSET_ID, 0, 0, BOB),
+            Error::<Test>::IncorrectAssetAmountRange
+        );
+
+        for _ in 0..100 {
+            submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, 1, 0);
+        }
+        assert_noop!(
+            DexPallet::submit_batch_swap(RawOrigin::Signed(BOB).into(), DOT_ASSET_ID, BTC_ASSET_ID, 1, 0, BOB),
+            Error::<Test>::TooManyBatchOrders
+        );
+    });
+}
+
+#[test]
+fn single_batch_swap_should_get_pool_price() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let expected = solo_amount_out(DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT);
+
+        submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, expected);
+        DexPallet::on_finalize(1);
+
+        assert_eq!(balance(BTC_ASSET_ID, BOB), 10 * BTC_UNIT + expected);
+        assert_eq!(balance(DOT_ASSET_ID, BOB), 9 * DOT_UNIT);
+    });
+}
+
+#[test]
+fn opposite_batch_swaps_should_clear_at_single_price() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let pair_account = DexPallet::pair_account_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        let solo_bob = solo_amount_out(DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT);
+        let solo_charlie = solo_amount_out(BTC_ASSET_ID, DOT_ASSET_ID, BTC_UNIT / 5);
+
+        submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, 0);
+        submit(CHARLIE, BTC_ASSET_ID, DOT_ASSET_ID, BTC_UNIT / 5, 0);
+        DexPallet::on_finalize(1);
+
+        let bob_out = balance(BTC_ASSET_ID, BOB) - 10 * BTC_UNIT;
+        let charlie_out = balance(DOT_ASSET_ID, CHARLIE) - 10 * DOT_UNIT;
+        // the matched amounts don't pay the fee of the pair
+        assert!(bob_out > solo_bob);
+        assert!(charlie_out > solo_charlie);
+
+        // both are paid at the reported price, up to its rounding
+        let price = clearing_price().unwrap();
+        assert!(bob_out.abs_diff(price.saturating_mul_int(DOT_UNIT)) <= 1);
+        // charlie sold more, so the pair bought the remaining btc
+        assert!(balance(BTC_ASSET_ID, pair_account) > BTC_UNIT);
+        assert!(balance(DOT_ASSET_ID, pair_account) < 10 * DOT_UNIT);
+        assert_eq!(balance(DOT_ASSET_ID, DexPallet::batch_account_id()), 0);
+        assert_eq!(balance(BTC_ASSET_ID, DexPallet::batch_account_id()), 0);
+    });
+}
+
+#[test]
+fn batch_swaps_should_not_depend_on_submission_order() {
+    let run = |reversed: bool| {
+        new_test_ext().execute_with(|| {
+            create_dot_btc_pair_at_block_1();
+            let mut orders = vec![
+                (BOB, DOT_ASSET_ID, BTC_ASSET_ID, 3 * DOT_UNIT),
+                (CHARLIE, BTC_ASSET_ID, DOT_ASSET_ID, BTC_UNIT / 10),
+                (ALICE, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT),
+            ];
+            if reversed {
+                orders.reverse();
+            }
+            for (who, asset_in, asset_out, amount_in) in orders {
+                submit(who, asset_in, asset_out, amount_in, 0);
+            }
+            DexPallet::on_finalize(1);
+
+            (
+                balance(BTC_ASSET_ID, BOB),
+                balance(DOT_ASSET_ID, CHARLIE),
+                balance(BTC_ASSET_ID, ALICE),
+                clearing_price(),
+            )
+        })
+    };
+
+    assert_eq!(run(false), run(true));
+}
+
+#[test]
+fn batch_swap_should_be_refunded_below_min_out() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let solo = solo_amount_out(DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT);
+
+        // can't be filled, even alone
+        submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, solo + 1);
+        submit(CHARLIE, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, 0);
+        DexPallet::on_finalize(1);
+
+        assert_eq!(balance(DOT_ASSET_ID, BOB), 10 * DOT_UNIT);
+        assert_eq!(balance(BTC_ASSET_ID, BOB), 10 * BTC_UNIT);
+        // the price is found again without the refunded order
+        assert_eq!(balance(BTC_ASSET_ID, CHARLIE), 10 * BTC_UNIT + solo);
+        assert!(System::events().into_iter().any(|record| record.event
+            == RuntimeEvent::DexGeneral(Event::BatchSwapRefunded {
+                owner: BOB,
+                asset_in: DOT_ASSET_ID,
+                amount_in: DOT_UNIT,
+            })));
+    });
+}
+
+#[test]
+fn batch_swaps_should_be_refunded_if_pair_is_empty() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(<Test as Config>::MultiCurrency::deposit(DOT_ASSET_ID, &BOB, DOT_UNIT));
+        assert_ok!(DexPallet::create_pair(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            DEFAULT_FEE_RATE,
+        ));
+
+        submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, 0);
+        DexPallet::on_finalize(1);
+
+        assert_eq!(balance(DOT_ASSET_ID, BOB), DOT_UNIT);
+        assert_eq!(clearing_price(), None);
+    });
+}
+
+#[test]
+fn batch_only_pair_should_reject_immediate_swaps() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let solo = solo_amount_out(DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT);
+
+        assert_noop!(
+            DexPallet::set_batch_only(RawOrigin::Signed(ALICE).into(), DOT_ASSET_ID, BTC_ASSET_ID, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DexPallet::set_batch_only(RawOrigin::Root.into(), DOT_ASSET_ID, ETH_ASSET_ID, true),
+            Error::<Test>::PairNotExists
+        );
+        assert_ok!(DexPallet::set_batch_only(
+            RawOrigin::Root.into(),
+            BTC_ASSET_ID,
+            DOT_ASSET_ID,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DexGeneral(Event::BatchOnlySet {
+            asset_0: BTC_ASSET_ID,
+            asset_1: DOT_ASSET_ID,
+            batch_only: true,
+        }));
+
+        // the pair can still be quoted to price orders, but immediate swaps can't trade ahead
+        // of the batch
+        for path in [[DOT_ASSET_ID, BTC_ASSET_ID], [BTC_ASSET_ID, DOT_ASSET_ID]] {
+            assert!(DexPallet::supply_out_amount(1000, path.to_vec()) > 0);
+            assert!(DexPallet::desired_in_amount(1000, path.to_vec()) > 0);
+            assert_noop!(
+                DexPallet::swap_exact_assets_for_assets(
+                    RawOrigin::Signed(CHARLIE).into(),
+                    1000,
+                    0,
+                    path.to_vec(),
+                    CHARLIE,
+                    100
+                ),
+                Error::<Test>::BatchOnlyPair
+            );
+            assert_noop!(
+                DexPallet::swap_assets_for_exact_assets(
+                    RawOrigin::Signed(CHARLIE).into(),
+                    1000,
+                    u128::MAX,
+                    path.to_vec(),
+                    CHARLIE,
+                    100
+                ),
+                Error::<Test>::BatchOnlyPair
+            );
+        }
+
+        submit(BOB, DOT_ASSET_ID, BTC_ASSET_ID, DOT_UNIT, 0);
+        DexPallet::on_finalize(1);
+        assert_eq!(balance(BTC_ASSET_ID, BOB), 10 * BTC_UNIT + solo);
+
+        assert_ok!(DexPallet::set_batch_only(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            false
+        ));
+        assert_ok!(DexPallet::swap_exact_assets_for_assets(
+            RawOrigin::Signed(CHARLIE).into(),
+            1000,
+            0,
+            vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            CHARLIE,
+            100
+        ));
+    });
+}
@@ -31,6 +31,38 @@ pub fn run_to_block<T: Config>(n: u32) {
     }
 }
 
+fn create_trading_pair<T: Config>(caller: &T::AccountId)
+where
+    T::AssetId: From<u32>,
+{
+    assert_ok!(<T as Config>::MultiCurrency::deposit(
+        ASSET_0.into(),
+        caller,
+        1000 * UNIT
+    ));
+    assert_ok!(<T as Config>::MultiCurrency::deposit(
+        ASSET_1.into(),
+        caller,
+        1000 * UNIT
+    ));
+    assert_ok!(Pallet::<T>::create_pair(
+        (RawOrigin::Root).into(),
+        ASSET_0.into(),
+        ASSET_1.into(),
+        DEFAULT_FEE_RATE,
+    ));
+    assert_ok!(Pallet::<T>::add_liquidity(
+        RawOrigin::Signed(caller.clone()).into(),
+        ASSET_0.into(),
+        ASSET_1.into(),
+        100 * UNIT,
+        100 * UNIT,
+        0,
+        0,
+        100u32.saturated_into()
+    ));
+}
+
 #[benchmarks(where T::AssetId: From<u32>)]
 pub mod benchmarks {
     use super::*;
@@ -568,6 +600,150 @@ pub mod benchmarks {
         );
     }
 
+    #[benchmark]
+    pub fn set_dynamic_fee() {
+        assert_ok!(DexGeneral::<T>::create_pair(
+            (RawOrigin::Root).into(),
+            ASSET_0.into(),
+            ASSET_1.into(),
+            DEFAULT_FEE_RATE,
+        ));
+        let parameters = DynamicFeeParameters {
+            base_fee_rate: DEFAULT_FEE_RATE,
+            max_fee_rate: 10 * DEFAULT_FEE_RATE,
+            volatility_fee_rate: 1000,
+            decay: Permill::from_percent(5),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, ASSET_0.into(), ASSET_1.into(), Some(parameters));
+
+        assert_eq!(
+            DexGeneral::<T>::dynamic_fee(DexGeneral::<T>::sort_asset_id(ASSET_0.into(), ASSET_1.into())),
+            Some(parameters)
+        );
+    }
+
+    #[benchmark]
+    pub fn set_batch_only() {
+        assert_ok!(DexGeneral::<T>::create_pair(
+            (RawOrigin::Root).into(),
+            ASSET_0.into(),
+            ASSET_1.into(),
+            DEFAULT_FEE_RATE,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, ASSET_0.into(), ASSET_1.into(), true);
+
+        assert!(DexGeneral::<T>::is_batch_only(DexGeneral::<T>::sort_asset_id(
+            ASSET_0.into(),
+            ASSET_1.into()
+        )));
+    }
+
+    #[benchmark]
+    pub fn submit_batch_swap() {
+        let caller: T::AccountId = whitelisted_caller();
+        create_trading_pair::<T>(&caller);
+
+        // the orders already queued in the block are decoded and encoded again
+        for _ in 1..T::MaxBatchOrders::get() {
+            assert_ok!(DexGeneral::<T>::submit_batch_swap(
+                RawOrigin::Signed(caller.clone()).into(),
+                ASSET_0.into(),
+                ASSET_1.into(),
+                UNIT,
+                0,
+                lookup_of_account::<T>(caller.clone()),
+            ));
+        }
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            ASSET_0.into(),
+            ASSET_1.into(),
+            UNIT,
+            0,
+            lookup_of_account::<T>(caller.clone()),
+        );
+
+        assert_eq!(
+            DexGeneral::<T>::batch_orders(DexGeneral::<T>::sort_asset_id(ASSET_0.into(), ASSET_1.into())).len() as u32,
+            T::MaxBatchOrders::get()
+        );
+    }
+
+    #[benchmark]
+    pub fn clear_batch(b: Linear<1, 100>) {
+        let caller: T::AccountId = whitelisted_caller();
+        create_trading_pair::<T>(&caller);
+
+        // orders of both directions, each paid out to a new account, which costs more than
+        // refunding them. The searches of the clearing price are charged by `batch_clearing_round`.
+        for i in 0..b {
+            let trader: T::AccountId = account("trader", i, 0);
+            let (asset_in, asset_out) = if i % 2 == 0 {
+                (ASSET_0, ASSET_1)
+            } else {
+                (ASSET_1, ASSET_0)
+            };
+            assert_ok!(<T as Config>::MultiCurrency::deposit(asset_in.into(), &trader, UNIT));
+            assert_ok!(DexGeneral::<T>::submit_batch_swap(
+                RawOrigin::Signed(trader.clone()).into(),
+                asset_in.into(),
+                asset_out.into(),
+                UNIT,
+                0,
+                lookup_of_account::<T>(trader),
+            ));
+        }
+
+        #[block]
+        {
+            DexGeneral::<T>::on_finalize(frame_system::Pallet::<T>::block_number());
+        }
+
+        assert!(
+            DexGeneral::<T>::batch_orders(DexGeneral::<T>::sort_asset_id(ASSET_0.into(), ASSET_1.into())).is_empty()
+        );
+    }
+
+    #[benchmark]
+    pub fn batch_clearing_round(b: Linear<1, 100>) {
+        let caller: T::AccountId = whitelisted_caller();
+        create_trading_pair::<T>(&caller);
+        let pair = DexGeneral::<T>::sort_asset_id(ASSET_0.into(), ASSET_1.into());
+        let pair_account = DexGeneral::<T>::pair_account_id(pair.0, pair.1);
+        let reserve_0 = <T as Config>::MultiCurrency::free_balance(pair.0, &pair_account);
+        let reserve_1 = <T as Config>::MultiCurrency::free_balance(pair.1, &pair_account);
+
+        // orders of both directions, all of which are checked against the clearing price
+        let orders: Vec<_> = (0..b)
+            .map(|i| BatchOrder {
+                owner: caller.clone(),
+                recipient: caller.clone(),
+                asset_in: if i % 2 == 0 { pair.0 } else { pair.1 },
+                amount_in: UNIT,
+                amount_out_min: 0,
+            })
+            .collect();
+
+        #[block]
+        {
+            let (price, filled, _) = DexGeneral::<T>::batch_clearing_round(
+                pair,
+                orders,
+                reserve_0,
+                reserve_1,
+                DexGeneral::<T>::pair_fee_rate(pair),
+            );
+            assert!(price.is_some());
+            assert_eq!(filled.len() as u32, b);
+        }
+    }
+
     impl_benchmark_test_suite!(
         DexGeneral,
         crate::fee::mock::ExtBuilder::build(),
@@ -52,6 +52,11 @@ pub trait WeightInfo {
 	fn swap_assets_for_exact_assets(a: u32, ) -> Weight;
 	fn bootstrap_charge_reward(r: u32, ) -> Weight;
 	fn bootstrap_withdraw_reward() -> Weight;
+	fn set_dynamic_fee() -> Weight;
+	fn submit_batch_swap() -> Weight;
+	fn clear_batch(b: u32) -> Weight;
+	fn set_batch_only() -> Weight;
+	fn batch_clearing_round(b: u32) -> Weight;
 }
 
 /// Weights for dex_general using the Substrate node and recommended hardware.
@@ -264,6 +269,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:9 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:9 w:0)
+	/// Proof Skipped: DexGeneral BatchOnlyPairs (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `a` is `[2, 10]`.
 	fn swap_exact_assets_for_assets(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -274,7 +281,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 42_930
 			.saturating_add(Weight::from_parts(38_614_635, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 9103).saturating_mul(a.into()))
 	}
@@ -284,6 +291,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:9 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:9 w:0)
+	/// Proof Skipped: DexGeneral BatchOnlyPairs (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `a` is `[2, 10]`.
 	fn swap_assets_for_exact_assets(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -294,7 +303,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 309_475
 			.saturating_add(Weight::from_parts(35_451_356, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 9103).saturating_mul(a.into()))
 	}
@@ -330,6 +339,87 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral DynamicFees (r:0 w:1)
+	/// Proof Skipped: DexGeneral DynamicFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral FeeVolatilities (r:0 w:1)
+	/// Proof Skipped: DexGeneral FeeVolatilities (max_values: None, max_size: None, mode: Measured)
+	fn set_dynamic_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1152`
+		//  Estimated: `4617`
+		// Minimum execution time: 21_846_000 picoseconds.
+		Weight::from_parts(22_713_000, 4617)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOrders (r:1 w:1)
+	/// Proof Skipped: DexGeneral BatchOrders (max_values: None, max_size: None, mode: Measured)
+	fn submit_batch_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9624`
+		//  Estimated: `13089`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(63_118_000, 13089)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: DexGeneral BatchOrders (r:2 w:1)
+	/// Proof Skipped: DexGeneral BatchOrders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: DexGeneral DynamicFees (r:1 w:0)
+	/// Proof Skipped: DexGeneral DynamicFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral PriceCumulatives (r:1 w:1)
+	/// Proof Skipped: DexGeneral PriceCumulatives (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 100]`.
+	fn clear_batch(b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1290`
+		//  Estimated: `11170`
+		// Minimum execution time: 118_430_000 picoseconds.
+		Weight::from_parts(104_902_113, 11170)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(31_874_552, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral BatchOnlyPairs (r:0 w:1)
+	/// Proof Skipped: DexGeneral BatchOnlyPairs (max_values: None, max_size: None, mode: Measured)
+	fn set_batch_only() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1152`
+		//  Estimated: `4617`
+		// Minimum execution time: 18_377_000 picoseconds.
+		Weight::from_parts(19_052_000, 4617)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `b` is `[1, 100]`.
+	fn batch_clearing_round(b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_433_000 picoseconds.
+		Weight::from_parts(19_108_416, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(187_305, 0).saturating_mul(b.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -541,6 +631,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:9 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:9 w:0)
+	/// Proof Skipped: DexGeneral BatchOnlyPairs (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `a` is `[2, 10]`.
 	fn swap_exact_assets_for_assets(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -551,7 +643,7 @@ impl WeightInfo for () {
 			// Standard Error: 42_930
 			.saturating_add(Weight::from_parts(38_614_635, 0).saturating_mul(a.into()))
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(a.into())))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 9103).saturating_mul(a.into()))
 	}
@@ -561,6 +653,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:9 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:9 w:0)
+	/// Proof Skipped: DexGeneral BatchOnlyPairs (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `a` is `[2, 10]`.
 	fn swap_assets_for_exact_assets(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -571,7 +665,7 @@ impl WeightInfo for () {
 			// Standard Error: 309_475
 			.saturating_add(Weight::from_parts(35_451_356, 0).saturating_mul(a.into()))
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(a.into())))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 9103).saturating_mul(a.into()))
 	}
@@ -607,4 +701,85 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral DynamicFees (r:0 w:1)
+	/// Proof Skipped: DexGeneral DynamicFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral FeeVolatilities (r:0 w:1)
+	/// Proof Skipped: DexGeneral FeeVolatilities (max_values: None, max_size: None, mode: Measured)
+	fn set_dynamic_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1152`
+		//  Estimated: `4617`
+		// Minimum execution time: 21_846_000 picoseconds.
+		Weight::from_parts(22_713_000, 4617)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOrders (r:1 w:1)
+	/// Proof Skipped: DexGeneral BatchOrders (max_values: None, max_size: None, mode: Measured)
+	fn submit_batch_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9624`
+		//  Estimated: `13089`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(63_118_000, 13089)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: DexGeneral BatchOrders (r:2 w:1)
+	/// Proof Skipped: DexGeneral BatchOrders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: DexGeneral DynamicFees (r:1 w:0)
+	/// Proof Skipped: DexGeneral DynamicFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral PriceCumulatives (r:1 w:1)
+	/// Proof Skipped: DexGeneral PriceCumulatives (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 100]`.
+	fn clear_batch(b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1290`
+		//  Estimated: `11170`
+		// Minimum execution time: 118_430_000 picoseconds.
+		Weight::from_parts(104_902_113, 11170)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(31_874_552, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexGeneral BatchOnlyPairs (r:0 w:1)
+	/// Proof Skipped: DexGeneral BatchOnlyPairs (max_values: None, max_size: None, mode: Measured)
+	fn set_batch_only() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1152`
+		//  Estimated: `4617`
+		// Minimum execution time: 18_377_000 picoseconds.
+		Weight::from_parts(19_052_000, 4617)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `b` is `[1, 100]`.
+	fn batch_clearing_round(b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_433_000 picoseconds.
+		Weight::from_parts(19_108_416, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(187_305, 0).saturating_mul(b.into()))
+	}
 }
@@ -165,6 +165,7 @@ impl Config for Test {
     type WeightInfo = ();
     type MaxBootstrapRewards = ConstU32<1000>;
     type MaxBootstrapLimits = ConstU32<1000>;
+    type MaxBatchOrders = ConstU32<100>;
 }
 
 pub struct ExtBuilder;
@@ -26,9 +26,13 @@ use frame_support::{
 };
 use orml_traits::MultiCurrency;
 use sp_core::U256;
-use sp_runtime::traits::{AccountIdConversion, Hash, MaybeSerializeDeserialize, One, StaticLookup, Zero};
+use sp_runtime::{
+    traits::{AccountIdConversion, Hash, MaybeSerializeDeserialize, One, SaturatedConversion, StaticLookup, Zero},
+    FixedPointNumber, FixedU128, Permill,
+};
 use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, fmt::Debug, prelude::*, vec};
 
+mod batch;
 mod fee;
 mod primitives;
 mod rpc;
@@ -42,12 +46,13 @@ mod default_weights;
 
 pub use default_weights::WeightInfo;
 pub use primitives::{
-    AssetBalance, BootstrapParameter, PairMetadata, PairStatus,
+    AssetBalance, BatchOrder, BootstrapParameter, DynamicFeeParameters, FeeVolatility, PairFeeRate, PairMetadata,
+    PairStatus,
     PairStatus::{Bootstrap, Disable, Trading},
-    DEFAULT_FEE_RATE, FEE_ADJUSTMENT,
+    PriceCumulative, PriceObservation, DEFAULT_FEE_RATE, FEE_ADJUSTMENT,
 };
 pub use rpc::PairInfo;
-pub use traits::{ExportDexGeneral, GenerateLpAssetId, ValidateAsset};
+pub use traits::{ExportDexGeneral, ExportDexGeneralTwap, GenerateLpAssetId, ValidateAsset};
 
 #[allow(type_alias_bounds)]
 type AccountIdOf<T: Config> = <T as frame_system::Config>::AccountId;
@@ -99,6 +104,10 @@ pub mod pallet {
         /// The maximum number of limits that can be stored
         #[pallet::constant]
         type MaxBootstrapLimits: Get<u32>;
+
+        /// The maximum number of batch swaps that can be queued through a pair in a block
+        #[pallet::constant]
+        type MaxBatchOrders: Get<u32>;
     }
 
     #[pallet::pallet]
@@ -173,6 +182,51 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Price accumulators of each trading pair, used for time-weighted average prices
+    ///
+    /// PriceCumulatives: map (T::AssetId, T::AssetId) => PriceCumulative
+    #[pallet::storage]
+    #[pallet::getter(fn price_cumulative)]
+    pub type PriceCumulatives<T: Config> =
+        StorageMap<_, Twox64Concat, (T::AssetId, T::AssetId), PriceCumulative<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Fee bounds of the trading pairs whose fee follows the volatility of their price
+    ///
+    /// DynamicFees: map (T::AssetId, T::AssetId) => Option<DynamicFeeParameters>
+    #[pallet::storage]
+    #[pallet::getter(fn dynamic_fee)]
+    pub type DynamicFees<T: Config> =
+        StorageMap<_, Twox64Concat, (T::AssetId, T::AssetId), DynamicFeeParameters, OptionQuery>;
+
+    /// Recent price movement of the trading pairs with a dynamic fee
+    ///
+    /// FeeVolatilities: map (T::AssetId, T::AssetId) => FeeVolatility
+    #[pallet::storage]
+    #[pallet::getter(fn fee_volatility)]
+    pub type FeeVolatilities<T: Config> =
+        StorageMap<_, Twox64Concat, (T::AssetId, T::AssetId), FeeVolatility<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Swaps queued through each trading pair in the current block, cleared in `on_finalize`
+    ///
+    /// BatchOrders: map (T::AssetId, T::AssetId) => BoundedVec<BatchOrder>
+    #[pallet::storage]
+    #[pallet::getter(fn batch_orders)]
+    pub type BatchOrders<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        (T::AssetId, T::AssetId),
+        BoundedVec<BatchOrder<T::AccountId, T::AssetId>, T::MaxBatchOrders>,
+        ValueQuery,
+    >;
+
+    /// Trading pairs which can only be swapped through with `submit_batch_swap`, so that
+    /// immediate swaps can't trade ahead of the batch
+    ///
+    /// BatchOnlyPairs: map (T::AssetId, T::AssetId) => bool
+    #[pallet::storage]
+    #[pallet::getter(fn is_batch_only)]
+    pub type BatchOnlyPairs<T: Config> = StorageMap<_, Twox64Concat, (T::AssetId, T::AssetId), bool, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     /// Refer: https://github.com/Uniswap/uniswap-v2-core/blob/master/contracts/UniswapV2Pair.sol#L88
@@ -328,6 +382,58 @@ pub mod pallet {
 
         /// A pair's admin fee was updated
         NewFeePoint { new_fee_point: u8 },
+
+        /// A pair's dynamic fee was set, or removed in favour of its static fee rate
+        DynamicFeeSet {
+            asset_0: T::AssetId,
+            asset_1: T::AssetId,
+            parameters: Option<DynamicFeeParameters>,
+        },
+
+        /// A swap was queued through a pair until the end of the block.
+        BatchSwapSubmitted {
+            owner: T::AccountId,
+            recipient: T::AccountId,
+            asset_in: T::AssetId,
+            asset_out: T::AssetId,
+            amount_in: AssetBalance,
+            amount_out_min: AssetBalance,
+        },
+
+        /// A queued swap was filled at the clearing price of its batch.
+        BatchSwapFilled {
+            owner: T::AccountId,
+            recipient: T::AccountId,
+            asset_in: T::AssetId,
+            asset_out: T::AssetId,
+            amount_in: AssetBalance,
+            amount_out: AssetBalance,
+        },
+
+        /// A queued swap couldn't be filled at the clearing price of its batch, its input was
+        /// refunded.
+        BatchSwapRefunded {
+            owner: T::AccountId,
+            asset_in: T::AssetId,
+            amount_in: AssetBalance,
+        },
+
+        /// The swaps queued through a pair were cleared at a single price, the price of
+        /// `asset_0` in `asset_1`.
+        BatchCleared {
+            asset_0: T::AssetId,
+            asset_1: T::AssetId,
+            clearing_price: FixedU128,
+            amount_0_in: AssetBalance,
+            amount_1_in: AssetBalance,
+        },
+
+        /// Immediate swaps through a pair were disabled in favour of batch swaps, or enabled again.
+        BatchOnlySet {
+            asset_0: T::AssetId,
+            asset_1: T::AssetId,
+            batch_only: bool,
+        },
     }
     #[pallet::error]
     pub enum Error<T> {
@@ -387,10 +493,23 @@ pub mod pallet {
         TooManyRewards,
         /// The number of limits exceeds the storage limit
         TooManyLimits,
+        /// The end of the averaging period is not after its start
+        InvalidTwapPeriod,
+        /// The number of batch swaps exceeds the storage limit
+        TooManyBatchOrders,
+        /// Swaps through the pair must be queued with `submit_batch_swap`
+        BatchOnlyPair,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            // the weight is charged upfront by `submit_batch_swap`
+            for (pair, orders) in BatchOrders::<T>::drain() {
+                Self::clear_batch(pair, orders.into_inner());
+            }
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -1096,5 +1215,120 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Let the exchange fee rate of a pair follow the volatility of its price.
+        ///
+        /// # Arguments
+        ///
+        /// - `asset_0`: Asset which makes up the pair
+        /// - `asset_1`: Asset which makes up the pair
+        /// - `parameters`:
+        /// (1) Some(parameters): the fee rate rises from the base fee rate with the recent price
+        /// movement, measured from the swaps through the pair, up to the max fee rate.
+        /// (2) None: the pair returns to the fee rate set by `set_exchange_fee`.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_dynamic_fee())]
+        #[frame_support::transactional]
+        pub fn set_dynamic_fee(
+            origin: OriginFor<T>,
+            asset_0: T::AssetId,
+            asset_1: T::AssetId,
+            parameters: Option<DynamicFeeParameters>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if let Some(parameters) = parameters {
+                ensure!(
+                    parameters.base_fee_rate <= parameters.max_fee_rate && parameters.max_fee_rate < FEE_ADJUSTMENT,
+                    Error::<T>::InvalidFeeRate
+                );
+            }
+
+            let pair = Self::sort_asset_id(asset_0, asset_1);
+            ensure!(matches!(Self::pair_status(pair), Trading(_)), Error::<T>::PairNotExists);
+
+            DynamicFees::<T>::set(pair, parameters);
+            // the volatility is only tracked while the fee is dynamic, so it starts over
+            FeeVolatilities::<T>::remove(pair);
+
+            Self::deposit_event(Event::DynamicFeeSet {
+                asset_0,
+                asset_1,
+                parameters,
+            });
+
+            Ok(())
+        }
+
+        /// Queue a swap through a pair until the end of the block.
+        ///
+        /// All swaps queued through the pair are cleared together in `on_finalize` at a single
+        /// price against its reserves, so their order within the block doesn't affect the price
+        /// they get. Swaps which would receive less than `amount_out_min` at the clearing price
+        /// are refunded.
+        ///
+        /// # Arguments
+        ///
+        /// - `asset_in`: Asset which will be sold
+        /// - `asset_out`: Asset which will be bought, the other asset of the pair
+        /// - `amount_in`: Amount of the asset which will be sold
+        /// - `amount_out_min`: Minimum amount of the asset which will be bought
+        /// - `recipient`: Account that receive the target asset
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::submit_batch_swap()
+            .saturating_add(T::WeightInfo::clear_batch(1))
+            .saturating_add(T::WeightInfo::batch_clearing_round(1).saturating_mul(batch::MAX_CLEARING_ROUNDS.into())))]
+        #[frame_support::transactional]
+        pub fn submit_batch_swap(
+            origin: OriginFor<T>,
+            asset_in: T::AssetId,
+            asset_out: T::AssetId,
+            #[pallet::compact] amount_in: AssetBalance,
+            #[pallet::compact] amount_out_min: AssetBalance,
+            recipient: <T::Lookup as StaticLookup>::Source,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let recipient = T::Lookup::lookup(recipient)?;
+            ensure!(
+                T::EnsurePairAsset::validate_asset(&asset_in) && T::EnsurePairAsset::validate_asset(&asset_out),
+                Error::<T>::UnsupportedAssetType
+            );
+            ensure!(asset_in != asset_out, Error::<T>::InvalidPath);
+            ensure!(!amount_in.is_zero(), Error::<T>::IncorrectAssetAmountRange);
+
+            Self::do_submit_batch_swap(who, recipient, asset_in, asset_out, amount_in, amount_out_min)
+        }
+
+        /// Only allow swaps through a pair with `submit_batch_swap`.
+        ///
+        /// # Arguments
+        ///
+        /// - `asset_0`: Asset which makes up the pair
+        /// - `asset_1`: Asset which makes up the pair
+        /// - `batch_only`: Whether immediate swaps through the pair are rejected
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_batch_only())]
+        #[frame_support::transactional]
+        pub fn set_batch_only(
+            origin: OriginFor<T>,
+            asset_0: T::AssetId,
+            asset_1: T::AssetId,
+            batch_only: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let pair = Self::sort_asset_id(asset_0, asset_1);
+            ensure!(matches!(Self::pair_status(pair), Trading(_)), Error::<T>::PairNotExists);
+
+            BatchOnlyPairs::<T>::insert(pair, batch_only);
+
+            Self::deposit_event(Event::BatchOnlySet {
+                asset_0,
+                asset_1,
+                batch_only,
+            });
+
+            Ok(())
+        }
     }
 }
@@ -59,3 +59,93 @@ pub struct PairMetadata<Balance, Account> {
     pub total_supply: Balance,
     pub fee_rate: Balance,
 }
+
+/// Bounds of the fee rate of a pair whose fee follows the volatility of its price. All rates
+/// are multiplied by `FEE_ADJUSTMENT`, like the static fee rate.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DynamicFeeParameters {
+    /// Fee rate charged while the price is stable.
+    pub base_fee_rate: AssetBalance,
+    /// Upper bound of the fee rate.
+    pub max_fee_rate: AssetBalance,
+    /// Fee rate added on top of the base fee rate per 100% of volatility.
+    pub volatility_fee_rate: AssetBalance,
+    /// Share of the volatility forgotten every block.
+    pub decay: Permill,
+}
+
+impl DynamicFeeParameters {
+    pub fn fee_rate(&self, volatility: FixedU128) -> AssetBalance {
+        self.base_fee_rate
+            .saturating_add(volatility.saturating_mul_int(self.volatility_fee_rate))
+            .min(self.max_fee_rate)
+    }
+}
+
+/// Recent price movement of a pair with a dynamic fee.
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct FeeVolatility<BlockNumber> {
+    /// Sum of the relative price changes of all swaps, decayed by the blocks since each swap.
+    pub volatility: FixedU128,
+    /// Block of the last update.
+    pub block_number_last: BlockNumber,
+}
+
+/// The fee rate of a trading pair and the inputs it is derived from.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PairFeeRate {
+    /// Fee rate charged by the next swap through the pair.
+    pub fee_rate: AssetBalance,
+    /// Fee rate set by `set_exchange_fee`, charged unless the pair has a dynamic fee.
+    pub static_fee_rate: AssetBalance,
+    pub dynamic_fee: Option<DynamicFeeParameters>,
+    /// Volatility of the pair as of the current block.
+    pub volatility: FixedU128,
+}
+
+/// Price accumulators of a trading pair, refer:
+/// https://github.com/Uniswap/v2-core/blob/master/contracts/UniswapV2Pair.sol#L73
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct PriceCumulative<BlockNumber> {
+    /// Sum of the price of `asset_0` in `asset_1` at the end of every block, times the number of
+    /// blocks it was valid for. Wraps around on overflow.
+    pub price_0_cumulative: U256,
+    /// Sum of the price of `asset_1` in `asset_0`, see `price_0_cumulative`.
+    pub price_1_cumulative: U256,
+    /// Reserve of `asset_0` as of the last update.
+    pub reserve_0: AssetBalance,
+    /// Reserve of `asset_1` as of the last update.
+    pub reserve_1: AssetBalance,
+    /// Block of the last update.
+    pub block_number_last: BlockNumber,
+}
+
+/// Snapshot of the price accumulators of a trading pair. The time-weighted average price
+/// between two snapshots is the difference of their accumulators, divided by the number
+/// of blocks between them.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PriceObservation<BlockNumber> {
+    pub price_0_cumulative: U256,
+    pub price_1_cumulative: U256,
+    pub block_number: BlockNumber,
+}
+
+/// A swap queued through a pair until the end of the block, when all swaps queued through the
+/// pair are cleared together at a single price. The input stays escrowed until then.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct BatchOrder<AccountId, AssetId> {
+    pub owner: AccountId,
+    /// Account receiving the output. The input is refunded to the owner if the order isn't filled.
+    pub recipient: AccountId,
+    /// Asset sold, the other asset of the pair is bought.
+    pub asset_in: AssetId,
+    pub amount_in: AssetBalance,
+    /// The order is refunded if the clearing price would pay out less.
+    pub amount_out_min: AssetBalance,
+}
@@ -92,6 +92,24 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    pub fn get_pair_fee_rate(asset_0: T::AssetId, asset_1: T::AssetId) -> Option<PairFeeRate> {
+        let pair = Self::sort_asset_id(asset_0, asset_1);
+        let static_fee_rate = match Self::pair_status(pair) {
+            Trading(metadata) => metadata.fee_rate,
+            _ => return None,
+        };
+        let dynamic_fee = Self::dynamic_fee(pair);
+
+        Some(PairFeeRate {
+            fee_rate: Self::pair_fee_rate(pair),
+            static_fee_rate,
+            dynamic_fee,
+            volatility: dynamic_fee
+                .map(|parameters| Self::current_volatility(pair, &parameters))
+                .unwrap_or_default(),
+        })
+    }
+
     /// Calculate the underlying amounts for burning LP tokens using
     /// the formula (lp_balance * reserve) / lp_total_supply
     pub fn calculate_remove_liquidity(
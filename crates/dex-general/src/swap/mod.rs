@@ -10,9 +10,10 @@
 
 use super::*;
 use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Saturating;
 
 #[cfg(test)]
-mod mock;
+pub mod mock;
 #[cfg(test)]
 mod tests;
 
@@ -113,6 +114,7 @@ impl<T: Config> Pallet<T> {
 
                 T::MultiCurrency::transfer(asset_0, who, &parameter.pair_account, amount_0)?;
                 T::MultiCurrency::transfer(asset_1, who, &parameter.pair_account, amount_1)?;
+                Self::update_price_cumulative(asset_0, asset_1);
 
                 if let Some(_fee_to) = Self::fee_meta().0 {
                     // update reserve_0 and reserve_1
@@ -188,6 +190,7 @@ impl<T: Config> Pallet<T> {
 
                 T::MultiCurrency::transfer(asset_0, &parameter.pair_account, recipient, amount_0)?;
                 T::MultiCurrency::transfer(asset_1, &parameter.pair_account, recipient, amount_1)?;
+                Self::update_price_cumulative(asset_0, asset_1);
 
                 if let Some(_fee_to) = Self::fee_meta().0 {
                     // update reserve_0 and reserve_1
@@ -225,6 +228,7 @@ impl<T: Config> Pallet<T> {
         path: &[T::AssetId],
         recipient: &T::AccountId,
     ) -> DispatchResult {
+        Self::ensure_immediate_swap_path(path)?;
         let amounts = Self::get_amount_out_by_path(amount_in, path)?;
         ensure!(
             amounts[amounts.len() - 1] >= amount_out_min,
@@ -259,6 +263,7 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UnsupportedAssetType
         );
 
+        Self::ensure_immediate_swap_path(path)?;
         let amounts = Self::get_amount_in_by_path(amount_out, path)?;
 
         ensure!(amounts[0] <= amount_in_max, Error::<T>::ExcessiveSoldAmount);
@@ -278,6 +283,17 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Pairs restricted to batch swaps can still be quoted, but not swapped through immediately.
+    fn ensure_immediate_swap_path(path: &[T::AssetId]) -> DispatchResult {
+        for &[asset_0, asset_1] in path.array_windows::<2>() {
+            ensure!(
+                !Self::is_batch_only(Self::sort_asset_id(asset_0, asset_1)),
+                Error::<T>::BatchOnlyPair
+            );
+        }
+        Ok(())
+    }
+
     pub(crate) fn calculate_share_amount(
         amount_0: AssetBalance,
         reserve_0: AssetBalance,
@@ -363,6 +379,151 @@ impl<T: Config> Pallet<T> {
         KLast::<T>::mutate(Self::sort_asset_id(asset_0, asset_1), |k| *k = last)
     }
 
+    /// The prices of `asset_0` in `asset_1` and of `asset_1` in `asset_0`, scaled like `FixedU128`.
+    fn spot_prices(reserve_0: AssetBalance, reserve_1: AssetBalance) -> (U256, U256) {
+        let price = |numerator: AssetBalance, denominator: AssetBalance| {
+            U256::from(numerator)
+                .saturating_mul(U256::from(FixedU128::DIV))
+                .checked_div(U256::from(denominator))
+                .unwrap_or_default()
+        };
+        (price(reserve_1, reserve_0), price(reserve_0, reserve_1))
+    }
+
+    /// The price accumulators of a pair, extended with the last recorded reserves up to `now`.
+    fn accumulate_price(cumulative: &PriceCumulative<BlockNumberFor<T>>, now: BlockNumberFor<T>) -> (U256, U256) {
+        let elapsed = now.saturating_sub(cumulative.block_number_last);
+        if elapsed.is_zero() || cumulative.reserve_0.is_zero() || cumulative.reserve_1.is_zero() {
+            return (cumulative.price_0_cumulative, cumulative.price_1_cumulative);
+        }
+        let elapsed = U256::from(elapsed.saturated_into::<u128>());
+        let (price_0, price_1) = Self::spot_prices(cumulative.reserve_0, cumulative.reserve_1);
+        // overflow is desired, only differences of the accumulators are meaningful
+        (
+            cumulative
+                .price_0_cumulative
+                .overflowing_add(price_0.overflowing_mul(elapsed).0)
+                .0,
+            cumulative
+                .price_1_cumulative
+                .overflowing_add(price_1.overflowing_mul(elapsed).0)
+                .0,
+        )
+    }
+
+    /// Refer: https://github.com/Uniswap/v2-core/blob/master/contracts/UniswapV2Pair.sol#L73
+    /// Has to be called after every change of the reserves of a trading pair. Only the reserves
+    /// at the end of a block are weighted into the accumulators, so the price can't be moved by
+    /// trades that are reverted within the same block.
+    pub(crate) fn update_price_cumulative(asset_0: T::AssetId, asset_1: T::AssetId) {
+        let pair = Self::sort_asset_id(asset_0, asset_1);
+        let pair_account = Self::pair_account_id(pair.0, pair.1);
+        let now = frame_system::Pallet::<T>::block_number();
+        PriceCumulatives::<T>::mutate(pair, |cumulative| {
+            let (price_0_cumulative, price_1_cumulative) = Self::accumulate_price(cumulative, now);
+            *cumulative = PriceCumulative {
+                price_0_cumulative,
+                price_1_cumulative,
+                reserve_0: T::MultiCurrency::free_balance(pair.0, &pair_account),
+                reserve_1: T::MultiCurrency::free_balance(pair.1, &pair_account),
+                block_number_last: now,
+            };
+        })
+    }
+
+    pub fn price_observation(
+        asset_0: T::AssetId,
+        asset_1: T::AssetId,
+    ) -> Result<PriceObservation<BlockNumberFor<T>>, DispatchError> {
+        let pair = Self::sort_asset_id(asset_0, asset_1);
+        ensure!(matches!(Self::pair_status(pair), Trading(_)), Error::<T>::PairNotExists);
+        let now = frame_system::Pallet::<T>::block_number();
+        let (price_0_cumulative, price_1_cumulative) = Self::accumulate_price(&Self::price_cumulative(pair), now);
+        Ok(PriceObservation {
+            price_0_cumulative,
+            price_1_cumulative,
+            block_number: now,
+        })
+    }
+
+    pub fn calculate_twap(
+        asset_0: T::AssetId,
+        asset_1: T::AssetId,
+        start: &PriceObservation<BlockNumberFor<T>>,
+        end: &PriceObservation<BlockNumberFor<T>>,
+    ) -> Result<FixedU128, DispatchError> {
+        ensure!(end.block_number > start.block_number, Error::<T>::InvalidTwapPeriod);
+        let elapsed = U256::from(
+            end.block_number
+                .saturating_sub(start.block_number)
+                .saturated_into::<u128>(),
+        );
+        let (start_cumulative, end_cumulative) = if asset_0 < asset_1 {
+            (start.price_0_cumulative, end.price_0_cumulative)
+        } else {
+            (start.price_1_cumulative, end.price_1_cumulative)
+        };
+        let price = end_cumulative
+            .overflowing_sub(start_cumulative)
+            .0
+            .checked_div(elapsed)
+            .and_then(|n| TryInto::<u128>::try_into(n).ok())
+            .ok_or(Error::<T>::Overflow)?;
+        Ok(FixedU128::from_inner(price))
+    }
+
+    /// The fee rate charged by swaps through a pair, which follows the volatility of its price
+    /// if the pair has a dynamic fee.
+    pub fn pair_fee_rate(pair: (T::AssetId, T::AssetId)) -> AssetBalance {
+        match Self::dynamic_fee(pair) {
+            Some(parameters) => parameters.fee_rate(Self::current_volatility(pair, &parameters)),
+            None => Self::pair_status(pair).fee_rate(),
+        }
+    }
+
+    /// The volatility of a pair, decayed by the blocks since its last update.
+    pub(crate) fn current_volatility(pair: (T::AssetId, T::AssetId), parameters: &DynamicFeeParameters) -> FixedU128 {
+        let fee_volatility = Self::fee_volatility(pair);
+        let now = frame_system::Pallet::<T>::block_number();
+        let elapsed = now.saturating_sub(fee_volatility.block_number_last);
+        let retained = Permill::one()
+            .saturating_sub(parameters.decay)
+            .saturating_pow(elapsed.saturated_into::<usize>());
+        FixedU128::from_inner(retained.mul_floor(fee_volatility.volatility.into_inner()))
+    }
+
+    /// Adds the relative change of the price of a pair with a dynamic fee since `before` to its
+    /// volatility. Has to be called after the price accumulators were updated for a swap.
+    fn update_fee_volatility(pair: (T::AssetId, T::AssetId), before: &PriceCumulative<BlockNumberFor<T>>) {
+        let parameters = match Self::dynamic_fee(pair) {
+            Some(parameters) => parameters,
+            None => return,
+        };
+        let after = Self::price_cumulative(pair);
+        let (price_before, _) = Self::spot_prices(before.reserve_0, before.reserve_1);
+        let (price_after, _) = Self::spot_prices(after.reserve_0, after.reserve_1);
+
+        let price_change = if price_before.is_zero() || price_after.is_zero() {
+            // nothing to compare with, e.g. before the first deposit
+            Zero::zero()
+        } else {
+            let change = price_after.max(price_before) - price_after.min(price_before);
+            change
+                .saturating_mul(U256::from(FixedU128::DIV))
+                .checked_div(price_before)
+                .map(|n| FixedU128::from_inner(TryInto::<u128>::try_into(n).unwrap_or(u128::MAX)))
+                .unwrap_or_default()
+        };
+
+        FeeVolatilities::<T>::insert(
+            pair,
+            FeeVolatility {
+                volatility: Self::current_volatility(pair, &parameters).saturating_add(price_change),
+                block_number_last: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+    }
+
     pub(crate) fn calculate_added_amount(
         amount_0_desired: AssetBalance,
         amount_1_desired: AssetBalance,
@@ -416,7 +577,7 @@ impl<T: Config> Pallet<T> {
         Ok(amount_in)
     }
 
-    fn get_amount_out(
+    pub(crate) fn get_amount_out(
         input_amount: AssetBalance,
         input_reserve: AssetBalance,
         output_reserve: AssetBalance,
@@ -467,7 +628,7 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::InvalidPath
             );
 
-            let fee_rate = Self::pair_status(Self::sort_asset_id(path[i], path[i - 1])).fee_rate();
+            let fee_rate = Self::pair_fee_rate(Self::sort_asset_id(path[i], path[i - 1]));
             let amount = Self::get_amount_in(out_vec[len - 1 - i], reserve_1, reserve_0, fee_rate)?;
             ensure!(amount >= One::one(), Error::<T>::InvalidPath);
 
@@ -515,7 +676,7 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::InvalidPath
             );
 
-            let fee_rate = Self::pair_status(Self::sort_asset_id(input_asset, output_asset)).fee_rate();
+            let fee_rate = Self::pair_fee_rate(Self::sort_asset_id(input_asset, output_asset));
             let amount = Self::get_amount_out(amount_in, reserve_0, reserve_1, fee_rate)?;
             ensure!(amount > Zero::zero(), Error::<T>::InvalidPath);
 
@@ -568,7 +729,7 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn pair_swap(
+    pub(crate) fn pair_swap(
         asset_0: T::AssetId,
         asset_1: T::AssetId,
         pair_account: &T::AccountId,
@@ -590,6 +751,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InsufficientPairReserve
         );
 
+        // the reserves as of the last update, before the input of this swap was received
+        let cumulative_before = Self::price_cumulative(pair);
+
         if amount_0 > Zero::zero() {
             T::MultiCurrency::transfer(asset_0, pair_account, recipient, amount_0)?;
         }
@@ -597,6 +761,8 @@ impl<T: Config> Pallet<T> {
         if amount_1 > Zero::zero() {
             T::MultiCurrency::transfer(asset_1, pair_account, recipient, amount_1)?;
         }
+        Self::update_price_cumulative(asset_0, asset_1);
+        Self::update_fee_volatility(pair, &cumulative_before);
 
         Ok(())
     }
@@ -744,6 +910,7 @@ impl<T: Config> Pallet<T> {
                 );
 
                 BootstrapEndStatus::<T>::insert(pair, Bootstrap(bootstrap_parameter.clone()));
+                Self::update_price_cumulative(pair.0, pair.1);
 
                 Self::deposit_event(Event::BootstrapEnd {
                     asset_0: pair.0,
@@ -987,6 +1154,15 @@ impl<T: Config> Pallet<T> {
 }
 
 impl<T: Config> ExportDexGeneral<T::AccountId, T::AssetId> for Pallet<T> {
+    fn get_trading_pairs() -> Vec<(T::AssetId, T::AssetId)> {
+        PairStatuses::<T>::iter()
+            .filter_map(|(pair, status)| match status {
+                Trading(_) if !Self::is_batch_only(pair) => Some(pair),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn get_amount_in_by_path(
         amount_out: AssetBalance,
         path: &[T::AssetId],
@@ -1062,7 +1238,26 @@ impl<T: Config> ExportDexGeneral<T::AccountId, T::AssetId> for Pallet<T> {
     }
 }
 
+impl<T: Config> ExportDexGeneralTwap<T::AssetId, BlockNumberFor<T>> for Pallet<T> {
+    fn observe(asset_0: T::AssetId, asset_1: T::AssetId) -> Result<PriceObservation<BlockNumberFor<T>>, DispatchError> {
+        Self::price_observation(asset_0, asset_1)
+    }
+
+    fn twap(
+        asset_0: T::AssetId,
+        asset_1: T::AssetId,
+        start: &PriceObservation<BlockNumberFor<T>>,
+        end: &PriceObservation<BlockNumberFor<T>>,
+    ) -> Result<FixedU128, DispatchError> {
+        Self::calculate_twap(asset_0, asset_1, start, end)
+    }
+}
+
 impl<AccountId, AssetId> ExportDexGeneral<AccountId, AssetId> for () {
+    fn get_trading_pairs() -> Vec<(AssetId, AssetId)> {
+        Vec::new()
+    }
+
     fn get_amount_in_by_path(_amount_out: AssetBalance, _path: &[AssetId]) -> Result<Vec<AssetBalance>, DispatchError> {
         unimplemented!()
     }
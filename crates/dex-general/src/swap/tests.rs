@@ -2,12 +2,12 @@
 // Licensed under Apache 2.0.
 
 use super::{mock::*, Error};
-use crate::{primitives::PairStatus::Trading, DEFAULT_FEE_RATE};
+use crate::{primitives::PairStatus::Trading, DynamicFeeParameters, PairFeeRate, DEFAULT_FEE_RATE, FEE_ADJUSTMENT};
 use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
 use sp_core::U256;
-use sp_runtime::{traits::Zero, DispatchError::BadOrigin};
+use sp_runtime::{traits::Zero, DispatchError::BadOrigin, FixedPointNumber, FixedU128, Permill};
 
 const DOT_ASSET_ID: CurrencyId = CurrencyId::Token(2);
 const BTC_ASSET_ID: CurrencyId = CurrencyId::Token(3);
@@ -2553,3 +2553,316 @@ fn bootstrap_zero_reward_claim_should_work() {
         assert_eq!(<Test as Config>::MultiCurrency::free_balance(KSM_ASSET_ID, &ALICE), 0);
     })
 }
+
+fn create_dot_btc_pair_at_block_1() {
+    System::set_block_number(1);
+    assert_ok!(<Test as Config>::MultiCurrency::deposit(
+        DOT_ASSET_ID,
+        &ALICE,
+        MAX_BALANCE
+    ));
+    assert_ok!(<Test as Config>::MultiCurrency::deposit(
+        BTC_ASSET_ID,
+        &ALICE,
+        MAX_BALANCE
+    ));
+    assert_ok!(DexPallet::create_pair(
+        RawOrigin::Root.into(),
+        DOT_ASSET_ID,
+        BTC_ASSET_ID,
+        DEFAULT_FEE_RATE,
+    ));
+    // 1 DOT = 0.1 BTC, with 10 decimals difference
+    assert_ok!(DexPallet::inner_add_liquidity(
+        &ALICE,
+        DOT_ASSET_ID,
+        BTC_ASSET_ID,
+        10 * DOT_UNIT,
+        1 * BTC_UNIT,
+        0,
+        0
+    ));
+}
+
+#[test]
+fn twap_should_average_price_over_blocks() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let start = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+
+        System::set_block_number(11);
+        // trades only move the average price from the next block onwards
+        assert_ok!(DexPallet::inner_swap_exact_assets_for_assets(
+            &ALICE,
+            10 * DOT_UNIT,
+            0,
+            &vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            &ALICE,
+        ));
+        let middle = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+        assert_eq!(middle.block_number, 11);
+        assert_eq!(
+            DexPallet::calculate_twap(DOT_ASSET_ID, BTC_ASSET_ID, &start, &middle),
+            Ok(FixedU128::from_rational(1, 100_000_000))
+        );
+        assert_eq!(
+            DexPallet::calculate_twap(BTC_ASSET_ID, DOT_ASSET_ID, &start, &middle),
+            Ok(FixedU128::saturating_from_integer(100_000_000))
+        );
+
+        let pair_account = DexPallet::pair_account_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        let reserve_dot = <Test as Config>::MultiCurrency::free_balance(DOT_ASSET_ID, &pair_account);
+        let reserve_btc = <Test as Config>::MultiCurrency::free_balance(BTC_ASSET_ID, &pair_account);
+        let price_after_swap = FixedU128::from_inner(reserve_btc * FixedU128::DIV / reserve_dot);
+
+        System::set_block_number(21);
+        let end = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+        assert_eq!(
+            DexPallet::calculate_twap(DOT_ASSET_ID, BTC_ASSET_ID, &middle, &end),
+            Ok(price_after_swap)
+        );
+        assert_eq!(
+            DexPallet::calculate_twap(DOT_ASSET_ID, BTC_ASSET_ID, &start, &end),
+            Ok(FixedU128::from_inner(
+                (FixedU128::from_rational(1, 100_000_000).into_inner() + price_after_swap.into_inner()) / 2
+            ))
+        );
+    })
+}
+
+#[test]
+fn twap_should_ignore_donations() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let start = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+
+        let pair_account = DexPallet::pair_account_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        assert_ok!(<Test as Config>::MultiCurrency::transfer(
+            BTC_ASSET_ID,
+            &ALICE,
+            &pair_account,
+            1 * BTC_UNIT
+        ));
+
+        System::set_block_number(11);
+        let end = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+        assert_eq!(
+            DexPallet::calculate_twap(DOT_ASSET_ID, BTC_ASSET_ID, &start, &end),
+            Ok(FixedU128::from_rational(1, 100_000_000))
+        );
+    })
+}
+
+#[test]
+fn twap_with_invalid_period_should_not_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID),
+            Error::<Test>::PairNotExists
+        );
+
+        create_dot_btc_pair_at_block_1();
+        let start = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+        System::set_block_number(11);
+        let end = DexPallet::price_observation(DOT_ASSET_ID, BTC_ASSET_ID).unwrap();
+
+        assert_noop!(
+            DexPallet::calculate_twap(DOT_ASSET_ID, BTC_ASSET_ID, &start, &start),
+            Error::<Test>::InvalidTwapPeriod
+        );
+        assert_noop!(
+            DexPallet::calculate_twap(DOT_ASSET_ID, BTC_ASSET_ID, &end, &start),
+            Error::<Test>::InvalidTwapPeriod
+        );
+    })
+}
+
+fn dynamic_fee_parameters(volatility_fee_rate: u128, max_fee_rate: u128) -> DynamicFeeParameters {
+    DynamicFeeParameters {
+        base_fee_rate: DEFAULT_FEE_RATE,
+        max_fee_rate,
+        volatility_fee_rate,
+        decay: Permill::from_percent(50),
+    }
+}
+
+#[test]
+fn dynamic_fee_should_follow_volatility() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let pair = DexPallet::sort_asset_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        let parameters = dynamic_fee_parameters(1000, 300);
+        assert_ok!(DexPallet::set_dynamic_fee(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            Some(parameters),
+        ));
+        assert_eq!(DexPallet::pair_fee_rate(pair), DEFAULT_FEE_RATE);
+
+        assert_ok!(DexPallet::inner_swap_exact_assets_for_assets(
+            &ALICE,
+            1 * DOT_UNIT,
+            0,
+            &vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            &ALICE,
+        ));
+
+        // the price of DOT in BTC fell by the relative change of the reserves
+        let pair_account = DexPallet::pair_account_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        let reserve_dot = <Test as Config>::MultiCurrency::free_balance(DOT_ASSET_ID, &pair_account);
+        let reserve_btc = <Test as Config>::MultiCurrency::free_balance(BTC_ASSET_ID, &pair_account);
+        let price_before = 1 * BTC_UNIT * FixedU128::DIV / (10 * DOT_UNIT);
+        let price_after = reserve_btc * FixedU128::DIV / reserve_dot;
+        let volatility = FixedU128::from_inner((price_before - price_after) * FixedU128::DIV / price_before);
+        assert_eq!(DexPallet::fee_volatility(pair).volatility, volatility);
+
+        let fee_rate = DEFAULT_FEE_RATE + volatility.saturating_mul_int(1000);
+        assert!(fee_rate > DEFAULT_FEE_RATE && fee_rate < 300);
+        assert_eq!(DexPallet::pair_fee_rate(pair), fee_rate);
+        assert_eq!(
+            DexPallet::get_pair_fee_rate(BTC_ASSET_ID, DOT_ASSET_ID),
+            Some(PairFeeRate {
+                fee_rate,
+                static_fee_rate: DEFAULT_FEE_RATE,
+                dynamic_fee: Some(parameters),
+                volatility,
+            })
+        );
+
+        // half of the volatility is forgotten every block
+        System::set_block_number(2);
+        assert_eq!(
+            DexPallet::pair_fee_rate(pair),
+            DEFAULT_FEE_RATE + FixedU128::from_inner(volatility.into_inner() / 2).saturating_mul_int(1000)
+        );
+
+        System::set_block_number(100);
+        assert_eq!(DexPallet::pair_fee_rate(pair), DEFAULT_FEE_RATE);
+    })
+}
+
+#[test]
+fn dynamic_fee_should_be_charged_up_to_max_fee_rate() {
+    new_test_ext().execute_with(|| {
+        create_dot_btc_pair_at_block_1();
+        let pair = DexPallet::sort_asset_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        assert_ok!(DexPallet::set_dynamic_fee(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            Some(dynamic_fee_parameters(9999, 100)),
+        ));
+
+        assert_ok!(DexPallet::inner_swap_exact_assets_for_assets(
+            &ALICE,
+            1 * DOT_UNIT,
+            0,
+            &vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            &ALICE,
+        ));
+        assert_eq!(DexPallet::pair_fee_rate(pair), 100);
+
+        let pair_account = DexPallet::pair_account_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        let reserve_dot = <Test as Config>::MultiCurrency::free_balance(DOT_ASSET_ID, &pair_account);
+        let reserve_btc = <Test as Config>::MultiCurrency::free_balance(BTC_ASSET_ID, &pair_account);
+        assert_eq!(
+            DexPallet::get_amount_out_by_path(DOT_UNIT, &[DOT_ASSET_ID, BTC_ASSET_ID]),
+            Ok(vec![
+                DOT_UNIT,
+                DexPallet::get_amount_out(DOT_UNIT, reserve_dot, reserve_btc, 100).unwrap()
+            ])
+        );
+    })
+}
+
+#[test]
+fn set_dynamic_fee_should_work() {
+    new_test_ext().execute_with(|| {
+        let parameters = dynamic_fee_parameters(1000, 300);
+        assert_noop!(
+            DexPallet::set_dynamic_fee(RawOrigin::Root.into(), DOT_ASSET_ID, BTC_ASSET_ID, Some(parameters)),
+            Error::<Test>::PairNotExists
+        );
+
+        create_dot_btc_pair_at_block_1();
+        let pair = DexPallet::sort_asset_id(DOT_ASSET_ID, BTC_ASSET_ID);
+        assert_noop!(
+            DexPallet::set_dynamic_fee(
+                RawOrigin::Signed(ALICE).into(),
+                DOT_ASSET_ID,
+                BTC_ASSET_ID,
+                Some(parameters)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            DexPallet::set_dynamic_fee(
+                RawOrigin::Root.into(),
+                DOT_ASSET_ID,
+                BTC_ASSET_ID,
+                Some(dynamic_fee_parameters(1000, DEFAULT_FEE_RATE - 1))
+            ),
+            Error::<Test>::InvalidFeeRate
+        );
+        assert_noop!(
+            DexPallet::set_dynamic_fee(
+                RawOrigin::Root.into(),
+                DOT_ASSET_ID,
+                BTC_ASSET_ID,
+                Some(dynamic_fee_parameters(1000, FEE_ADJUSTMENT))
+            ),
+            Error::<Test>::InvalidFeeRate
+        );
+
+        assert_ok!(DexPallet::set_dynamic_fee(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            Some(parameters)
+        ));
+        assert_ok!(DexPallet::inner_swap_exact_assets_for_assets(
+            &ALICE,
+            1 * DOT_UNIT,
+            0,
+            &vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            &ALICE,
+        ));
+        assert!(DexPallet::pair_fee_rate(pair) > DEFAULT_FEE_RATE);
+
+        // the static fee rate applies again
+        assert_ok!(DexPallet::set_exchange_fee(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            50
+        ));
+        assert_ok!(DexPallet::set_dynamic_fee(
+            RawOrigin::Root.into(),
+            DOT_ASSET_ID,
+            BTC_ASSET_ID,
+            None
+        ));
+        assert_eq!(DexPallet::pair_fee_rate(pair), 50);
+        assert_eq!(DexPallet::fee_volatility(pair), Default::default());
+        assert_eq!(
+            DexPallet::get_pair_fee_rate(DOT_ASSET_ID, BTC_ASSET_ID),
+            Some(PairFeeRate {
+                fee_rate: 50,
+                static_fee_rate: 50,
+                dynamic_fee: None,
+                volatility: Zero::zero(),
+            })
+        );
+
+        // volatility is not tracked for static fees
+        assert_ok!(DexPallet::inner_swap_exact_assets_for_assets(
+            &ALICE,
+            1 * DOT_UNIT,
+            0,
+            &vec![DOT_ASSET_ID, BTC_ASSET_ID],
+            &ALICE,
+        ));
+        assert_eq!(DexPallet::fee_volatility(pair), Default::default());
+    })
+}
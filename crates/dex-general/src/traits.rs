@@ -18,6 +18,9 @@ pub trait GenerateLpAssetId<AssetId> {
 }
 
 pub trait ExportDexGeneral<AccountId, AssetId> {
+    /// The sorted assets of all pairs which are open for immediate swaps.
+    fn get_trading_pairs() -> Vec<(AssetId, AssetId)>;
+
     fn get_amount_in_by_path(amount_out: AssetBalance, path: &[AssetId]) -> Result<Vec<AssetBalance>, DispatchError>;
 
     fn get_amount_out_by_path(amount_in: AssetBalance, path: &[AssetId]) -> Result<Vec<AssetBalance>, DispatchError>;
@@ -58,3 +61,18 @@ pub trait ExportDexGeneral<AccountId, AssetId> {
         recipient: &AccountId,
     ) -> DispatchResult;
 }
+
+/// Manipulation-resistant prices derived from the price accumulators of the trading pairs.
+pub trait ExportDexGeneralTwap<AssetId, BlockNumber> {
+    /// Snapshot of the price accumulators of the pair, as of the current block.
+    fn observe(asset_0: AssetId, asset_1: AssetId) -> Result<PriceObservation<BlockNumber>, DispatchError>;
+
+    /// The time-weighted average price of `asset_0` in `asset_1` between two observations
+    /// of the pair, `start` being the older one.
+    fn twap(
+        asset_0: AssetId,
+        asset_1: AssetId,
+        start: &PriceObservation<BlockNumber>,
+        end: &PriceObservation<BlockNumber>,
+    ) -> Result<FixedU128, DispatchError>;
+}
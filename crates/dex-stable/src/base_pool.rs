@@ -103,9 +103,10 @@ impl<T: Config> Pallet<T> {
 
         let mut d0 = Balance::default();
         let amp = Self::get_a_precise(pool).ok_or(Error::<T>::Arithmetic)?;
+        let rates = Self::base_pool_rates(pool)?;
         if lp_total_supply > Zero::zero() {
             d0 = Self::get_d(
-                &Self::xp(&pool.balances, &pool.token_multipliers).ok_or(Error::<T>::Arithmetic)?,
+                &Self::rated_xp(&pool.balances, &rates).ok_or(Error::<T>::Arithmetic)?,
                 amp,
             )
             .ok_or(Error::<T>::Arithmetic)?;
@@ -128,7 +129,7 @@ impl<T: Config> Pallet<T> {
         }
 
         let mut d1 = Self::get_d(
-            &Self::xp(&new_balances, &pool.token_multipliers).ok_or(Error::<T>::Arithmetic)?,
+            &Self::rated_xp(&new_balances, &rates).ok_or(Error::<T>::Arithmetic)?,
             amp,
         )
         .ok_or(Error::<T>::Arithmetic)?;
@@ -142,6 +143,7 @@ impl<T: Config> Pallet<T> {
         } else {
             (mint_amount, fees) = Self::calculate_base_mint_amount(
                 pool,
+                &rates,
                 &mut new_balances,
                 d0,
                 &mut d1,
@@ -183,38 +185,16 @@ impl<T: Config> Pallet<T> {
 
         let in_amount = Self::do_transfer_in(pool.currency_ids[i], who, &pool.account, in_amount)?;
 
-        let normalized_balances = Self::xp(&pool.balances, &pool.token_multipliers).ok_or(Error::<T>::Arithmetic)?;
-
-        let x = in_amount
-            .checked_mul(pool.token_multipliers[i])
-            .and_then(|n| n.checked_add(normalized_balances[i]))
-            .ok_or(Error::<T>::Arithmetic)?;
-
-        let y = Self::get_y(pool, i, j, x, &normalized_balances).ok_or(Error::<T>::Arithmetic)?;
-
-        let mut dy = normalized_balances[j]
-            .checked_sub(y)
-            .and_then(|n| n.checked_sub(One::one()))
-            .ok_or(Error::<T>::Arithmetic)?;
-
-        let dy_fee = U256::from(dy)
-            .checked_mul(U256::from(pool.fee))
-            .and_then(|n| n.checked_div(U256::from(FEE_DENOMINATOR)))
-            .and_then(|n| TryInto::<Balance>::try_into(n).ok())
-            .ok_or(Error::<T>::Arithmetic)?;
-
-        dy = dy
-            .checked_sub(dy_fee)
-            .and_then(|n| n.checked_div(pool.token_multipliers[j]))
-            .ok_or(Error::<T>::Arithmetic)?;
+        let rates = Self::base_pool_rates(pool)?;
+        let (dy, dy_fee) = Self::base_pool_swap_amount(pool, &rates, i, j, in_amount).ok_or(Error::<T>::Arithmetic)?;
 
         ensure!(dy >= out_min_amount, Error::<T>::AmountSlippage);
 
         let admin_fee = U256::from(dy_fee)
             .checked_mul(U256::from(pool.admin_fee))
             .and_then(|n| n.checked_div(U256::from(FEE_DENOMINATOR)))
-            .and_then(|n| n.checked_div(U256::from(pool.token_multipliers[j])))
             .and_then(|n| TryInto::<Balance>::try_into(n).ok())
+            .and_then(|n| Self::div_rate(n, rates[j]))
             .ok_or(Error::<T>::Arithmetic)?;
 
         // update pool balance
@@ -240,6 +220,72 @@ impl<T: Config> Pallet<T> {
         Ok(dy)
     }
 
+    /// The output amount and the normalized fee of a swap, as executed by `base_pool_swap`.
+    pub(crate) fn base_pool_swap_amount(
+        pool: &BasePool<T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
+        rates: &[Balance],
+        i: usize,
+        j: usize,
+        in_amount: Balance,
+    ) -> Option<(Balance, Balance)> {
+        let normalized_balances = Self::rated_xp(&pool.balances, rates)?;
+
+        let x = Self::mul_rate(in_amount, rates[i]).and_then(|n| n.checked_add(normalized_balances[i]))?;
+
+        let y = Self::get_y(pool, i, j, x, &normalized_balances)?;
+
+        let dy = normalized_balances[j].checked_sub(y)?.checked_sub(One::one())?;
+
+        let dy_fee = U256::from(dy)
+            .checked_mul(U256::from(pool.fee))
+            .and_then(|n| n.checked_div(U256::from(FEE_DENOMINATOR)))
+            .and_then(|n| TryInto::<Balance>::try_into(n).ok())?;
+
+        let dy = Self::div_rate(dy.checked_sub(dy_fee)?, rates[j])?;
+
+        Some((dy, dy_fee))
+    }
+
+    /// The amount of lp currency minted by `base_pool_add_liquidity`, for a pool that already
+    /// has liquidity.
+    pub(crate) fn base_pool_mint_amount(
+        pool: &BasePool<T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
+        amounts: &[Balance],
+    ) -> Option<Balance> {
+        if pool.currency_ids.len() != amounts.len() {
+            return None;
+        }
+        let lp_total_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+        if lp_total_supply.is_zero() {
+            return None;
+        }
+        let fee_per_token = Self::calculate_fee_per_token(pool)?;
+        let amp = Self::get_a_precise(pool)?;
+        let rates = Self::base_pool_rates(pool).ok()?;
+        let d0 = Self::get_d(&Self::rated_xp(&pool.balances, &rates)?, amp)?;
+
+        let mut new_balances = pool.balances.to_vec();
+        for (balance, amount) in new_balances.iter_mut().zip(amounts) {
+            *balance = balance.checked_add(*amount)?;
+        }
+        let mut d1 = Self::get_d(&Self::rated_xp(&new_balances, &rates)?, amp)?;
+        if d1 <= d0 {
+            return None;
+        }
+
+        Self::calculate_base_mint_amount(
+            &mut pool.clone(),
+            &rates,
+            &mut new_balances,
+            d0,
+            &mut d1,
+            fee_per_token,
+            amp,
+            lp_total_supply,
+        )
+        .map(|(mint_amount, _)| mint_amount)
+    }
+
     pub(crate) fn base_pool_remove_liquidity_one_currency(
         pool_id: T::PoolId,
         pool: &mut BasePool<T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
@@ -334,7 +380,7 @@ impl<T: Config> Pallet<T> {
         pool: &BasePool<T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
     ) -> Option<Balance> {
         let d = Self::get_d(
-            &Self::xp(&pool.balances, &pool.token_multipliers)?,
+            &Self::rated_xp(&pool.balances, &Self::base_pool_rates(pool).ok()?)?,
             Self::get_a_precise(pool)?,
         )?;
 
@@ -358,15 +404,16 @@ impl<T: Config> Pallet<T> {
         let currencies_len = pool.currency_ids.len();
         let fee_per_token = U256::from(Self::calculate_fee_per_token(pool)?);
         let amp = Self::get_a_precise(pool)?;
+        let rates = Self::base_pool_rates(pool).ok()?;
 
         let mut new_balances = pool.balances.clone();
-        let d0 = U256::from(Self::get_d(&Self::xp(&pool.balances, &pool.token_multipliers)?, amp)?);
+        let d0 = U256::from(Self::get_d(&Self::rated_xp(&pool.balances, &rates)?, amp)?);
 
         for (i, x) in amounts.iter().enumerate() {
             new_balances[i] = new_balances[i].checked_sub(*x)?;
         }
 
-        let d1 = U256::from(Self::get_d(&Self::xp(&new_balances, &pool.token_multipliers)?, amp)?);
+        let d1 = U256::from(Self::get_d(&Self::rated_xp(&new_balances, &rates)?, amp)?);
         let mut fees = vec![Balance::default(); currencies_len];
         let fee_denominator = U256::from(FEE_DENOMINATOR);
 
@@ -389,7 +436,7 @@ impl<T: Config> Pallet<T> {
             new_balances[i] = new_balances[i].checked_sub(fees[i])?;
         }
 
-        let d1 = Self::get_d(&Self::xp(&new_balances, &pool.token_multipliers)?, amp)?;
+        let d1 = Self::get_d(&Self::rated_xp(&new_balances, &rates)?, amp)?;
         let burn_amount = d0
             .checked_sub(U256::from(d1))?
             .checked_mul(U256::from(total_supply))?
@@ -410,7 +457,8 @@ impl<T: Config> Pallet<T> {
         let total_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
 
         let amp = Self::get_a_precise(pool)?;
-        let xp = Self::xp(&pool.balances, &pool.token_multipliers)?;
+        let rates = Self::base_pool_rates(pool).ok()?;
+        let xp = Self::rated_xp(&pool.balances, &rates)?;
         let d0 = Self::get_d(&xp, amp)?;
 
         let d1 = U256::from(d0)
@@ -449,14 +497,9 @@ impl<T: Config> Pallet<T> {
         }
 
         let mut dy = xp_reduced[index as usize].checked_sub(Self::get_yd(pool, amp, index, &xp_reduced, d1)?)?;
-        dy = dy
-            .checked_sub(One::one())?
-            .checked_div(pool.token_multipliers[index as usize])?;
+        dy = Self::div_rate(dy.checked_sub(One::one())?, rates[index as usize])?;
 
-        let fee = xp[index as usize]
-            .checked_sub(new_y)?
-            .checked_div(pool.token_multipliers[index as usize])?
-            .checked_sub(dy)?;
+        let fee = Self::div_rate(xp[index as usize].checked_sub(new_y)?, rates[index as usize])?.checked_sub(dy)?;
 
         Some((dy, fee))
     }
@@ -472,14 +515,17 @@ impl<T: Config> Pallet<T> {
             return None;
         }
 
-        let normalized_balances = Self::xp(&pool.balances, &pool.token_multipliers)?;
-        let new_in_balance = normalized_balances[i].checked_add(in_balance.checked_mul(pool.token_multipliers[i])?)?;
+        let rates = Self::base_pool_rates(pool).ok()?;
+        let normalized_balances = Self::rated_xp(&pool.balances, &rates)?;
+        let new_in_balance = normalized_balances[i].checked_add(Self::mul_rate(in_balance, rates[i])?)?;
 
         let out_balance = Self::get_y(pool, i, j, new_in_balance, &normalized_balances)?;
-        let mut out_amount = normalized_balances[j]
-            .checked_sub(out_balance)?
-            .checked_sub(One::one())?
-            .checked_div(pool.token_multipliers[j])?;
+        let mut out_amount = Self::div_rate(
+            normalized_balances[j]
+                .checked_sub(out_balance)?
+                .checked_sub(One::one())?,
+            rates[j],
+        )?;
 
         let fee = U256::from(out_amount)
             .checked_mul(U256::from(pool.fee))?
@@ -493,6 +539,7 @@ impl<T: Config> Pallet<T> {
 
     pub(crate) fn calculate_base_mint_amount(
         pool: &mut BasePool<T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
+        rates: &[Balance],
         new_balances: &mut [Balance],
         d0: Balance,
         d1: &mut Balance,
@@ -527,7 +574,7 @@ impl<T: Config> Pallet<T> {
 
             new_balances[i] = new_balances[i].checked_sub(fees[i])?;
         }
-        *d1 = Self::get_d(&Self::xp(new_balances, &pool.token_multipliers)?, amp)?;
+        *d1 = Self::get_d(&Self::rated_xp(new_balances, rates)?, amp)?;
 
         let mint_amount = U256::from(total_supply)
             .checked_mul(U256::from(*d1).checked_sub(U256::from(d0))?)?
@@ -564,8 +611,9 @@ impl<T: Config> Pallet<T> {
     ) -> Result<Balance, DispatchError> {
         ensure!(pool.currency_ids.len() == amounts.len(), Error::<T>::MismatchParameter);
         let amp = Self::get_a_precise(pool).ok_or(Error::<T>::Arithmetic)?;
+        let rates = Self::base_pool_rates(pool)?;
 
-        let d0 = Self::xp(&pool.balances, &pool.token_multipliers)
+        let d0 = Self::rated_xp(&pool.balances, &rates)
             .and_then(|xp| Self::get_d(&xp, amp))
             .ok_or(Error::<T>::Arithmetic)?;
 
@@ -578,7 +626,7 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        let d1 = Self::xp(&new_balances, &pool.token_multipliers)
+        let d1 = Self::rated_xp(&new_balances, &rates)
             .and_then(|xp| Self::get_d(&xp, amp))
             .ok_or(Error::<T>::Arithmetic)?;
 
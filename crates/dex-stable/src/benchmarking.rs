@@ -137,6 +137,7 @@ fn setup_meta_pool_and_add_liquidity<T: Config>(
     where
         T: Config,
         T::CurrencyId: From<u32>,
+        T::RateSource: From<u32>,
         T: pallet_timestamp::Config,
 
 )]
@@ -161,6 +162,26 @@ pub mod benchmarks {
         );
     }
 
+    #[benchmark]
+    pub fn create_rate_pool(b: Linear<2, 10>, s: Linear<0, 50>) {
+        let admin_fee_receiver: T::AccountId = whitelisted_caller();
+        let currency_ids: Vec<T::CurrencyId> = base_currencies::<T>(b);
+        let rate_sources: Vec<Option<T::RateSource>> = (0..b).map(|c| Some(c.into())).collect();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            currency_ids,
+            vec![12; b as usize],
+            rate_sources,
+            INITIAL_A_VALUE,
+            SWAP_FEE,
+            ADMIN_FEE,
+            admin_fee_receiver,
+            vec![0; s as usize],
+        );
+    }
+
     #[benchmark]
     pub fn create_meta_pool(m: Linear<2, 10>, s: Linear<0, 50>) {
         let caller: T::AccountId = whitelisted_caller();
@@ -9,6 +9,10 @@
 //! - Basic: pairs two or more stablecoins
 //! - Meta: pairs stablecoins with the LP token of another base pool
 //!
+//! Basic pools can be created with rate providers for some of their currencies, in which case
+//! the invariant is kept on the value of the balances rather than on the balances themselves.
+//! This allows pooling yield-bearing currencies, such as lend tokens, with their underlying.
+//!
 //! ## Overview
 //!
 //! This pallet provides functionality for:
@@ -42,6 +46,8 @@ pub mod benchmarking;
 mod meta_pool_tests;
 #[cfg(test)]
 mod mock;
+#[cfg(test)]
+mod rate_pool_tests;
 
 mod base_pool;
 mod default_weights;
@@ -63,8 +69,9 @@ use sp_std::{ops::Sub, vec, vec::Vec};
 
 pub use default_weights::WeightInfo;
 pub use pallet::*;
+pub use primitives::RATE_PRECISION;
 use primitives::*;
-use traits::{StablePoolLpCurrencyIdGenerate, ValidateCurrency};
+use traits::{RateProvider, StablePoolLpCurrencyIdGenerate, ValidateCurrency};
 
 #[allow(type_alias_bounds)]
 type AccountIdOf<T: Config> = <T as frame_system::Config>::AccountId;
@@ -96,6 +103,13 @@ pub mod pallet {
         /// The trait get timestamp of chain.
         type TimeProvider: UnixTime;
 
+        /// Identifies the rate of a currency in a rate-provider pool, e.g. a lend token or an
+        /// oracle key.
+        type RateSource: Parameter + Member + MaxEncodedLen;
+
+        /// Get the rates of the currencies in rate-provider pools.
+        type RateProvider: RateProvider<Self::RateSource>;
+
         #[pallet::constant]
         type PoolCurrencyLimit: Get<u32>;
 
@@ -133,6 +147,13 @@ pub mod pallet {
     #[pallet::getter(fn lp_currencies)]
     pub type LpCurrencies<T: Config> = StorageMap<_, Blake2_128Concat, T::CurrencyId, T::PoolId>;
 
+    /// The rate sources of the currencies of a rate-provider pool, `None` for currencies valued
+    /// one to one with the peg of the pool.
+    #[pallet::storage]
+    #[pallet::getter(fn rate_sources)]
+    pub type RateSources<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::PoolId, BoundedVec<Option<T::RateSource>, T::PoolCurrencyLimit>>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -147,6 +168,11 @@ pub mod pallet {
             admin_fee: Number,
             admin_fee_receiver: T::AccountId,
         },
+        /// The currencies of a pool were given rate sources.
+        SetRateSources {
+            pool_id: T::PoolId,
+            rate_sources: Vec<Option<T::RateSource>>,
+        },
         /// A pool's admin_fee_receiver was updated.
         UpdateAdminFeeReceiver {
             pool_id: T::PoolId,
@@ -300,6 +326,10 @@ pub mod pallet {
         InvalidBasePoolLpCurrency,
         /// The token index out of range.
         TokenIndexOutOfRange,
+        /// A rate-provider pool needs a rate source for at least one currency.
+        NoRateSource,
+        /// The rate provider has no rate for a currency of the pool.
+        RateUnavailable,
     }
 
     #[pallet::call]
@@ -1104,6 +1134,63 @@ pub mod pallet {
                 Ok(())
             })
         }
+
+        /// Create a stable amm pool whose currencies are valued through rate providers.
+        ///
+        /// Only admin can create pool.
+        ///
+        /// # Argument
+        ///
+        /// - `currency_ids`: The currencies will be join the created pool.
+        /// - `currency_decimals`: The currencies corresponding decimals.
+        /// - `rate_sources`: The rate source of each currency, `None` for currencies valued one to one with the peg of
+        ///   the pool. The rates are only read when the pool is used, which fails while any of them is unavailable.
+        /// - `a`: The initial A of created pool.
+        /// - `fee`: The swap fee of created pool.
+        /// - `admin_fee`: The admin fee of created pool.
+        /// - `admin_fee_receiver`: The admin fee receiver of created pool.
+        /// - `lp_currency_symbol`: The symbol of created pool lp currency.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::create_rate_pool(
+            currency_ids.len() as u32,
+            T::PoolCurrencySymbolLimit::get()
+        ))]
+        #[transactional]
+        pub fn create_rate_pool(
+            origin: OriginFor<T>,
+            currency_ids: Vec<T::CurrencyId>,
+            currency_decimals: Vec<u32>,
+            rate_sources: Vec<Option<T::RateSource>>,
+            a: Number,
+            fee: Number,
+            admin_fee: Number,
+            admin_fee_receiver: T::AccountId,
+            lp_currency_symbol: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin.clone())?;
+            ensure!(currency_ids.len() == rate_sources.len(), Error::<T>::MismatchParameter);
+            ensure!(rate_sources.iter().any(Option::is_some), Error::<T>::NoRateSource);
+
+            let pool_id = Self::next_pool_id();
+            Self::create_base_pool(
+                origin,
+                currency_ids,
+                currency_decimals,
+                a,
+                fee,
+                admin_fee,
+                admin_fee_receiver,
+                lp_currency_symbol,
+            )?;
+
+            RateSources::<T>::insert(
+                pool_id,
+                BoundedVec::try_from(rate_sources.clone()).map_err(|_| Error::<T>::TooManyCurrencies)?,
+            );
+
+            Self::deposit_event(Event::SetRateSources { pool_id, rate_sources });
+            Ok(())
+        }
     }
 }
 
@@ -1458,6 +1545,90 @@ impl<T: Config> Pallet<T> {
         Ok(out_amount)
     }
 
+    /// The output of `inner_swap`, without executing it.
+    pub(crate) fn calculate_exact_swap(pool_id: T::PoolId, i: usize, j: usize, in_amount: Balance) -> Option<Balance> {
+        match Self::pools(pool_id)? {
+            Pool::Base(bp) => {
+                if i == j || i >= bp.currency_ids.len() || j >= bp.currency_ids.len() {
+                    return None;
+                }
+                let rates = Self::base_pool_rates(&bp).ok()?;
+                Self::base_pool_swap_amount(&bp, &rates, i, j, in_amount).map(|(dy, _)| dy)
+            }
+            Pool::Meta(mp) => {
+                let base_virtual_price = Self::meta_pool_base_virtual_price(&mp)?;
+                Self::calculate_meta_swap_amount(&mp, i, j, in_amount, base_virtual_price).map(|(dy, _)| dy)
+            }
+        }
+    }
+
+    /// The output of `inner_swap_pool_from_base`, without executing it.
+    ///
+    /// The base virtual price cached by the meta pool is assumed not to be refreshed by the swap.
+    pub(crate) fn calculate_exact_swap_pool_from_base(
+        meta_pool_id: T::PoolId,
+        base_pool_id: T::PoolId,
+        in_index: u32,
+        out_index: u32,
+        dx: Balance,
+    ) -> Option<Balance> {
+        let base_pool = match Self::pools(base_pool_id)? {
+            Pool::Base(bp) => bp,
+            Pool::Meta(_) => return None,
+        };
+        let meta_pool = match Self::pools(meta_pool_id)? {
+            Pool::Meta(mp) if mp.base_pool_id == base_pool_id => mp,
+            _ => return None,
+        };
+        let base_pool_lp_currency_in_meta_index = meta_pool
+            .info
+            .currency_ids
+            .iter()
+            .position(|c| *c == base_pool.lp_currency_id)?;
+        if base_pool_lp_currency_in_meta_index == out_index as usize {
+            return None;
+        }
+
+        let mut base_amounts = vec![Balance::default(); base_pool.currency_ids.len()];
+        *base_amounts.get_mut(in_index as usize)? = dx;
+        let base_lp_amount = Self::base_pool_mint_amount(&base_pool, &base_amounts)?;
+
+        let base_virtual_price = Self::meta_pool_base_virtual_price(&meta_pool)?;
+        Self::calculate_meta_swap_amount(
+            &meta_pool,
+            base_pool_lp_currency_in_meta_index,
+            out_index as usize,
+            base_lp_amount,
+            base_virtual_price,
+        )
+        .map(|(dy, _)| dy)
+    }
+
+    /// The output of `inner_swap_pool_to_base`, without executing it.
+    pub(crate) fn calculate_exact_swap_pool_to_base(
+        pool_id: T::PoolId,
+        base_pool_id: T::PoolId,
+        in_index: u32,
+        out_index: u32,
+        dx: Balance,
+    ) -> Option<Balance> {
+        let base_pool = match Self::pools(base_pool_id)? {
+            Pool::Base(bp) => bp,
+            Pool::Meta(_) => return None,
+        };
+        let base_pool_currency_index = Self::get_currency_index(pool_id, base_pool.lp_currency_id)?;
+        if base_pool_currency_index == in_index || out_index as usize >= base_pool.currency_ids.len() {
+            return None;
+        }
+
+        let base_lp_amount =
+            Self::calculate_exact_swap(pool_id, in_index as usize, base_pool_currency_index as usize, dx)?;
+        if base_lp_amount.is_zero() {
+            return None;
+        }
+        Self::calculate_base_remove_liquidity_one_token(&base_pool, base_lp_amount, out_index).map(|(dy, _)| dy)
+    }
+
     pub(crate) fn calculate_currency_amount(
         pool_id: T::PoolId,
         amounts: Vec<Balance>,
@@ -532,7 +532,7 @@ impl<T: Config> Pallet<T> {
         Some(xp)
     }
 
-    fn meta_pool_base_virtual_price(
+    pub(crate) fn meta_pool_base_virtual_price(
         meta_pool: &MetaPool<T::PoolId, T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
     ) -> Option<Balance> {
         let now = T::TimeProvider::now().as_secs();
@@ -20,7 +20,7 @@ use sp_runtime::{
 
 use crate as dex_stable;
 use crate::{
-    traits::{StablePoolLpCurrencyIdGenerate, ValidateCurrency},
+    traits::{RateProvider, StablePoolLpCurrencyIdGenerate, ValidateCurrency},
     Config, Pallet,
 };
 use orml_traits::{parameter_type_with_key, MultiCurrency};
@@ -37,6 +37,7 @@ parameter_types! {
     pub const MinimumPeriod: Moment = SLOT_DURATION / 2;
     pub const PoolCurrencyLimit: u32 = 10;
     pub const PoolCurrencySymbolLimit: u32 = 50;
+    pub static CurrencyRates: Vec<(CurrencyId, Balance)> = Vec::new();
 }
 
 parameter_type_with_key! {
@@ -207,6 +208,8 @@ impl Config for Test {
     type EnsurePoolAsset = EnsurePoolAssetImpl<Tokens>;
     type LpGenerate = PoolLpGenerate;
     type TimeProvider = Timestamp;
+    type RateSource = CurrencyId;
+    type RateProvider = MockRateProvider;
     type PoolCurrencyLimit = PoolCurrencyLimit;
     type PoolCurrencySymbolLimit = PoolCurrencySymbolLimit;
     type PalletId = StableAmmPalletId;
@@ -224,6 +227,24 @@ impl ExtBuilder {
 
 pub struct EnsurePoolAssetImpl<Local>(PhantomData<Local>);
 
+pub struct MockRateProvider;
+
+impl RateProvider<CurrencyId> for MockRateProvider {
+    fn get_rate(currency_id: &CurrencyId) -> Option<Balance> {
+        CurrencyRates::get()
+            .into_iter()
+            .find(|(rate_currency_id, _)| rate_currency_id == currency_id)
+            .map(|(_, rate)| rate)
+    }
+}
+
+pub fn set_currency_rate(currency_id: CurrencyId, rate: Balance) {
+    let mut rates = CurrencyRates::get();
+    rates.retain(|(rate_currency_id, _)| *rate_currency_id != currency_id);
+    rates.push((currency_id, rate));
+    CurrencyRates::set(rates);
+}
+
 pub struct PoolLpGenerate;
 
 impl StablePoolLpCurrencyIdGenerate<CurrencyId, PoolId> for PoolLpGenerate {
@@ -13,6 +13,9 @@ pub const POOL_LP_CURRENCY_ID_DECIMAL: u8 = 18;
 
 pub const BASE_VIRTUAL_PRICE_PRECISION: Balance = 1_000_000_000_000_000_000;
 
+// precision of the rates of rate-provider pool currencies
+pub const RATE_PRECISION: Balance = 1_000_000_000_000_000_000;
+
 // protect from division loss when run approximation loop
 pub const A_PRECISION: Number = 100;
 
@@ -0,0 +1,219 @@
+// Copyright 2021-2022 Zenlink.
+// Licensed under Apache 2.0.
+
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError::BadOrigin;
+
+use super::{
+    mock::{CurrencyId::*, *},
+    traits::StableAmmApi,
+    *,
+};
+
+const INITIAL_A_VALUE: Balance = 50;
+const SWAP_FEE: Balance = 1e7 as Balance;
+const ADMIN_FEE: Balance = 0;
+
+/// A pool of token1, worth two token2 each, and token2, balanced in value.
+fn setup_test_rate_pool() -> PoolId {
+    set_currency_rate(Token(TOKEN1_SYMBOL), 2 * RATE_PRECISION);
+
+    let pool_id = StableAmm::next_pool_id();
+    assert_ok!(StableAmm::create_rate_pool(
+        RawOrigin::Root.into(),
+        vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+        vec![TOKEN1_DECIMAL, TOKEN2_DECIMAL],
+        vec![Some(Token(TOKEN1_SYMBOL)), None],
+        INITIAL_A_VALUE,
+        SWAP_FEE,
+        ADMIN_FEE,
+        ALICE,
+        Vec::from("rate_pool_lp"),
+    ));
+
+    assert_ok!(StableAmm::add_liquidity(
+        RawOrigin::Signed(ALICE).into(),
+        pool_id,
+        vec![1e18 as Balance, 2e18 as Balance],
+        0,
+        ALICE,
+        u64::MAX,
+    ));
+    pool_id
+}
+
+#[test]
+fn create_rate_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(StableAmm::create_rate_pool(
+            RawOrigin::Root.into(),
+            vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+            vec![TOKEN1_DECIMAL, TOKEN2_DECIMAL],
+            vec![Some(Token(TOKEN1_SYMBOL)), None],
+            INITIAL_A_VALUE,
+            SWAP_FEE,
+            ADMIN_FEE,
+            ALICE,
+            Vec::from("rate_pool_lp"),
+        ));
+
+        assert!(StableAmm::pools(0).is_some());
+        assert_eq!(
+            StableAmm::rate_sources(0).map(|sources| sources.to_vec()),
+            Some(vec![Some(Token(TOKEN1_SYMBOL)), None])
+        );
+        System::assert_last_event(RuntimeEvent::StableAMM(Event::SetRateSources {
+            pool_id: 0,
+            rate_sources: vec![Some(Token(TOKEN1_SYMBOL)), None],
+        }));
+    });
+}
+
+#[test]
+fn create_rate_pool_with_incorrect_parameter_should_not_work() {
+    new_test_ext().execute_with(|| {
+        // only root can create pool
+        assert_noop!(
+            StableAmm::create_rate_pool(
+                RawOrigin::Signed(ALICE).into(),
+                vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+                vec![TOKEN1_DECIMAL, TOKEN2_DECIMAL],
+                vec![Some(Token(TOKEN1_SYMBOL)), None],
+                INITIAL_A_VALUE,
+                SWAP_FEE,
+                ADMIN_FEE,
+                ALICE,
+                Vec::from("rate_pool_lp"),
+            ),
+            BadOrigin
+        );
+
+        // every currency needs an entry
+        assert_noop!(
+            StableAmm::create_rate_pool(
+                RawOrigin::Root.into(),
+                vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+                vec![TOKEN1_DECIMAL, TOKEN2_DECIMAL],
+                vec![Some(Token(TOKEN1_SYMBOL))],
+                INITIAL_A_VALUE,
+                SWAP_FEE,
+                ADMIN_FEE,
+                ALICE,
+                Vec::from("rate_pool_lp"),
+            ),
+            Error::<Test>::MismatchParameter
+        );
+
+        // a pool without rate sources is a base pool
+        assert_noop!(
+            StableAmm::create_rate_pool(
+                RawOrigin::Root.into(),
+                vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+                vec![TOKEN1_DECIMAL, TOKEN2_DECIMAL],
+                vec![None, None],
+                INITIAL_A_VALUE,
+                SWAP_FEE,
+                ADMIN_FEE,
+                ALICE,
+                Vec::from("rate_pool_lp"),
+            ),
+            Error::<Test>::NoRateSource
+        );
+
+        assert_eq!(StableAmm::next_pool_id(), 0);
+        assert_eq!(StableAmm::pools(0), None);
+        assert_eq!(StableAmm::rate_sources(0), None);
+    });
+}
+
+#[test]
+fn rate_pool_swap_should_trade_by_value() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_rate_pool();
+        let amount_in = 1e16 as Balance;
+
+        let quoted = StableAmm::quote_swap(pool_id, 0, 1, amount_in).unwrap();
+        let calculated = StableAmm::stable_amm_calculate_swap_amount(pool_id, 0, 1, amount_in).unwrap();
+
+        let token2_before = get_user_balance(Token(TOKEN2_SYMBOL), &BOB);
+        assert_ok!(StableAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            0,
+            1,
+            amount_in,
+            0,
+            BOB,
+            u64::MAX,
+        ));
+        let received = get_user_balance(Token(TOKEN2_SYMBOL), &BOB) - token2_before;
+
+        // one token1 buys about two token2, less the 0.1% swap fee
+        assert!(received > 1_997e13 as Balance && received < 2e16 as Balance);
+        assert_eq!(quoted, received);
+        assert!(calculated <= received && received - calculated < 10);
+    });
+}
+
+#[test]
+fn rate_pool_virtual_price_should_follow_rate() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_rate_pool();
+        assert_eq!(StableAmm::get_virtual_price(pool_id), 1e18 as Balance);
+
+        // token1 accrues 10%, so the pool value grows by 5%
+        set_currency_rate(Token(TOKEN1_SYMBOL), 22 * RATE_PRECISION / 10);
+        let virtual_price = StableAmm::get_virtual_price(pool_id);
+        assert!(virtual_price > 1_049e15 as Balance && virtual_price <= 1_050e15 as Balance);
+
+        // removing liquidity in balance is independent of the rates
+        let lp_currency_id = StableAmm::pools(pool_id).unwrap().get_lp_currency();
+        let lp_supply = <Test as Config>::MultiCurrency::total_issuance(lp_currency_id);
+        let lp_amount = lp_supply / 2;
+        assert_eq!(
+            StableAmm::stable_amm_calculate_remove_liquidity(pool_id, lp_amount),
+            Some(vec![
+                1e18 as Balance * lp_amount / lp_supply,
+                2e18 as Balance * lp_amount / lp_supply
+            ])
+        );
+    });
+}
+
+#[test]
+fn rate_pool_without_rate_should_not_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_rate_pool();
+        set_currency_rate(Token(TOKEN1_SYMBOL), 0);
+
+        assert_noop!(
+            StableAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                0,
+                1,
+                1e16 as Balance,
+                0,
+                BOB,
+                u64::MAX,
+            ),
+            Error::<Test>::RateUnavailable
+        );
+        assert_noop!(
+            StableAmm::add_liquidity(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                vec![1e16 as Balance, 1e16 as Balance],
+                0,
+                BOB,
+                u64::MAX,
+            ),
+            Error::<Test>::RateUnavailable
+        );
+        assert_eq!(StableAmm::quote_swap(pool_id, 0, 1, 1e16 as Balance), None);
+        assert_eq!(StableAmm::get_virtual_price(pool_id), 0);
+    });
+}
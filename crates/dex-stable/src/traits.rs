@@ -12,6 +12,20 @@ pub trait StablePoolLpCurrencyIdGenerate<CurrencyId, PoolId> {
     fn generate_by_pool_id(pool_id: PoolId) -> CurrencyId;
 }
 
+/// Provides the rates of the currencies of rate-provider pools, such as the exchange rate of a lend
+/// token to its underlying currency.
+pub trait RateProvider<RateSource> {
+    /// The value of one unit of a currency in units of the peg of its pool, with `RATE_PRECISION`,
+    /// or `None` if the rate is currently unavailable.
+    fn get_rate(source: &RateSource) -> Option<Balance>;
+}
+
+impl<RateSource> RateProvider<RateSource> for () {
+    fn get_rate(_source: &RateSource) -> Option<Balance> {
+        None
+    }
+}
+
 pub trait StableAmmApi<PoolId, CurrencyId, AccountId, Balance> {
     fn stable_amm_calculate_currency_amount(
         pool_id: PoolId,
@@ -31,6 +45,34 @@ pub trait StableAmmApi<PoolId, CurrencyId, AccountId, Balance> {
 
     fn currency_index(pool_id: PoolId, currency: CurrencyId) -> Option<u32>;
 
+    fn pool_ids() -> Vec<PoolId>;
+
+    fn pool_currencies(pool_id: PoolId) -> Vec<CurrencyId>;
+
+    /// The base pool of a meta pool, `None` for base pools.
+    fn base_pool_id(pool_id: PoolId) -> Option<PoolId>;
+
+    /// Output of `swap`, computed exactly as the swap would.
+    fn quote_swap(pool_id: PoolId, from_index: u32, to_index: u32, in_amount: Balance) -> Option<Balance>;
+
+    /// Output of `swap_pool_from_base`, computed exactly as the swap would.
+    fn quote_swap_pool_from_base(
+        pool_id: PoolId,
+        base_pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        dx: Balance,
+    ) -> Option<Balance>;
+
+    /// Output of `swap_pool_to_base`, computed exactly as the swap would.
+    fn quote_swap_pool_to_base(
+        pool_id: PoolId,
+        base_pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        dx: Balance,
+    ) -> Option<Balance>;
+
     fn add_liquidity(
         who: &AccountId,
         pool_id: PoolId,
@@ -158,6 +200,45 @@ impl<T: Config> StableAmmApi<T::PoolId, T::CurrencyId, T::AccountId, Balance> fo
         Self::get_currency_index(pool_id, currency)
     }
 
+    fn pool_ids() -> Vec<T::PoolId> {
+        Pools::<T>::iter_keys().collect()
+    }
+
+    fn pool_currencies(pool_id: T::PoolId) -> Vec<T::CurrencyId> {
+        Self::get_currencies(pool_id)
+    }
+
+    fn base_pool_id(pool_id: T::PoolId) -> Option<T::PoolId> {
+        match Self::pools(pool_id)? {
+            Pool::Base(_) => None,
+            Pool::Meta(mp) => Some(mp.base_pool_id),
+        }
+    }
+
+    fn quote_swap(pool_id: T::PoolId, from_index: u32, to_index: u32, in_amount: Balance) -> Option<Balance> {
+        Self::calculate_exact_swap(pool_id, from_index as usize, to_index as usize, in_amount)
+    }
+
+    fn quote_swap_pool_from_base(
+        pool_id: T::PoolId,
+        base_pool_id: T::PoolId,
+        in_index: u32,
+        out_index: u32,
+        dx: Balance,
+    ) -> Option<Balance> {
+        Self::calculate_exact_swap_pool_from_base(pool_id, base_pool_id, in_index, out_index, dx)
+    }
+
+    fn quote_swap_pool_to_base(
+        pool_id: T::PoolId,
+        base_pool_id: T::PoolId,
+        in_index: u32,
+        out_index: u32,
+        dx: Balance,
+    ) -> Option<Balance> {
+        Self::calculate_exact_swap_pool_to_base(pool_id, base_pool_id, in_index, out_index, dx)
+    }
+
     fn add_liquidity(
         who: &T::AccountId,
         pool_id: T::PoolId,
@@ -12,6 +12,53 @@ impl<T: Config> Pallet<T> {
         Some(normalized_res)
     }
 
+    /// The rates converting the balances of a base pool to `POOL_TOKEN_COMMON_DECIMALS`, with
+    /// `RATE_PRECISION`. For rate-provider pools these include the current rates of the
+    /// currencies, so that the invariant is kept on the value of the balances.
+    pub(crate) fn base_pool_rates(
+        pool: &BasePool<T::CurrencyId, T::AccountId, T::PoolCurrencyLimit, T::PoolCurrencySymbolLimit>,
+    ) -> Result<Vec<Balance>, Error<T>> {
+        let rate_sources = Self::lp_currencies(pool.lp_currency_id).and_then(Self::rate_sources);
+
+        let mut rates = Vec::new();
+        for (i, multiplier) in pool.token_multipliers.iter().enumerate() {
+            let mut rate = multiplier.checked_mul(RATE_PRECISION).ok_or(Error::<T>::Arithmetic)?;
+            if let Some(source) = rate_sources.as_ref().and_then(|sources| sources.get(i)?.as_ref()) {
+                let source_rate = T::RateProvider::get_rate(source)
+                    .filter(|rate| !rate.is_zero())
+                    .ok_or(Error::<T>::RateUnavailable)?;
+                rate = Self::mul_rate(rate, source_rate).ok_or(Error::<T>::Arithmetic)?;
+            }
+            rates.push(rate);
+        }
+        Ok(rates)
+    }
+
+    /// `xp` for rates with `RATE_PRECISION`.
+    pub(crate) fn rated_xp(balances: &[Balance], rates: &[Balance]) -> Option<Vec<Balance>> {
+        balances
+            .iter()
+            .zip(rates)
+            .map(|(balance, rate)| Self::mul_rate(*balance, *rate))
+            .collect()
+    }
+
+    /// Scales `amount` by a rate with `RATE_PRECISION`, rounding down.
+    pub(crate) fn mul_rate(amount: Balance, rate: Balance) -> Option<Balance> {
+        U256::from(amount)
+            .checked_mul(U256::from(rate))
+            .and_then(|n| n.checked_div(U256::from(RATE_PRECISION)))
+            .and_then(|n| TryInto::<Balance>::try_into(n).ok())
+    }
+
+    /// Scales `amount` by the inverse of a rate with `RATE_PRECISION`, rounding down.
+    pub(crate) fn div_rate(amount: Balance, rate: Balance) -> Option<Balance> {
+        U256::from(amount)
+            .checked_mul(U256::from(RATE_PRECISION))
+            .and_then(|n| n.checked_div(U256::from(rate)))
+            .and_then(|n| TryInto::<Balance>::try_into(n).ok())
+    }
+
     pub(crate) fn get_d(balances: &[Balance], amp: Balance) -> Option<Balance> {
         let n_currencies = Balance::from(balances.len() as u64);
         let sum = Self::sum_of(balances)?;
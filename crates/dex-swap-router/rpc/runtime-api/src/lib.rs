@@ -0,0 +1,35 @@
+// Copyright 2021-2022 Zenlink.
+// Licensed under Apache 2.0.
+
+//! Runtime API definition for the swap router.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+use codec::Codec;
+use dex_swap_router::{LimitOrder, OrderId, RouteQuote};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait DexSwapRouterApi<AccountId, PoolId, CurrencyId, Balance, BlockNumber> where
+        AccountId: Codec,
+        PoolId: Codec,
+        CurrencyId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        fn find_best_route(
+            currency_in: CurrencyId,
+            currency_out: CurrencyId,
+            amount_in: Balance,
+            max_hops: u32,
+        ) -> Option<RouteQuote<PoolId, CurrencyId, Balance>>;
+
+        fn get_open_orders(
+            currency_in: CurrencyId,
+            currency_out: CurrencyId,
+        ) -> Vec<(OrderId, LimitOrder<AccountId, CurrencyId, Balance, BlockNumber>)>;
+    }
+}
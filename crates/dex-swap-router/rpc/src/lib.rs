@@ -0,0 +1,149 @@
+// Copyright 2021-2022 Zenlink.
+// Licensed under Apache 2.0.
+
+//! RPC interface for the swap router pallet.
+#![allow(clippy::type_complexity)]
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+use dex_swap_router::{LimitOrder, OrderId, RouteQuote};
+pub use dex_swap_router_rpc_runtime_api::DexSwapRouterApi as DexSwapRouterRuntimeApi;
+
+#[rpc(client, server)]
+pub trait DexSwapRouterApi<BlockHash, AccountId, PoolId, CurrencyId, Balance, BlockNumber> {
+    #[method(name = "dexSwapRouter_findBestRoute")]
+    fn find_best_route(
+        &self,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        amount_in: Balance,
+        max_hops: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RouteQuote<PoolId, CurrencyId, NumberOrHex>>>;
+
+    #[method(name = "dexSwapRouter_getOpenOrders")]
+    fn get_open_orders(
+        &self,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(OrderId, LimitOrder<AccountId, CurrencyId, NumberOrHex, BlockNumber>)>>;
+}
+
+pub struct DexSwapRouter<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> DexSwapRouter<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, PoolId, CurrencyId, Balance, BlockNumber>
+    DexSwapRouterApiServer<<Block as BlockT>::Hash, AccountId, PoolId, CurrencyId, Balance, BlockNumber>
+    for DexSwapRouter<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    PoolId: Codec,
+    CurrencyId: Codec,
+    Balance: Codec + Into<NumberOrHex>,
+    BlockNumber: Codec,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: DexSwapRouterRuntimeApi<Block, AccountId, PoolId, CurrencyId, Balance, BlockNumber>,
+{
+    fn find_best_route(
+        &self,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        amount_in: Balance,
+        max_hops: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<RouteQuote<PoolId, CurrencyId, NumberOrHex>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.find_best_route(at, currency_in, currency_out, amount_in, max_hops)
+            .map(|quote| {
+                quote.map(|quote| RouteQuote {
+                    routes: quote.routes,
+                    amounts: quote.amounts.into_iter().map(Into::into).collect(),
+                    amount_out: quote.amount_out.into(),
+                })
+            })
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_open_orders(
+        &self,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(OrderId, LimitOrder<AccountId, CurrencyId, NumberOrHex, BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_open_orders(at, currency_in, currency_out)
+            .map(|orders| {
+                orders
+                    .into_iter()
+                    .map(|(order_id, order)| {
+                        (
+                            order_id,
+                            LimitOrder {
+                                owner: order.owner,
+                                currency_in: order.currency_in,
+                                currency_out: order.currency_out,
+                                amount_in: order.amount_in.into(),
+                                amount_out_min: order.amount_out_min.into(),
+                                expiry: order.expiry,
+                            },
+                        )
+                    })
+                    .collect()
+            })
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Display) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "error in swap router pallet",
+        Some(err.to_string()),
+    ))
+    .into()
+}
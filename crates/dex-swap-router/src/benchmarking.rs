@@ -4,9 +4,73 @@
 
 use super::*;
 use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
 use sp_std::prelude::*;
+use traits::SwapApi;
 
-#[benchmarks(where T::CurrencyId: From<u32>)]
+const UNIT: u128 = 1_000_000_000_000;
+
+const ASSET_0: u32 = 0;
+const ASSET_1: u32 = 1;
+
+fn place_order<T: Config>(owner: &T::AccountId) -> OrderId
+where
+    T::CurrencyId: From<u32>,
+{
+    assert_ok!(T::MultiCurrency::deposit(ASSET_0.into(), owner, (1000 * UNIT).into()));
+    assert_ok!(Pallet::<T>::place_order(
+        RawOrigin::Signed(owner.clone()).into(),
+        ASSET_0.into(),
+        ASSET_1.into(),
+        UNIT.into(),
+        (UNIT / 2).into(),
+        100u32.into(),
+    ));
+    Pallet::<T>::next_order_id() - 1
+}
+
+/// Creates `h` general hops `ASSET_0 -> X -> ASSET_1` through `h / 2` intermediate assets,
+/// each of which is quoted by the search of `SwapApi::swap_for_exact`.
+fn swap_api_hops<T: Config + dex_general::Config<AssetId = T::CurrencyId>>(
+    owner: &T::AccountId,
+    h: u32,
+) -> BoundedVec<Hop<T::StablePoolId, T::CurrencyId>, ConstU32<MAX_SWAP_API_HOPS>>
+where
+    T::CurrencyId: From<u32>,
+{
+    let mut add_pair = |asset_0: u32, asset_1: u32| {
+        assert_ok!(T::MultiCurrency::deposit(asset_0.into(), owner, (1000 * UNIT).into()));
+        assert_ok!(T::MultiCurrency::deposit(asset_1.into(), owner, (1000 * UNIT).into()));
+        assert_ok!(dex_general::Pallet::<T>::create_pair(
+            RawOrigin::Root.into(),
+            asset_0.into(),
+            asset_1.into(),
+            dex_general::DEFAULT_FEE_RATE
+        ));
+        assert_ok!(dex_general::Pallet::<T>::add_liquidity(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_0.into(),
+            asset_1.into(),
+            10 * UNIT,
+            10 * UNIT,
+            0,
+            0,
+            100u32.into()
+        ));
+    };
+
+    let mut hops = Vec::new();
+    for asset in (ASSET_1 + 1..).take((h / 2) as usize) {
+        add_pair(ASSET_0, asset);
+        add_pair(asset, ASSET_1);
+        hops.push(Hop::General(ASSET_0.into(), asset.into()));
+        hops.push(Hop::General(asset.into(), ASSET_1.into()));
+    }
+    hops.try_into().expect("At most MAX_SWAP_API_HOPS hops")
+}
+
+#[benchmarks(where T::CurrencyId: From<u32>, T: dex_general::Config<AssetId = T::CurrencyId>)]
 pub mod benchmarks {
     use super::*;
     use crate::Pallet as DexSwapRouter;
@@ -26,5 +90,87 @@ pub mod benchmarks {
         }
     }
 
+    #[benchmark]
+    pub fn place_order() {
+        let caller: T::AccountId = whitelisted_caller();
+        assert_ok!(T::MultiCurrency::deposit(ASSET_0.into(), &caller, (1000 * UNIT).into()));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            ASSET_0.into(),
+            ASSET_1.into(),
+            UNIT.into(),
+            (UNIT / 2).into(),
+            100u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn cancel_order() {
+        let caller: T::AccountId = whitelisted_caller();
+        let order_id = super::place_order::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), order_id);
+    }
+
+    #[benchmark]
+    pub fn fill_order() {
+        let caller: T::AccountId = whitelisted_caller();
+        let order_id = super::place_order::<T>(&caller);
+
+        assert_ok!(T::MultiCurrency::deposit(ASSET_1.into(), &caller, (1000 * UNIT).into()));
+        assert_ok!(dex_general::Pallet::<T>::create_pair(
+            RawOrigin::Root.into(),
+            ASSET_0.into(),
+            ASSET_1.into(),
+            dex_general::DEFAULT_FEE_RATE
+        ));
+        assert_ok!(dex_general::Pallet::<T>::add_liquidity(
+            RawOrigin::Signed(caller).into(),
+            ASSET_0.into(),
+            ASSET_1.into(),
+            10 * UNIT,
+            10 * UNIT,
+            0,
+            0,
+            100u32.into()
+        ));
+
+        let keeper: T::AccountId = account("keeper", 0, 0);
+        let routes = vec![Route::General(vec![ASSET_0.into(), ASSET_1.into()])];
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(keeper), order_id, UNIT.into(), routes);
+    }
+
+    #[benchmark]
+    pub fn swap_for_exact(h: Linear<2, MAX_SWAP_API_HOPS>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let hops = super::swap_api_hops::<T>(&caller, h);
+        SwapApiHops::<T>::put(hops);
+
+        #[block]
+        {
+            assert_ok!(<DexSwapRouter<T> as SwapApi<_, _, _>>::swap_for_exact(
+                &caller,
+                ASSET_0.into(),
+                ASSET_1.into(),
+                UNIT.into(),
+                (10 * UNIT).into(),
+            ));
+        }
+    }
+
+    #[benchmark]
+    pub fn set_swap_api_hops(h: Linear<2, MAX_SWAP_API_HOPS>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let hops = super::swap_api_hops::<T>(&caller, h);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, hops);
+    }
+
     impl_benchmark_test_suite!(DexSwapRouter, crate::mock::ExtBuilder::build(), crate::mock::Test);
 }
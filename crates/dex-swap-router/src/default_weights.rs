@@ -38,6 +38,11 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for dex_swap_router.
 pub trait WeightInfo {
 	fn validate_routes(a: u32, ) -> Weight;
+	fn place_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn fill_order() -> Weight;
+	fn swap_for_exact(h: u32) -> Weight;
+	fn set_swap_api_hops(h: u32) -> Weight;
 }
 
 /// Weights for dex_swap_router using the Substrate node and recommended hardware.
@@ -53,6 +58,102 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 2_409
 			.saturating_add(Weight::from_parts(111_119, 0).saturating_mul(a.into()))
 	}
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter NextOrderId (r:1 w:1)
+	/// Proof: DexSwapRouter NextOrderId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter Orders (r:0 w:1)
+	/// Proof: DexSwapRouter Orders (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter PairOrders (r:0 w:1)
+	/// Proof: DexSwapRouter PairOrders (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1162`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_338_000 picoseconds.
+		Weight::from_parts(72_604_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexSwapRouter Orders (r:1 w:1)
+	/// Proof: DexSwapRouter Orders (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter PairOrders (r:0 w:1)
+	/// Proof: DexSwapRouter PairOrders (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1401`
+		//  Estimated: `6196`
+		// Minimum execution time: 68_912_000 picoseconds.
+		Weight::from_parts(70_118_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: DexSwapRouter Orders (r:1 w:1)
+	/// Proof: DexSwapRouter Orders (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:6 w:6)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PriceCumulatives (r:1 w:1)
+	/// Proof Skipped: DexGeneral PriceCumulatives (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexSwapRouter PairOrders (r:0 w:1)
+	/// Proof: DexSwapRouter PairOrders (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2214`
+		//  Estimated: `16590`
+		// Minimum execution time: 204_663_000 picoseconds.
+		Weight::from_parts(207_930_000, 16590)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: DexSwapRouter SwapApiHops (r:1 w:0)
+	/// Proof: DexSwapRouter SwapApiHops (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PairStatuses (r:18 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:38 w:6)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `h` is `[2, 16]`.
+	fn swap_for_exact(h: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2431`
+		//  Estimated: `13338`
+		// Minimum execution time: 131_502_000 picoseconds.
+		Weight::from_parts(98_217_316, 13338)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(14_862_093, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexGeneral PairStatuses (r:16 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:32 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter SwapApiHops (r:0 w:1)
+	/// Proof: DexSwapRouter SwapApiHops (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// The range of component `h` is `[2, 16]`.
+	fn set_swap_api_hops(h: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1372`
+		//  Estimated: `6196`
+		// Minimum execution time: 31_204_000 picoseconds.
+		Weight::from_parts(19_873_522, 6196)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_937_214, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -67,4 +168,100 @@ impl WeightInfo for () {
 			// Standard Error: 2_409
 			.saturating_add(Weight::from_parts(111_119, 0).saturating_mul(a.into()))
 	}
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter NextOrderId (r:1 w:1)
+	/// Proof: DexSwapRouter NextOrderId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter Orders (r:0 w:1)
+	/// Proof: DexSwapRouter Orders (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter PairOrders (r:0 w:1)
+	/// Proof: DexSwapRouter PairOrders (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1162`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_338_000 picoseconds.
+		Weight::from_parts(72_604_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexSwapRouter Orders (r:1 w:1)
+	/// Proof: DexSwapRouter Orders (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter PairOrders (r:0 w:1)
+	/// Proof: DexSwapRouter PairOrders (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1401`
+		//  Estimated: `6196`
+		// Minimum execution time: 68_912_000 picoseconds.
+		Weight::from_parts(70_118_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: DexSwapRouter Orders (r:1 w:1)
+	/// Proof: DexSwapRouter Orders (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:6 w:6)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PriceCumulatives (r:1 w:1)
+	/// Proof Skipped: DexGeneral PriceCumulatives (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DexSwapRouter PairOrders (r:0 w:1)
+	/// Proof: DexSwapRouter PairOrders (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2214`
+		//  Estimated: `16590`
+		// Minimum execution time: 204_663_000 picoseconds.
+		Weight::from_parts(207_930_000, 16590)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: DexSwapRouter SwapApiHops (r:1 w:0)
+	/// Proof: DexSwapRouter SwapApiHops (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PairStatuses (r:18 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:38 w:6)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `h` is `[2, 16]`.
+	fn swap_for_exact(h: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2431`
+		//  Estimated: `13338`
+		// Minimum execution time: 131_502_000 picoseconds.
+		Weight::from_parts(98_217_316, 13338)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(14_862_093, 0).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexGeneral PairStatuses (r:16 w:0)
+	/// Proof Skipped: DexGeneral PairStatuses (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:32 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: DexSwapRouter SwapApiHops (r:0 w:1)
+	/// Proof: DexSwapRouter SwapApiHops (max_values: Some(1), max_size: Some(513), added: 1008, mode: MaxEncodedLen)
+	/// The range of component `h` is `[2, 16]`.
+	fn set_swap_api_hops(h: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1372`
+		//  Estimated: `6196`
+		// Minimum execution time: 31_204_000 picoseconds.
+		Weight::from_parts(19_873_522, 6196)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_937_214, 0).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(h.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
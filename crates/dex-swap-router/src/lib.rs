@@ -19,20 +19,35 @@ pub mod benchmarking;
 mod default_weights;
 pub use default_weights::WeightInfo;
 
+mod rpc;
+pub use rpc::{RouteQuote, MAX_ROUTE_HOPS};
+
 use codec::{Decode, Encode};
 
 use frame_support::{
     dispatch::{Codec, DispatchResult},
     pallet_prelude::*,
-    transactional,
+    storage::with_storage_layer,
+    transactional, PalletId,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use orml_traits::MultiCurrency;
+use sp_arithmetic::Rounding;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Saturating, Zero},
+    ArithmeticError, Permill,
 };
-use sp_runtime::traits::{AtLeast32BitUnsigned, One, Zero};
-use sp_std::{fmt::Debug, prelude::*, vec::Vec};
+use sp_std::{cell::Cell, fmt::Debug, prelude::*, vec, vec::Vec};
 
+use dex_concentrated::{traits::ConcentratedAmmApi, WeightInfo as DexConcentratedWeightInfo, MAX_TICK_CROSSINGS};
 use dex_general::{AssetBalance, ExportDexGeneral, WeightInfo as DexGeneralWeightInfo};
 use dex_stable::{traits::StableAmmApi, WeightInfo as DexStableWeightInfo};
+use dex_weighted::{traits::WeightedAmmApi, WeightInfo as DexWeightedWeightInfo};
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct StablePath<PoolId, CurrencyId> {
     pub pool_id: PoolId,
     pub base_pool_id: PoolId,
@@ -41,17 +56,39 @@ pub struct StablePath<PoolId, CurrencyId> {
     pub to_currency: CurrencyId,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum StableSwapMode {
     Single,
     FromBase,
     ToBase,
 }
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct WeightedPath<PoolId, CurrencyId> {
+    pub pool_id: PoolId,
+    pub from_currency: CurrencyId,
+    pub to_currency: CurrencyId,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ConcentratedPath<PoolId, CurrencyId> {
+    pub pool_id: PoolId,
+    pub from_currency: CurrencyId,
+    pub to_currency: CurrencyId,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum Route<PoolId, CurrencyId> {
     General(Vec<CurrencyId>),
     Stable(StablePath<PoolId, CurrencyId>),
+    Weighted(WeightedPath<PoolId, CurrencyId>),
+    Concentrated(ConcentratedPath<PoolId, CurrencyId>),
 }
 
 impl<PoolId, CurrencyId: Clone> Route<PoolId, CurrencyId> {
@@ -59,6 +96,8 @@ impl<PoolId, CurrencyId: Clone> Route<PoolId, CurrencyId> {
         match self {
             Route::General(x) => x.first().cloned(),
             Route::Stable(x) => Some(x.from_currency.clone()),
+            Route::Weighted(x) => Some(x.from_currency.clone()),
+            Route::Concentrated(x) => Some(x.from_currency.clone()),
         }
     }
 
@@ -66,15 +105,81 @@ impl<PoolId, CurrencyId: Clone> Route<PoolId, CurrencyId> {
         match self {
             Route::General(x) => x.last().cloned(),
             Route::Stable(x) => Some(x.to_currency.clone()),
+            Route::Weighted(x) => Some(x.to_currency.clone()),
+            Route::Concentrated(x) => Some(x.to_currency.clone()),
+        }
+    }
+}
+
+/// A single hop searched on chain by `SwapApi`, see `SwapApiHops`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hop<PoolId, CurrencyId> {
+    General(CurrencyId, CurrencyId),
+    Stable(StablePath<PoolId, CurrencyId>),
+    Weighted(WeightedPath<PoolId, CurrencyId>),
+    Concentrated(ConcentratedPath<PoolId, CurrencyId>),
+}
+
+impl<PoolId, CurrencyId> From<Hop<PoolId, CurrencyId>> for Route<PoolId, CurrencyId> {
+    fn from(hop: Hop<PoolId, CurrencyId>) -> Self {
+        match hop {
+            Hop::General(currency_in, currency_out) => Route::General(vec![currency_in, currency_out]),
+            Hop::Stable(path) => Route::Stable(path),
+            Hop::Weighted(path) => Route::Weighted(path),
+            Hop::Concentrated(path) => Route::Concentrated(path),
         }
     }
 }
 
+/// A share of the amount of a split swap, traded along its own routes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitRoute<PoolId, CurrencyId> {
+    pub proportion: Permill,
+    pub routes: Vec<Route<PoolId, CurrencyId>>,
+}
+
+pub type OrderId = u64;
+
+/// A resting order to sell `amount_in` of `currency_in` for at least `amount_out_min` of
+/// `currency_out`. The input stays escrowed until the order is filled, cancelled or expires.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LimitOrder<AccountId, CurrencyId, Balance, BlockNumber> {
+    pub owner: AccountId,
+    pub currency_in: CurrencyId,
+    pub currency_out: CurrencyId,
+    /// The input which is not filled yet.
+    pub amount_in: Balance,
+    /// The minimum output of the remaining input, reduced pro rata by partial fills.
+    pub amount_out_min: Balance,
+    /// The first block in which the order can no longer be filled.
+    pub expiry: BlockNumber,
+}
+
+/// Number of proportional estimates made before searching for the input of a stable path.
+const AMOUNT_IN_ESTIMATES: usize = 4;
+
+/// Upper bound for the number of quotes made while searching for the input of a stable path.
+/// A quote does the computation of a swap without its writes, so each is charged as a swap.
+const MAX_AMOUNT_IN_QUOTES: u32 = 24;
+
+/// Upper bound for the number of hops of the routes searched on chain by `SwapApi::swap_for_exact`.
+pub const SWAP_API_MAX_HOPS: u32 = 2;
+
+/// Upper bound for the number of hops in `SwapApiHops`.
+pub const MAX_SWAP_API_HOPS: u32 = 16;
+
 pub use pallet::*;
 
 #[allow(type_alias_bounds)]
 type AccountIdOf<T: Config> = <T as frame_system::Config>::AccountId;
 
+#[allow(type_alias_bounds)]
+type LimitOrderOf<T: Config> = LimitOrder<AccountIdOf<T>, T::CurrencyId, T::Balance, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -84,7 +189,7 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        type StablePoolId: Parameter + Codec + Copy + Ord + AtLeast32BitUnsigned + Zero + One + Default;
+        type StablePoolId: Parameter + Codec + Copy + Ord + AtLeast32BitUnsigned + Zero + One + Default + MaxEncodedLen;
 
         type Balance: Parameter
             + Member
@@ -106,17 +211,98 @@ pub mod pallet {
 
         type StableAmm: StableAmmApi<Self::StablePoolId, Self::CurrencyId, AccountIdOf<Self>, Self::Balance>;
 
+        /// The weighted amm, whose pools share their id type with the stable amm.
+        type WeightedAmm: WeightedAmmApi<Self::StablePoolId, Self::CurrencyId, AccountIdOf<Self>, Self::Balance>;
+
+        /// The concentrated liquidity amm, whose pools also share their id type with the stable amm.
+        type ConcentratedAmm: ConcentratedAmmApi<Self::StablePoolId, Self::CurrencyId, AccountIdOf<Self>, Self::Balance>;
+
+        /// The currencies escrowed by limit orders.
+        type MultiCurrency: MultiCurrency<AccountIdOf<Self>, CurrencyId = Self::CurrencyId, Balance = Self::Balance>;
+
+        /// The pallet id of the account escrowing the input of limit orders.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The share of the output of a limit order fill paid to the keeper executing it.
+        #[pallet::constant]
+        type KeeperRewardRate: Get<Permill>;
+
         /// Weight information for extrinsics in this pallet.
         type GeneralWeightInfo: DexGeneralWeightInfo;
         type StableWeightInfo: DexStableWeightInfo;
+        type WeightedWeightInfo: DexWeightedWeightInfo;
+        type ConcentratedWeightInfo: DexConcentratedWeightInfo;
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::storage]
+    #[pallet::getter(fn next_order_id)]
+    pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn order)]
+    pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, LimitOrderOf<T>, OptionQuery>;
+
+    /// The ids of the open orders by their input and output currency.
+    #[pallet::storage]
+    pub type PairOrders<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, (T::CurrencyId, T::CurrencyId), Twox64Concat, OrderId, (), OptionQuery>;
+
+    /// The last order visited by the `on_idle` sweep, which continues after it in the next block.
+    #[pallet::storage]
+    pub type SweepCursor<T: Config> = StorageValue<_, OrderId, OptionQuery>;
+
+    /// The hops through which `SwapApi` searches routes on chain, e.g. to swap fees. Bounding
+    /// them bounds the cost of the search, which is charged by `SwapApi::swap_for_exact_weight`.
+    /// Unlike the RPC, which searches all pools, the on-chain search is deliberately limited to
+    /// these hops: the cost of a search over all live pools can't be bounded up front.
+    #[pallet::storage]
+    #[pallet::getter(fn swap_api_hops)]
+    pub type SwapApiHops<T: Config> =
+        StorageValue<_, BoundedVec<Hop<T::StablePoolId, T::CurrencyId>, ConstU32<MAX_SWAP_API_HOPS>>, ValueQuery>;
+
     #[pallet::event]
-    pub enum Event<T: Config> {}
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A limit order was placed and its input escrowed.
+        OrderPlaced {
+            order_id: OrderId,
+            owner: T::AccountId,
+            currency_in: T::CurrencyId,
+            currency_out: T::CurrencyId,
+            amount_in: T::Balance,
+            amount_out_min: T::Balance,
+            expiry: BlockNumberFor<T>,
+        },
+        /// A limit order was filled, partially if inputs remain. Fills of the `on_idle` sweep
+        /// have no keeper.
+        OrderFilled {
+            order_id: OrderId,
+            keeper: Option<T::AccountId>,
+            amount_in: T::Balance,
+            amount_out: T::Balance,
+            keeper_reward: T::Balance,
+        },
+        /// A limit order was cancelled by its owner and the remaining input refunded.
+        OrderCancelled { order_id: OrderId, amount_in: T::Balance },
+        /// A limit order expired and the remaining input was refunded.
+        OrderExpired { order_id: OrderId, amount_in: T::Balance },
+        /// The hops searched by `SwapApi` were replaced.
+        SwapApiHopsSet {
+            hops: Vec<Hop<T::StablePoolId, T::CurrencyId>>,
+        },
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_orders(n, remaining_weight)
+        }
+    }
 
     #[pallet::error]
     pub enum Error<T> {
@@ -126,29 +312,26 @@ pub mod pallet {
         ConvertCurrencyFailed,
         AmountSlippage,
         InvalidPath,
+        InvalidSplits,
+        InsufficientLiquidity,
+        InvalidOrder,
+        OrderNotFound,
+        NotOrderOwner,
+        OrderExpired,
+        InvalidFillAmount,
+        OrderPriceNotMet,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Atomically execute a series of trades using `DexGeneral` and/or `DexStable`.
+        /// Atomically execute a series of trades using `DexGeneral`, `DexStable`, `DexWeighted`
+        /// and/or `DexConcentrated`.
         /// The whole transaction will rollback if any of the trades fail.
         ///
         /// ## Complexity
         /// - O(T) where T is the number of trades.
         #[pallet::call_index(0)]
-        #[pallet::weight({
-            routes.iter()
-                .map(|route| match route {
-                    Route::General(path) => T::GeneralWeightInfo::swap_exact_assets_for_assets(path.len() as u32),
-                    Route::Stable(stable_path) => match stable_path.mode {
-                        StableSwapMode::Single => T::StableWeightInfo::swap(),
-                        StableSwapMode::FromBase => T::StableWeightInfo::swap_pool_from_base(),
-                        StableSwapMode::ToBase => T::StableWeightInfo::swap_pool_to_base(),
-                    }
-                })
-                .fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
-                .saturating_add(T::WeightInfo::validate_routes(routes.len() as u32))
-        })]
+        #[pallet::weight(Pallet::<T>::swap_exact_tokens_for_tokens_weight(routes))]
         #[transactional]
         pub fn swap_exact_tokens_for_tokens(
             origin: OriginFor<T>,
@@ -164,80 +347,536 @@ pub mod pallet {
             let now = frame_system::Pallet::<T>::block_number();
             ensure!(deadline > now, Error::<T>::Deadline);
 
-            Self::validate_routes(&routes)?;
+            Self::do_swap_exact_tokens_for_tokens(&who, amount_in, amount_out_min, routes, &to)?;
 
-            let mut amount_out = amount_in;
-            let mut receiver = who.clone();
+            Ok(())
+        }
 
-            let num_routes = routes.len();
-            for (i, route) in routes.into_iter().enumerate() {
-                if i == num_routes - 1 {
-                    receiver = to.clone();
-                }
-                match route {
-                    Route::General(path) => {
-                        let amounts = T::GeneralAmm::get_amount_out_by_path(amount_out.into(), &path)?;
-                        Self::swap(&who, amount_out, &path, &receiver)?;
-                        amount_out = T::Balance::from(*amounts.last().ok_or(Error::<T>::InvalidPath)?);
-                    }
-                    Route::Stable(stable_path) => {
-                        (amount_out) = Self::stable_swap(&who, &stable_path, amount_out, &receiver)?;
-                    }
-                }
+        /// Atomically execute a series of trades using `DexGeneral`, `DexStable`, `DexWeighted`
+        /// and/or `DexConcentrated` such that `to` receives exactly `amount_out`. The required
+        /// inputs are computed from the last route backwards, any trade but a `DexGeneral` one may
+        /// return slightly more than the next route requires, in which case the excess stays with
+        /// the caller.
+        ///
+        /// ## Complexity
+        /// - O(T) where T is the number of trades.
+        #[pallet::call_index(1)]
+        #[pallet::weight(Pallet::<T>::swap_tokens_for_exact_tokens_weight(routes))]
+        #[transactional]
+        pub fn swap_tokens_for_exact_tokens(
+            origin: OriginFor<T>,
+            amount_out: T::Balance,
+            amount_in_max: T::Balance,
+            routes: Vec<Route<T::StablePoolId, T::CurrencyId>>,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // not captured in benchmark because read is whitelisted
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::do_swap_tokens_for_exact_tokens(&who, amount_out, amount_in_max, routes, &to)?;
+
+            Ok(())
+        }
+
+        /// Divide `amount_in` across several series of trades by the proportions of `splits`,
+        /// which must add up to one. Only the combined output is checked against `amount_out_min`.
+        ///
+        /// ## Complexity
+        /// - O(T) where T is the number of trades of all splits.
+        #[pallet::call_index(2)]
+        #[pallet::weight(Pallet::<T>::splits_weight(splits, Pallet::<T>::swap_exact_tokens_for_tokens_weight))]
+        #[transactional]
+        pub fn swap_exact_tokens_for_tokens_split(
+            origin: OriginFor<T>,
+            amount_in: T::Balance,
+            amount_out_min: T::Balance,
+            splits: Vec<SplitRoute<T::StablePoolId, T::CurrencyId>>,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // not captured in benchmark because read is whitelisted
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::validate_splits(&splits)?;
+
+            let mut total_amount_out = T::Balance::zero();
+            let amounts = Self::split_amounts(amount_in, &splits);
+            for (split, amount_in) in splits.into_iter().zip(amounts) {
+                let amount_out =
+                    Self::do_swap_exact_tokens_for_tokens(&who, amount_in, Zero::zero(), split.routes, &to)?;
+                total_amount_out = total_amount_out
+                    .checked_add(&amount_out)
+                    .ok_or(ArithmeticError::Overflow)?;
             }
+            ensure!(total_amount_out >= amount_out_min, Error::<T>::AmountSlippage);
+
+            Ok(())
+        }
+
+        /// Divide `amount_out` across several series of trades by the proportions of `splits`,
+        /// which must add up to one. Only the combined input is checked against `amount_in_max`.
+        ///
+        /// ## Complexity
+        /// - O(T) where T is the number of trades of all splits.
+        #[pallet::call_index(3)]
+        #[pallet::weight(Pallet::<T>::splits_weight(splits, Pallet::<T>::swap_tokens_for_exact_tokens_weight))]
+        #[transactional]
+        pub fn swap_tokens_for_exact_tokens_split(
+            origin: OriginFor<T>,
+            amount_out: T::Balance,
+            amount_in_max: T::Balance,
+            splits: Vec<SplitRoute<T::StablePoolId, T::CurrencyId>>,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // not captured in benchmark because read is whitelisted
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::validate_splits(&splits)?;
+
+            let mut total_amount_in = T::Balance::zero();
+            let amounts = Self::split_amounts(amount_out, &splits);
+            for (split, amount_out) in splits.into_iter().zip(amounts) {
+                let amount_in_left = amount_in_max.saturating_sub(total_amount_in);
+                let amount_in =
+                    Self::do_swap_tokens_for_exact_tokens(&who, amount_out, amount_in_left, split.routes, &to)?;
+                total_amount_in = total_amount_in.saturating_add(amount_in);
+            }
+
+            Ok(())
+        }
+
+        /// Escrow `amount_in` of `currency_in` to be sold for at least `amount_out_min` of
+        /// `currency_out` before block `expiry`. The order is filled by keepers through
+        /// `fill_order`, or through the direct `DexGeneral` pair by the `on_idle` sweep.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::place_order())]
+        #[transactional]
+        pub fn place_order(
+            origin: OriginFor<T>,
+            currency_in: T::CurrencyId,
+            currency_out: T::CurrencyId,
+            amount_in: T::Balance,
+            amount_out_min: T::Balance,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                expiry > now && currency_in != currency_out && !amount_in.is_zero() && !amount_out_min.is_zero(),
+                Error::<T>::InvalidOrder
+            );
 
-            ensure!(amount_out >= amount_out_min, Error::<T>::AmountSlippage);
+            T::MultiCurrency::transfer(currency_in, &who, &Self::escrow_account(), amount_in)?;
+
+            let order_id = NextOrderId::<T>::get();
+            NextOrderId::<T>::put(order_id.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+            Orders::<T>::insert(
+                order_id,
+                LimitOrder {
+                    owner: who.clone(),
+                    currency_in,
+                    currency_out,
+                    amount_in,
+                    amount_out_min,
+                    expiry,
+                },
+            );
+            PairOrders::<T>::insert((currency_in, currency_out), order_id, ());
+
+            Self::deposit_event(Event::OrderPlaced {
+                order_id,
+                owner: who,
+                currency_in,
+                currency_out,
+                amount_in,
+                amount_out_min,
+                expiry,
+            });
 
             Ok(())
         }
+
+        /// Cancel an open order and refund its remaining input to the owner.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::cancel_order())]
+        #[transactional]
+        pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+            ensure!(order.owner == who, Error::<T>::NotOrderOwner);
+
+            Self::close_order(order_id, &order)?;
+
+            Self::deposit_event(Event::OrderCancelled {
+                order_id,
+                amount_in: order.amount_in,
+            });
+
+            Ok(())
+        }
+
+        /// Fill `amount_in` of an open order by trading it along `routes`. The keeper receives
+        /// `KeeperRewardRate` of the output, the owner the rest, which must meet the limit price.
+        ///
+        /// ## Complexity
+        /// - O(T) where T is the number of trades.
+        #[pallet::call_index(6)]
+        // the benchmark includes a single hop swap, the routes are charged on top
+        #[pallet::weight(T::WeightInfo::fill_order().saturating_add(Pallet::<T>::swap_exact_tokens_for_tokens_weight(routes)))]
+        #[transactional]
+        pub fn fill_order(
+            origin: OriginFor<T>,
+            order_id: OrderId,
+            amount_in: T::Balance,
+            routes: Vec<Route<T::StablePoolId, T::CurrencyId>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(order.expiry > now, Error::<T>::OrderExpired);
+
+            Self::do_fill_order(order_id, order, amount_in, routes, Some(&who))?;
+
+            Ok(())
+        }
+
+        /// Replace the hops through which `SwapApi` searches routes on chain.
+        ///
+        /// ## Complexity
+        /// - O(H) where H is the number of hops.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_swap_api_hops(hops.len() as u32))]
+        #[transactional]
+        pub fn set_swap_api_hops(
+            origin: OriginFor<T>,
+            hops: BoundedVec<Hop<T::StablePoolId, T::CurrencyId>, ConstU32<MAX_SWAP_API_HOPS>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            for hop in hops.iter() {
+                Self::validate_hop(&hop.clone().into())?;
+            }
+            SwapApiHops::<T>::put(&hops);
+
+            Self::deposit_event(Event::<T>::SwapApiHopsSet {
+                hops: hops.into_inner(),
+            });
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    fn do_swap_exact_tokens_for_tokens(
+        who: &T::AccountId,
+        amount_in: T::Balance,
+        amount_out_min: T::Balance,
+        routes: Vec<Route<T::StablePoolId, T::CurrencyId>>,
+        to: &T::AccountId,
+    ) -> Result<T::Balance, DispatchError> {
+        Self::validate_routes(&routes)?;
+
+        let mut amount_out = amount_in;
+        let mut receiver = who.clone();
+
+        let num_routes = routes.len();
+        for (i, route) in routes.into_iter().enumerate() {
+            if i == num_routes - 1 {
+                receiver = to.clone();
+            }
+            match route {
+                Route::General(path) => {
+                    let amounts = T::GeneralAmm::get_amount_out_by_path(amount_out.into(), &path)?;
+                    Self::swap(who, amount_out, &path, &receiver)?;
+                    amount_out = T::Balance::from(*amounts.last().ok_or(Error::<T>::InvalidPath)?);
+                }
+                Route::Stable(stable_path) => {
+                    (amount_out) = Self::stable_swap(who, &stable_path, amount_out, &receiver)?;
+                }
+                Route::Weighted(weighted_path) => {
+                    amount_out = Self::weighted_swap(who, &weighted_path, amount_out, &receiver)?;
+                }
+                Route::Concentrated(concentrated_path) => {
+                    amount_out = Self::concentrated_swap(who, &concentrated_path, amount_out, &receiver)?;
+                }
+            }
+        }
+
+        ensure!(amount_out >= amount_out_min, Error::<T>::AmountSlippage);
+
+        Ok(amount_out)
+    }
+
+    fn do_swap_tokens_for_exact_tokens(
+        who: &T::AccountId,
+        amount_out: T::Balance,
+        amount_in_max: T::Balance,
+        routes: Vec<Route<T::StablePoolId, T::CurrencyId>>,
+        to: &T::AccountId,
+    ) -> Result<T::Balance, DispatchError> {
+        Self::validate_routes(&routes)?;
+
+        // amounts[i] is the input of routes[i] and the output of routes[i - 1]
+        let mut amounts = vec![amount_out];
+        for route in routes.iter().rev() {
+            let route_amount_out = *amounts.last().ok_or(Error::<T>::InvalidPath)?;
+            amounts.push(Self::route_amount_in(route, route_amount_out)?);
+        }
+        amounts.reverse();
+
+        let amount_in = amounts[0];
+        ensure!(amount_in <= amount_in_max, Error::<T>::AmountSlippage);
+
+        let mut receiver = who.clone();
+
+        let num_routes = routes.len();
+        for (i, route) in routes.into_iter().enumerate() {
+            if i == num_routes - 1 {
+                receiver = to.clone();
+            }
+            match route {
+                Route::General(path) => {
+                    T::GeneralAmm::inner_swap_assets_for_exact_assets(
+                        who,
+                        amounts[i + 1].into(),
+                        amounts[i].into(),
+                        &path,
+                        &receiver,
+                    )?;
+                }
+                Route::Stable(stable_path) => {
+                    let route_amount_out = Self::stable_swap(who, &stable_path, amounts[i], &receiver)?;
+                    ensure!(route_amount_out >= amounts[i + 1], Error::<T>::AmountSlippage);
+                }
+                Route::Weighted(weighted_path) => {
+                    let route_amount_out = Self::weighted_swap(who, &weighted_path, amounts[i], &receiver)?;
+                    ensure!(route_amount_out >= amounts[i + 1], Error::<T>::AmountSlippage);
+                }
+                Route::Concentrated(concentrated_path) => {
+                    let route_amount_out = Self::concentrated_swap(who, &concentrated_path, amounts[i], &receiver)?;
+                    ensure!(route_amount_out >= amounts[i + 1], Error::<T>::AmountSlippage);
+                }
+            }
+        }
+
+        Ok(amount_in)
+    }
+
+    /// The input required for `route` to return at least `amount_out`.
+    fn route_amount_in(
+        route: &Route<T::StablePoolId, T::CurrencyId>,
+        amount_out: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        match route {
+            Route::General(path) => {
+                let amounts = T::GeneralAmm::get_amount_in_by_path(amount_out.into(), path)?;
+                Ok(T::Balance::from(*amounts.first().ok_or(Error::<T>::InvalidPath)?))
+            }
+            Route::Stable(stable_path) => {
+                let (from_index, to_index) = Self::stable_path_indices(stable_path)?;
+                let quote = |amount_in| match stable_path.mode {
+                    StableSwapMode::Single => {
+                        T::StableAmm::quote_swap(stable_path.pool_id, from_index, to_index, amount_in)
+                    }
+                    StableSwapMode::FromBase => T::StableAmm::quote_swap_pool_from_base(
+                        stable_path.pool_id,
+                        stable_path.base_pool_id,
+                        from_index,
+                        to_index,
+                        amount_in,
+                    ),
+                    StableSwapMode::ToBase => T::StableAmm::quote_swap_pool_to_base(
+                        stable_path.pool_id,
+                        stable_path.base_pool_id,
+                        from_index,
+                        to_index,
+                        amount_in,
+                    ),
+                };
+                Self::search_amount_in(quote, amount_out).ok_or_else(|| Error::<T>::InsufficientLiquidity.into())
+            }
+            Route::Weighted(weighted_path) => {
+                let (from_index, to_index) = Self::weighted_path_indices(weighted_path)?;
+                T::WeightedAmm::quote_swap_for_exact(weighted_path.pool_id, from_index, to_index, amount_out)
+                    .ok_or_else(|| Error::<T>::InsufficientLiquidity.into())
+            }
+            Route::Concentrated(concentrated_path) => {
+                Self::ensure_concentrated_path(concentrated_path)?;
+                T::ConcentratedAmm::quote_swap_for_exact(
+                    concentrated_path.pool_id,
+                    concentrated_path.from_currency,
+                    amount_out,
+                )
+                .ok_or_else(|| Error::<T>::InsufficientLiquidity.into())
+            }
+        }
+    }
+
+    /// Finds the smallest input for which the non-decreasing `quote` returns at least `amount_out`.
+    /// After `MAX_AMOUNT_IN_QUOTES` quotes, the smallest sufficient input found so far is returned.
+    fn search_amount_in(
+        quote: impl Fn(T::Balance) -> Option<T::Balance>,
+        amount_out: T::Balance,
+    ) -> Option<T::Balance> {
+        let quotes_left = Cell::new(MAX_AMOUNT_IN_QUOTES);
+        let quote = |amount_in: AssetBalance| {
+            quotes_left.set(quotes_left.get().checked_sub(1)?);
+            quote(amount_in.into()).map(Into::<AssetBalance>::into)
+        };
+        let amount_out: AssetBalance = amount_out.into();
+        if amount_out.is_zero() {
+            return Some(Zero::zero());
+        }
+
+        // the output of `insufficient` is below `amount_out`, the output of `sufficient` is not
+        let mut insufficient: AssetBalance = 0;
+        let mut sufficient = None;
+
+        // the stable curve is nearly linear, so scaling the input by the missing output converges fast
+        let mut amount_in = amount_out;
+        for _ in 0..AMOUNT_IN_ESTIMATES {
+            let quoted = quote(amount_in)?;
+            if quoted >= amount_out {
+                sufficient = Some(amount_in);
+            } else {
+                insufficient = amount_in;
+            }
+            let upper = sufficient.unwrap_or(AssetBalance::MAX);
+            if upper - insufficient <= 1 {
+                break;
+            }
+            amount_in = multiply_by_rational_with_rounding(amount_in, amount_out, quoted.max(1), Rounding::Up)?
+                .clamp(insufficient + 1, upper - 1);
+        }
+
+        let mut sufficient = match sufficient {
+            Some(sufficient) => sufficient,
+            None => {
+                let mut step: AssetBalance = 1;
+                loop {
+                    let candidate = insufficient.checked_add(step)?;
+                    if quote(candidate)? >= amount_out {
+                        break candidate;
+                    }
+                    insufficient = candidate;
+                    step = step.checked_mul(2)?;
+                }
+            }
+        };
+
+        while sufficient - insufficient > 1 && quotes_left.get() > 0 {
+            let middle = insufficient + (sufficient - insufficient) / 2;
+            if quote(middle)? >= amount_out {
+                sufficient = middle;
+            } else {
+                insufficient = middle;
+            }
+        }
+
+        Some(sufficient.into())
+    }
+
     fn stable_swap(
         who: &T::AccountId,
         path: &StablePath<T::StablePoolId, T::CurrencyId>,
         amount_in: T::Balance,
         to: &T::AccountId,
     ) -> Result<T::Balance, DispatchError> {
+        let (from_index, to_index) = Self::stable_path_indices(path)?;
         let out_amount = match path.mode {
             StableSwapMode::Single => {
-                let from_index = Self::currency_index_from_stable_pool(path.pool_id, path.from_currency)?;
-                let to_index = Self::currency_index_from_stable_pool(path.pool_id, path.to_currency)?;
                 T::StableAmm::swap(who, path.pool_id, from_index, to_index, amount_in, Zero::zero(), to)?
             }
-            StableSwapMode::FromBase => {
-                let from_index = Self::currency_index_from_stable_pool(path.base_pool_id, path.from_currency)?;
-                let to_index = Self::currency_index_from_stable_pool(path.pool_id, path.to_currency)?;
-                T::StableAmm::swap_pool_from_base(
-                    who,
-                    path.pool_id,
-                    path.base_pool_id,
-                    from_index,
-                    to_index,
-                    amount_in,
-                    Zero::zero(),
-                    to,
-                )?
-            }
-            StableSwapMode::ToBase => {
-                let from_index = Self::currency_index_from_stable_pool(path.pool_id, path.from_currency)?;
-                let to_index = Self::currency_index_from_stable_pool(path.base_pool_id, path.to_currency)?;
-                T::StableAmm::swap_pool_to_base(
-                    who,
-                    path.pool_id,
-                    path.base_pool_id,
-                    from_index,
-                    to_index,
-                    amount_in,
-                    Zero::zero(),
-                    to,
-                )?
-            }
+            StableSwapMode::FromBase => T::StableAmm::swap_pool_from_base(
+                who,
+                path.pool_id,
+                path.base_pool_id,
+                from_index,
+                to_index,
+                amount_in,
+                Zero::zero(),
+                to,
+            )?,
+            StableSwapMode::ToBase => T::StableAmm::swap_pool_to_base(
+                who,
+                path.pool_id,
+                path.base_pool_id,
+                from_index,
+                to_index,
+                amount_in,
+                Zero::zero(),
+                to,
+            )?,
         };
         Ok(out_amount)
     }
 
+    /// The indices of the input and output currency of `path` in their respective pools.
+    fn stable_path_indices(path: &StablePath<T::StablePoolId, T::CurrencyId>) -> Result<(u32, u32), DispatchError> {
+        let (from_pool_id, to_pool_id) = match path.mode {
+            StableSwapMode::Single => (path.pool_id, path.pool_id),
+            StableSwapMode::FromBase => (path.base_pool_id, path.pool_id),
+            StableSwapMode::ToBase => (path.pool_id, path.base_pool_id),
+        };
+        Ok((
+            Self::currency_index_from_stable_pool(from_pool_id, path.from_currency)?,
+            Self::currency_index_from_stable_pool(to_pool_id, path.to_currency)?,
+        ))
+    }
+
+    fn weighted_swap(
+        who: &T::AccountId,
+        path: &WeightedPath<T::StablePoolId, T::CurrencyId>,
+        amount_in: T::Balance,
+        to: &T::AccountId,
+    ) -> Result<T::Balance, DispatchError> {
+        let (from_index, to_index) = Self::weighted_path_indices(path)?;
+        T::WeightedAmm::swap(who, path.pool_id, from_index, to_index, amount_in, Zero::zero(), to)
+    }
+
+    fn weighted_path_indices(path: &WeightedPath<T::StablePoolId, T::CurrencyId>) -> Result<(u32, u32), DispatchError> {
+        let index = |currency_id| {
+            T::WeightedAmm::currency_index(path.pool_id, currency_id).ok_or(Error::<T>::MismatchPoolAndCurrencyId)
+        };
+        Ok((index(path.from_currency)?, index(path.to_currency)?))
+    }
+
+    fn concentrated_swap(
+        who: &T::AccountId,
+        path: &ConcentratedPath<T::StablePoolId, T::CurrencyId>,
+        amount_in: T::Balance,
+        to: &T::AccountId,
+    ) -> Result<T::Balance, DispatchError> {
+        Self::ensure_concentrated_path(path)?;
+        T::ConcentratedAmm::swap(who, path.pool_id, path.from_currency, amount_in, Zero::zero(), to)
+    }
+
+    /// A concentrated pool trades two currencies, so the path must name both of them.
+    fn ensure_concentrated_path(path: &ConcentratedPath<T::StablePoolId, T::CurrencyId>) -> DispatchResult {
+        let currencies = T::ConcentratedAmm::pool_currencies(path.pool_id);
+        ensure!(
+            path.from_currency != path.to_currency
+                && currencies.contains(&path.from_currency)
+                && currencies.contains(&path.to_currency),
+            Error::<T>::MismatchPoolAndCurrencyId
+        );
+        Ok(())
+    }
+
     fn swap(who: &T::AccountId, amount_in: T::Balance, path: &[T::CurrencyId], to: &T::AccountId) -> DispatchResult {
         T::GeneralAmm::inner_swap_exact_assets_for_assets(who, amount_in.into(), Zero::zero(), path, to)
     }
@@ -249,6 +888,27 @@ impl<T: Config> Pallet<T> {
         T::StableAmm::currency_index(pool_id, currency_id).ok_or_else(|| Error::<T>::MismatchPoolAndCurrencyId.into())
     }
 
+    /// Ensures that `route` is a single hop through an existing pair or pool.
+    fn validate_hop(route: &Route<T::StablePoolId, T::CurrencyId>) -> DispatchResult {
+        match route {
+            Route::General(path) => {
+                ensure!(path.len() == 2 && path[0] != path[1], Error::<T>::InvalidPath);
+                // fails unless the pair is trading
+                T::GeneralAmm::get_amount_in_by_path(One::one(), path)?;
+            }
+            Route::Stable(stable_path) => {
+                Self::stable_path_indices(stable_path)?;
+            }
+            Route::Weighted(weighted_path) => {
+                Self::weighted_path_indices(weighted_path)?;
+            }
+            Route::Concentrated(concentrated_path) => {
+                Self::ensure_concentrated_path(concentrated_path)?;
+            }
+        }
+        Ok(())
+    }
+
     fn validate_routes(routes: &[Route<T::StablePoolId, T::CurrencyId>]) -> DispatchResult {
         for [route_1, route_2] in routes.array_windows::<2>() {
             let output_1 = route_1.output_currency().ok_or(Error::<T>::InvalidPath)?;
@@ -257,4 +917,304 @@ impl<T: Config> Pallet<T> {
         }
         Ok(())
     }
+
+    /// The account escrowing the input of limit orders.
+    pub fn escrow_account() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// The open orders selling `currency_in` for `currency_out`.
+    pub fn open_orders(currency_in: T::CurrencyId, currency_out: T::CurrencyId) -> Vec<(OrderId, LimitOrderOf<T>)> {
+        PairOrders::<T>::iter_key_prefix((currency_in, currency_out))
+            .filter_map(|order_id| Some((order_id, Orders::<T>::get(order_id)?)))
+            .collect()
+    }
+
+    #[transactional]
+    fn do_fill_order(
+        order_id: OrderId,
+        mut order: LimitOrderOf<T>,
+        amount_in: T::Balance,
+        routes: Vec<Route<T::StablePoolId, T::CurrencyId>>,
+        keeper: Option<&T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(
+            !amount_in.is_zero() && amount_in <= order.amount_in,
+            Error::<T>::InvalidFillAmount
+        );
+        ensure!(
+            routes.first().and_then(Route::input_currency) == Some(order.currency_in)
+                && routes.last().and_then(Route::output_currency) == Some(order.currency_out),
+            Error::<T>::InvalidRoutes
+        );
+
+        // round up so that partial fills never lower the limit price
+        let required_amount_out: T::Balance = multiply_by_rational_with_rounding(
+            amount_in.into(),
+            order.amount_out_min.into(),
+            order.amount_in.into(),
+            Rounding::Up,
+        )
+        .ok_or(ArithmeticError::Overflow)?
+        .into();
+
+        let escrow_account = Self::escrow_account();
+        let amount_out =
+            Self::do_swap_exact_tokens_for_tokens(&escrow_account, amount_in, Zero::zero(), routes, &escrow_account)?;
+
+        let keeper_reward = match keeper {
+            Some(_) => T::KeeperRewardRate::get().mul_floor(amount_out),
+            None => Zero::zero(),
+        };
+        let owner_amount_out = amount_out.saturating_sub(keeper_reward);
+        ensure!(owner_amount_out >= required_amount_out, Error::<T>::OrderPriceNotMet);
+
+        T::MultiCurrency::transfer(order.currency_out, &escrow_account, &order.owner, owner_amount_out)?;
+        if let Some(keeper) = keeper {
+            T::MultiCurrency::transfer(order.currency_out, &escrow_account, keeper, keeper_reward)?;
+        }
+
+        order.amount_in = order.amount_in.saturating_sub(amount_in);
+        order.amount_out_min = order.amount_out_min.saturating_sub(required_amount_out);
+        if order.amount_in.is_zero() {
+            Orders::<T>::remove(order_id);
+            PairOrders::<T>::remove((order.currency_in, order.currency_out), order_id);
+        } else {
+            Orders::<T>::insert(order_id, order);
+        }
+
+        Self::deposit_event(Event::OrderFilled {
+            order_id,
+            keeper: keeper.cloned(),
+            amount_in,
+            amount_out,
+            keeper_reward,
+        });
+
+        Ok(())
+    }
+
+    /// Refunds the remaining input of `order` and removes it.
+    fn close_order(order_id: OrderId, order: &LimitOrderOf<T>) -> DispatchResult {
+        T::MultiCurrency::transfer(
+            order.currency_in,
+            &Self::escrow_account(),
+            &order.owner,
+            order.amount_in,
+        )?;
+        Orders::<T>::remove(order_id);
+        PairOrders::<T>::remove((order.currency_in, order.currency_out), order_id);
+        Ok(())
+    }
+
+    /// Visits as many orders as `remaining_weight` allows, continuing after the order visited
+    /// last. Expired orders are refunded and orders whose remaining input meets the limit price
+    /// in the direct `DexGeneral` pair are filled without keeper reward.
+    fn sweep_orders(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+        let order_weight = T::WeightInfo::fill_order();
+        let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+        if used_weight.saturating_add(order_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut orders = match SweepCursor::<T>::get() {
+            Some(order_id) => Orders::<T>::iter_from(Orders::<T>::hashed_key_for(order_id)),
+            None => Orders::<T>::iter(),
+        };
+        // collect first, the orders are modified while being processed
+        let mut batch = Vec::new();
+        let mut exhausted = false;
+        while used_weight.saturating_add(order_weight).all_lte(remaining_weight) {
+            match orders.next() {
+                Some(entry) => {
+                    used_weight = used_weight.saturating_add(order_weight);
+                    batch.push(entry);
+                }
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+
+        // start over once all orders were visited
+        let cursor = if exhausted {
+            None
+        } else {
+            batch.last().map(|(order_id, _)| *order_id)
+        };
+        SweepCursor::<T>::set(cursor);
+
+        for (order_id, order) in batch {
+            if order.expiry <= now {
+                if Self::close_order(order_id, &order).is_ok() {
+                    Self::deposit_event(Event::OrderExpired {
+                        order_id,
+                        amount_in: order.amount_in,
+                    });
+                }
+                continue;
+            }
+
+            let path = vec![order.currency_in, order.currency_out];
+            let fillable = T::GeneralAmm::get_amount_out_by_path(order.amount_in.into(), &path)
+                .ok()
+                .and_then(|amounts| amounts.last().copied())
+                .map_or(false, |amount_out| T::Balance::from(amount_out) >= order.amount_out_min);
+            if fillable {
+                let amount_in = order.amount_in;
+                // a failed fill is rolled back and retried in a later sweep
+                let _ = Self::do_fill_order(order_id, order, amount_in, vec![Route::General(path)], None);
+            }
+        }
+
+        used_weight
+    }
+
+    /// Splits must be non-empty, trade between the same currencies and have non-zero proportions
+    /// adding up to one.
+    fn validate_splits(splits: &[SplitRoute<T::StablePoolId, T::CurrencyId>]) -> DispatchResult {
+        let currencies = |routes: &[Route<T::StablePoolId, T::CurrencyId>]| {
+            (
+                routes.first().and_then(Route::input_currency),
+                routes.last().and_then(Route::output_currency),
+            )
+        };
+        let first = splits.first().ok_or(Error::<T>::InvalidSplits)?;
+        let (currency_in, currency_out) = currencies(&first.routes);
+        ensure!(
+            currency_in.is_some() && currency_out.is_some(),
+            Error::<T>::InvalidSplits
+        );
+
+        let mut total_proportion: u32 = 0;
+        for split in splits {
+            ensure!(!split.proportion.is_zero(), Error::<T>::InvalidSplits);
+            ensure!(
+                currencies(&split.routes) == (currency_in, currency_out),
+                Error::<T>::InvalidSplits
+            );
+            total_proportion = total_proportion.saturating_add(split.proportion.deconstruct());
+        }
+        ensure!(
+            total_proportion == Permill::one().deconstruct(),
+            Error::<T>::InvalidSplits
+        );
+        Ok(())
+    }
+
+    /// Divides `amount` by the proportions of `splits`, rounding remainders into the last split.
+    fn split_amounts(amount: T::Balance, splits: &[SplitRoute<T::StablePoolId, T::CurrencyId>]) -> Vec<T::Balance> {
+        let mut remaining = amount;
+        let mut amounts: Vec<T::Balance> = splits
+            .iter()
+            .take(splits.len().saturating_sub(1))
+            .map(|split| {
+                let split_amount = split.proportion.mul_floor(amount);
+                remaining = remaining.saturating_sub(split_amount);
+                split_amount
+            })
+            .collect();
+        amounts.push(remaining);
+        amounts
+    }
+
+    fn routes_weight(
+        routes: &[Route<T::StablePoolId, T::CurrencyId>],
+        general_swap_weight: fn(u32) -> Weight,
+        stable_quotes: u32,
+    ) -> Weight {
+        routes
+            .iter()
+            .map(|route| match route {
+                Route::General(path) => general_swap_weight(path.len() as u32),
+                Route::Stable(stable_path) => match stable_path.mode {
+                    StableSwapMode::Single => T::StableWeightInfo::swap(),
+                    StableSwapMode::FromBase => T::StableWeightInfo::swap_pool_from_base(),
+                    StableSwapMode::ToBase => T::StableWeightInfo::swap_pool_to_base(),
+                }
+                .saturating_mul(stable_quotes.saturating_add(1).into()),
+                Route::Weighted(_) => T::WeightedWeightInfo::swap(),
+                Route::Concentrated(_) => T::ConcentratedWeightInfo::swap(MAX_TICK_CROSSINGS),
+            })
+            .fold(Weight::zero(), |total: Weight, weight: Weight| {
+                total.saturating_add(weight)
+            })
+            .saturating_add(T::WeightInfo::validate_routes(routes.len() as u32))
+    }
+
+    fn swap_exact_tokens_for_tokens_weight(routes: &[Route<T::StablePoolId, T::CurrencyId>]) -> Weight {
+        Self::routes_weight(routes, T::GeneralWeightInfo::swap_exact_assets_for_assets, 0)
+    }
+
+    fn swap_tokens_for_exact_tokens_weight(routes: &[Route<T::StablePoolId, T::CurrencyId>]) -> Weight {
+        // the input of stable paths is searched by quoting them
+        Self::routes_weight(
+            routes,
+            T::GeneralWeightInfo::swap_assets_for_exact_assets,
+            MAX_AMOUNT_IN_QUOTES,
+        )
+    }
+
+    fn splits_weight(
+        splits: &[SplitRoute<T::StablePoolId, T::CurrencyId>],
+        routes_weight: fn(&[Route<T::StablePoolId, T::CurrencyId>]) -> Weight,
+    ) -> Weight {
+        splits
+            .iter()
+            .map(|split| routes_weight(&split.routes))
+            .fold(Weight::zero(), |total: Weight, weight: Weight| {
+                total.saturating_add(weight)
+            })
+    }
+}
+
+impl<T: Config> traits::SwapApi<T::AccountId, T::CurrencyId, T::Balance> for Pallet<T> {
+    fn swap_exact_for(
+        who: &T::AccountId,
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_in: T::Balance,
+        amount_out_min: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        // only direct pairs in the general amm are considered
+        let routes = vec![Route::General(vec![currency_in, currency_out])];
+        Self::do_swap_exact_tokens_for_tokens(who, amount_in, amount_out_min, routes, who)
+    }
+
+    fn quote_for_exact(
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_out: T::Balance,
+    ) -> Option<T::Balance> {
+        Self::find_swap_api_route_for_exact(currency_in, currency_out, amount_out)
+            .and_then(|quote| quote.amounts.first().copied())
+    }
+
+    fn swap_for_exact(
+        who: &T::AccountId,
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_out: T::Balance,
+        amount_in_max: T::Balance,
+    ) -> Result<T::Balance, DispatchError> {
+        let quote = Self::find_swap_api_route_for_exact(currency_in, currency_out, amount_out)
+            .ok_or(Error::<T>::InsufficientLiquidity)?;
+        with_storage_layer(|| Self::do_swap_tokens_for_exact_tokens(who, amount_out, amount_in_max, quote.routes, who))
+    }
+
+    fn swap_for_exact_weight() -> Weight {
+        let hops = SwapApiHops::<T>::get();
+        let hops_count = hops.len() as u32;
+        // every hop is quoted once at the first level of the search and at most once after
+        // every first-level hop, pools other than general pairs are not covered by the benchmark
+        let quotes_weight = hops
+            .into_iter()
+            .map(|hop| Self::swap_tokens_for_exact_tokens_weight(&[hop.into()]))
+            .fold(Weight::zero(), |total: Weight, weight: Weight| {
+                total.saturating_add(weight)
+            })
+            .saturating_mul(hops_count.into());
+        T::WeightInfo::swap_for_exact(hops_count).saturating_add(quotes_weight)
+    }
 }
@@ -8,13 +8,14 @@ use serde::{Deserialize, Serialize};
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, RuntimeDebug,
+    BuildStorage, Permill, RuntimeDebug,
 };
 
 use crate as dex_swap_router;
 use crate::Config;
 use dex_general::{GenerateLpAssetId, ValidateAsset};
 use dex_stable::traits::{StablePoolLpCurrencyIdGenerate, ValidateCurrency};
+use dex_weighted::traits::WeightedPoolLpCurrencyIdGenerate;
 use orml_traits::parameter_type_with_key;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -25,6 +26,13 @@ parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const DexStablePalletId: PalletId = PalletId(*b"dex/stab");
     pub const DexGeneralPalletId: PalletId = PalletId(*b"dex/genr");
+    pub const DexSwapRouterPalletId: PalletId = PalletId(*b"dex/rout");
+    pub const DexWeightedPalletId: PalletId = PalletId(*b"dex/wght");
+    pub const DexConcentratedPalletId: PalletId = PalletId(*b"dex/conc");
+    pub const MaxTicks: u32 = 100;
+    pub const NativeCurrencyId: CurrencyId = CurrencyId::Token(TOKEN1_SYMBOL);
+    pub const PositionDeposit: Balance = TOKEN1_UNIT;
+    pub const KeeperRewardRate: Permill = Permill::from_percent(1);
     pub const MaxReserves: u32 = 50;
     pub const MaxLocks:u32 = 50;
     pub const MinimumPeriod: Moment = SLOT_DURATION / 2;
@@ -69,6 +77,7 @@ pub enum CurrencyId {
     Token(TokenSymbol),
     StableLP(PoolId),
     LpToken(TokenSymbol, TokenSymbol),
+    WeightedLP(PoolId),
 }
 
 impl CurrencyId {
@@ -232,12 +241,43 @@ impl dex_stable::Config for Test {
     type EnsurePoolAsset = VerifyPoolAsset;
     type LpGenerate = PoolLpGenerate;
     type TimeProvider = Timestamp;
+    type RateSource = CurrencyId;
+    type RateProvider = ();
     type PoolCurrencyLimit = PoolCurrencyLimit;
     type PoolCurrencySymbolLimit = PoolCurrencySymbolLimit;
     type PalletId = DexStablePalletId;
     type WeightInfo = ();
 }
 
+pub struct WeightedPoolLpGenerate;
+impl WeightedPoolLpCurrencyIdGenerate<CurrencyId, PoolId> for WeightedPoolLpGenerate {
+    fn generate_by_pool_id(pool_id: PoolId) -> CurrencyId {
+        CurrencyId::WeightedLP(pool_id)
+    }
+}
+
+impl dex_weighted::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CurrencyId = CurrencyId;
+    type MultiCurrency = Tokens;
+    type PoolId = PoolId;
+    type LpGenerate = WeightedPoolLpGenerate;
+    type PalletId = DexWeightedPalletId;
+    type WeightInfo = ();
+}
+
+impl dex_concentrated::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CurrencyId = CurrencyId;
+    type MultiCurrency = Tokens;
+    type PoolId = PoolId;
+    type NativeCurrencyId = NativeCurrencyId;
+    type PositionDeposit = PositionDeposit;
+    type MaxTicks = MaxTicks;
+    type PalletId = DexConcentratedPalletId;
+    type WeightInfo = ();
+}
+
 pub struct PairLpIdentity;
 impl GenerateLpAssetId<CurrencyId> for PairLpIdentity {
     fn generate_lp_asset_id(asset_0: CurrencyId, asset_1: CurrencyId) -> Option<CurrencyId> {
@@ -262,11 +302,13 @@ impl dex_general::Config for Test {
     type WeightInfo = ();
     type MaxBootstrapRewards = MaxBootstrapRewards;
     type MaxBootstrapLimits = MaxBootstrapLimits;
+    type MaxBatchOrders = MaxBatchOrders;
 }
 
 parameter_types! {
     pub const MaxBootstrapRewards: u32 = 1000;
     pub const MaxBootstrapLimits:u32 = 1000;
+    pub const MaxBatchOrders: u32 = 100;
 }
 
 impl Config for Test {
@@ -276,8 +318,15 @@ impl Config for Test {
     type CurrencyId = CurrencyId;
     type GeneralAmm = DexGeneral;
     type StableAmm = DexStable;
+    type WeightedAmm = DexWeighted;
+    type ConcentratedAmm = DexConcentrated;
+    type MultiCurrency = Tokens;
+    type PalletId = DexSwapRouterPalletId;
+    type KeeperRewardRate = KeeperRewardRate;
     type GeneralWeightInfo = ();
     type StableWeightInfo = ();
+    type WeightedWeightInfo = ();
+    type ConcentratedWeightInfo = ();
     type WeightInfo = ();
 }
 
@@ -300,7 +349,9 @@ frame_support::construct_runtime!(
         Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 10,
         DexGeneral: dex_general::{Pallet, Call, Storage, Event<T>} = 11,
         DexStable: dex_stable::{Pallet, Call, Storage, Event<T>} = 12,
-        DexSwapRouter: dex_swap_router::{Pallet, Call, Event<T>} = 13,
+        DexSwapRouter: dex_swap_router::{Pallet, Call, Storage, Event<T>} = 13,
+        DexWeighted: dex_weighted::{Pallet, Call, Storage, Event<T>} = 14,
+        DexConcentrated: dex_concentrated::{Pallet, Call, Storage, Event<T>} = 15,
     }
 );
 
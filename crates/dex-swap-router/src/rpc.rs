@@ -0,0 +1,419 @@
+// Copyright 2021-2022 Zenlink.
+// Licensed under Apache 2.0.
+
+use super::*;
+
+/// Upper bound for the number of hops of the routes searched by `find_best_route`.
+pub const MAX_ROUTE_HOPS: u32 = 4;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RouteQuote<PoolId, CurrencyId, Balance> {
+    /// The routes to pass to `swap_exact_tokens_for_tokens`.
+    pub routes: Vec<Route<PoolId, CurrencyId>>,
+    /// The input amount, followed by the output amount of every hop.
+    pub amounts: Vec<Balance>,
+    pub amount_out: Balance,
+}
+
+/// A general amm pair or a stable, weighted or concentrated amm pool traded with by a hop.
+#[derive(PartialEq)]
+enum Venue<PoolId, CurrencyId> {
+    Pair(CurrencyId, CurrencyId),
+    Pool(PoolId),
+    WeightedPool(PoolId),
+    ConcentratedPool(PoolId),
+}
+
+impl<PoolId: Copy, CurrencyId: Copy + Ord> Route<PoolId, CurrencyId> {
+    fn venues(&self) -> Vec<Venue<PoolId, CurrencyId>> {
+        match self {
+            Route::General(path) => path
+                .windows(2)
+                .map(|pair| Venue::Pair(pair[0].min(pair[1]), pair[0].max(pair[1])))
+                .collect(),
+            Route::Stable(stable_path) => match stable_path.mode {
+                StableSwapMode::Single => vec![Venue::Pool(stable_path.pool_id)],
+                StableSwapMode::FromBase | StableSwapMode::ToBase => {
+                    vec![Venue::Pool(stable_path.pool_id), Venue::Pool(stable_path.base_pool_id)]
+                }
+            },
+            Route::Weighted(weighted_path) => vec![Venue::WeightedPool(weighted_path.pool_id)],
+            Route::Concentrated(concentrated_path) => vec![Venue::ConcentratedPool(concentrated_path.pool_id)],
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Searches the routes through the pairs of `DexGeneral` and the pools of `DexStable`,
+    /// `DexWeighted` and `DexConcentrated` with at most `max_hops` hops for the one with the
+    /// highest output. Every hop is priced exactly as `swap_exact_tokens_for_tokens` would execute
+    /// it, so routes trading with a pair or pool more than once are not considered.
+    pub fn find_best_route(
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_in: T::Balance,
+        max_hops: u32,
+    ) -> Option<RouteQuote<T::StablePoolId, T::CurrencyId, T::Balance>> {
+        if currency_in == currency_out || amount_in.is_zero() {
+            return None;
+        }
+
+        let hops = Self::available_hops();
+        let mut best = None;
+        Self::search_routes(
+            &hops,
+            amount_in,
+            currency_out,
+            max_hops.min(MAX_ROUTE_HOPS) as usize,
+            &mut vec![currency_in],
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &mut best,
+        );
+
+        best.map(|quoted_hops| Self::into_route_quote(amount_in, quoted_hops))
+    }
+
+    /// Searches the routes with at most `max_hops` hops for the one requiring the lowest input
+    /// to receive exactly `amount_out`, pricing every hop as `swap_tokens_for_exact_tokens`
+    /// would execute it.
+    pub fn find_best_route_for_exact(
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_out: T::Balance,
+        max_hops: u32,
+    ) -> Option<RouteQuote<T::StablePoolId, T::CurrencyId, T::Balance>> {
+        Self::find_best_route_for_exact_through(
+            &Self::available_hops(),
+            currency_in,
+            currency_out,
+            amount_out,
+            max_hops,
+        )
+    }
+
+    /// Searches the routes with at most `SWAP_API_MAX_HOPS` hops through the `SwapApiHops`
+    /// for the one requiring the lowest input to receive exactly `amount_out`.
+    pub(crate) fn find_swap_api_route_for_exact(
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_out: T::Balance,
+    ) -> Option<RouteQuote<T::StablePoolId, T::CurrencyId, T::Balance>> {
+        let hops = SwapApiHops::<T>::get().into_iter().map(Into::into).collect::<Vec<_>>();
+        Self::find_best_route_for_exact_through(&hops, currency_in, currency_out, amount_out, SWAP_API_MAX_HOPS)
+    }
+
+    fn find_best_route_for_exact_through(
+        hops: &[Route<T::StablePoolId, T::CurrencyId>],
+        currency_in: T::CurrencyId,
+        currency_out: T::CurrencyId,
+        amount_out: T::Balance,
+        max_hops: u32,
+    ) -> Option<RouteQuote<T::StablePoolId, T::CurrencyId, T::Balance>> {
+        if currency_in == currency_out || amount_out.is_zero() {
+            return None;
+        }
+
+        let mut best = None;
+        Self::search_routes_for_exact(
+            hops,
+            amount_out,
+            currency_in,
+            max_hops.min(MAX_ROUTE_HOPS) as usize,
+            &mut vec![currency_out],
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &mut best,
+        );
+
+        best.map(|mut quoted_hops| {
+            // the hops were found backwards, each with its input
+            quoted_hops.reverse();
+            let amount_in = quoted_hops.first().map(|(_, amount)| *amount).unwrap_or_default();
+            let amounts_out = quoted_hops
+                .iter()
+                .skip(1)
+                .map(|(_, amount)| *amount)
+                .chain(sp_std::iter::once(amount_out))
+                .collect::<Vec<_>>();
+            let quoted_hops = quoted_hops.into_iter().map(|(hop, _)| hop).zip(amounts_out).collect();
+            Self::into_route_quote(amount_in, quoted_hops)
+        })
+    }
+
+    /// Every single-hop route of all amms.
+    fn available_hops() -> Vec<Route<T::StablePoolId, T::CurrencyId>> {
+        let mut hops = Vec::new();
+
+        for (asset_0, asset_1) in T::GeneralAmm::get_trading_pairs() {
+            hops.push(Route::General(vec![asset_0, asset_1]));
+            hops.push(Route::General(vec![asset_1, asset_0]));
+        }
+
+        let stable_hop = |pool_id, base_pool_id, mode, from_currency, to_currency| {
+            Route::Stable(StablePath {
+                pool_id,
+                base_pool_id,
+                mode,
+                from_currency,
+                to_currency,
+            })
+        };
+        for pool_id in T::StableAmm::pool_ids() {
+            let currencies = T::StableAmm::pool_currencies(pool_id);
+            for &from_currency in currencies.iter() {
+                for &to_currency in currencies.iter().filter(|c| **c != from_currency) {
+                    hops.push(stable_hop(
+                        pool_id,
+                        pool_id,
+                        StableSwapMode::Single,
+                        from_currency,
+                        to_currency,
+                    ));
+                }
+            }
+
+            if let Some(base_pool_id) = T::StableAmm::base_pool_id(pool_id) {
+                // hops from or to the lp currency of the base pool can't be priced and are skipped
+                for &base_currency in T::StableAmm::pool_currencies(base_pool_id).iter() {
+                    for &currency in currencies.iter() {
+                        hops.push(stable_hop(
+                            pool_id,
+                            base_pool_id,
+                            StableSwapMode::FromBase,
+                            base_currency,
+                            currency,
+                        ));
+                        hops.push(stable_hop(
+                            pool_id,
+                            base_pool_id,
+                            StableSwapMode::ToBase,
+                            currency,
+                            base_currency,
+                        ));
+                    }
+                }
+            }
+        }
+
+        for pool_id in T::WeightedAmm::pool_ids() {
+            let currencies = T::WeightedAmm::pool_currencies(pool_id);
+            for &from_currency in currencies.iter() {
+                for &to_currency in currencies.iter().filter(|c| **c != from_currency) {
+                    hops.push(Route::Weighted(WeightedPath {
+                        pool_id,
+                        from_currency,
+                        to_currency,
+                    }));
+                }
+            }
+        }
+
+        for pool_id in T::ConcentratedAmm::pool_ids() {
+            let currencies = T::ConcentratedAmm::pool_currencies(pool_id);
+            for &from_currency in currencies.iter() {
+                for &to_currency in currencies.iter().filter(|c| **c != from_currency) {
+                    hops.push(Route::Concentrated(ConcentratedPath {
+                        pool_id,
+                        from_currency,
+                        to_currency,
+                    }));
+                }
+            }
+        }
+
+        hops
+    }
+
+    /// Depth-first search over all routes without cycles, starting at the last visited currency.
+    fn search_routes(
+        hops: &[Route<T::StablePoolId, T::CurrencyId>],
+        amount_in: T::Balance,
+        currency_out: T::CurrencyId,
+        remaining_hops: usize,
+        visited: &mut Vec<T::CurrencyId>,
+        used_venues: &mut Vec<Venue<T::StablePoolId, T::CurrencyId>>,
+        current: &mut Vec<(Route<T::StablePoolId, T::CurrencyId>, T::Balance)>,
+        best: &mut Option<Vec<(Route<T::StablePoolId, T::CurrencyId>, T::Balance)>>,
+    ) {
+        if remaining_hops == 0 {
+            return;
+        }
+        let currency_in = visited.last().copied();
+
+        for hop in hops.iter().filter(|hop| hop.input_currency() == currency_in) {
+            let next_currency = match hop.output_currency() {
+                Some(currency) if !visited.contains(&currency) => currency,
+                _ => continue,
+            };
+            let venues = hop.venues();
+            if venues.iter().any(|venue| used_venues.contains(venue)) {
+                continue;
+            }
+            let amount_out = match Self::quote_route(hop, amount_in) {
+                Some(amount) if !amount.is_zero() => amount,
+                _ => continue,
+            };
+
+            current.push((hop.clone(), amount_out));
+            if next_currency == currency_out {
+                let is_better = best.as_ref().map_or(true, |best| {
+                    let best_amount_out = best.last().map(|(_, amount)| *amount).unwrap_or_default();
+                    // prefer fewer hops if the output is the same
+                    amount_out > best_amount_out || (amount_out == best_amount_out && current.len() < best.len())
+                });
+                if is_better {
+                    *best = Some(current.clone());
+                }
+            } else {
+                let used_count = used_venues.len();
+                visited.push(next_currency);
+                used_venues.extend(venues);
+                Self::search_routes(
+                    hops,
+                    amount_out,
+                    currency_out,
+                    remaining_hops - 1,
+                    visited,
+                    used_venues,
+                    current,
+                    best,
+                );
+                used_venues.truncate(used_count);
+                visited.pop();
+            }
+            current.pop();
+        }
+    }
+
+    /// Depth-first search over all routes without cycles, ending at the first visited currency
+    /// and walking backwards from the last one.
+    fn search_routes_for_exact(
+        hops: &[Route<T::StablePoolId, T::CurrencyId>],
+        amount_out: T::Balance,
+        currency_in: T::CurrencyId,
+        remaining_hops: usize,
+        visited: &mut Vec<T::CurrencyId>,
+        used_venues: &mut Vec<Venue<T::StablePoolId, T::CurrencyId>>,
+        current: &mut Vec<(Route<T::StablePoolId, T::CurrencyId>, T::Balance)>,
+        best: &mut Option<Vec<(Route<T::StablePoolId, T::CurrencyId>, T::Balance)>>,
+    ) {
+        if remaining_hops == 0 {
+            return;
+        }
+        let currency_out = visited.last().copied();
+
+        for hop in hops.iter().filter(|hop| hop.output_currency() == currency_out) {
+            let previous_currency = match hop.input_currency() {
+                Some(currency) if !visited.contains(&currency) => currency,
+                _ => continue,
+            };
+            let venues = hop.venues();
+            if venues.iter().any(|venue| used_venues.contains(venue)) {
+                continue;
+            }
+            let amount_in = match Self::route_amount_in(hop, amount_out) {
+                Ok(amount) if !amount.is_zero() => amount,
+                _ => continue,
+            };
+
+            current.push((hop.clone(), amount_in));
+            if previous_currency == currency_in {
+                let is_better = best.as_ref().map_or(true, |best| {
+                    let best_amount_in = best.last().map(|(_, amount)| *amount).unwrap_or_default();
+                    // prefer fewer hops if the input is the same
+                    amount_in < best_amount_in || (amount_in == best_amount_in && current.len() < best.len())
+                });
+                if is_better {
+                    *best = Some(current.clone());
+                }
+            } else {
+                let used_count = used_venues.len();
+                visited.push(previous_currency);
+                used_venues.extend(venues);
+                Self::search_routes_for_exact(
+                    hops,
+                    amount_in,
+                    currency_in,
+                    remaining_hops - 1,
+                    visited,
+                    used_venues,
+                    current,
+                    best,
+                );
+                used_venues.truncate(used_count);
+                visited.pop();
+            }
+            current.pop();
+        }
+    }
+
+    /// The output of a route, as executed by `do_swap_exact_tokens_for_tokens`.
+    fn quote_route(route: &Route<T::StablePoolId, T::CurrencyId>, amount_in: T::Balance) -> Option<T::Balance> {
+        match route {
+            Route::General(path) => T::GeneralAmm::get_amount_out_by_path(amount_in.into(), path)
+                .ok()?
+                .last()
+                .map(|amount| T::Balance::from(*amount)),
+            Route::Stable(stable_path) => match stable_path.mode {
+                StableSwapMode::Single => T::StableAmm::quote_swap(
+                    stable_path.pool_id,
+                    T::StableAmm::currency_index(stable_path.pool_id, stable_path.from_currency)?,
+                    T::StableAmm::currency_index(stable_path.pool_id, stable_path.to_currency)?,
+                    amount_in,
+                ),
+                StableSwapMode::FromBase => T::StableAmm::quote_swap_pool_from_base(
+                    stable_path.pool_id,
+                    stable_path.base_pool_id,
+                    T::StableAmm::currency_index(stable_path.base_pool_id, stable_path.from_currency)?,
+                    T::StableAmm::currency_index(stable_path.pool_id, stable_path.to_currency)?,
+                    amount_in,
+                ),
+                StableSwapMode::ToBase => T::StableAmm::quote_swap_pool_to_base(
+                    stable_path.pool_id,
+                    stable_path.base_pool_id,
+                    T::StableAmm::currency_index(stable_path.pool_id, stable_path.from_currency)?,
+                    T::StableAmm::currency_index(stable_path.base_pool_id, stable_path.to_currency)?,
+                    amount_in,
+                ),
+            },
+            Route::Weighted(weighted_path) => T::WeightedAmm::quote_swap(
+                weighted_path.pool_id,
+                T::WeightedAmm::currency_index(weighted_path.pool_id, weighted_path.from_currency)?,
+                T::WeightedAmm::currency_index(weighted_path.pool_id, weighted_path.to_currency)?,
+                amount_in,
+            ),
+            Route::Concentrated(concentrated_path) => {
+                Self::ensure_concentrated_path(concentrated_path).ok()?;
+                T::ConcentratedAmm::quote_swap(concentrated_path.pool_id, concentrated_path.from_currency, amount_in)
+            }
+        }
+    }
+
+    /// Joins consecutive hops through `DexGeneral` into a single path.
+    fn into_route_quote(
+        amount_in: T::Balance,
+        quoted_hops: Vec<(Route<T::StablePoolId, T::CurrencyId>, T::Balance)>,
+    ) -> RouteQuote<T::StablePoolId, T::CurrencyId, T::Balance> {
+        let mut routes: Vec<Route<T::StablePoolId, T::CurrencyId>> = Vec::new();
+        let mut amounts = vec![amount_in];
+
+        for (hop, amount_out) in quoted_hops {
+            match (routes.last_mut(), hop) {
+                (Some(Route::General(path)), Route::General(hop_path)) => {
+                    path.extend(hop_path.into_iter().skip(1));
+                }
+                (_, hop) => routes.push(hop),
+            }
+            amounts.push(amount_out);
+        }
+
+        RouteQuote {
+            routes,
+            amount_out: *amounts.last().unwrap_or(&amount_in),
+            amounts,
+        }
+    }
+}
@@ -7,8 +7,10 @@ use super::{
     *,
 };
 use dex_general::DEFAULT_FEE_RATE;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use frame_system::RawOrigin;
+use sp_runtime::Perbill;
+use traits::SwapApi;
 
 const INITIAL_A_VALUE: Balance = 50;
 const SWAP_FEE: Balance = 1e7 as Balance;
@@ -215,3 +217,1056 @@ fn test_validate_routes() {
         );
     })
 }
+
+#[test]
+fn find_best_route_should_quote_executed_amounts() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        for (currency_in, currency_out) in [
+            // from base, possibly through the general amm
+            (Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL)),
+            // to base
+            (Token(TOKEN4_SYMBOL), Token(TOKEN1_SYMBOL)),
+            // within the base pool
+            (Token(TOKEN1_SYMBOL), Token(TOKEN3_SYMBOL)),
+        ] {
+            let amount_in = 1e16 as Balance;
+            let quote = DexSwapRouter::find_best_route(currency_in, currency_out, amount_in, MAX_ROUTE_HOPS).unwrap();
+            assert_eq!(quote.amounts.first(), Some(&amount_in));
+            assert_eq!(quote.amounts.last(), Some(&quote.amount_out));
+            assert_ok!(DexSwapRouter::validate_routes(&quote.routes));
+
+            let balance_before = Tokens::accounts(USER2, currency_out).free;
+            assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens(
+                RawOrigin::Signed(USER1).into(),
+                amount_in,
+                quote.amount_out,
+                quote.routes,
+                USER2,
+                u64::MAX,
+            ));
+            assert_eq!(
+                Tokens::accounts(USER2, currency_out).free,
+                balance_before + quote.amount_out
+            );
+        }
+    })
+}
+
+#[test]
+fn find_best_route_should_not_be_worse_than_known_route() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        // the route of `swap_exact_tokens_for_tokens_should_work` yields 9854
+        let quote =
+            DexSwapRouter::find_best_route(TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 1e16 as Balance, MAX_ROUTE_HOPS)
+                .unwrap();
+        assert!(quote.amount_out >= 9854);
+    })
+}
+
+#[test]
+fn find_best_route_without_route_should_return_none() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        assert_eq!(
+            DexSwapRouter::find_best_route(TOKEN1_ASSET_ID, TOKEN1_ASSET_ID, 1e16 as Balance, MAX_ROUTE_HOPS),
+            None
+        );
+        assert_eq!(
+            DexSwapRouter::find_best_route(TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 1e16 as Balance, 0),
+            None
+        );
+        // nothing to swap
+        assert_eq!(
+            DexSwapRouter::find_best_route(TOKEN1_ASSET_ID, Token(TOKEN4_SYMBOL), 0, MAX_ROUTE_HOPS),
+            None
+        );
+    })
+}
+
+#[test]
+fn find_best_route_for_exact_should_quote_executed_amounts() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        for (currency_in, currency_out, amount_out) in [
+            (Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL), 9854),
+            (Token(TOKEN4_SYMBOL), Token(TOKEN1_SYMBOL), 1e15 as Balance),
+            (Token(TOKEN1_SYMBOL), Token(TOKEN3_SYMBOL), 1000),
+        ] {
+            let quote = DexSwapRouter::find_best_route_for_exact(currency_in, currency_out, amount_out, MAX_ROUTE_HOPS)
+                .unwrap();
+            assert_eq!(quote.amount_out, amount_out);
+            assert_eq!(quote.amounts.last(), Some(&amount_out));
+            assert_ok!(DexSwapRouter::validate_routes(&quote.routes));
+
+            let balance_in_before = Tokens::accounts(USER1, currency_in).free;
+            let balance_out_before = Tokens::accounts(USER2, currency_out).free;
+            assert_ok!(DexSwapRouter::swap_tokens_for_exact_tokens(
+                RawOrigin::Signed(USER1).into(),
+                amount_out,
+                quote.amounts[0],
+                quote.routes,
+                USER2,
+                u64::MAX,
+            ));
+            assert_eq!(
+                Tokens::accounts(USER1, currency_in).free,
+                balance_in_before - quote.amounts[0]
+            );
+            assert!(Tokens::accounts(USER2, currency_out).free >= balance_out_before + amount_out);
+        }
+
+        assert_eq!(
+            DexSwapRouter::find_best_route_for_exact(TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 9854, 0),
+            None
+        );
+        // more than the pool holds
+        assert_eq!(
+            DexSwapRouter::find_best_route_for_exact(
+                TOKEN2_ASSET_ID,
+                Token(TOKEN4_SYMBOL),
+                2e6 as Balance,
+                MAX_ROUTE_HOPS
+            ),
+            None
+        );
+    })
+}
+
+#[test]
+fn swap_api_swap_for_exact_should_use_best_route() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        // only the hops registered by governance are searched
+        assert_eq!(
+            DexSwapRouter::quote_for_exact(TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 9854),
+            None
+        );
+        set_token2_to_token4_swap_api_hops();
+
+        let amount_in = DexSwapRouter::quote_for_exact(TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 9854).unwrap();
+
+        assert_noop!(
+            DexSwapRouter::swap_for_exact(&USER1, TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 9854, amount_in - 1,),
+            Error::<Test>::AmountSlippage
+        );
+
+        let balance_before = Tokens::accounts(USER1, TOKEN2_ASSET_ID).free;
+        assert_eq!(
+            DexSwapRouter::swap_for_exact(&USER1, TOKEN2_ASSET_ID, Token(TOKEN4_SYMBOL), 9854, amount_in,),
+            Ok(amount_in)
+        );
+        assert_eq!(
+            Tokens::accounts(USER1, TOKEN2_ASSET_ID).free,
+            balance_before - amount_in
+        );
+    })
+}
+
+fn set_token2_to_token4_swap_api_hops() {
+    assert_ok!(DexSwapRouter::set_swap_api_hops(
+        RawOrigin::Root.into(),
+        vec![
+            Hop::General(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID),
+            Hop::Stable(StablePath::<PoolId, CurrencyId> {
+                pool_id: 1,
+                base_pool_id: 0,
+                mode: FromBase,
+                from_currency: Token(TOKEN1_SYMBOL),
+                to_currency: Token(TOKEN4_SYMBOL),
+            }),
+        ]
+        .try_into()
+        .unwrap(),
+    ));
+}
+
+#[test]
+fn set_swap_api_hops_should_validate_hops() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        let hops: BoundedVec<_, _> = vec![Hop::General(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID)].try_into().unwrap();
+        assert_noop!(
+            DexSwapRouter::set_swap_api_hops(RawOrigin::Signed(USER1).into(), hops.clone()),
+            DispatchError::BadOrigin
+        );
+
+        // there is no such pair
+        let invalid_hops: BoundedVec<_, _> = vec![Hop::General(TOKEN1_ASSET_ID, Token(TOKEN4_SYMBOL))]
+            .try_into()
+            .unwrap();
+        assert_noop!(
+            DexSwapRouter::set_swap_api_hops(RawOrigin::Root.into(), invalid_hops),
+            Error::<Test>::InvalidPath
+        );
+
+        let weight_before = DexSwapRouter::swap_for_exact_weight();
+        assert_ok!(DexSwapRouter::set_swap_api_hops(RawOrigin::Root.into(), hops.clone()));
+        assert_eq!(DexSwapRouter::swap_api_hops(), hops);
+        // the search through more hops is charged
+        assert!(DexSwapRouter::swap_for_exact_weight().ref_time() > weight_before.ref_time());
+    })
+}
+
+fn token2_to_token4_routes() -> Vec<Route<PoolId, CurrencyId>> {
+    vec![
+        Route::General(vec![TOKEN2_ASSET_ID, TOKEN1_ASSET_ID]),
+        Route::Stable(StablePath::<PoolId, CurrencyId> {
+            pool_id: 1,
+            base_pool_id: 0,
+            mode: FromBase,
+            from_currency: Token(TOKEN1_SYMBOL),
+            to_currency: Token(TOKEN4_SYMBOL),
+        }),
+    ]
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_should_spend_minimal_amount() {
+    // the general amm rounds its inputs up, so only the stable pools are checked for the minimal input
+    let amount_in = new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        let token1_balance_before = Tokens::accounts(USER1, Token(TOKEN1_SYMBOL)).free;
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_ok!(DexSwapRouter::swap_tokens_for_exact_tokens(
+            RawOrigin::Signed(USER1).into(),
+            9854,
+            1e16 as Balance,
+            token2_to_token4_routes()[1..].to_vec(),
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free,
+            token4_balance_before + 9854
+        );
+        token1_balance_before - Tokens::accounts(USER1, Token(TOKEN1_SYMBOL)).free
+    });
+
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        assert_noop!(
+            DexSwapRouter::swap_exact_tokens_for_tokens(
+                RawOrigin::Signed(USER1).into(),
+                amount_in - 1,
+                9854,
+                token2_to_token4_routes()[1..].to_vec(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens(
+            RawOrigin::Signed(USER1).into(),
+            amount_in,
+            9854,
+            token2_to_token4_routes()[1..].to_vec(),
+            USER2,
+            u64::MAX,
+        ));
+    });
+}
+
+#[test]
+fn search_amount_in_should_be_bounded() {
+    // the proportional estimates undershoot a step function by far, which forces a long search
+    let quotes = core::cell::Cell::new(0);
+    let quote = |amount_in: Balance| {
+        quotes.set(quotes.get() + 1);
+        Some(if amount_in >= 50_000 { 10 } else { 0 })
+    };
+
+    let amount_in = DexSwapRouter::search_amount_in(quote, 10).unwrap();
+    assert_eq!(quotes.get(), MAX_AMOUNT_IN_QUOTES);
+    // the search stops early with an input that is sufficient, but not the smallest one
+    assert!(amount_in > 50_000);
+    assert_eq!(quote(amount_in), Some(10));
+
+    // without a sufficient input within the bound, the search fails
+    let far_quote = |amount_in: Balance| Some(if amount_in >= 1e12 as Balance { 10 } else { 0 });
+    assert_eq!(DexSwapRouter::search_amount_in(far_quote, 10), None);
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_through_general_amm_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_pools();
+
+        let path = vec![TOKEN2_ASSET_ID, TOKEN1_ASSET_ID];
+        let amount_in = DexGeneral::get_amount_in_by_path(1e15 as Balance, &path).unwrap()[0];
+        let token1_balance_before = Tokens::accounts(USER2, TOKEN1_ASSET_ID).free;
+        let token2_balance_before = Tokens::accounts(USER1, TOKEN2_ASSET_ID).free;
+
+        assert_ok!(DexSwapRouter::swap_tokens_for_exact_tokens(
+            RawOrigin::Signed(USER1).into(),
+            1e15 as Balance,
+            amount_in,
+            vec![Route::General(path)],
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER2, TOKEN1_ASSET_ID).free,
+            token1_balance_before + 1e15 as Balance
+        );
+        assert_eq!(
+            Tokens::accounts(USER1, TOKEN2_ASSET_ID).free,
+            token2_balance_before - amount_in
+        );
+    })
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_with_amount_slippage_should_failed() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        assert_noop!(
+            DexSwapRouter::swap_tokens_for_exact_tokens(
+                RawOrigin::Signed(USER1).into(),
+                9854,
+                1e15 as Balance,
+                token2_to_token4_routes(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        // more than the pool holds
+        assert_noop!(
+            DexSwapRouter::swap_tokens_for_exact_tokens(
+                RawOrigin::Signed(USER1).into(),
+                2e6 as Balance,
+                u128::MAX,
+                token2_to_token4_routes(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::InsufficientLiquidity
+        );
+    })
+}
+
+fn token2_to_token4_splits() -> Vec<SplitRoute<PoolId, CurrencyId>> {
+    vec![
+        SplitRoute {
+            proportion: Permill::from_percent(40),
+            routes: token2_to_token4_routes(),
+        },
+        SplitRoute {
+            proportion: Permill::from_percent(60),
+            routes: vec![Route::Stable(StablePath::<PoolId, CurrencyId> {
+                pool_id: 1,
+                base_pool_id: 0,
+                mode: FromBase,
+                from_currency: Token(TOKEN2_SYMBOL),
+                to_currency: Token(TOKEN4_SYMBOL),
+            })],
+        },
+    ]
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_split_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        let token2_balance_before = Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free;
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_noop!(
+            DexSwapRouter::swap_exact_tokens_for_tokens_split(
+                RawOrigin::Signed(USER1).into(),
+                2e16 as Balance,
+                u128::MAX,
+                token2_to_token4_splits(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens_split(
+            RawOrigin::Signed(USER1).into(),
+            2e16 as Balance,
+            0,
+            token2_to_token4_splits(),
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free,
+            token2_balance_before - 2e16 as Balance
+        );
+        assert!(Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free > token4_balance_before);
+    })
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_split_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        let token2_balance_before = Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free;
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_noop!(
+            DexSwapRouter::swap_tokens_for_exact_tokens_split(
+                RawOrigin::Signed(USER1).into(),
+                20_000,
+                1e15 as Balance,
+                token2_to_token4_splits(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_ok!(DexSwapRouter::swap_tokens_for_exact_tokens_split(
+            RawOrigin::Signed(USER1).into(),
+            20_000,
+            1e17 as Balance,
+            token2_to_token4_splits(),
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free,
+            token4_balance_before + 20_000
+        );
+        assert!(Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free < token2_balance_before);
+    })
+}
+
+#[test]
+fn split_swaps_with_invalid_splits_should_fail() {
+    new_test_ext().execute_with(|| {
+        setup_stable_pools();
+        setup_pools();
+
+        let mut not_adding_up = token2_to_token4_splits();
+        not_adding_up[1].proportion = Permill::from_percent(50);
+
+        let mut other_currency = token2_to_token4_splits();
+        other_currency[1].routes = vec![Route::General(vec![TOKEN2_ASSET_ID, TOKEN1_ASSET_ID])];
+
+        let mut zero_proportion = token2_to_token4_splits();
+        zero_proportion[0].proportion = Permill::one();
+        zero_proportion[1].proportion = Permill::zero();
+
+        for splits in [vec![], not_adding_up, other_currency, zero_proportion] {
+            assert_noop!(
+                DexSwapRouter::swap_exact_tokens_for_tokens_split(
+                    RawOrigin::Signed(USER1).into(),
+                    2e16 as Balance,
+                    0,
+                    splits,
+                    USER2,
+                    u64::MAX,
+                ),
+                Error::<Test>::InvalidSplits
+            );
+        }
+    })
+}
+
+fn setup_weighted_pool() {
+    assert_ok!(DexWeighted::create_pool(
+        RawOrigin::Root.into(),
+        vec![Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL)],
+        vec![Perbill::from_percent(80), Perbill::from_percent(20)],
+        Permill::from_parts(3000),
+    ));
+    assert_ok!(DexWeighted::add_liquidity(
+        RawOrigin::Signed(USER1).into(),
+        0,
+        vec![8e17 as Balance, 2e6 as Balance],
+        0,
+        USER1,
+        u64::MAX,
+    ));
+}
+
+fn weighted(from_currency: CurrencyId, to_currency: CurrencyId) -> Route<PoolId, CurrencyId> {
+    Route::Weighted(WeightedPath {
+        pool_id: 0,
+        from_currency,
+        to_currency,
+    })
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_through_weighted_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_pools();
+        setup_weighted_pool();
+
+        let routes = vec![
+            Route::General(vec![TOKEN1_ASSET_ID, TOKEN2_ASSET_ID]),
+            weighted(Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL)),
+        ];
+        let token2_amount =
+            DexGeneral::get_amount_out_by_path(1e16 as Balance, &[TOKEN1_ASSET_ID, TOKEN2_ASSET_ID]).unwrap()[1];
+        let amount_out = DexWeighted::calculate_swap(0, 0, 1, token2_amount).unwrap();
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_noop!(
+            DexSwapRouter::swap_exact_tokens_for_tokens(
+                RawOrigin::Signed(USER1).into(),
+                1e16 as Balance,
+                amount_out + 1,
+                routes.clone(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens(
+            RawOrigin::Signed(USER1).into(),
+            1e16 as Balance,
+            amount_out,
+            routes,
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free,
+            token4_balance_before + amount_out
+        );
+    })
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_through_weighted_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_weighted_pool();
+
+        let routes = vec![weighted(Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL))];
+        let amount_in = DexWeighted::calculate_swap_for_exact(0, 0, 1, 10_000).unwrap();
+        let token2_balance_before = Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free;
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_ok!(DexSwapRouter::swap_tokens_for_exact_tokens(
+            RawOrigin::Signed(USER1).into(),
+            10_000,
+            amount_in,
+            routes.clone(),
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free,
+            token2_balance_before - amount_in
+        );
+        assert!(Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free >= token4_balance_before + 10_000);
+
+        // more than the pool can pay out in a single swap
+        assert_noop!(
+            DexSwapRouter::swap_tokens_for_exact_tokens(
+                RawOrigin::Signed(USER1).into(),
+                1e6 as Balance,
+                u128::MAX,
+                routes,
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::InsufficientLiquidity
+        );
+    })
+}
+
+#[test]
+fn weighted_route_with_foreign_currency_should_fail() {
+    new_test_ext().execute_with(|| {
+        setup_weighted_pool();
+
+        assert_noop!(
+            DexSwapRouter::swap_exact_tokens_for_tokens(
+                RawOrigin::Signed(USER1).into(),
+                1e16 as Balance,
+                0,
+                vec![weighted(Token(TOKEN1_SYMBOL), Token(TOKEN4_SYMBOL))],
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::MismatchPoolAndCurrencyId
+        );
+    })
+}
+
+#[test]
+fn find_best_route_should_consider_weighted_pools() {
+    new_test_ext().execute_with(|| {
+        setup_pools();
+        setup_weighted_pool();
+
+        // the weighted pool is the only venue trading token 4
+        let quote =
+            DexSwapRouter::find_best_route(TOKEN1_ASSET_ID, Token(TOKEN4_SYMBOL), 1e16 as Balance, MAX_ROUTE_HOPS)
+                .unwrap();
+        assert_eq!(
+            quote.routes,
+            vec![
+                Route::General(vec![TOKEN1_ASSET_ID, TOKEN2_ASSET_ID]),
+                weighted(Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL)),
+            ]
+        );
+
+        let balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+        assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens(
+            RawOrigin::Signed(USER1).into(),
+            1e16 as Balance,
+            quote.amount_out,
+            quote.routes,
+            USER2,
+            u64::MAX,
+        ));
+        assert_eq!(
+            Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free,
+            balance_before + quote.amount_out
+        );
+    })
+}
+
+/// A concentrated pool at about one token 4 per token 2, which holds one of each around the price.
+fn setup_concentrated_pool() {
+    assert_ok!(DexConcentrated::create_pool(
+        RawOrigin::Root.into(),
+        Token(TOKEN2_SYMBOL),
+        Token(TOKEN4_SYMBOL),
+        Permill::from_parts(3000),
+        10,
+        -276_320,
+    ));
+    assert_ok!(DexConcentrated::create_position(
+        RawOrigin::Signed(USER1).into(),
+        0,
+        -277_320,
+        -275_320,
+        TOKEN2_UNIT,
+        TOKEN4_UNIT,
+        0,
+        0,
+        u64::MAX,
+    ));
+}
+
+fn concentrated(from_currency: CurrencyId, to_currency: CurrencyId) -> Route<PoolId, CurrencyId> {
+    Route::Concentrated(ConcentratedPath {
+        pool_id: 0,
+        from_currency,
+        to_currency,
+    })
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_through_concentrated_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_pools();
+        setup_concentrated_pool();
+
+        let routes = vec![
+            Route::General(vec![TOKEN1_ASSET_ID, TOKEN2_ASSET_ID]),
+            concentrated(Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL)),
+        ];
+        let token2_amount =
+            DexGeneral::get_amount_out_by_path(1e16 as Balance, &[TOKEN1_ASSET_ID, TOKEN2_ASSET_ID]).unwrap()[1];
+        let amount_out = DexConcentrated::calculate_swap(0, Token(TOKEN2_SYMBOL), token2_amount).unwrap();
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_noop!(
+            DexSwapRouter::swap_exact_tokens_for_tokens(
+                RawOrigin::Signed(USER1).into(),
+                1e16 as Balance,
+                amount_out + 1,
+                routes.clone(),
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens(
+            RawOrigin::Signed(USER1).into(),
+            1e16 as Balance,
+            amount_out,
+            routes,
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free,
+            token4_balance_before + amount_out
+        );
+    })
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_through_concentrated_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        setup_concentrated_pool();
+
+        let routes = vec![concentrated(Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL))];
+        let amount_in = DexConcentrated::calculate_swap_for_exact(0, Token(TOKEN2_SYMBOL), 10_000).unwrap();
+        let token2_balance_before = Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free;
+        let token4_balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+
+        assert_ok!(DexSwapRouter::swap_tokens_for_exact_tokens(
+            RawOrigin::Signed(USER1).into(),
+            10_000,
+            amount_in,
+            routes.clone(),
+            USER2,
+            u64::MAX,
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER1, Token(TOKEN2_SYMBOL)).free,
+            token2_balance_before - amount_in
+        );
+        assert!(Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free >= token4_balance_before + 10_000);
+
+        // more than the liquidity in range of the pool
+        assert_noop!(
+            DexSwapRouter::swap_tokens_for_exact_tokens(
+                RawOrigin::Signed(USER1).into(),
+                1e7 as Balance,
+                u128::MAX,
+                routes,
+                USER2,
+                u64::MAX,
+            ),
+            Error::<Test>::InsufficientLiquidity
+        );
+    })
+}
+
+#[test]
+fn concentrated_route_with_foreign_currency_should_fail() {
+    new_test_ext().execute_with(|| {
+        setup_concentrated_pool();
+
+        for route in [
+            concentrated(Token(TOKEN1_SYMBOL), Token(TOKEN4_SYMBOL)),
+            concentrated(Token(TOKEN2_SYMBOL), Token(TOKEN1_SYMBOL)),
+            concentrated(Token(TOKEN2_SYMBOL), Token(TOKEN2_SYMBOL)),
+        ] {
+            assert_noop!(
+                DexSwapRouter::swap_exact_tokens_for_tokens(
+                    RawOrigin::Signed(USER1).into(),
+                    1e16 as Balance,
+                    0,
+                    vec![route],
+                    USER2,
+                    u64::MAX,
+                ),
+                Error::<Test>::MismatchPoolAndCurrencyId
+            );
+        }
+    })
+}
+
+#[test]
+fn find_best_route_should_consider_concentrated_pools() {
+    new_test_ext().execute_with(|| {
+        setup_pools();
+        setup_concentrated_pool();
+
+        // the concentrated pool is the only venue trading token 4
+        let quote =
+            DexSwapRouter::find_best_route(TOKEN1_ASSET_ID, Token(TOKEN4_SYMBOL), 1e16 as Balance, MAX_ROUTE_HOPS)
+                .unwrap();
+        assert_eq!(
+            quote.routes,
+            vec![
+                Route::General(vec![TOKEN1_ASSET_ID, TOKEN2_ASSET_ID]),
+                concentrated(Token(TOKEN2_SYMBOL), Token(TOKEN4_SYMBOL)),
+            ]
+        );
+
+        let balance_before = Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free;
+        assert_ok!(DexSwapRouter::swap_exact_tokens_for_tokens(
+            RawOrigin::Signed(USER1).into(),
+            1e16 as Balance,
+            quote.amount_out,
+            quote.routes,
+            USER2,
+            u64::MAX,
+        ));
+        assert_eq!(
+            Tokens::accounts(USER2, Token(TOKEN4_SYMBOL)).free,
+            balance_before + quote.amount_out
+        );
+    })
+}
+
+fn place_token2_order(owner: AccountId, amount_in: Balance, amount_out_min: Balance, expiry: u64) -> OrderId {
+    assert_ok!(DexSwapRouter::place_order(
+        RawOrigin::Signed(owner).into(),
+        TOKEN2_ASSET_ID,
+        TOKEN1_ASSET_ID,
+        amount_in,
+        amount_out_min,
+        expiry,
+    ));
+    DexSwapRouter::next_order_id() - 1
+}
+
+#[test]
+fn place_and_cancel_order_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_pools();
+
+        let balance_before = Tokens::accounts(USER2, TOKEN2_ASSET_ID).free;
+        let order_id = place_token2_order(USER2, 1e16 as Balance, 1e16 as Balance, 10);
+
+        assert_eq!(
+            Tokens::accounts(USER2, TOKEN2_ASSET_ID).free,
+            balance_before - 1e16 as Balance
+        );
+        assert_eq!(
+            Tokens::accounts(DexSwapRouter::escrow_account(), TOKEN2_ASSET_ID).free,
+            1e16 as Balance
+        );
+        assert_eq!(
+            DexSwapRouter::open_orders(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID),
+            vec![(
+                order_id,
+                LimitOrder {
+                    owner: USER2,
+                    currency_in: TOKEN2_ASSET_ID,
+                    currency_out: TOKEN1_ASSET_ID,
+                    amount_in: 1e16 as Balance,
+                    amount_out_min: 1e16 as Balance,
+                    expiry: 10,
+                }
+            )]
+        );
+        assert!(DexSwapRouter::open_orders(TOKEN1_ASSET_ID, TOKEN2_ASSET_ID).is_empty());
+
+        assert_noop!(
+            DexSwapRouter::cancel_order(RawOrigin::Signed(USER3).into(), order_id),
+            Error::<Test>::NotOrderOwner
+        );
+        assert_ok!(DexSwapRouter::cancel_order(RawOrigin::Signed(USER2).into(), order_id));
+
+        assert_eq!(Tokens::accounts(USER2, TOKEN2_ASSET_ID).free, balance_before);
+        assert_eq!(DexSwapRouter::order(order_id), None);
+        assert!(DexSwapRouter::open_orders(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID).is_empty());
+        System::assert_last_event(RuntimeEvent::DexSwapRouter(Event::OrderCancelled {
+            order_id,
+            amount_in: 1e16 as Balance,
+        }));
+    })
+}
+
+#[test]
+fn place_invalid_order_should_fail() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        setup_pools();
+
+        for (currency_out, amount_in, amount_out_min, expiry) in [
+            (TOKEN2_ASSET_ID, 1e16 as Balance, 1e16 as Balance, 10),
+            (TOKEN1_ASSET_ID, 0, 1e16 as Balance, 10),
+            (TOKEN1_ASSET_ID, 1e16 as Balance, 0, 10),
+            (TOKEN1_ASSET_ID, 1e16 as Balance, 1e16 as Balance, 5),
+        ] {
+            assert_noop!(
+                DexSwapRouter::place_order(
+                    RawOrigin::Signed(USER2).into(),
+                    TOKEN2_ASSET_ID,
+                    currency_out,
+                    amount_in,
+                    amount_out_min,
+                    expiry,
+                ),
+                Error::<Test>::InvalidOrder
+            );
+        }
+    })
+}
+
+#[test]
+fn fill_order_partially_should_pay_owner_and_keeper() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_pools();
+
+        let order_id = place_token2_order(USER2, 1e16 as Balance, 9e15 as Balance, 10);
+        let path = vec![TOKEN2_ASSET_ID, TOKEN1_ASSET_ID];
+        let amount_out = *DexGeneral::get_amount_out_by_path(4e15 as Balance, &path)
+            .unwrap()
+            .last()
+            .unwrap();
+        let keeper_reward = amount_out / 100;
+        let owner_balance_before = Tokens::accounts(USER2, TOKEN1_ASSET_ID).free;
+        let keeper_balance_before = Tokens::accounts(USER3, TOKEN1_ASSET_ID).free;
+
+        assert_ok!(DexSwapRouter::fill_order(
+            RawOrigin::Signed(USER3).into(),
+            order_id,
+            4e15 as Balance,
+            vec![Route::General(path)],
+        ));
+
+        assert_eq!(
+            Tokens::accounts(USER2, TOKEN1_ASSET_ID).free,
+            owner_balance_before + amount_out - keeper_reward
+        );
+        assert_eq!(
+            Tokens::accounts(USER3, TOKEN1_ASSET_ID).free,
+            keeper_balance_before + keeper_reward
+        );
+        assert_eq!(
+            Tokens::accounts(DexSwapRouter::escrow_account(), TOKEN2_ASSET_ID).free,
+            6e15 as Balance
+        );
+        let order = DexSwapRouter::order(order_id).unwrap();
+        assert_eq!(order.amount_in, 6e15 as Balance);
+        // the limit price of the remaining input is unchanged
+        assert_eq!(order.amount_out_min, 54e14 as Balance);
+        System::assert_last_event(RuntimeEvent::DexSwapRouter(Event::OrderFilled {
+            order_id,
+            keeper: Some(USER3),
+            amount_in: 4e15 as Balance,
+            amount_out,
+            keeper_reward,
+        }));
+
+        assert_ok!(DexSwapRouter::fill_order(
+            RawOrigin::Signed(USER3).into(),
+            order_id,
+            6e15 as Balance,
+            vec![Route::General(vec![TOKEN2_ASSET_ID, TOKEN1_ASSET_ID])],
+        ));
+        assert_eq!(DexSwapRouter::order(order_id), None);
+        assert!(DexSwapRouter::open_orders(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID).is_empty());
+    })
+}
+
+#[test]
+fn fill_order_should_respect_order() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_stable_pools();
+        setup_pools();
+
+        // the pair fee alone keeps the output below the input
+        let order_id = place_token2_order(USER2, 1e16 as Balance, 1e16 as Balance, 10);
+        let fill =
+            |amount_in, routes| DexSwapRouter::fill_order(RawOrigin::Signed(USER3).into(), order_id, amount_in, routes);
+        let route = || vec![Route::General(vec![TOKEN2_ASSET_ID, TOKEN1_ASSET_ID])];
+
+        assert_noop!(fill(1e16 as Balance, route()), Error::<Test>::OrderPriceNotMet);
+        assert_noop!(fill(2e16 as Balance, route()), Error::<Test>::InvalidFillAmount);
+        assert_noop!(fill(0, route()), Error::<Test>::InvalidFillAmount);
+        assert_noop!(
+            fill(
+                1e16 as Balance,
+                vec![Route::Stable(StablePath::<PoolId, CurrencyId> {
+                    pool_id: 0,
+                    base_pool_id: 0,
+                    mode: StableSwapMode::Single,
+                    from_currency: Token(TOKEN2_SYMBOL),
+                    to_currency: Token(TOKEN3_SYMBOL),
+                })]
+            ),
+            Error::<Test>::InvalidRoutes
+        );
+        assert_noop!(
+            DexSwapRouter::fill_order(RawOrigin::Signed(USER3).into(), order_id + 1, 1e16 as Balance, route()),
+            Error::<Test>::OrderNotFound
+        );
+
+        System::set_block_number(10);
+        assert_noop!(fill(1e16 as Balance, route()), Error::<Test>::OrderExpired);
+    })
+}
+
+#[test]
+fn on_idle_should_fill_and_expire_orders() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_pools();
+
+        let fillable = place_token2_order(USER2, 1e16 as Balance, 9e15 as Balance, 10);
+        let unfillable = place_token2_order(USER3, 1e16 as Balance, 1e16 as Balance, 5);
+        let owner_balance_before = Tokens::accounts(USER2, TOKEN1_ASSET_ID).free;
+        let amount_out = *DexGeneral::get_amount_out_by_path(1e16 as Balance, &[TOKEN2_ASSET_ID, TOKEN1_ASSET_ID])
+            .unwrap()
+            .last()
+            .unwrap();
+
+        DexSwapRouter::on_idle(1, Weight::MAX);
+
+        // no keeper reward is paid by the sweep
+        assert_eq!(
+            Tokens::accounts(USER2, TOKEN1_ASSET_ID).free,
+            owner_balance_before + amount_out
+        );
+        assert_eq!(DexSwapRouter::order(fillable), None);
+        assert!(DexSwapRouter::order(unfillable).is_some());
+
+        let balance_before = Tokens::accounts(USER3, TOKEN2_ASSET_ID).free;
+        DexSwapRouter::on_idle(5, Weight::MAX);
+
+        assert_eq!(
+            Tokens::accounts(USER3, TOKEN2_ASSET_ID).free,
+            balance_before + 1e16 as Balance
+        );
+        assert_eq!(DexSwapRouter::order(unfillable), None);
+        System::assert_last_event(RuntimeEvent::DexSwapRouter(Event::OrderExpired {
+            order_id: unfillable,
+            amount_in: 1e16 as Balance,
+        }));
+    })
+}
+
+#[test]
+fn on_idle_should_continue_after_last_visited_order() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_pools();
+
+        place_token2_order(USER2, 1e15 as Balance, 9e14 as Balance, 10);
+        place_token2_order(USER3, 1e15 as Balance, 9e14 as Balance, 10);
+
+        // enough weight for a single order
+        let weight = DexSwapRouter::on_idle(1, <() as WeightInfo>::fill_order());
+        assert_eq!(weight, <() as WeightInfo>::fill_order());
+        assert_eq!(DexSwapRouter::open_orders(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID).len(), 1);
+
+        DexSwapRouter::on_idle(2, <() as WeightInfo>::fill_order());
+        assert!(DexSwapRouter::open_orders(TOKEN2_ASSET_ID, TOKEN1_ASSET_ID).is_empty());
+
+        DexSwapRouter::on_idle(3, Weight::MAX);
+        assert_eq!(SweepCursor::<Test>::get(), None);
+    })
+}
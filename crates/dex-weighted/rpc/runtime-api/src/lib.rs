@@ -0,0 +1,43 @@
+//! Runtime API definition for weighted amm.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+// The `unnecessary_mut_passed` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::unnecessary_mut_passed)]
+use codec::Codec;
+use sp_runtime::{Perbill, Permill};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait DexWeightedApi<CurrencyId, Balance, AccountId, PoolId> where
+        Balance: Codec,
+        CurrencyId: Codec,
+        AccountId: Codec,
+        PoolId: Codec,
+    {
+        fn get_currencies(pool_id: PoolId) -> Vec<CurrencyId>;
+
+        fn get_weights(pool_id: PoolId) -> Vec<Perbill>;
+
+        fn get_lp_currency(pool_id: PoolId) -> Option<CurrencyId>;
+
+        fn get_currency_balances(pool_id: PoolId) -> Vec<Balance>;
+
+        fn get_swap_fee(pool_id: PoolId) -> Permill;
+
+        fn get_spot_price(pool_id: PoolId, in_index: u32, out_index: u32) -> Option<Balance>;
+
+        fn calculate_swap(pool_id: PoolId, in_index: u32, out_index: u32, in_amount: Balance) -> Option<Balance>;
+
+        fn calculate_swap_for_exact(pool_id: PoolId, in_index: u32, out_index: u32, out_amount: Balance) -> Option<Balance>;
+
+        fn calculate_add_liquidity(pool_id: PoolId, amounts: Vec<Balance>) -> Option<Balance>;
+
+        fn calculate_add_liquidity_one_currency(pool_id: PoolId, index: u32, amount: Balance) -> Option<Balance>;
+
+        fn calculate_remove_liquidity(pool_id: PoolId, amount: Balance) -> Option<Vec<Balance>>;
+
+        fn calculate_remove_liquidity_one_currency(pool_id: PoolId, amount: Balance, index: u32) -> Option<Balance>;
+    }
+}
@@ -0,0 +1,338 @@
+//! RPC interface for the weighted amm pallet.
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+    traits::{Block as BlockT, MaybeDisplay},
+    Perbill, Permill,
+};
+use std::sync::Arc;
+
+pub use dex_weighted_rpc_runtime_api::DexWeightedApi as DexWeightedRuntimeApi;
+
+#[rpc(client, server)]
+pub trait DexWeightedApi<BlockHash, CurrencyId, Balance, AccountId, PoolId> {
+    #[method(name = "dexWeighted_getCurrencies")]
+    fn get_currencies(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Vec<CurrencyId>>;
+
+    #[method(name = "dexWeighted_getWeights")]
+    fn get_weights(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Vec<Perbill>>;
+
+    #[method(name = "dexWeighted_getLpCurrency")]
+    fn get_lp_currency(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<CurrencyId>;
+
+    #[method(name = "dexWeighted_getCurrencyBalances")]
+    fn get_currency_balances(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Vec<NumberOrHex>>;
+
+    #[method(name = "dexWeighted_getSwapFee")]
+    fn get_swap_fee(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Permill>;
+
+    #[method(name = "dexWeighted_getSpotPrice")]
+    fn get_spot_price(
+        &self,
+        pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexWeighted_calculateSwap")]
+    fn calculate_swap(
+        &self,
+        pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        in_amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexWeighted_calculateSwapForExact")]
+    fn calculate_swap_for_exact(
+        &self,
+        pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        out_amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexWeighted_calculateAddLiquidity")]
+    fn calculate_add_liquidity(
+        &self,
+        pool_id: PoolId,
+        amounts: Vec<Balance>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexWeighted_calculateAddLiquidityOneCurrency")]
+    fn calculate_add_liquidity_one_currency(
+        &self,
+        pool_id: PoolId,
+        index: u32,
+        amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    #[method(name = "dexWeighted_calculateRemoveLiquidity")]
+    fn calculate_remove_liquidity(
+        &self,
+        pool_id: PoolId,
+        amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NumberOrHex>>;
+
+    #[method(name = "dexWeighted_calculateRemoveLiquidityOneCurrency")]
+    fn calculate_remove_liquidity_one_currency(
+        &self,
+        pool_id: PoolId,
+        amount: Balance,
+        index: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+}
+
+pub struct DexWeighted<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> DexWeighted<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, CurrencyId, Balance, AccountId, PoolId>
+    DexWeightedApiServer<<Block as BlockT>::Hash, CurrencyId, Balance, AccountId, PoolId> for DexWeighted<C, Block>
+where
+    Block: BlockT,
+    CurrencyId: Codec,
+    Balance: Codec + TryInto<NumberOrHex> + std::fmt::Debug + MaybeDisplay + Copy,
+    AccountId: Codec,
+    PoolId: Codec,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: DexWeightedRuntimeApi<Block, CurrencyId, Balance, AccountId, PoolId>,
+{
+    fn get_currencies(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<CurrencyId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_currencies(at, pool_id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_weights(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<Perbill>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_weights(at, pool_id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_lp_currency(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<CurrencyId> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_lp_currency(at, pool_id).map_or_else(
+            |e| Err(runtime_error_into_rpc_err(e)),
+            |v| v.ok_or(runtime_error_into_rpc_err("not found")),
+        )
+    }
+
+    fn get_currency_balances(
+        &self,
+        pool_id: PoolId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_currency_balances(at, pool_id)
+            .map_err(runtime_error_into_rpc_err)?
+            .iter()
+            .map(|b| try_into_rpc_balance(*b))
+            .collect()
+    }
+
+    fn get_swap_fee(&self, pool_id: PoolId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Permill> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_swap_fee(at, pool_id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_spot_price(
+        &self,
+        pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let price = api
+            .get_spot_price(at, pool_id, in_index, out_index)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("invalid pool or index"))?;
+
+        try_into_rpc_balance(price)
+    }
+
+    fn calculate_swap(
+        &self,
+        pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        in_amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_swap(at, pool_id, in_index, out_index, in_amount)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("swap not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn calculate_swap_for_exact(
+        &self,
+        pool_id: PoolId,
+        in_index: u32,
+        out_index: u32,
+        out_amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_swap_for_exact(at, pool_id, in_index, out_index, out_amount)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("swap not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn calculate_add_liquidity(
+        &self,
+        pool_id: PoolId,
+        amounts: Vec<Balance>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_add_liquidity(at, pool_id, amounts)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("deposit not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn calculate_add_liquidity_one_currency(
+        &self,
+        pool_id: PoolId,
+        index: u32,
+        amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_add_liquidity_one_currency(at, pool_id, index, amount)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("deposit not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+
+    fn calculate_remove_liquidity(
+        &self,
+        pool_id: PoolId,
+        amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.calculate_remove_liquidity(at, pool_id, amount)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("withdrawal not possible"))?
+            .iter()
+            .map(|b| try_into_rpc_balance(*b))
+            .collect()
+    }
+
+    fn calculate_remove_liquidity_one_currency(
+        &self,
+        pool_id: PoolId,
+        amount: Balance,
+        index: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let amount = api
+            .calculate_remove_liquidity_one_currency(at, pool_id, amount, index)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or(runtime_error_into_rpc_err("withdrawal not possible"))?;
+
+        try_into_rpc_balance(amount)
+    }
+}
+
+fn try_into_rpc_balance<Balance: Codec + TryInto<NumberOrHex> + MaybeDisplay + Copy + std::fmt::Debug>(
+    value: Balance,
+) -> RpcResult<NumberOrHex> {
+    value.try_into().map_err(|_| {
+        CallError::Custom(ErrorObject::owned(
+            Error::RuntimeError.into(),
+            "error in weighted amm pallet",
+            Some("transfer into rpc balance".to_string()),
+        ))
+        .into()
+    })
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Display) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "error in weighted pallet",
+        Some(err.to_string()),
+    ))
+    .into()
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as WeightedPallet;
+
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+const UNIT: Balance = 1_000_000_000_000;
+
+fn pool_currencies<T: Config>(b: u32) -> Vec<T::CurrencyId>
+where
+    T::CurrencyId: From<u32>,
+{
+    (0..b).map(Into::into).collect()
+}
+
+fn pool_weights(b: u32) -> Vec<Perbill> {
+    let mut weights = vec![Perbill::from_rational(1, b); b as usize];
+    // the rounding remainder goes to the first currency
+    let total = weights.iter().map(|weight| weight.deconstruct()).sum::<u32>();
+    weights[0] = Perbill::from_parts(weights[0].deconstruct() + Perbill::one().deconstruct() - total);
+    weights
+}
+
+fn setup_pool_and_add_liquidity<T: Config>(caller: T::AccountId, b: u32) -> T::PoolId
+where
+    T::CurrencyId: From<u32>,
+{
+    let pool_id = WeightedPallet::<T>::next_pool_id();
+    let currency_ids = pool_currencies::<T>(b);
+
+    assert_ok!(WeightedPallet::<T>::create_pool(
+        RawOrigin::Root.into(),
+        currency_ids.clone(),
+        pool_weights(b),
+        MAX_SWAP_FEE,
+    ));
+
+    for currency_id in &currency_ids {
+        assert_ok!(T::MultiCurrency::deposit(*currency_id, &caller, 1000 * UNIT));
+    }
+
+    assert_ok!(WeightedPallet::<T>::add_liquidity(
+        RawOrigin::Signed(caller.clone()).into(),
+        pool_id,
+        vec![100 * UNIT; b as usize],
+        0,
+        caller,
+        1000u32.into()
+    ));
+
+    pool_id
+}
+
+#[benchmarks(
+    where
+        T: Config,
+        T::CurrencyId: From<u32>,
+)]
+pub mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    pub fn create_pool(b: Linear<MIN_POOL_CURRENCIES, MAX_POOL_CURRENCIES>) {
+        let currency_ids = pool_currencies::<T>(b);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, currency_ids, pool_weights(b), MAX_SWAP_FEE);
+
+        assert!(WeightedPallet::<T>::pools(T::PoolId::zero()).is_some());
+    }
+
+    #[benchmark]
+    pub fn add_liquidity(b: Linear<MIN_POOL_CURRENCIES, MAX_POOL_CURRENCIES>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_add_liquidity::<T>(caller.clone(), b);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            vec![10 * UNIT; b as usize],
+            0,
+            caller.clone(),
+            1000u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn add_liquidity_one_currency() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_add_liquidity::<T>(caller.clone(), MAX_POOL_CURRENCIES);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            MAX_POOL_CURRENCIES - 1,
+            10 * UNIT,
+            0,
+            caller.clone(),
+            1000u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn swap() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_add_liquidity::<T>(caller.clone(), MAX_POOL_CURRENCIES);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            0,
+            1,
+            10 * UNIT,
+            0,
+            caller.clone(),
+            1000u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn remove_liquidity(b: Linear<MIN_POOL_CURRENCIES, MAX_POOL_CURRENCIES>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_add_liquidity::<T>(caller.clone(), b);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            INIT_POOL_SUPPLY / 10,
+            vec![0; b as usize],
+            caller.clone(),
+            1000u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn remove_liquidity_one_currency() {
+        let caller: T::AccountId = whitelisted_caller();
+        let pool_id = setup_pool_and_add_liquidity::<T>(caller.clone(), MAX_POOL_CURRENCIES);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            pool_id,
+            INIT_POOL_SUPPLY / 100,
+            MAX_POOL_CURRENCIES - 1,
+            0,
+            caller.clone(),
+            1000u32.into(),
+        );
+    }
+
+    impl_benchmark_test_suite!(WeightedPallet, crate::mock::ExtBuilder::build(), crate::mock::Test);
+}
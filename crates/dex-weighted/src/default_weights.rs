@@ -0,0 +1,285 @@
+
+//! Autogenerated weights for dex_weighted
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-07-24, STEPS: `10`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: ``, CPU: `Intel(R) Core(TM) i7-6700 CPU @ 3.40GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kintsugi-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/debug/interbtc-parachain
+// benchmark
+// pallet
+// --pallet
+// dex-weighted
+// --extrinsic
+// *
+// --chain
+// kintsugi-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --steps
+// 10
+// --repeat
+// 1
+// --output
+// ./crates/dex-weighted/src/default_weights.rs
+// --template
+// .deploy/default-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for dex_weighted.
+pub trait WeightInfo {
+	fn create_pool(b: u32, ) -> Weight;
+	fn add_liquidity(b: u32, ) -> Weight;
+	fn add_liquidity_one_currency() -> Weight;
+	fn swap() -> Weight;
+	fn remove_liquidity(b: u32, ) -> Weight;
+	fn remove_liquidity_one_currency() -> Weight;
+}
+
+/// Weights for dex_weighted using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DexWeighted NextPoolId (r:1 w:1)
+	/// Proof: DexWeighted NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DexWeighted LpCurrencies (r:1 w:1)
+	/// Proof: DexWeighted LpCurrencies (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexWeighted Pools (r:0 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 8]`.
+	fn create_pool(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3593`
+		// Minimum execution time: 41_287_000 picoseconds.
+		Weight::from_parts(40_126_391, 3593)
+			// Standard Error: 38_902
+			.saturating_add(Weight::from_parts(412_530, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:17 w:17)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 8]`.
+	fn add_liquidity(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208`
+		//  Estimated: `3783`
+		// Minimum execution time: 118_914_000 picoseconds.
+		Weight::from_parts(71_554_120, 3783)
+			// Standard Error: 1_187_045
+			.saturating_add(Weight::from_parts(23_017_652, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn add_liquidity_one_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1302`
+		//  Estimated: `8760`
+		// Minimum execution time: 132_605_000 picoseconds.
+		Weight::from_parts(134_118_000, 8760)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1395`
+		//  Estimated: `11350`
+		// Minimum execution time: 109_732_000 picoseconds.
+		Weight::from_parts(111_266_000, 11350)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:17 w:17)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 8]`.
+	fn remove_liquidity(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `3783`
+		// Minimum execution time: 109_157_000 picoseconds.
+		Weight::from_parts(63_804_775, 3783)
+			// Standard Error: 1_093_226
+			.saturating_add(Weight::from_parts(22_460_318, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_liquidity_one_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1436`
+		//  Estimated: `8760`
+		// Minimum execution time: 139_420_000 picoseconds.
+		Weight::from_parts(141_031_000, 8760)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DexWeighted NextPoolId (r:1 w:1)
+	/// Proof: DexWeighted NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DexWeighted LpCurrencies (r:1 w:1)
+	/// Proof: DexWeighted LpCurrencies (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexWeighted Pools (r:0 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 8]`.
+	fn create_pool(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3593`
+		// Minimum execution time: 41_287_000 picoseconds.
+		Weight::from_parts(40_126_391, 3593)
+			// Standard Error: 38_902
+			.saturating_add(Weight::from_parts(412_530, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:17 w:17)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 8]`.
+	fn add_liquidity(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208`
+		//  Estimated: `3783`
+		// Minimum execution time: 118_914_000 picoseconds.
+		Weight::from_parts(71_554_120, 3783)
+			// Standard Error: 1_187_045
+			.saturating_add(Weight::from_parts(23_017_652, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn add_liquidity_one_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1302`
+		//  Estimated: `8760`
+		// Minimum execution time: 132_605_000 picoseconds.
+		Weight::from_parts(134_118_000, 8760)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1395`
+		//  Estimated: `11350`
+		// Minimum execution time: 109_732_000 picoseconds.
+		Weight::from_parts(111_266_000, 11350)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:17 w:17)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 8]`.
+	fn remove_liquidity(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `3783`
+		// Minimum execution time: 109_157_000 picoseconds.
+		Weight::from_parts(63_804_775, 3783)
+			// Standard Error: 1_093_226
+			.saturating_add(Weight::from_parts(22_460_318, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexWeighted Pools (r:1 w:1)
+	/// Proof: DexWeighted Pools (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_liquidity_one_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1436`
+		//  Estimated: `8760`
+		// Minimum execution time: 139_420_000 picoseconds.
+		Weight::from_parts(141_031_000, 8760)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
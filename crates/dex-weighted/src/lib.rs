@@ -0,0 +1,774 @@
+//! # Weighted AMM Pallet
+//!
+//! Based on the Balancer V1 weighted pool architecture.
+//!
+//! A pool holds two to eight currencies, each with a fixed weight. The pool keeps the product of
+//! its balances, each raised to the power of its weight, constant on swaps, so the value of every
+//! currency in the pool stays at its weight, e.g. 80% INTR and 20% IBTC.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality for:
+//!
+//! - Creating pools
+//! - Adding / removing liquidity in proportion to the balances of a pool
+//! - Adding / removing liquidity in a single currency
+//! - Swapping currencies
+//!
+//! ### Terminology
+//!
+//! - **Weight:** The share of the value of a pool held in one of its currencies.
+//!
+//! - **Swap Fee:** The fee taken from the input currency. Single currency joins and exits pay it on the share which is
+//!   implicitly traded against the other currencies.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+#![allow(clippy::too_many_arguments)]
+
+pub mod math;
+pub mod rpc;
+pub mod traits;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod default_weights;
+mod primitives;
+
+use frame_support::{
+    dispatch::{Codec, DispatchResult},
+    pallet_prelude::*,
+    transactional, PalletId,
+};
+use orml_traits::MultiCurrency;
+use sp_arithmetic::Rounding;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Zero},
+    Perbill, Permill,
+};
+use sp_std::{vec, vec::Vec};
+
+pub use default_weights::WeightInfo;
+pub use pallet::*;
+pub use primitives::*;
+use traits::WeightedPoolLpCurrencyIdGenerate;
+
+#[allow(type_alias_bounds)]
+type AccountIdOf<T: Config> = <T as frame_system::Config>::AccountId;
+
+#[allow(type_alias_bounds)]
+type PoolOf<T: Config> = WeightedPool<T::CurrencyId, AccountIdOf<T>>;
+
+/// Number of times the input computed for an exact output is topped up, in case the power
+/// approximation leaves the output just short.
+const AMOUNT_IN_CORRECTIONS: usize = 4;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency ID type
+        type CurrencyId: Parameter + Member + Copy + MaybeSerializeDeserialize + Ord + TypeInfo + MaxEncodedLen;
+
+        /// The trait control all currencies
+        type MultiCurrency: MultiCurrency<AccountIdOf<Self>, CurrencyId = Self::CurrencyId, Balance = Balance>;
+
+        /// The pool ID type
+        type PoolId: Parameter + Codec + Copy + Ord + AtLeast32BitUnsigned + Zero + One + Default + MaxEncodedLen;
+
+        /// Generate the CurrencyId for the pool.
+        type LpGenerate: WeightedPoolLpCurrencyIdGenerate<Self::CurrencyId, Self::PoolId>;
+
+        /// This pallet ID.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The id of next pool
+    #[pallet::storage]
+    #[pallet::getter(fn next_pool_id)]
+    pub type NextPoolId<T: Config> = StorageValue<_, T::PoolId, ValueQuery>;
+
+    /// Info of a pool.
+    #[pallet::storage]
+    #[pallet::getter(fn pools)]
+    pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, PoolOf<T>>;
+
+    /// The pool id corresponding to lp currency
+    #[pallet::storage]
+    #[pallet::getter(fn lp_currencies)]
+    pub type LpCurrencies<T: Config> = StorageMap<_, Blake2_128Concat, T::CurrencyId, T::PoolId>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A pool was created.
+        CreatePool {
+            pool_id: T::PoolId,
+            currency_ids: Vec<T::CurrencyId>,
+            weights: Vec<Perbill>,
+            lp_currency_id: T::CurrencyId,
+            swap_fee: Permill,
+            account: T::AccountId,
+        },
+        /// Supply some liquidity to a pool in proportion to its balances.
+        AddLiquidity {
+            pool_id: T::PoolId,
+            who: T::AccountId,
+            to: T::AccountId,
+            supply_amounts: Vec<Balance>,
+            mint_amount: Balance,
+        },
+        /// Supply some liquidity to a pool in a single currency.
+        AddLiquidityOneCurrency {
+            pool_id: T::PoolId,
+            who: T::AccountId,
+            to: T::AccountId,
+            in_index: u32,
+            in_amount: Balance,
+            mint_amount: Balance,
+        },
+        /// Swap a amounts of currency to get other.
+        CurrencyExchange {
+            pool_id: T::PoolId,
+            who: T::AccountId,
+            to: T::AccountId,
+            in_index: u32,
+            in_amount: Balance,
+            out_index: u32,
+            out_amount: Balance,
+        },
+        /// Remove some liquidity from a pool in proportion to its balances.
+        RemoveLiquidity {
+            pool_id: T::PoolId,
+            who: T::AccountId,
+            to: T::AccountId,
+            amounts: Vec<Balance>,
+            burn_amount: Balance,
+        },
+        /// Remove some liquidity from a pool to get only one currency.
+        RemoveLiquidityOneCurrency {
+            pool_id: T::PoolId,
+            who: T::AccountId,
+            to: T::AccountId,
+            out_index: u32,
+            burn_amount: Balance,
+            out_amount: Balance,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The currencies of a pool must be distinct and can't include its lp currency.
+        InvalidPooledCurrency,
+        /// The parameters of a call are contradictory.
+        MismatchParameter,
+        /// A pool needs between MIN_POOL_CURRENCIES and MAX_POOL_CURRENCIES currencies.
+        InvalidCurrencyCount,
+        /// The weights must be at least MIN_WEIGHT and add up to one.
+        InvalidWeights,
+        /// The fee parameter exceeds MAX_SWAP_FEE when creating the pool.
+        ExceedMaxFee,
+        /// The lp currency id is already used when create pool.
+        LpCurrencyAlreadyUsed,
+        /// The pool id is invalid.
+        InvalidPoolId,
+        /// The index of currency id bigger the length of pool's currencies.
+        CurrencyIndexOutRange,
+        /// Forbid swap same currency.
+        SwapSameCurrency,
+        /// The error generate by some arithmetic function.
+        Arithmetic,
+        /// The call already expired.
+        Deadline,
+        /// Slippage is too large.
+        AmountSlippage,
+        /// Require all currencies of this pool when first supply.
+        RequireAllCurrencies,
+        /// The caller does not have enough currencies.
+        InsufficientSupply,
+        /// The pool does not have enough currencies.
+        InsufficientReserve,
+        /// The input exceeds MAX_IN_RATIO of the balance of the pool.
+        ExceedMaxInRatio,
+        /// The output exceeds MAX_OUT_RATIO of the balance of the pool.
+        ExceedMaxOutRatio,
+        /// The transaction change nothing.
+        InvalidTransaction,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a weighted amm pool.
+        ///
+        /// Only admin can create pool.
+        ///
+        /// # Argument
+        ///
+        /// - `currency_ids`: The currencies will be join the created pool.
+        /// - `weights`: The weights of the currencies, which must add up to one.
+        /// - `fee`: The swap fee of created pool.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_pool(currency_ids.len() as u32))]
+        #[transactional]
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            currency_ids: Vec<T::CurrencyId>,
+            weights: Vec<Perbill>,
+            fee: Permill,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(currency_ids.len() == weights.len(), Error::<T>::MismatchParameter);
+            ensure!(
+                (MIN_POOL_CURRENCIES as usize..=MAX_POOL_CURRENCIES as usize).contains(&currency_ids.len()),
+                Error::<T>::InvalidCurrencyCount
+            );
+            let total_weight = weights.iter().map(|weight| weight.deconstruct() as u64).sum::<u64>();
+            ensure!(
+                total_weight == Perbill::one().deconstruct() as u64 && weights.iter().all(|w| *w >= MIN_WEIGHT),
+                Error::<T>::InvalidWeights
+            );
+            ensure!(fee <= MAX_SWAP_FEE, Error::<T>::ExceedMaxFee);
+
+            let pool_id = Self::next_pool_id();
+            let lp_currency_id = T::LpGenerate::generate_by_pool_id(pool_id);
+
+            let mut sorted_currency_ids = currency_ids.clone();
+            sorted_currency_ids.sort();
+            sorted_currency_ids.dedup();
+            ensure!(
+                sorted_currency_ids.len() == currency_ids.len() && !currency_ids.contains(&lp_currency_id),
+                Error::<T>::InvalidPooledCurrency
+            );
+            ensure!(
+                Self::lp_currencies(lp_currency_id).is_none()
+                    && T::MultiCurrency::total_issuance(lp_currency_id).is_zero(),
+                Error::<T>::LpCurrencyAlreadyUsed
+            );
+
+            let account = Self::pool_account_id(&pool_id);
+            frame_system::Pallet::<T>::inc_providers(&account);
+
+            Pools::<T>::insert(
+                pool_id,
+                WeightedPool {
+                    currency_ids: BoundedVec::truncate_from(currency_ids.clone()),
+                    weights: BoundedVec::truncate_from(weights.clone()),
+                    balances: BoundedVec::truncate_from(vec![Zero::zero(); currency_ids.len()]),
+                    lp_currency_id,
+                    fee,
+                    account: account.clone(),
+                },
+            );
+            LpCurrencies::<T>::insert(lp_currency_id, pool_id);
+            NextPoolId::<T>::put(pool_id.checked_add(&One::one()).ok_or(Error::<T>::Arithmetic)?);
+
+            Self::deposit_event(Event::CreatePool {
+                pool_id,
+                currency_ids,
+                weights,
+                lp_currency_id,
+                swap_fee: fee,
+                account,
+            });
+
+            Ok(())
+        }
+
+        /// Supply liquidity to a pool in proportion to its balances. The deposit is the largest
+        /// proportional share within `amounts`, the rest stays with the caller. The first deposit
+        /// sets the initial balances and requires all currencies.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `amounts`: The maximum amounts of the pool's currencies to supply.
+        /// - `min_mint_amount`: The min amount of lp currency get.
+        /// - `to`: The account which receives the lp currency.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::add_liquidity(amounts.len() as u32))]
+        #[transactional]
+        pub fn add_liquidity(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            amounts: Vec<Balance>,
+            min_mint_amount: Balance,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_add_liquidity(&who, pool_id, &amounts, min_mint_amount, &to)?;
+
+            Ok(())
+        }
+
+        /// Supply liquidity to a pool in a single currency.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `index`: The index of the supplied currency.
+        /// - `amount`: The amount of the supplied currency.
+        /// - `min_mint_amount`: The min amount of lp currency get.
+        /// - `to`: The account which receives the lp currency.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::add_liquidity_one_currency())]
+        #[transactional]
+        pub fn add_liquidity_one_currency(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            index: u32,
+            amount: Balance,
+            min_mint_amount: Balance,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_add_liquidity_one_currency(&who, pool_id, index as usize, amount, min_mint_amount, &to)?;
+
+            Ok(())
+        }
+
+        /// Swap a amounts of currencies to get other.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `from_index`: The index of swap currency id.
+        /// - `to_index`: The index of target currency id.
+        /// - `in_amount`: The amounts of currencies swap.
+        /// - `min_out_amount`: The min amounts of target currency get.
+        /// - `to`: The account which receives the target currency.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::swap())]
+        #[transactional]
+        pub fn swap(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            from_index: u32,
+            to_index: u32,
+            in_amount: Balance,
+            min_out_amount: Balance,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_swap(
+                &who,
+                pool_id,
+                from_index as usize,
+                to_index as usize,
+                in_amount,
+                min_out_amount,
+                &to,
+            )?;
+
+            Ok(())
+        }
+
+        /// Remove liquidity from a pool in proportion to its balances.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `lp_amount`: The amounts of lp currency.
+        /// - `min_amounts`: The min amounts of pool's currencies to get.
+        /// - `to`: The account which receives the pool's currencies.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::remove_liquidity(min_amounts.len() as u32))]
+        #[transactional]
+        pub fn remove_liquidity(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            lp_amount: Balance,
+            min_amounts: Vec<Balance>,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_remove_liquidity(&who, pool_id, lp_amount, &min_amounts, &to)
+        }
+
+        /// Remove liquidity from a pool to get only one currency.
+        ///
+        /// # Argument
+        ///
+        /// - `pool_id`: The id of pool.
+        /// - `lp_amount`: The amounts of lp currency.
+        /// - `index`: The index of target currency.
+        /// - `min_amount`: The min amounts of target currency get.
+        /// - `to`: The account which receives the target currency.
+        /// - `deadline`: Height of the cutoff block of this transaction
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_liquidity_one_currency())]
+        #[transactional]
+        pub fn remove_liquidity_one_currency(
+            origin: OriginFor<T>,
+            pool_id: T::PoolId,
+            lp_amount: Balance,
+            index: u32,
+            min_amount: Balance,
+            to: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now, Error::<T>::Deadline);
+
+            Self::inner_remove_liquidity_one_currency(&who, pool_id, lp_amount, index as usize, min_amount, &to)?;
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// only use two byte prefix to support 16 byte account id (used by test)
+    /// "modl" ++ "dex/wght" is 12 bytes, and 4 bytes remaining for PoolId
+    pub fn pool_account_id(pool_id: &T::PoolId) -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(pool_id)
+    }
+
+    fn inner_add_liquidity(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        amounts: &[Balance],
+        min_mint_amount: Balance,
+        to: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        Pools::<T>::try_mutate_exists(pool_id, |optioned_pool| -> Result<Balance, DispatchError> {
+            let pool = optioned_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+            let (supply_amounts, mint_amount) =
+                Self::calculate_add_liquidity_amounts(pool, amounts)?.ok_or(Error::<T>::Arithmetic)?;
+            ensure!(!mint_amount.is_zero(), Error::<T>::InvalidTransaction);
+            ensure!(mint_amount >= min_mint_amount, Error::<T>::AmountSlippage);
+
+            for (i, amount) in supply_amounts.iter().enumerate() {
+                T::MultiCurrency::transfer(pool.currency_ids[i], who, &pool.account, *amount)
+                    .map_err(|_| Error::<T>::InsufficientSupply)?;
+                pool.balances[i] = pool.balances[i].checked_add(*amount).ok_or(Error::<T>::Arithmetic)?;
+            }
+            T::MultiCurrency::deposit(pool.lp_currency_id, to, mint_amount)?;
+
+            Self::deposit_event(Event::AddLiquidity {
+                pool_id,
+                who: who.clone(),
+                to: to.clone(),
+                supply_amounts,
+                mint_amount,
+            });
+            Ok(mint_amount)
+        })
+    }
+
+    fn inner_add_liquidity_one_currency(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        index: usize,
+        amount: Balance,
+        min_mint_amount: Balance,
+        to: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        Pools::<T>::try_mutate_exists(pool_id, |optioned_pool| -> Result<Balance, DispatchError> {
+            let pool = optioned_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+            ensure!(index < pool.currency_ids.len(), Error::<T>::CurrencyIndexOutRange);
+            let lp_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+            ensure!(!lp_supply.is_zero(), Error::<T>::RequireAllCurrencies);
+            ensure!(
+                math::within_ratio(amount, pool.balances[index], MAX_IN_RATIO),
+                Error::<T>::ExceedMaxInRatio
+            );
+
+            let mint_amount =
+                math::calculate_lp_out_given_in(pool.balances[index], pool.weights[index], lp_supply, amount, pool.fee)
+                    .ok_or(Error::<T>::Arithmetic)?;
+            ensure!(!mint_amount.is_zero(), Error::<T>::InvalidTransaction);
+            ensure!(mint_amount >= min_mint_amount, Error::<T>::AmountSlippage);
+
+            T::MultiCurrency::transfer(pool.currency_ids[index], who, &pool.account, amount)
+                .map_err(|_| Error::<T>::InsufficientSupply)?;
+            pool.balances[index] = pool.balances[index].checked_add(amount).ok_or(Error::<T>::Arithmetic)?;
+            T::MultiCurrency::deposit(pool.lp_currency_id, to, mint_amount)?;
+
+            Self::deposit_event(Event::AddLiquidityOneCurrency {
+                pool_id,
+                who: who.clone(),
+                to: to.clone(),
+                in_index: index as u32,
+                in_amount: amount,
+                mint_amount,
+            });
+            Ok(mint_amount)
+        })
+    }
+
+    fn inner_swap(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        i: usize,
+        j: usize,
+        in_amount: Balance,
+        out_min_amount: Balance,
+        to: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        ensure!(i != j, Error::<T>::SwapSameCurrency);
+
+        Pools::<T>::try_mutate_exists(pool_id, |optioned_pool| -> Result<Balance, DispatchError> {
+            let pool = optioned_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+            let n_currencies = pool.currency_ids.len();
+            ensure!(i < n_currencies && j < n_currencies, Error::<T>::CurrencyIndexOutRange);
+            ensure!(
+                math::within_ratio(in_amount, pool.balances[i], MAX_IN_RATIO),
+                Error::<T>::ExceedMaxInRatio
+            );
+
+            let out_amount = Self::calculate_swap_amount(pool, i, j, in_amount).ok_or(Error::<T>::Arithmetic)?;
+            ensure!(!out_amount.is_zero(), Error::<T>::InvalidTransaction);
+            ensure!(out_amount >= out_min_amount, Error::<T>::AmountSlippage);
+
+            T::MultiCurrency::transfer(pool.currency_ids[i], who, &pool.account, in_amount)
+                .map_err(|_| Error::<T>::InsufficientSupply)?;
+            pool.balances[i] = pool.balances[i].checked_add(in_amount).ok_or(Error::<T>::Arithmetic)?;
+            pool.balances[j] = pool.balances[j]
+                .checked_sub(out_amount)
+                .ok_or(Error::<T>::InsufficientReserve)?;
+            T::MultiCurrency::transfer(pool.currency_ids[j], &pool.account, to, out_amount)
+                .map_err(|_| Error::<T>::InsufficientReserve)?;
+
+            Self::deposit_event(Event::CurrencyExchange {
+                pool_id,
+                who: who.clone(),
+                to: to.clone(),
+                in_index: i as u32,
+                in_amount,
+                out_index: j as u32,
+                out_amount,
+            });
+            Ok(out_amount)
+        })
+    }
+
+    fn inner_remove_liquidity(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        lp_amount: Balance,
+        min_amounts: &[Balance],
+        to: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(!lp_amount.is_zero(), Error::<T>::InvalidTransaction);
+
+        Pools::<T>::try_mutate_exists(pool_id, |optioned_pool| -> DispatchResult {
+            let pool = optioned_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+            ensure!(
+                min_amounts.len() == pool.currency_ids.len(),
+                Error::<T>::MismatchParameter
+            );
+
+            let amounts = Self::calculate_remove_liquidity_amounts(pool, lp_amount).ok_or(Error::<T>::Arithmetic)?;
+            for (amount, min_amount) in amounts.iter().zip(min_amounts) {
+                ensure!(amount >= min_amount, Error::<T>::AmountSlippage);
+            }
+
+            T::MultiCurrency::withdraw(pool.lp_currency_id, who, lp_amount)
+                .map_err(|_| Error::<T>::InsufficientSupply)?;
+            for (i, amount) in amounts.iter().enumerate() {
+                pool.balances[i] = pool.balances[i]
+                    .checked_sub(*amount)
+                    .ok_or(Error::<T>::InsufficientReserve)?;
+                T::MultiCurrency::transfer(pool.currency_ids[i], &pool.account, to, *amount)
+                    .map_err(|_| Error::<T>::InsufficientReserve)?;
+            }
+
+            Self::deposit_event(Event::RemoveLiquidity {
+                pool_id,
+                who: who.clone(),
+                to: to.clone(),
+                amounts,
+                burn_amount: lp_amount,
+            });
+            Ok(())
+        })
+    }
+
+    fn inner_remove_liquidity_one_currency(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        lp_amount: Balance,
+        index: usize,
+        min_amount: Balance,
+        to: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        ensure!(!lp_amount.is_zero(), Error::<T>::InvalidTransaction);
+
+        Pools::<T>::try_mutate_exists(pool_id, |optioned_pool| -> Result<Balance, DispatchError> {
+            let pool = optioned_pool.as_mut().ok_or(Error::<T>::InvalidPoolId)?;
+            ensure!(index < pool.currency_ids.len(), Error::<T>::CurrencyIndexOutRange);
+            let lp_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+            ensure!(lp_amount < lp_supply, Error::<T>::InsufficientSupply);
+
+            let out_amount = math::calculate_out_given_lp_in(
+                pool.balances[index],
+                pool.weights[index],
+                lp_supply,
+                lp_amount,
+                pool.fee,
+            )
+            .ok_or(Error::<T>::Arithmetic)?;
+            ensure!(
+                math::within_ratio(out_amount, pool.balances[index], MAX_OUT_RATIO),
+                Error::<T>::ExceedMaxOutRatio
+            );
+            ensure!(out_amount >= min_amount, Error::<T>::AmountSlippage);
+
+            T::MultiCurrency::withdraw(pool.lp_currency_id, who, lp_amount)
+                .map_err(|_| Error::<T>::InsufficientSupply)?;
+            pool.balances[index] = pool.balances[index]
+                .checked_sub(out_amount)
+                .ok_or(Error::<T>::InsufficientReserve)?;
+            T::MultiCurrency::transfer(pool.currency_ids[index], &pool.account, to, out_amount)
+                .map_err(|_| Error::<T>::InsufficientReserve)?;
+
+            Self::deposit_event(Event::RemoveLiquidityOneCurrency {
+                pool_id,
+                who: who.clone(),
+                to: to.clone(),
+                out_index: index as u32,
+                burn_amount: lp_amount,
+                out_amount,
+            });
+            Ok(out_amount)
+        })
+    }
+
+    /// The amounts taken from `amounts` and the lp currency minted for them.
+    pub(crate) fn calculate_add_liquidity_amounts(
+        pool: &PoolOf<T>,
+        amounts: &[Balance],
+    ) -> Result<Option<(Vec<Balance>, Balance)>, DispatchError> {
+        ensure!(amounts.len() == pool.currency_ids.len(), Error::<T>::MismatchParameter);
+
+        let lp_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+        if lp_supply.is_zero() {
+            ensure!(
+                amounts.iter().all(|amount| !amount.is_zero()),
+                Error::<T>::RequireAllCurrencies
+            );
+            return Ok(Some((amounts.to_vec(), INIT_POOL_SUPPLY)));
+        }
+
+        // the share of the smallest deposit relative to its balance determines the mint
+        let mut mint_amount = Balance::MAX;
+        for (amount, balance) in amounts.iter().zip(pool.balances.iter()) {
+            let share = match multiply_by_rational_with_rounding(*amount, lp_supply, *balance, Rounding::Down) {
+                Some(share) => share,
+                None => return Ok(None),
+            };
+            mint_amount = mint_amount.min(share);
+        }
+
+        let supply_amounts = pool
+            .balances
+            .iter()
+            .map(|balance| multiply_by_rational_with_rounding(mint_amount, *balance, lp_supply, Rounding::Up))
+            .collect::<Option<Vec<_>>>();
+        Ok(supply_amounts.map(|supply_amounts| (supply_amounts, mint_amount)))
+    }
+
+    pub(crate) fn calculate_remove_liquidity_amounts(pool: &PoolOf<T>, lp_amount: Balance) -> Option<Vec<Balance>> {
+        let lp_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+        if lp_amount > lp_supply {
+            return None;
+        }
+        pool.balances
+            .iter()
+            .map(|balance| multiply_by_rational_with_rounding(*balance, lp_amount, lp_supply, Rounding::Down))
+            .collect()
+    }
+
+    pub(crate) fn calculate_swap_amount(pool: &PoolOf<T>, i: usize, j: usize, in_amount: Balance) -> Option<Balance> {
+        if i == j || i >= pool.currency_ids.len() || j >= pool.currency_ids.len() {
+            return None;
+        }
+        math::calculate_out_given_in(
+            pool.balances[i],
+            pool.weights[i],
+            pool.balances[j],
+            pool.weights[j],
+            in_amount,
+            pool.fee,
+        )
+    }
+
+    /// The input for which `calculate_swap_amount` returns at least `out_amount`.
+    pub(crate) fn calculate_swap_amount_in(
+        pool: &PoolOf<T>,
+        i: usize,
+        j: usize,
+        out_amount: Balance,
+    ) -> Option<Balance> {
+        if i == j || i >= pool.currency_ids.len() || j >= pool.currency_ids.len() {
+            return None;
+        }
+        if !math::within_ratio(out_amount, pool.balances[j], MAX_OUT_RATIO) {
+            return None;
+        }
+        let mut in_amount = math::calculate_in_given_out(
+            pool.balances[i],
+            pool.weights[i],
+            pool.balances[j],
+            pool.weights[j],
+            out_amount,
+            pool.fee,
+        )?;
+
+        for _ in 0..AMOUNT_IN_CORRECTIONS {
+            let quoted = Self::calculate_swap_amount(pool, i, j, in_amount)?;
+            if quoted >= out_amount {
+                return Some(in_amount);
+            }
+            let shortfall =
+                multiply_by_rational_with_rounding(in_amount, out_amount - quoted, quoted.max(1), Rounding::Up)?;
+            in_amount = in_amount.checked_add(shortfall.max(1))?;
+        }
+        None
+    }
+}
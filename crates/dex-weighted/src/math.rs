@@ -0,0 +1,187 @@
+//! Fixed point math of weighted pools with a precision of `ONE`, following Balancer V1.
+//!
+//! Outputs are rounded down and inputs up, so that rounding never drains a pool.
+
+use crate::primitives::*;
+use sp_arithmetic::Rounding;
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, PerThing, Perbill, Permill};
+
+fn mul(a: Balance, b: Balance, rounding: Rounding) -> Option<Balance> {
+    multiply_by_rational_with_rounding(a, b, ONE, rounding)
+}
+
+fn div(a: Balance, b: Balance, rounding: Rounding) -> Option<Balance> {
+    multiply_by_rational_with_rounding(a, ONE, b, rounding)
+}
+
+pub fn from_perbill(value: Perbill) -> Balance {
+    value.deconstruct() as Balance * (ONE / Perbill::ACCURACY as Balance)
+}
+
+pub fn from_permill(value: Permill) -> Balance {
+    value.deconstruct() as Balance * (ONE / Permill::ACCURACY as Balance)
+}
+
+/// `base` to the power of the integer `exp`, by squaring.
+fn pow_int(mut base: Balance, mut exp: Balance) -> Option<Balance> {
+    let mut result = if exp % 2 != 0 { base } else { ONE };
+    exp /= 2;
+    while exp != 0 {
+        base = mul(base, base, Rounding::NearestPrefDown)?;
+        if exp % 2 != 0 {
+            result = mul(result, base, Rounding::NearestPrefDown)?;
+        }
+        exp /= 2;
+    }
+    Some(result)
+}
+
+/// `base` to the power of `exp` below one, by the binomial series of `(1 + (base - 1))^exp`.
+fn pow_approx(base: Balance, exp: Balance) -> Option<Balance> {
+    let (x, x_negative) = if base >= ONE {
+        (base - ONE, false)
+    } else {
+        (ONE - base, true)
+    };
+
+    let mut term = ONE;
+    let mut sum = ONE;
+    let mut negative = false;
+    // the k-th term is the previous one multiplied by (exp - (k - 1)) * x / k
+    for k in 1..=MAX_POW_ITERATIONS as Balance {
+        let big_k = k * ONE;
+        let (c, c_negative) = if exp >= big_k - ONE {
+            (exp - (big_k - ONE), false)
+        } else {
+            (big_k - ONE - exp, true)
+        };
+        term = mul(term, mul(c, x, Rounding::NearestPrefDown)?, Rounding::NearestPrefDown)?;
+        term = div(term, big_k, Rounding::NearestPrefDown)?;
+        if term == 0 {
+            return Some(sum);
+        }
+
+        if x_negative {
+            negative = !negative;
+        }
+        if c_negative {
+            negative = !negative;
+        }
+        sum = if negative {
+            sum.checked_sub(term)?
+        } else {
+            sum.checked_add(term)?
+        };
+
+        if term < POW_PRECISION {
+            return Some(sum);
+        }
+    }
+    None
+}
+
+/// `base` to the power of `exp`, both with a precision of `ONE`. The base must be in
+/// `[MIN_POW_BASE, MAX_POW_BASE]`.
+pub fn pow(base: Balance, exp: Balance) -> Option<Balance> {
+    if !(MIN_POW_BASE..=MAX_POW_BASE).contains(&base) {
+        return None;
+    }
+
+    let whole_pow = pow_int(base, exp / ONE)?;
+    let remain = exp % ONE;
+    if remain == 0 {
+        return Some(whole_pow);
+    }
+
+    let partial_pow = pow_approx(base, remain)?;
+    mul(whole_pow, partial_pow, Rounding::NearestPrefDown)
+}
+
+/// The output of swapping `amount_in`, of which `fee` is kept by the pool.
+pub fn calculate_out_given_in(
+    balance_in: Balance,
+    weight_in: Perbill,
+    balance_out: Balance,
+    weight_out: Perbill,
+    amount_in: Balance,
+    fee: Permill,
+) -> Option<Balance> {
+    let weight_ratio = div(from_perbill(weight_in), from_perbill(weight_out), Rounding::Down)?;
+    let amount_in = amount_in.checked_sub(fee.mul_ceil(amount_in))?;
+    let y = div(balance_in, balance_in.checked_add(amount_in)?, Rounding::Up)?;
+    let balance_out_ratio = pow(y, weight_ratio)?;
+    mul(balance_out, ONE.checked_sub(balance_out_ratio)?, Rounding::Down)
+}
+
+/// The input which is swapped for `amount_out`, including the fee.
+pub fn calculate_in_given_out(
+    balance_in: Balance,
+    weight_in: Perbill,
+    balance_out: Balance,
+    weight_out: Perbill,
+    amount_out: Balance,
+    fee: Permill,
+) -> Option<Balance> {
+    let weight_ratio = div(from_perbill(weight_out), from_perbill(weight_in), Rounding::Up)?;
+    let y = div(balance_out, balance_out.checked_sub(amount_out)?, Rounding::Up)?;
+    let balance_in_ratio = pow(y, weight_ratio)?.checked_sub(ONE)?;
+    let amount_in = mul(balance_in, balance_in_ratio, Rounding::Up)?;
+    multiply_by_rational_with_rounding(
+        amount_in,
+        Permill::ACCURACY as Balance,
+        Permill::one().saturating_sub(fee).deconstruct() as Balance,
+        Rounding::Up,
+    )
+}
+
+/// The lp currency minted for depositing `amount_in` of a single currency.
+pub fn calculate_lp_out_given_in(
+    balance_in: Balance,
+    weight_in: Perbill,
+    lp_supply: Balance,
+    amount_in: Balance,
+    fee: Permill,
+) -> Option<Balance> {
+    let weight_in = from_perbill(weight_in);
+    // only the share of the deposit which is implicitly traded for the other currencies pays the fee
+    let fee_share = mul(ONE.checked_sub(weight_in)?, from_permill(fee), Rounding::Up)?;
+    let amount_in = mul(amount_in, ONE.checked_sub(fee_share)?, Rounding::Down)?;
+    let balance_in_ratio = div(balance_in.checked_add(amount_in)?, balance_in, Rounding::Down)?;
+    let lp_supply_ratio = pow(balance_in_ratio, weight_in)?;
+    mul(lp_supply_ratio, lp_supply, Rounding::Down)?.checked_sub(lp_supply)
+}
+
+/// The output of a single currency for burning `lp_amount`.
+pub fn calculate_out_given_lp_in(
+    balance_out: Balance,
+    weight_out: Perbill,
+    lp_supply: Balance,
+    lp_amount: Balance,
+    fee: Permill,
+) -> Option<Balance> {
+    let weight_out = from_perbill(weight_out);
+    let lp_supply_ratio = div(lp_supply.checked_sub(lp_amount)?, lp_supply, Rounding::Up)?;
+    let balance_out_ratio = pow(lp_supply_ratio, div(ONE, weight_out, Rounding::Down)?)?;
+    let amount_out = balance_out.checked_sub(mul(balance_out_ratio, balance_out, Rounding::Up)?)?;
+    let fee_share = mul(ONE.checked_sub(weight_out)?, from_permill(fee), Rounding::Up)?;
+    mul(amount_out, ONE.checked_sub(fee_share)?, Rounding::Down)
+}
+
+/// The price of the output currency in the input currency, including the fee.
+pub fn calculate_spot_price(
+    balance_in: Balance,
+    weight_in: Perbill,
+    balance_out: Balance,
+    weight_out: Perbill,
+    fee: Permill,
+) -> Option<Balance> {
+    let numerator = div(balance_in, from_perbill(weight_in), Rounding::Up)?;
+    let denominator = div(balance_out, from_perbill(weight_out), Rounding::Down)?;
+    let price = div(numerator, denominator, Rounding::Up)?;
+    div(price, ONE.checked_sub(from_permill(fee))?, Rounding::Up)
+}
+
+/// Whether `amount` is at most `max_ratio` of `balance`.
+pub fn within_ratio(amount: Balance, balance: Balance, max_ratio: Balance) -> bool {
+    mul(balance, max_ratio, Rounding::Down).map_or(false, |max_amount| amount <= max_amount)
+}
@@ -0,0 +1,176 @@
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+
+use frame_support::{parameter_types, traits::Everything, PalletId};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, RuntimeDebug,
+};
+
+use crate as dex_weighted;
+use crate::{traits::WeightedPoolLpCurrencyIdGenerate, Config, Pallet};
+use orml_traits::parameter_type_with_key;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const WeightedAmmPalletId: PalletId = PalletId(*b"dex/wght");
+    pub const MaxReserves: u32 = 50;
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: CurrencyId| -> u128 {
+        0
+    };
+}
+
+pub type AccountId = u128;
+pub type TokenSymbol = u8;
+pub type PoolId = u32;
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    PartialOrd,
+    MaxEncodedLen,
+    Ord,
+    TypeInfo,
+)]
+pub enum CurrencyId {
+    Token(TokenSymbol),
+    WeightedLp(PoolId),
+}
+
+impl From<u32> for CurrencyId {
+    fn from(value: u32) -> Self {
+        if value < 1000 {
+            // Inner value must fit inside `u8`
+            CurrencyId::Token((value % 256).try_into().unwrap())
+        } else {
+            CurrencyId::WeightedLp(value % 256)
+        }
+    }
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type PalletInfo = PalletInfo;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl orml_tokens::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Amount = i128;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type MaxLocks = ();
+    type DustRemovalWhitelist = Everything;
+    type MaxReserves = MaxReserves;
+    type ReserveIdentifier = [u8; 8];
+    type CurrencyHooks = ();
+}
+
+pub struct PoolLpGenerate;
+
+impl WeightedPoolLpCurrencyIdGenerate<CurrencyId, PoolId> for PoolLpGenerate {
+    fn generate_by_pool_id(pool_id: PoolId) -> CurrencyId {
+        CurrencyId::WeightedLp(pool_id)
+    }
+}
+
+impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CurrencyId = CurrencyId;
+    type MultiCurrency = Tokens;
+    type PoolId = PoolId;
+    type LpGenerate = PoolLpGenerate;
+    type PalletId = WeightedAmmPalletId;
+    type WeightInfo = ();
+}
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>} = 0,
+        WeightedAMM: dex_weighted::{Pallet, Call, Storage, Event<T>} = 9,
+        Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 11,
+    }
+);
+
+pub type WeightedAmm = Pallet<Test>;
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    pub fn build() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+        storage.into()
+    }
+}
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const TOKEN1_SYMBOL: u8 = 1;
+pub const TOKEN2_SYMBOL: u8 = 2;
+pub const TOKEN3_SYMBOL: u8 = 3;
+
+pub const UNIT: Balance = 1_000_000_000_000_000_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    orml_tokens::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, CurrencyId::Token(TOKEN1_SYMBOL), UNIT * 1_000_000),
+            (ALICE, CurrencyId::Token(TOKEN2_SYMBOL), UNIT * 1_000_000),
+            (ALICE, CurrencyId::Token(TOKEN3_SYMBOL), UNIT * 1_000_000),
+            (BOB, CurrencyId::Token(TOKEN1_SYMBOL), UNIT * 1_000),
+            (BOB, CurrencyId::Token(TOKEN2_SYMBOL), UNIT * 1_000),
+            (BOB, CurrencyId::Token(TOKEN3_SYMBOL), UNIT * 1_000),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+pub fn get_user_balance(currency_id: CurrencyId, user: &AccountId) -> Balance {
+    <Test as Config>::MultiCurrency::free_balance(currency_id, user)
+}
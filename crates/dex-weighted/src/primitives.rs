@@ -0,0 +1,45 @@
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::*;
+use sp_runtime::{Perbill, Permill};
+
+pub type Balance = u128;
+
+/// The fixed point precision of the pool math.
+pub const ONE: Balance = 1_000_000_000_000_000_000;
+
+pub const MIN_POOL_CURRENCIES: u32 = 2;
+pub const MAX_POOL_CURRENCIES: u32 = 8;
+pub type PoolCurrencyLimit = ConstU32<MAX_POOL_CURRENCIES>;
+
+/// The lower bound of the weight of a single currency.
+pub const MIN_WEIGHT: Perbill = Perbill::from_percent(1);
+pub const MAX_SWAP_FEE: Permill = Permill::from_percent(10);
+
+/// The lp currency minted by the first deposit into a pool.
+pub const INIT_POOL_SUPPLY: Balance = 100 * ONE;
+
+/// A single trade may add at most half of the balance of the input currency of a pool.
+pub const MAX_IN_RATIO: Balance = ONE / 2;
+/// A single trade may remove at most a third of the balance of the output currency of a pool.
+pub const MAX_OUT_RATIO: Balance = ONE / 3 + 1;
+
+// the series approximating fractional powers converges for bases in (0, 2)
+pub const MIN_POW_BASE: Balance = 1;
+pub const MAX_POW_BASE: Balance = 2 * ONE - 1;
+pub const POW_PRECISION: Balance = ONE / 10_000_000_000;
+pub const MAX_POW_ITERATIONS: u32 = 255;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct WeightedPool<CurrencyId, AccountId> {
+    pub currency_ids: BoundedVec<CurrencyId, PoolCurrencyLimit>,
+    // normalized weights which add up to one
+    pub weights: BoundedVec<Perbill, PoolCurrencyLimit>,
+    // the balances accounted to the pool, donations to the pool account are not included
+    pub balances: BoundedVec<Balance, PoolCurrencyLimit>,
+    pub lp_currency_id: CurrencyId,
+    // charged on the input of swaps and on the share of single currency joins and exits which
+    // is traded against the other currencies
+    pub fee: Permill,
+    // the pool's account
+    pub account: AccountId,
+}
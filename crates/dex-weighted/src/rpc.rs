@@ -0,0 +1,101 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_currencies(pool_id: T::PoolId) -> Vec<T::CurrencyId> {
+        Self::pools(pool_id)
+            .map(|pool| pool.currency_ids.to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn get_currency_index(pool_id: T::PoolId, currency_id: T::CurrencyId) -> Option<u32> {
+        let pool = Self::pools(pool_id)?;
+        pool.currency_ids
+            .iter()
+            .position(|c| *c == currency_id)
+            .map(|i| i as u32)
+    }
+
+    pub fn get_weights(pool_id: T::PoolId) -> Vec<Perbill> {
+        Self::pools(pool_id)
+            .map(|pool| pool.weights.to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn get_currency_balances(pool_id: T::PoolId) -> Vec<Balance> {
+        Self::pools(pool_id)
+            .map(|pool| pool.balances.to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn get_lp_currency(pool_id: T::PoolId) -> Option<T::CurrencyId> {
+        Self::pools(pool_id).map(|pool| pool.lp_currency_id)
+    }
+
+    pub fn get_swap_fee(pool_id: T::PoolId) -> Permill {
+        Self::pools(pool_id).map(|pool| pool.fee).unwrap_or_default()
+    }
+
+    /// The price of the currency at `out_index` in the currency at `in_index`, with a precision
+    /// of `ONE` and including the swap fee.
+    pub fn get_spot_price(pool_id: T::PoolId, in_index: u32, out_index: u32) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        let (i, j) = (in_index as usize, out_index as usize);
+        math::calculate_spot_price(
+            *pool.balances.get(i)?,
+            *pool.weights.get(i)?,
+            *pool.balances.get(j)?,
+            *pool.weights.get(j)?,
+            pool.fee,
+        )
+    }
+
+    pub fn calculate_swap(pool_id: T::PoolId, in_index: u32, out_index: u32, in_amount: Balance) -> Option<Balance> {
+        use crate::traits::WeightedAmmApi;
+        Self::quote_swap(pool_id, in_index, out_index, in_amount)
+    }
+
+    pub fn calculate_swap_for_exact(
+        pool_id: T::PoolId,
+        in_index: u32,
+        out_index: u32,
+        out_amount: Balance,
+    ) -> Option<Balance> {
+        use crate::traits::WeightedAmmApi;
+        Self::quote_swap_for_exact(pool_id, in_index, out_index, out_amount)
+    }
+
+    pub fn calculate_add_liquidity(pool_id: T::PoolId, amounts: Vec<Balance>) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        Self::calculate_add_liquidity_amounts(&pool, &amounts)
+            .ok()
+            .flatten()
+            .map(|(_, mint_amount)| mint_amount)
+    }
+
+    pub fn calculate_add_liquidity_one_currency(pool_id: T::PoolId, index: u32, amount: Balance) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        let i = index as usize;
+        let lp_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+        if lp_supply.is_zero() || !math::within_ratio(amount, *pool.balances.get(i)?, MAX_IN_RATIO) {
+            return None;
+        }
+        math::calculate_lp_out_given_in(pool.balances[i], pool.weights[i], lp_supply, amount, pool.fee)
+    }
+
+    pub fn calculate_remove_liquidity(pool_id: T::PoolId, amount: Balance) -> Option<Vec<Balance>> {
+        let pool = Self::pools(pool_id)?;
+        Self::calculate_remove_liquidity_amounts(&pool, amount)
+    }
+
+    pub fn calculate_remove_liquidity_one_currency(pool_id: T::PoolId, amount: Balance, index: u32) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        let i = index as usize;
+        let lp_supply = T::MultiCurrency::total_issuance(pool.lp_currency_id);
+        if amount >= lp_supply {
+            return None;
+        }
+        let out_amount =
+            math::calculate_out_given_lp_in(*pool.balances.get(i)?, pool.weights[i], lp_supply, amount, pool.fee)?;
+        math::within_ratio(out_amount, pool.balances[i], MAX_OUT_RATIO).then_some(out_amount)
+    }
+}
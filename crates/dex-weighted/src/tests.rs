@@ -0,0 +1,464 @@
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError::BadOrigin;
+
+use super::{
+    mock::{CurrencyId::*, *},
+    traits::WeightedAmmApi,
+    *,
+};
+
+const SWAP_FEE: Permill = Permill::from_parts(3_000);
+
+/// A pool of 800 token1 and 20 token2, weighted 80/20, so one token2 is worth ten token1.
+fn setup_test_pool() -> PoolId {
+    let pool_id = WeightedAmm::next_pool_id();
+    assert_ok!(WeightedAmm::create_pool(
+        RawOrigin::Root.into(),
+        vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+        vec![Perbill::from_percent(80), Perbill::from_percent(20)],
+        SWAP_FEE,
+    ));
+    assert_ok!(WeightedAmm::add_liquidity(
+        RawOrigin::Signed(ALICE).into(),
+        pool_id,
+        vec![800 * UNIT, 20 * UNIT],
+        0,
+        ALICE,
+        u64::MAX,
+    ));
+    pool_id
+}
+
+fn assert_close(actual: Balance, expected: Balance, tolerance: Balance) {
+    assert!(
+        actual.abs_diff(expected) <= tolerance,
+        "{} is not within {} of {}",
+        actual,
+        tolerance,
+        expected
+    );
+}
+
+#[test]
+fn create_pool_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WeightedAmm::create_pool(
+            RawOrigin::Root.into(),
+            vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL), Token(TOKEN3_SYMBOL)],
+            vec![
+                Perbill::from_percent(50),
+                Perbill::from_percent(25),
+                Perbill::from_percent(25)
+            ],
+            SWAP_FEE,
+        ));
+
+        let pool = WeightedAmm::pools(0).unwrap();
+        assert_eq!(pool.lp_currency_id, WeightedLp(0));
+        assert_eq!(pool.balances.to_vec(), vec![0, 0, 0]);
+        assert_eq!(WeightedAmm::lp_currencies(WeightedLp(0)), Some(0));
+        assert_eq!(WeightedAmm::next_pool_id(), 1);
+        System::assert_last_event(RuntimeEvent::WeightedAMM(Event::CreatePool {
+            pool_id: 0,
+            currency_ids: vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL), Token(TOKEN3_SYMBOL)],
+            weights: vec![
+                Perbill::from_percent(50),
+                Perbill::from_percent(25),
+                Perbill::from_percent(25),
+            ],
+            lp_currency_id: WeightedLp(0),
+            swap_fee: SWAP_FEE,
+            account: pool.account,
+        }));
+    });
+}
+
+#[test]
+fn create_pool_with_incorrect_parameter_should_not_work() {
+    new_test_ext().execute_with(|| {
+        let currency_ids = vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)];
+        let weights = vec![Perbill::from_percent(80), Perbill::from_percent(20)];
+
+        // only root can create pool
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Signed(ALICE).into(),
+                currency_ids.clone(),
+                weights.clone(),
+                SWAP_FEE
+            ),
+            BadOrigin
+        );
+
+        // every currency needs a weight
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Root.into(),
+                currency_ids.clone(),
+                vec![Perbill::one()],
+                SWAP_FEE
+            ),
+            Error::<Test>::MismatchParameter
+        );
+
+        // at least two and at most eight currencies
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Root.into(),
+                vec![Token(TOKEN1_SYMBOL)],
+                vec![Perbill::one()],
+                SWAP_FEE
+            ),
+            Error::<Test>::InvalidCurrencyCount
+        );
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Root.into(),
+                (0..9).map(Token).collect(),
+                vec![Perbill::from_rational(1u32, 9u32); 9],
+                SWAP_FEE
+            ),
+            Error::<Test>::InvalidCurrencyCount
+        );
+
+        // the weights must add up to one, without negligible weights
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Root.into(),
+                currency_ids.clone(),
+                vec![Perbill::from_percent(80), Perbill::from_percent(10)],
+                SWAP_FEE
+            ),
+            Error::<Test>::InvalidWeights
+        );
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Root.into(),
+                currency_ids.clone(),
+                vec![Perbill::from_parts(999_000_000), Perbill::from_parts(1_000_000)],
+                SWAP_FEE
+            ),
+            Error::<Test>::InvalidWeights
+        );
+
+        // the currencies must be distinct
+        assert_noop!(
+            WeightedAmm::create_pool(
+                RawOrigin::Root.into(),
+                vec![Token(TOKEN1_SYMBOL), Token(TOKEN1_SYMBOL)],
+                weights.clone(),
+                SWAP_FEE
+            ),
+            Error::<Test>::InvalidPooledCurrency
+        );
+
+        assert_noop!(
+            WeightedAmm::create_pool(RawOrigin::Root.into(), currency_ids, weights, Permill::from_percent(11)),
+            Error::<Test>::ExceedMaxFee
+        );
+
+        assert_eq!(WeightedAmm::next_pool_id(), 0);
+    });
+}
+
+#[test]
+fn add_liquidity_should_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        assert_eq!(get_user_balance(WeightedLp(pool_id), &ALICE), INIT_POOL_SUPPLY);
+        assert_eq!(WeightedAmm::get_currency_balances(pool_id), vec![800 * UNIT, 20 * UNIT]);
+
+        // only the proportional share of the amounts is taken
+        assert_ok!(WeightedAmm::add_liquidity(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            vec![80 * UNIT, 10 * UNIT],
+            0,
+            BOB,
+            u64::MAX,
+        ));
+        assert_eq!(get_user_balance(WeightedLp(pool_id), &BOB), INIT_POOL_SUPPLY / 10);
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &BOB), 920 * UNIT);
+        assert_eq!(get_user_balance(Token(TOKEN2_SYMBOL), &BOB), 998 * UNIT);
+        assert_eq!(WeightedAmm::get_currency_balances(pool_id), vec![880 * UNIT, 22 * UNIT]);
+
+        assert_noop!(
+            WeightedAmm::add_liquidity(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                vec![80 * UNIT, 10 * UNIT],
+                INIT_POOL_SUPPLY / 10 + 1,
+                BOB,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+    });
+}
+
+#[test]
+fn first_add_liquidity_requires_all_currencies() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(WeightedAmm::create_pool(
+            RawOrigin::Root.into(),
+            vec![Token(TOKEN1_SYMBOL), Token(TOKEN2_SYMBOL)],
+            vec![Perbill::from_percent(80), Perbill::from_percent(20)],
+            SWAP_FEE,
+        ));
+
+        assert_noop!(
+            WeightedAmm::add_liquidity(
+                RawOrigin::Signed(ALICE).into(),
+                0,
+                vec![800 * UNIT, 0],
+                0,
+                ALICE,
+                u64::MAX,
+            ),
+            Error::<Test>::RequireAllCurrencies
+        );
+        assert_noop!(
+            WeightedAmm::add_liquidity_one_currency(
+                RawOrigin::Signed(ALICE).into(),
+                0,
+                0,
+                800 * UNIT,
+                0,
+                ALICE,
+                u64::MAX,
+            ),
+            Error::<Test>::RequireAllCurrencies
+        );
+    });
+}
+
+#[test]
+fn swap_should_follow_weights() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+        let in_amount = 10 * UNIT;
+
+        let quoted = WeightedAmm::quote_swap(pool_id, 0, 1, in_amount).unwrap();
+        assert_ok!(WeightedAmm::swap(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            0,
+            1,
+            in_amount,
+            0,
+            BOB,
+            u64::MAX,
+        ));
+        let received = get_user_balance(Token(TOKEN2_SYMBOL), &BOB) - 1_000 * UNIT;
+
+        // 20 * (1 - (800 / (800 + 10 * 0.997)) ^ 4)
+        assert_close(received, 966_694_903_402_591_800, UNIT / 1_000_000_000);
+        assert_eq!(quoted, received);
+        assert_eq!(
+            WeightedAmm::get_currency_balances(pool_id),
+            vec![810 * UNIT, 20 * UNIT - received]
+        );
+        System::assert_last_event(RuntimeEvent::WeightedAMM(Event::CurrencyExchange {
+            pool_id,
+            who: BOB,
+            to: BOB,
+            in_index: 0,
+            in_amount,
+            out_index: 1,
+            out_amount: received,
+        }));
+    });
+}
+
+#[test]
+fn swap_with_incorrect_parameter_should_not_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        assert_noop!(
+            WeightedAmm::swap(RawOrigin::Signed(BOB).into(), pool_id, 0, 0, UNIT, 0, BOB, u64::MAX),
+            Error::<Test>::SwapSameCurrency
+        );
+        assert_noop!(
+            WeightedAmm::swap(RawOrigin::Signed(BOB).into(), pool_id, 0, 2, UNIT, 0, BOB, u64::MAX),
+            Error::<Test>::CurrencyIndexOutRange
+        );
+        // at most half of the balance of the input currency
+        assert_noop!(
+            WeightedAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                1,
+                0,
+                10 * UNIT + 1,
+                0,
+                BOB,
+                u64::MAX
+            ),
+            Error::<Test>::ExceedMaxInRatio
+        );
+        assert_noop!(
+            WeightedAmm::swap(
+                RawOrigin::Signed(BOB).into(),
+                pool_id,
+                0,
+                1,
+                10 * UNIT,
+                UNIT,
+                BOB,
+                u64::MAX
+            ),
+            Error::<Test>::AmountSlippage
+        );
+        assert_noop!(
+            WeightedAmm::swap(RawOrigin::Signed(BOB).into(), pool_id, 0, 1, UNIT, 0, BOB, 0),
+            Error::<Test>::Deadline
+        );
+    });
+}
+
+#[test]
+fn quote_swap_for_exact_should_cover_output() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        let in_amount = WeightedAmm::quote_swap_for_exact(pool_id, 0, 1, UNIT).unwrap();
+        assert_close(in_amount, 10_355_783_334_669_365_000, UNIT / 1_000_000_000);
+        assert!(WeightedAmm::quote_swap(pool_id, 0, 1, in_amount).unwrap() >= UNIT);
+        assert!(WeightedAmm::quote_swap(pool_id, 0, 1, in_amount - UNIT / 1_000_000).unwrap() < UNIT);
+
+        // at most a third of the balance of the output currency
+        assert_eq!(WeightedAmm::quote_swap_for_exact(pool_id, 0, 1, 7 * UNIT), None);
+    });
+}
+
+#[test]
+fn single_currency_liquidity_should_only_pay_fee_on_traded_share() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        assert_ok!(WeightedAmm::add_liquidity_one_currency(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            0,
+            8 * UNIT,
+            0,
+            BOB,
+            u64::MAX,
+        ));
+        // 100 * ((800 + 8 * (1 - 0.2 * 0.003)) / 800) ^ 0.8 - 100
+        let lp_amount = get_user_balance(WeightedLp(pool_id), &BOB);
+        assert_close(lp_amount, 798_724_136_509_959_800, UNIT / 1_000_000_000);
+        assert_eq!(WeightedAmm::get_currency_balances(pool_id), vec![808 * UNIT, 20 * UNIT]);
+
+        let quoted = WeightedAmm::calculate_remove_liquidity_one_currency(pool_id, lp_amount, 0).unwrap();
+        assert_ok!(WeightedAmm::remove_liquidity_one_currency(
+            RawOrigin::Signed(BOB).into(),
+            pool_id,
+            lp_amount,
+            0,
+            0,
+            BOB,
+            u64::MAX,
+        ));
+        let received = get_user_balance(Token(TOKEN1_SYMBOL), &BOB) - 992 * UNIT;
+        assert_eq!(quoted, received);
+        // the round trip costs the fee on a fifth of the amount, twice
+        assert_close(received, 7_990_450_348_025_144_261, UNIT / 1_000_000_000);
+        assert_eq!(get_user_balance(WeightedLp(pool_id), &BOB), 0);
+    });
+}
+
+#[test]
+fn remove_liquidity_should_work() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        assert_noop!(
+            WeightedAmm::remove_liquidity(
+                RawOrigin::Signed(ALICE).into(),
+                pool_id,
+                INIT_POOL_SUPPLY / 4,
+                vec![200 * UNIT + 1, 0],
+                ALICE,
+                u64::MAX,
+            ),
+            Error::<Test>::AmountSlippage
+        );
+
+        assert_eq!(
+            WeightedAmm::calculate_remove_liquidity(pool_id, INIT_POOL_SUPPLY / 4),
+            Some(vec![200 * UNIT, 5 * UNIT])
+        );
+        assert_ok!(WeightedAmm::remove_liquidity(
+            RawOrigin::Signed(ALICE).into(),
+            pool_id,
+            INIT_POOL_SUPPLY / 4,
+            vec![200 * UNIT, 5 * UNIT],
+            BOB,
+            u64::MAX,
+        ));
+        assert_eq!(get_user_balance(Token(TOKEN1_SYMBOL), &BOB), 1_200 * UNIT);
+        assert_eq!(get_user_balance(Token(TOKEN2_SYMBOL), &BOB), 1_005 * UNIT);
+        assert_eq!(WeightedAmm::get_currency_balances(pool_id), vec![600 * UNIT, 15 * UNIT]);
+        assert_eq!(get_user_balance(WeightedLp(pool_id), &ALICE), INIT_POOL_SUPPLY * 3 / 4);
+    });
+}
+
+#[test]
+fn remove_liquidity_one_currency_should_respect_max_out_ratio() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        // burning 8% of the supply would withdraw more than a third of the token2
+        assert_noop!(
+            WeightedAmm::remove_liquidity_one_currency(
+                RawOrigin::Signed(ALICE).into(),
+                pool_id,
+                INIT_POOL_SUPPLY * 8 / 100,
+                1,
+                0,
+                ALICE,
+                u64::MAX,
+            ),
+            Error::<Test>::ExceedMaxOutRatio
+        );
+        assert_eq!(
+            WeightedAmm::calculate_remove_liquidity_one_currency(pool_id, INIT_POOL_SUPPLY * 8 / 100, 1),
+            None
+        );
+    });
+}
+
+#[test]
+fn spot_price_should_follow_weights() {
+    new_test_ext().execute_with(|| {
+        let pool_id = setup_test_pool();
+
+        // (800 / 0.8) / (20 / 0.2) / (1 - 0.003)
+        assert_close(
+            WeightedAmm::get_spot_price(pool_id, 0, 1).unwrap(),
+            10_030_090_270_812_437_312,
+            1_000,
+        );
+    });
+}
+
+#[test]
+fn pow_should_approximate_fractional_powers() {
+    // 0.5 ^ (1 / 3)
+    assert_close(
+        math::pow(ONE / 2, ONE / 3).unwrap(),
+        793_700_525_984_099_737,
+        ONE / 10_000_000_000,
+    );
+    // 1.5 ^ 2.5
+    assert_close(
+        math::pow(3 * ONE / 2, 5 * ONE / 2).unwrap(),
+        2_755_675_960_631_075_360,
+        ONE / 1_000_000_000,
+    );
+    assert_eq!(math::pow(2 * ONE, ONE / 2), None);
+    assert_eq!(math::pow(0, ONE / 2), None);
+}
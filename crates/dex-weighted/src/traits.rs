@@ -0,0 +1,85 @@
+use super::*;
+
+pub trait WeightedPoolLpCurrencyIdGenerate<CurrencyId, PoolId> {
+    fn generate_by_pool_id(pool_id: PoolId) -> CurrencyId;
+}
+
+pub trait WeightedAmmApi<PoolId, CurrencyId, AccountId, Balance> {
+    fn pool_ids() -> Vec<PoolId>;
+
+    fn pool_currencies(pool_id: PoolId) -> Vec<CurrencyId>;
+
+    fn currency_index(pool_id: PoolId, currency: CurrencyId) -> Option<u32>;
+
+    /// Output of `swap`, computed exactly as the swap would.
+    fn quote_swap(pool_id: PoolId, from_index: u32, to_index: u32, in_amount: Balance) -> Option<Balance>;
+
+    /// The smallest input known to return at least `out_amount` from `swap`.
+    fn quote_swap_for_exact(pool_id: PoolId, from_index: u32, to_index: u32, out_amount: Balance) -> Option<Balance>;
+
+    fn swap(
+        who: &AccountId,
+        pool_id: PoolId,
+        from_index: u32,
+        to_index: u32,
+        in_amount: Balance,
+        min_out_amount: Balance,
+        to: &AccountId,
+    ) -> Result<Balance, DispatchError>;
+}
+
+impl<T: Config> WeightedAmmApi<T::PoolId, T::CurrencyId, T::AccountId, Balance> for Pallet<T> {
+    fn pool_ids() -> Vec<T::PoolId> {
+        Pools::<T>::iter_keys().collect()
+    }
+
+    fn pool_currencies(pool_id: T::PoolId) -> Vec<T::CurrencyId> {
+        Self::get_currencies(pool_id)
+    }
+
+    fn currency_index(pool_id: T::PoolId, currency: T::CurrencyId) -> Option<u32> {
+        Self::get_currency_index(pool_id, currency)
+    }
+
+    fn quote_swap(pool_id: T::PoolId, from_index: u32, to_index: u32, in_amount: Balance) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        if !math::within_ratio(in_amount, *pool.balances.get(from_index as usize)?, MAX_IN_RATIO) {
+            return None;
+        }
+        Self::calculate_swap_amount(&pool, from_index as usize, to_index as usize, in_amount)
+    }
+
+    fn quote_swap_for_exact(
+        pool_id: T::PoolId,
+        from_index: u32,
+        to_index: u32,
+        out_amount: Balance,
+    ) -> Option<Balance> {
+        let pool = Self::pools(pool_id)?;
+        let in_amount = Self::calculate_swap_amount_in(&pool, from_index as usize, to_index as usize, out_amount)?;
+        if !math::within_ratio(in_amount, *pool.balances.get(from_index as usize)?, MAX_IN_RATIO) {
+            return None;
+        }
+        Some(in_amount)
+    }
+
+    fn swap(
+        who: &T::AccountId,
+        pool_id: T::PoolId,
+        from_index: u32,
+        to_index: u32,
+        in_amount: Balance,
+        min_out_amount: Balance,
+        to: &T::AccountId,
+    ) -> Result<Balance, DispatchError> {
+        Self::inner_swap(
+            who,
+            pool_id,
+            from_index as usize,
+            to_index as usize,
+            in_amount,
+            min_out_amount,
+            to,
+        )
+    }
+}
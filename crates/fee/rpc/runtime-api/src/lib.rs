@@ -0,0 +1,19 @@
+//! Runtime API definition for the Fee pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use frame_support::dispatch::DispatchError;
+
+pub use primitives::{fee::FeeKind, VaultCurrencyPair};
+
+sp_api::decl_runtime_apis! {
+    pub trait FeeApi<CurrencyId, UnsignedFixedPoint> where
+        CurrencyId: Codec + Copy,
+        UnsignedFixedPoint: Codec,
+    {
+        /// Get the fee rate that applies to the given vault currency pair, taking
+        /// per-pair overrides and dynamic fee models into account
+        fn get_fee_rate(currency_pair: VaultCurrencyPair<CurrencyId>, fee_kind: FeeKind) -> Result<UnsignedFixedPoint, DispatchError>;
+    }
+}
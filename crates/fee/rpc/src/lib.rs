@@ -0,0 +1,89 @@
+//! RPC interface for the Fee Module.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+    DispatchError,
+};
+use std::sync::Arc;
+
+pub use fee_rpc_runtime_api::{FeeApi as FeeRuntimeApi, FeeKind, VaultCurrencyPair};
+
+#[rpc(client, server)]
+pub trait FeeApi<BlockHash, CurrencyId, UnsignedFixedPoint>
+where
+    CurrencyId: Codec + Copy,
+    UnsignedFixedPoint: Codec + MaybeDisplay + MaybeFromStr,
+{
+    /// fee rate for the vault currency pair, including per-pair overrides and dynamic fees
+    #[method(name = "fee_getFeeRate")]
+    fn get_fee_rate(
+        &self,
+        currency_pair: VaultCurrencyPair<CurrencyId>,
+        fee_kind: FeeKind,
+        at: Option<BlockHash>,
+    ) -> RpcResult<UnsignedFixedPoint>;
+}
+
+fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        ErrorCode::InternalError.code(),
+        message.to_string(),
+        None::<()>,
+    )))
+}
+
+/// A struct that implements the [`FeeApi`].
+pub struct Fee<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Fee<C, B> {
+    /// Create new `Fee` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Fee {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn handle_response<T, E: std::fmt::Debug>(result: Result<Result<T, DispatchError>, E>, msg: String) -> RpcResult<T> {
+    result
+        .map_err(|err| internal_err(format!("Runtime error: {:?}: {:?}", msg, err)))?
+        .map_err(|err| internal_err(format!("Execution error: {:?}: {:?}", msg, err)))
+}
+
+#[async_trait]
+impl<C, Block, CurrencyId, UnsignedFixedPoint> FeeApiServer<<Block as BlockT>::Hash, CurrencyId, UnsignedFixedPoint>
+    for Fee<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: FeeRuntimeApi<Block, CurrencyId, UnsignedFixedPoint>,
+    CurrencyId: Codec + Copy,
+    UnsignedFixedPoint: Codec + MaybeDisplay + MaybeFromStr,
+{
+    fn get_fee_rate(
+        &self,
+        currency_pair: VaultCurrencyPair<CurrencyId>,
+        fee_kind: FeeKind,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<UnsignedFixedPoint> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_fee_rate(at, currency_pair, fee_kind),
+            "Unable to obtain the fee rate".into(),
+        )
+    }
+}
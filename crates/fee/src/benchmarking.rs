@@ -117,5 +117,94 @@ pub mod benchmarks {
         set_commission(RawOrigin::Signed(nominator), arbitrary_pair, commission);
     }
 
+    #[benchmark]
+    fn set_currency_pair_fee() {
+        let currency_pair = VaultCurrencyPair {
+            collateral: T::GetNativeCurrencyId::get(),
+            wrapped: T::GetWrappedCurrencyId::get(),
+        };
+        let rate = Fee::<T>::get_max_expected_value();
+
+        #[extrinsic_call]
+        set_currency_pair_fee(RawOrigin::Root, currency_pair, FeeKind::IssueFee, Some(rate));
+    }
+
+    #[benchmark]
+    fn set_issue_fee_model() {
+        let currency_pair = VaultCurrencyPair {
+            collateral: T::GetNativeCurrencyId::get(),
+            wrapped: T::GetWrappedCurrencyId::get(),
+        };
+        let max_fee = Fee::<T>::get_max_expected_value();
+        let fee_model = FeeModel::new_model(
+            Zero::zero(),
+            Zero::zero(),
+            max_fee,
+            UnsignedFixedPoint::<T>::saturating_from_rational(1u32, 2u32),
+        );
+
+        #[extrinsic_call]
+        set_issue_fee_model(RawOrigin::Root, currency_pair, Some(fee_model));
+    }
+
+    #[benchmark]
+    fn set_redeem_fee_model() {
+        let currency_pair = VaultCurrencyPair {
+            collateral: T::GetNativeCurrencyId::get(),
+            wrapped: T::GetWrappedCurrencyId::get(),
+        };
+        let max_fee = Fee::<T>::get_max_expected_value();
+        let fee_model = FeeModel::new_model(
+            Zero::zero(),
+            Zero::zero(),
+            max_fee,
+            UnsignedFixedPoint::<T>::saturating_from_rational(1u32, 2u32),
+        );
+
+        #[extrinsic_call]
+        set_redeem_fee_model(RawOrigin::Root, currency_pair, Some(fee_model));
+    }
+
+    #[benchmark]
+    fn set_auto_compound() {
+        let nominator: T::AccountId = account("recipient", 0, SEED);
+        let vault_id = VaultId::new(
+            nominator.clone(),
+            T::GetNativeCurrencyId::get(),
+            T::GetWrappedCurrencyId::get(),
+        );
+        let setting = AutoCompound {
+            max_slippage: Some(Fee::<T>::get_max_expected_value()),
+        };
+
+        #[extrinsic_call]
+        set_auto_compound(RawOrigin::Signed(nominator), vault_id, Some(setting));
+    }
+
+    #[benchmark]
+    fn compound_rewards() {
+        let nominator: T::AccountId = account("recipient", 0, SEED);
+        let vault_id = VaultId::new(
+            nominator.clone(),
+            T::GetNativeCurrencyId::get(),
+            T::GetWrappedCurrencyId::get(),
+        );
+        let wrapped = T::GetWrappedCurrencyId::get();
+        let native = T::GetNativeCurrencyId::get();
+
+        // set stakes so that we don't bail early
+        T::CapacityRewards::set_stake(&(), &native, 1000u32.into()).unwrap();
+        T::VaultRewards::set_stake(&native, &vault_id, 1000u32.into()).unwrap();
+        T::VaultStaking::set_stake(&(None, vault_id.clone()), &nominator, 1000u32.into()).unwrap();
+
+        distribute_rewards::<T>(wrapped);
+        distribute_rewards::<T>(native);
+
+        AutoCompoundSettings::<T>::insert(&vault_id, &nominator, AutoCompound::default());
+
+        #[extrinsic_call]
+        compound_rewards(RawOrigin::Signed(nominator.clone()), vault_id, nominator.clone());
+    }
+
     impl_benchmark_test_suite! { Fee, crate::mock::ExtBuilder::build(), crate::mock::Test }
 }
@@ -42,6 +42,11 @@ pub trait WeightInfo {
 	fn set_punishment_fee() -> Weight;
 	fn set_replace_griefing_collateral() -> Weight;
 	fn set_commission() -> Weight;
+	fn set_currency_pair_fee() -> Weight;
+	fn set_issue_fee_model() -> Weight;
+	fn set_redeem_fee_model() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn compound_rewards() -> Weight;
 }
 
 /// Weights for fee using the Substrate node and recommended hardware.
@@ -107,6 +112,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_835_000 as u64, 0u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+
+	// Storage: Fee CurrencyPairFees (r:0 w:1)
+	fn set_currency_pair_fee() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee IssueFeeModel (r:0 w:1)
+	fn set_issue_fee_model() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee RedeemFeeModel (r:0 w:1)
+	fn set_redeem_fee_model() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee AutoCompoundSettings (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee AutoCompoundSettings (r:1 w:0)
+	// Storage: Staking Nonce (r:1 w:0)
+	// Storage: Staking TotalCurrentStake (r:1 w:1)
+	// Storage: Staking RewardPerToken (r:1 w:0)
+	// Storage: Staking RewardTally (r:1 w:1)
+	// Storage: Staking TotalRewards (r:1 w:1)
+	// Storage: Staking Stake (r:1 w:1)
+	// Storage: DexGeneral PairStatuses (r:1 w:1)
+	// Storage: Nomination NominationEnabled (r:1 w:0)
+	// Storage: Nomination Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	fn compound_rewards() -> Weight {
+		Weight::from_parts(412_630_000 as u64, 0u64)
+			.saturating_add(T::DbWeight::get().reads(48 as u64))
+			.saturating_add(T::DbWeight::get().writes(29 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -171,5 +217,46 @@ impl WeightInfo for () {
 		Weight::from_parts(2_835_000 as u64, 0u64)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+
+	// Storage: Fee CurrencyPairFees (r:0 w:1)
+	fn set_currency_pair_fee() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee IssueFeeModel (r:0 w:1)
+	fn set_issue_fee_model() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee RedeemFeeModel (r:0 w:1)
+	fn set_redeem_fee_model() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee AutoCompoundSettings (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		Weight::from_parts(2_835_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Fee AutoCompoundSettings (r:1 w:0)
+	// Storage: Staking Nonce (r:1 w:0)
+	// Storage: Staking TotalCurrentStake (r:1 w:1)
+	// Storage: Staking RewardPerToken (r:1 w:0)
+	// Storage: Staking RewardTally (r:1 w:1)
+	// Storage: Staking TotalRewards (r:1 w:1)
+	// Storage: Staking Stake (r:1 w:1)
+	// Storage: DexGeneral PairStatuses (r:1 w:1)
+	// Storage: Nomination NominationEnabled (r:1 w:0)
+	// Storage: Nomination Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	fn compound_rewards() -> Weight {
+		Weight::from_parts(412_630_000 as u64, 0u64)
+			.saturating_add(RocksDbWeight::get().reads(48 as u64))
+			.saturating_add(RocksDbWeight::get().writes(29 as u64))
+	}
 }
 
@@ -0,0 +1,73 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_arithmetic::{
+    traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Saturating, Zero},
+    FixedPointNumber,
+};
+use sp_runtime::RuntimeDebug;
+
+/// Kinked fee curve over the capacity utilization of a vault currency pair.
+///
+/// The fee increases linearly from `base_fee` at zero utilization to `kink_fee`
+/// at `kink_utilization`, and from there to `full_fee` at full utilization.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeModel<UnsignedFixedPoint> {
+    /// The fee when utilization is 0
+    pub base_fee: UnsignedFixedPoint,
+    /// The fee at the kink utilization point
+    pub kink_fee: UnsignedFixedPoint,
+    /// The fee when utilization is 100%
+    pub full_fee: UnsignedFixedPoint,
+    /// The utilization point at which the slope changes
+    pub kink_utilization: UnsignedFixedPoint,
+}
+
+impl<UnsignedFixedPoint: FixedPointNumber> FeeModel<UnsignedFixedPoint> {
+    /// Create a new fee model
+    pub fn new_model(
+        base_fee: UnsignedFixedPoint,
+        kink_fee: UnsignedFixedPoint,
+        full_fee: UnsignedFixedPoint,
+        kink_utilization: UnsignedFixedPoint,
+    ) -> Self {
+        Self {
+            base_fee,
+            kink_fee,
+            full_fee,
+            kink_utilization,
+        }
+    }
+
+    /// Check the fee model for sanity, given the upper bound for any fee
+    pub fn check_model(&self, max_fee: UnsignedFixedPoint) -> bool {
+        if self.full_fee > max_fee {
+            return false;
+        }
+        if self.base_fee > self.kink_fee || self.kink_fee > self.full_fee {
+            return false;
+        }
+        // the kink must lie strictly between 0 and 100% utilization
+        !self.kink_utilization.is_zero() && self.kink_utilization < UnsignedFixedPoint::one()
+    }
+
+    /// Calculates the fee at the given utilization, which saturates at 100%
+    pub fn get_fee(&self, utilization: UnsignedFixedPoint) -> Option<UnsignedFixedPoint> {
+        let utilization = utilization.min(UnsignedFixedPoint::one());
+        if utilization <= self.kink_utilization {
+            // utilization * (kink_fee - base_fee) / kink_utilization + base_fee
+            self.kink_fee
+                .checked_sub(&self.base_fee)?
+                .checked_mul(&utilization)?
+                .checked_div(&self.kink_utilization)?
+                .checked_add(&self.base_fee)
+        } else {
+            // (utilization - kink_utilization) * (full_fee - kink_fee) / (1 - kink_utilization) + kink_fee
+            let excess_utilization = utilization.saturating_sub(self.kink_utilization);
+            self.full_fee
+                .checked_sub(&self.kink_fee)?
+                .checked_mul(&excess_utilization)?
+                .checked_div(&UnsignedFixedPoint::one().saturating_sub(self.kink_utilization))?
+                .checked_add(&self.kink_fee)
+        }
+    }
+}
@@ -17,6 +17,9 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+mod fee_model;
+pub use fee_model::FeeModel;
+
 pub mod types;
 
 #[cfg(test)]
@@ -29,22 +32,30 @@ use codec::{Decode, Encode, EncodeLike};
 use currency::{Amount, CurrencyId, OnSweep, Rounding};
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
-    storage,
+    ensure, storage,
     traits::Get,
-    transactional, PalletId,
+    transactional,
+    weights::Weight,
+    PalletId,
 };
 use frame_system::ensure_signed;
 pub use pallet::*;
-use primitives::VaultId;
+use primitives::{fee::FeeKind, VaultId};
 use reward::RewardsApi;
 use sp_arithmetic::{traits::*, FixedPointNumber};
-use sp_runtime::{traits::AccountIdConversion, TransactionOutcome};
+use sp_runtime::{traits::AccountIdConversion, ArithmeticError, TransactionOutcome};
 use sp_std::{
     convert::{TryFrom, TryInto},
     fmt::Debug,
+    vec::Vec,
 };
-use staking::StakingApi;
-use types::{BalanceOf, DefaultVaultCurrencyPair, DefaultVaultId, UnsignedFixedPoint, Version};
+use staking::{StakingApi, UnbondingApi};
+use traits::{CapacityUtilizationApi, SwapApi};
+use types::{AutoCompound, BalanceOf, DefaultVaultCurrencyPair, DefaultVaultId, UnsignedFixedPoint, Version};
+
+/// Number of consecutive failed compounds after which the `on_idle` sweep disables the
+/// auto-compounding of an account.
+pub const MAX_COMPOUND_FAILURES: u32 = 3;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -57,6 +68,9 @@ pub mod pallet {
     /// The pallet's configuration trait.
     #[pallet::config]
     pub trait Config: frame_system::Config + security::Config + currency::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
         /// The fee module id, used for deriving its sovereign account ID.
         #[pallet::constant]
         type FeePalletId: Get<PalletId>;
@@ -87,6 +101,7 @@ pub mod pallet {
 
         /// Vault staking pool.
         type VaultStaking: StakingApi<DefaultVaultId<Self>, Self::Nonce, BalanceOf<Self>>
+            + UnbondingApi<DefaultVaultId<Self>, Self::AccountId, Self::Nonce, BlockNumberFor<Self>, BalanceOf<Self>>
             + RewardsApi<
                 (Option<Self::Nonce>, DefaultVaultId<Self>),
                 Self::AccountId,
@@ -102,7 +117,29 @@ pub mod pallet {
         type MaxExpectedValue: Get<UnsignedFixedPoint<Self>>;
 
         /// Api of the nomination pallet; used to make sure that commission can only be set if opted in.
-        type NominationApi: NominationApi<DefaultVaultId<Self>, Amount<Self>>;
+        type NominationApi: NominationApi<DefaultVaultId<Self>, Self::AccountId, Amount<Self>>;
+
+        /// Api of the vault registry; used to compute dynamic issue and redeem fees.
+        type CapacityUtilization: CapacityUtilizationApi<DefaultVaultCurrencyPair<Self>, UnsignedFixedPoint<Self>>;
+
+        /// Dex used to swap rewards into collateral when auto-compounding.
+        type RewardSwap: SwapApi<Self::AccountId, CurrencyId<Self>, BalanceOf<Self>>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Compounding the rewards of an account failed, so they were left to withdraw.
+        AutoCompoundFailed {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+            error: DispatchError,
+        },
+        /// The auto-compounding of an account was disabled after failing repeatedly.
+        AutoCompoundRemoved {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+        },
     }
 
     #[pallet::error]
@@ -111,10 +148,18 @@ pub mod pallet {
         TryIntoIntError,
         /// Value exceeds the expected upper bound for storage fields in this pallet.
         AboveMaxExpectedValue,
+        /// The fee model is not monotonic or its kink is out of range.
+        InvalidFeeModel,
+        /// The account has not enabled auto-compounding for this vault.
+        AutoCompoundDisabled,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::compound_on_idle(remaining_weight)
+        }
+    }
 
     /// # Issue
 
@@ -175,6 +220,54 @@ pub mod pallet {
     pub(super) type Commission<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, UnsignedFixedPoint<T>, OptionQuery>;
 
+    /// Fee and griefing collateral rates that override the global values above for
+    /// a specific vault currency pair.
+    #[pallet::storage]
+    pub(super) type CurrencyPairFees<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DefaultVaultCurrencyPair<T>,
+        Blake2_128Concat,
+        FeeKind,
+        UnsignedFixedPoint<T>,
+        OptionQuery,
+    >;
+
+    /// Dynamic issue fee for a vault currency pair. If set, the issue fee increases
+    /// with the capacity utilization of the pair.
+    #[pallet::storage]
+    pub(super) type IssueFeeModel<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, FeeModel<UnsignedFixedPoint<T>>, OptionQuery>;
+
+    /// Dynamic redeem fee for a vault currency pair. If set, the redeem fee decreases
+    /// with the capacity utilization of the pair, i.e. the model is evaluated at the
+    /// unused fraction of the capacity.
+    #[pallet::storage]
+    pub(super) type RedeemFeeModel<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, FeeModel<UnsignedFixedPoint<T>>, OptionQuery>;
+
+    /// Accounts that reinvest their rewards from a vault's staking pool as collateral.
+    #[pallet::storage]
+    pub(super) type AutoCompoundSettings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DefaultVaultId<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        AutoCompound<UnsignedFixedPoint<T>>,
+        OptionQuery,
+    >;
+
+    /// Number of consecutive compounds of an account that failed in the `on_idle` sweep.
+    #[pallet::storage]
+    pub(super) type CompoundFailures<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, DefaultVaultId<T>, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The last auto-compound setting visited by the `on_idle` sweep, which continues after it
+    /// in the next block.
+    #[pallet::storage]
+    pub(super) type CompoundCursor<T: Config> = StorageValue<_, (DefaultVaultId<T>, T::AccountId), OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -205,12 +298,16 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Withdraw all rewards from the `origin` account in the `vault_id` staking pool.
+        /// Rewards of the current stake are reinvested instead if the account enabled
+        /// auto-compounding, and withdrawn as usual if compounding them fails.
         ///
         /// # Arguments
         ///
         /// * `origin` - signing account
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as Config>::WeightInfo::withdraw_rewards())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::withdraw_rewards().saturating_add(<T as Config>::WeightInfo::compound_rewards())
+        )]
         #[transactional]
         pub fn withdraw_rewards(
             origin: OriginFor<T>,
@@ -218,8 +315,22 @@ pub mod pallet {
             index: Option<T::Nonce>,
         ) -> DispatchResultWithPostInfo {
             let nominator_id = ensure_signed(origin)?;
-            for currency_id in [vault_id.wrapped_currency(), T::GetNativeCurrencyId::get()] {
-                Self::withdraw_vault_rewards(&vault_id, &nominator_id, index, currency_id)?;
+            match (index, AutoCompoundSettings::<T>::get(&vault_id, &nominator_id)) {
+                (None, Some(setting)) => {
+                    if let Err(error) =
+                        storage::with_storage_layer(|| Self::compound_vault_rewards(&vault_id, &nominator_id, setting))
+                    {
+                        Self::deposit_event(Event::AutoCompoundFailed {
+                            vault_id: vault_id.clone(),
+                            nominator_id: nominator_id.clone(),
+                            error,
+                        });
+                        Self::withdraw_all_vault_rewards(&vault_id, &nominator_id, index)?;
+                    }
+                }
+                _ => {
+                    Self::withdraw_all_vault_rewards(&vault_id, &nominator_id, index)?;
+                }
             }
             Ok(().into())
         }
@@ -351,6 +462,140 @@ pub mod pallet {
             Commission::<T>::insert(vault_id, commission);
             Ok(().into())
         }
+
+        /// Sets or clears a fee override for a vault currency pair (only executable by the Root account)
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `currency_pair` - the vault currency pair to override the fee for
+        /// * `fee_kind` - the fee parameter to override
+        /// * `fee` - the new fee, or `None` to fall back to the global value
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_currency_pair_fee())]
+        #[transactional]
+        pub fn set_currency_pair_fee(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            fee_kind: FeeKind,
+            fee: Option<UnsignedFixedPoint<T>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(fee) = fee {
+                ensure!(fee <= Self::get_max_expected_value(), Error::<T>::AboveMaxExpectedValue);
+                CurrencyPairFees::<T>::insert(currency_pair, fee_kind, fee);
+            } else {
+                CurrencyPairFees::<T>::remove(currency_pair, fee_kind);
+            }
+            Ok(().into())
+        }
+
+        /// Sets or clears the dynamic issue fee model for a vault currency pair (only
+        /// executable by the Root account)
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `currency_pair` - the vault currency pair to set the model for
+        /// * `fee_model` - the new fee model, or `None` to fall back to the static fee
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_issue_fee_model())]
+        #[transactional]
+        pub fn set_issue_fee_model(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            fee_model: Option<FeeModel<UnsignedFixedPoint<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(fee_model) = fee_model {
+                Self::ensure_valid_fee_model(&fee_model)?;
+                IssueFeeModel::<T>::insert(currency_pair, fee_model);
+            } else {
+                IssueFeeModel::<T>::remove(currency_pair);
+            }
+            Ok(().into())
+        }
+
+        /// Sets or clears the dynamic redeem fee model for a vault currency pair (only
+        /// executable by the Root account)
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `currency_pair` - the vault currency pair to set the model for
+        /// * `fee_model` - the new fee model, or `None` to fall back to the static fee
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_redeem_fee_model())]
+        #[transactional]
+        pub fn set_redeem_fee_model(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            fee_model: Option<FeeModel<UnsignedFixedPoint<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(fee_model) = fee_model {
+                Self::ensure_valid_fee_model(&fee_model)?;
+                RedeemFeeModel::<T>::insert(currency_pair, fee_model);
+            } else {
+                RedeemFeeModel::<T>::remove(currency_pair);
+            }
+            Ok(().into())
+        }
+
+        /// Enables or disables auto-compounding of the rewards of `origin` in the
+        /// `vault_id` staking pool.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - the vault or nominator
+        /// * `vault_id` - the vault whose staking pool pays the rewards
+        /// * `setting` - the auto-compound setting, or `None` to disable
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+        #[transactional]
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            setting: Option<AutoCompound<UnsignedFixedPoint<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator_id = ensure_signed(origin)?;
+            if let Some(setting) = setting {
+                if let Some(max_slippage) = setting.max_slippage {
+                    ensure!(
+                        max_slippage <= UnsignedFixedPoint::<T>::one(),
+                        Error::<T>::AboveMaxExpectedValue
+                    );
+                }
+                AutoCompoundSettings::<T>::insert(&vault_id, &nominator_id, setting);
+            } else {
+                AutoCompoundSettings::<T>::remove(&vault_id, &nominator_id);
+            }
+            CompoundFailures::<T>::remove(vault_id, nominator_id);
+            Ok(().into())
+        }
+
+        /// Reinvests the rewards of an account that enabled auto-compounding as collateral
+        /// of the vault. Callable by anyone, ahead of the `on_idle` sweep.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - signing account
+        /// * `vault_id` - the vault whose staking pool pays the rewards
+        /// * `nominator_id` - the account to compound the rewards of
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::compound_rewards())]
+        #[transactional]
+        pub fn compound_rewards(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let setting =
+                AutoCompoundSettings::<T>::get(&vault_id, &nominator_id).ok_or(Error::<T>::AutoCompoundDisabled)?;
+            Self::compound_vault_rewards(&vault_id, &nominator_id, setting)?;
+            Ok(().into())
+        }
     }
 }
 
@@ -372,6 +617,24 @@ impl<T: Config> Pallet<T> {
         <T as Config>::MaxExpectedValue::get()
     }
 
+    fn ensure_valid_fee_model(fee_model: &FeeModel<UnsignedFixedPoint<T>>) -> DispatchResult {
+        ensure!(
+            fee_model.full_fee <= Self::get_max_expected_value(),
+            Error::<T>::AboveMaxExpectedValue
+        );
+        ensure!(
+            fee_model.check_model(Self::get_max_expected_value()),
+            Error::<T>::InvalidFeeModel
+        );
+        Ok(())
+    }
+
+    fn get_capacity_utilization(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        T::CapacityUtilization::get_capacity_utilization(currency_pair)
+    }
+
     // Public functions exposed to other pallets
 
     /// Distribute rewards to participants.
@@ -389,22 +652,94 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Get the rate of a fee parameter for the given vault currency pair. Issue and
+    /// redeem fees are derived from the capacity utilization if a fee model is set
+    /// for the pair. Otherwise, falls back to the pair override or the global value.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency_pair` - the currency pair of the vault
+    /// * `fee_kind` - the fee parameter to look up
+    pub fn get_fee_rate(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        fee_kind: FeeKind,
+    ) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        if let Some(fee) = Self::get_dynamic_fee_rate(currency_pair, fee_kind)? {
+            return Ok(fee);
+        }
+        Ok(Self::get_static_fee_rate(currency_pair, fee_kind))
+    }
+
+    /// Get the fee rate given by the fee model of the currency pair, if any. Falls back
+    /// to the static rate when the utilization can't be computed, e.g. without a price,
+    /// so that issuing and redeeming don't depend on the oracle.
+    fn get_dynamic_fee_rate(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        fee_kind: FeeKind,
+    ) -> Result<Option<UnsignedFixedPoint<T>>, DispatchError> {
+        let fee_model = match fee_kind {
+            FeeKind::IssueFee => IssueFeeModel::<T>::get(currency_pair),
+            FeeKind::RedeemFee => RedeemFeeModel::<T>::get(currency_pair),
+            _ => None,
+        };
+        let fee_model = match fee_model {
+            Some(fee_model) => fee_model,
+            None => return Ok(None),
+        };
+        let utilization = match Self::get_capacity_utilization(currency_pair) {
+            Ok(utilization) => utilization,
+            Err(_) => return Ok(None),
+        };
+        let utilization = match fee_kind {
+            // redeeming is cheapest when the capacity is fully used
+            FeeKind::RedeemFee => UnsignedFixedPoint::<T>::one().saturating_sub(utilization),
+            _ => utilization,
+        };
+        let fee = fee_model.get_fee(utilization).ok_or(ArithmeticError::Overflow)?;
+        Ok(Some(fee))
+    }
+
+    fn get_static_fee_rate(currency_pair: &DefaultVaultCurrencyPair<T>, fee_kind: FeeKind) -> UnsignedFixedPoint<T> {
+        CurrencyPairFees::<T>::get(currency_pair, fee_kind).unwrap_or_else(|| match fee_kind {
+            FeeKind::IssueFee => IssueFee::<T>::get(),
+            FeeKind::IssueGriefingCollateral => IssueGriefingCollateral::<T>::get(),
+            FeeKind::RedeemFee => RedeemFee::<T>::get(),
+            FeeKind::PremiumRedeemFee => PremiumRedeemFee::<T>::get(),
+            FeeKind::PunishmentFee => PunishmentFee::<T>::get(),
+            FeeKind::ReplaceGriefingCollateral => ReplaceGriefingCollateral::<T>::get(),
+        })
+    }
+
     /// Calculate the required issue fee in tokens.
     ///
     /// # Arguments
     ///
+    /// * `currency_pair` - the currency pair of the vault
     /// * `amount` - issue amount in tokens
-    pub fn get_issue_fee(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        amount.checked_rounded_mul(&<IssueFee<T>>::get(), Rounding::NearestPrefUp)
+    pub fn get_issue_fee(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(
+            &Self::get_fee_rate(currency_pair, FeeKind::IssueFee)?,
+            Rounding::NearestPrefUp,
+        )
     }
 
     /// Calculate the required issue griefing collateral.
     ///
     /// # Arguments
     ///
+    /// * `currency_pair` - the currency pair of the vault
     /// * `amount` - issue amount in collateral (at current exchange rate)
-    pub fn get_issue_griefing_collateral(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        amount.checked_rounded_mul(&<IssueGriefingCollateral<T>>::get(), Rounding::NearestPrefUp)
+    pub fn get_issue_griefing_collateral(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(
+            &Self::get_fee_rate(currency_pair, FeeKind::IssueGriefingCollateral)?,
+            Rounding::NearestPrefUp,
+        )
     }
 
     /// Calculate the required redeem fee in tokens. Upon execution, the
@@ -412,9 +747,16 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Arguments
     ///
+    /// * `currency_pair` - the currency pair of the vault
     /// * `amount` - redeem amount in tokens
-    pub fn get_redeem_fee(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        amount.checked_rounded_mul(&<RedeemFee<T>>::get(), Rounding::NearestPrefUp)
+    pub fn get_redeem_fee(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(
+            &Self::get_fee_rate(currency_pair, FeeKind::RedeemFee)?,
+            Rounding::NearestPrefUp,
+        )
     }
 
     /// Calculate the premium redeem fee in collateral for a user to get if redeeming
@@ -422,9 +764,16 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Arguments
     ///
+    /// * `currency_pair` - the currency pair of the vault
     /// * `amount` - amount in collateral (at current exchange rate)
-    pub fn get_premium_redeem_fee(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        amount.checked_rounded_mul(&<PremiumRedeemFee<T>>::get(), Rounding::NearestPrefUp)
+    pub fn get_premium_redeem_fee(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(
+            &Self::get_fee_rate(currency_pair, FeeKind::PremiumRedeemFee)?,
+            Rounding::NearestPrefUp,
+        )
     }
 
     /// Calculate punishment fee for a Vault that fails to execute a redeem
@@ -432,18 +781,32 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Arguments
     ///
+    /// * `currency_pair` - the currency pair of the vault
     /// * `amount` - amount in collateral (at current exchange rate)
-    pub fn get_punishment_fee(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        amount.checked_rounded_mul(&<PunishmentFee<T>>::get(), Rounding::NearestPrefUp)
+    pub fn get_punishment_fee(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(
+            &Self::get_fee_rate(currency_pair, FeeKind::PunishmentFee)?,
+            Rounding::NearestPrefUp,
+        )
     }
 
     /// Calculate the required replace griefing collateral.
     ///
     /// # Arguments
     ///
+    /// * `currency_pair` - the currency pair of the vault
     /// * `amount` - replace amount in collateral (at current exchange rate)
-    pub fn get_replace_griefing_collateral(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        amount.checked_rounded_mul(&<ReplaceGriefingCollateral<T>>::get(), Rounding::NearestPrefUp)
+    pub fn get_replace_griefing_collateral(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        amount.checked_rounded_mul(
+            &Self::get_fee_rate(currency_pair, FeeKind::ReplaceGriefingCollateral)?,
+            Rounding::NearestPrefUp,
+        )
     }
 
     pub fn compute_vault_rewards(
@@ -464,6 +827,38 @@ impl<T: Config> Pallet<T> {
         storage::with_transaction(|| TransactionOutcome::Rollback(get_rewards()))
     }
 
+    /// Withdraw the rewards of `nominator_id` and deposit them as collateral of `vault_id`.
+    /// Rewards in other currencies are swapped into collateral if allowed by `setting`,
+    /// otherwise they remain with the nominator. Returns the deposited amount.
+    pub fn compound_vault_rewards(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        setting: AutoCompound<UnsignedFixedPoint<T>>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let collateral_id = vault_id.collateral_currency();
+        let mut compounded = Amount::<T>::zero(collateral_id);
+
+        for currency_id in [vault_id.wrapped_currency(), T::GetNativeCurrencyId::get()] {
+            let rewards = Self::withdraw_vault_rewards(vault_id, nominator_id, None, currency_id)?;
+            let reward = Amount::<T>::new(rewards, currency_id);
+            if reward.is_zero() {
+                continue;
+            }
+
+            if currency_id == collateral_id {
+                compounded.checked_accrue(&reward)?;
+            } else if let Some(max_slippage) = setting.max_slippage {
+                let collateral = Self::swap_to_collateral(nominator_id, &reward, collateral_id, max_slippage)?;
+                compounded.checked_accrue(&collateral)?;
+            }
+        }
+
+        if !compounded.is_zero() {
+            T::NominationApi::deposit_nominator_collateral(vault_id, nominator_id, &compounded)?;
+        }
+        Ok(compounded)
+    }
+
     pub fn distribute_all_vault_rewards(vault_id: &DefaultVaultId<T>) -> DispatchResult {
         for currency_id in [vault_id.wrapped_currency(), T::GetNativeCurrencyId::get()] {
             Self::distribute_vault_rewards(&vault_id, currency_id)?;
@@ -473,6 +868,17 @@ impl<T: Config> Pallet<T> {
 
     // Private functions internal to this pallet
 
+    fn withdraw_all_vault_rewards(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        index: Option<T::Nonce>,
+    ) -> DispatchResult {
+        for currency_id in [vault_id.wrapped_currency(), T::GetNativeCurrencyId::get()] {
+            Self::withdraw_vault_rewards(vault_id, nominator_id, index, currency_id)?;
+        }
+        Ok(())
+    }
+
     /// Withdraw rewards from a pool and transfer to `account_id`.
     fn withdraw_vault_rewards(
         vault_id: &DefaultVaultId<T>,
@@ -491,6 +897,93 @@ impl<T: Config> Pallet<T> {
         Ok(rewards)
     }
 
+    /// Compounds the rewards of as many opted-in accounts as `remaining_weight` allows,
+    /// continuing after the account visited last.
+    fn compound_on_idle(remaining_weight: Weight) -> Weight {
+        let compound_weight = <T as Config>::WeightInfo::compound_rewards();
+        let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+        if used_weight.saturating_add(compound_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut settings = match CompoundCursor::<T>::get() {
+            Some((vault_id, nominator_id)) => {
+                AutoCompoundSettings::<T>::iter_from(AutoCompoundSettings::<T>::hashed_key_for(vault_id, nominator_id))
+            }
+            None => AutoCompoundSettings::<T>::iter(),
+        };
+        // collect first, compounding writes to the staking pools
+        let mut batch = Vec::new();
+        let mut exhausted = false;
+        while used_weight.saturating_add(compound_weight).all_lte(remaining_weight) {
+            match settings.next() {
+                Some(entry) => {
+                    used_weight = used_weight.saturating_add(compound_weight);
+                    batch.push(entry);
+                }
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+
+        // start over once all accounts were visited
+        let cursor = if exhausted {
+            None
+        } else {
+            batch
+                .last()
+                .map(|(vault_id, nominator_id, _)| (vault_id.clone(), nominator_id.clone()))
+        };
+        CompoundCursor::<T>::set(cursor);
+
+        for (vault_id, nominator_id, setting) in batch {
+            // a failed compound, e.g. on slippage, is rolled back and retried in a later sweep,
+            // unless it keeps failing
+            match storage::with_storage_layer(|| Self::compound_vault_rewards(&vault_id, &nominator_id, setting)) {
+                Ok(_) => CompoundFailures::<T>::remove(&vault_id, &nominator_id),
+                Err(error) => {
+                    Self::deposit_event(Event::AutoCompoundFailed {
+                        vault_id: vault_id.clone(),
+                        nominator_id: nominator_id.clone(),
+                        error,
+                    });
+                    let failures = CompoundFailures::<T>::get(&vault_id, &nominator_id).saturating_add(1);
+                    if failures >= MAX_COMPOUND_FAILURES {
+                        AutoCompoundSettings::<T>::remove(&vault_id, &nominator_id);
+                        CompoundFailures::<T>::remove(&vault_id, &nominator_id);
+                        Self::deposit_event(Event::AutoCompoundRemoved { vault_id, nominator_id });
+                    } else {
+                        CompoundFailures::<T>::insert(&vault_id, &nominator_id, failures);
+                    }
+                }
+            }
+        }
+
+        used_weight
+    }
+
+    /// Swap `amount` held by `account_id` into `collateral_id`, failing if the received amount
+    /// is worth less than `1 - max_slippage` of the input at the oracle price.
+    fn swap_to_collateral(
+        account_id: &T::AccountId,
+        amount: &Amount<T>,
+        collateral_id: CurrencyId<T>,
+        max_slippage: UnsignedFixedPoint<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let expected = amount.convert_to(collateral_id)?;
+        let min_amount_out = expected.checked_sub(&expected.checked_mul(&max_slippage)?)?;
+        let amount_out = T::RewardSwap::swap_exact_for(
+            account_id,
+            amount.currency(),
+            collateral_id,
+            amount.amount(),
+            min_amount_out.amount(),
+        )?;
+        Ok(Amount::new(amount_out, collateral_id))
+    }
+
     fn distribute(reward: &Amount<T>) -> Result<Amount<T>, DispatchError> {
         Ok(
             if let Err(_) = T::CapacityRewards::distribute_reward(&(), reward.currency(), reward.amount()) {
@@ -7,8 +7,8 @@ use frame_support::{
 };
 use mocktopus::mocking::clear_mocks;
 use orml_traits::parameter_type_with_key;
-use primitives::VaultId;
 pub use primitives::{CurrencyId, CurrencyId::Token, TokenSymbol::*};
+use primitives::{VaultCurrencyPair, VaultId};
 use sp_arithmetic::{FixedI128, FixedU128};
 use sp_core::H256;
 use sp_runtime::{
@@ -34,7 +34,7 @@ frame_support::construct_runtime!(
 
         // Operational
         Security: security::{Pallet, Call, Storage, Event<T>},
-        Fee: fee::{Pallet, Call, Config<T>, Storage},
+        Fee: fee::{Pallet, Call, Config<T>, Storage, Event<T>},
     }
 );
 
@@ -149,6 +149,7 @@ impl pallet_timestamp::Config for Test {
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 pub struct CurrencyConvert;
@@ -179,13 +180,20 @@ parameter_types! {
 
 pub struct MockNomination;
 
-impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test>> for MockNomination {
+impl traits::NominationApi<VaultId<AccountId, CurrencyId>, AccountId, currency::Amount<Test>> for MockNomination {
     fn deposit_vault_collateral(
         _vault_id: &VaultId<AccountId, CurrencyId>,
         _amount: &currency::Amount<Test>,
     ) -> Result<(), DispatchError> {
         Ok(())
     }
+    fn deposit_nominator_collateral(
+        _vault_id: &VaultId<AccountId, CurrencyId>,
+        _nominator_id: &AccountId,
+        _amount: &currency::Amount<Test>,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
     fn ensure_opted_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
         Ok(())
     }
@@ -194,7 +202,18 @@ impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test
     fn opt_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) {}
 }
 
+pub struct MockCapacityUtilization;
+
+impl traits::CapacityUtilizationApi<VaultCurrencyPair<CurrencyId>, UnsignedFixedPoint> for MockCapacityUtilization {
+    fn get_capacity_utilization(
+        _currency_pair: &VaultCurrencyPair<CurrencyId>,
+    ) -> Result<UnsignedFixedPoint, DispatchError> {
+        Ok(UnsignedFixedPoint::zero())
+    }
+}
+
 impl Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type FeePalletId = FeePalletId;
     type WeightInfo = ();
     type SignedFixedPoint = SignedFixedPoint;
@@ -205,6 +224,8 @@ impl Config for Test {
     type OnSweep = ();
     type MaxExpectedValue = MaxExpectedValue;
     type NominationApi = MockNomination;
+    type CapacityUtilization = MockCapacityUtilization;
+    type RewardSwap = ();
 }
 
 #[allow(dead_code)]
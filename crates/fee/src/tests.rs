@@ -1,7 +1,11 @@
-use crate::{mock::*, Commission, IssueFee};
+use crate::{
+    mock::*, types::AutoCompound, AutoCompoundSettings, Commission, CompoundCursor, CompoundFailures, CurrencyPairFees,
+    Event, FeeModel, IssueFee, RedeemFee, WeightInfo, MAX_COMPOUND_FAILURES,
+};
 use currency::Amount;
-use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
-use primitives::VaultId;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo, traits::Hooks, weights::Weight};
+use mocktopus::mocking::*;
+use primitives::{fee::FeeKind, TokenSymbol, VaultCurrencyPair, VaultId};
 use reward::RewardsApi;
 use sp_arithmetic::FixedI128;
 use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
@@ -26,17 +30,213 @@ where
     })
 }
 
+fn currency_pair(collateral: TokenSymbol) -> VaultCurrencyPair<CurrencyId> {
+    VaultCurrencyPair {
+        collateral: Token(collateral),
+        wrapped: Token(IBTC),
+    }
+}
+
 #[test]
 fn should_get_issue_fee() {
     run_test(|| {
         <IssueFee<Test>>::put(UnsignedFixedPoint::checked_from_rational(10, 100).unwrap());
         assert_ok!(
-            Fee::get_issue_fee(&Amount::<Test>::new(100, Token(IBTC))),
+            Fee::get_issue_fee(&currency_pair(DOT), &Amount::<Test>::new(100, Token(IBTC))),
             Amount::<Test>::new(10, Token(IBTC))
         );
     })
 }
 
+#[test]
+fn should_get_currency_pair_fee_with_fallback() {
+    run_test(|| {
+        <RedeemFee<Test>>::put(UnsignedFixedPoint::checked_from_rational(10, 100).unwrap());
+        <CurrencyPairFees<Test>>::insert(
+            currency_pair(KSM),
+            FeeKind::RedeemFee,
+            UnsignedFixedPoint::checked_from_rational(20, 100).unwrap(),
+        );
+
+        let amount = Amount::<Test>::new(100, Token(IBTC));
+        assert_ok!(
+            Fee::get_redeem_fee(&currency_pair(KSM), &amount),
+            Amount::<Test>::new(20, Token(IBTC))
+        );
+        // pairs without override use the global value
+        assert_ok!(
+            Fee::get_redeem_fee(&currency_pair(DOT), &amount),
+            Amount::<Test>::new(10, Token(IBTC))
+        );
+    })
+}
+
+#[test]
+fn should_set_currency_pair_fee() {
+    run_test(|| {
+        let large_value = UnsignedFixedPoint::checked_from_rational::<u128, u128>(101, 100).unwrap(); // 101%
+        assert_noop!(
+            Fee::set_currency_pair_fee(
+                RuntimeOrigin::root(),
+                currency_pair(KSM),
+                FeeKind::IssueFee,
+                Some(large_value)
+            ),
+            TestError::AboveMaxExpectedValue
+        );
+
+        let valid_value = UnsignedFixedPoint::checked_from_rational::<u128, u128>(5, 100).unwrap(); // 5%
+        assert_noop!(
+            Fee::set_currency_pair_fee(
+                RuntimeOrigin::signed(6),
+                currency_pair(KSM),
+                FeeKind::IssueFee,
+                Some(valid_value)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Fee::set_currency_pair_fee(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            FeeKind::IssueFee,
+            Some(valid_value)
+        ));
+        assert_ok!(Fee::get_fee_rate(&currency_pair(KSM), FeeKind::IssueFee), valid_value);
+
+        // clearing the override falls back to the global value
+        assert_ok!(Fee::set_currency_pair_fee(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            FeeKind::IssueFee,
+            None
+        ));
+        assert_ok!(
+            Fee::get_fee_rate(&currency_pair(KSM), FeeKind::IssueFee),
+            Fee::issue_fee()
+        );
+    })
+}
+
+fn percent(value: u128) -> UnsignedFixedPoint {
+    UnsignedFixedPoint::checked_from_rational(value, 100).unwrap()
+}
+
+fn sample_fee_model() -> FeeModel<UnsignedFixedPoint> {
+    FeeModel::new_model(percent(0), percent(1), percent(10), percent(80))
+}
+
+#[test]
+fn should_get_fee_from_fee_model() {
+    let fee_model = sample_fee_model();
+    assert_eq!(fee_model.get_fee(percent(0)), Some(percent(0)));
+    assert_eq!(
+        fee_model.get_fee(percent(40)),
+        Some(UnsignedFixedPoint::checked_from_rational(5, 1000).unwrap())
+    );
+    assert_eq!(fee_model.get_fee(percent(80)), Some(percent(1)));
+    assert_eq!(
+        fee_model.get_fee(percent(90)),
+        Some(UnsignedFixedPoint::checked_from_rational(55, 1000).unwrap())
+    );
+    assert_eq!(fee_model.get_fee(percent(100)), Some(percent(10)));
+    // utilization above 100% is treated as full utilization
+    assert_eq!(fee_model.get_fee(percent(120)), Some(percent(10)));
+}
+
+#[test]
+fn should_set_fee_model() {
+    run_test(|| {
+        let fee_model = sample_fee_model();
+        assert_noop!(
+            Fee::set_issue_fee_model(RuntimeOrigin::signed(6), currency_pair(KSM), Some(fee_model)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Fee::set_issue_fee_model(
+                RuntimeOrigin::root(),
+                currency_pair(KSM),
+                Some(FeeModel::new_model(percent(0), percent(1), percent(101), percent(80)))
+            ),
+            TestError::AboveMaxExpectedValue
+        );
+        // fees must not decrease with utilization
+        assert_noop!(
+            Fee::set_redeem_fee_model(
+                RuntimeOrigin::root(),
+                currency_pair(KSM),
+                Some(FeeModel::new_model(percent(2), percent(1), percent(10), percent(80)))
+            ),
+            TestError::InvalidFeeModel
+        );
+        assert_noop!(
+            Fee::set_redeem_fee_model(
+                RuntimeOrigin::root(),
+                currency_pair(KSM),
+                Some(FeeModel::new_model(percent(0), percent(1), percent(10), percent(100)))
+            ),
+            TestError::InvalidFeeModel
+        );
+        assert_ok!(Fee::set_issue_fee_model(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            Some(fee_model)
+        ));
+        assert_ok!(Fee::set_redeem_fee_model(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            Some(fee_model)
+        ));
+    })
+}
+
+#[test]
+fn should_get_dynamic_issue_and_redeem_fees() {
+    run_test(|| {
+        <IssueFee<Test>>::put(percent(5));
+        <RedeemFee<Test>>::put(percent(5));
+        assert_ok!(Fee::set_issue_fee_model(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            Some(sample_fee_model())
+        ));
+        assert_ok!(Fee::set_redeem_fee_model(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            Some(sample_fee_model())
+        ));
+        Fee::get_capacity_utilization.mock_safe(|_| MockResult::Return(Ok(percent(90))));
+
+        let amount = Amount::<Test>::new(1000, Token(IBTC));
+        // issuing into a nearly full system is expensive
+        assert_ok!(
+            Fee::get_issue_fee(&currency_pair(KSM), &amount),
+            Amount::<Test>::new(55, Token(IBTC))
+        );
+        // redeeming is cheap, the model is evaluated at 10% unused capacity
+        assert_ok!(
+            Fee::get_redeem_fee(&currency_pair(KSM), &amount),
+            Amount::<Test>::new(1, Token(IBTC))
+        );
+        // other fees and pairs without a model are not affected
+        assert_ok!(
+            Fee::get_fee_rate(&currency_pair(KSM), FeeKind::PunishmentFee),
+            Fee::punishment_fee()
+        );
+        assert_ok!(Fee::get_fee_rate(&currency_pair(DOT), FeeKind::IssueFee), percent(5));
+
+        assert_ok!(Fee::set_issue_fee_model(
+            RuntimeOrigin::root(),
+            currency_pair(KSM),
+            None
+        ));
+        assert_ok!(Fee::get_fee_rate(&currency_pair(KSM), FeeKind::IssueFee), percent(5));
+
+        // without a utilization, e.g. when there is no price, the static rate is charged
+        Fee::get_capacity_utilization.mock_safe(|_| MockResult::Return(Err(DispatchError::Other("NoPrice"))));
+        assert_ok!(Fee::get_fee_rate(&currency_pair(KSM), FeeKind::RedeemFee), percent(5));
+    })
+}
+
 #[test]
 fn should_set_issue_fee() {
     test_setter(Fee::set_issue_fee, Fee::issue_fee);
@@ -149,3 +349,201 @@ fn compute_vault_rewards_works_with_commission() {
         );
     })
 }
+
+#[test]
+fn should_set_auto_compound() {
+    run_test(|| {
+        let vault_id = VaultId::new(1, Token(DOT), Token(IBTC));
+        let setting = AutoCompound {
+            max_slippage: Some(percent(5)),
+        };
+
+        assert_noop!(
+            Fee::set_auto_compound(
+                RuntimeOrigin::signed(2),
+                vault_id.clone(),
+                Some(AutoCompound {
+                    max_slippage: Some(percent(101)),
+                })
+            ),
+            TestError::AboveMaxExpectedValue
+        );
+        assert_noop!(
+            Fee::compound_rewards(RuntimeOrigin::signed(3), vault_id.clone(), 2),
+            TestError::AutoCompoundDisabled
+        );
+
+        assert_ok!(Fee::set_auto_compound(
+            RuntimeOrigin::signed(2),
+            vault_id.clone(),
+            Some(setting)
+        ));
+        assert_eq!(AutoCompoundSettings::<Test>::get(&vault_id, 2), Some(setting));
+
+        assert_ok!(Fee::set_auto_compound(RuntimeOrigin::signed(2), vault_id.clone(), None));
+        assert_eq!(AutoCompoundSettings::<Test>::get(&vault_id, 2), None);
+    })
+}
+
+#[test]
+fn should_compound_vault_rewards() {
+    run_test(|| {
+        // rewards in the native currency can be deposited as collateral directly
+        let vault_id = VaultId::new(1, Token(INTR), Token(IBTC));
+        Fee::withdraw_vault_rewards.mock_safe(|_, _, _, currency_id| match currency_id {
+            Token(INTR) => MockResult::Return(Ok(100)),
+            _ => MockResult::Return(Ok(50)),
+        });
+
+        // without swapping, the wrapped rewards are paid out
+        Fee::swap_to_collateral.mock_safe(|_, _, _, _| panic!("Should not swap rewards"));
+        assert_ok!(
+            Fee::compound_vault_rewards(&vault_id, &2, AutoCompound::default()),
+            Amount::new(100, Token(INTR))
+        );
+
+        Fee::swap_to_collateral.mock_safe(|_, amount, collateral_id, max_slippage| {
+            assert_eq!(amount, &Amount::new(50, Token(IBTC)));
+            assert_eq!(max_slippage, percent(5));
+            MockResult::Return(Ok(Amount::new(200, collateral_id)))
+        });
+        assert_ok!(
+            Fee::compound_vault_rewards(
+                &vault_id,
+                &2,
+                AutoCompound {
+                    max_slippage: Some(percent(5))
+                }
+            ),
+            Amount::new(300, Token(INTR))
+        );
+    })
+}
+
+#[test]
+fn should_compound_on_withdraw_rewards() {
+    run_test(|| {
+        let vault_id = VaultId::new(1, Token(DOT), Token(IBTC));
+        let setting = AutoCompound {
+            max_slippage: Some(percent(5)),
+        };
+        assert_ok!(Fee::set_auto_compound(
+            RuntimeOrigin::signed(2),
+            vault_id.clone(),
+            Some(setting)
+        ));
+
+        Fee::withdraw_vault_rewards.mock_safe(|_, _, _, _| panic!("Should not pay out rewards"));
+        Fee::compound_vault_rewards.mock_safe(move |_, nominator_id, compound_setting| {
+            assert_eq!(nominator_id, &2);
+            assert_eq!(compound_setting, setting);
+            MockResult::Return(Ok(Amount::new(100, Token(DOT))))
+        });
+        assert_ok!(Fee::withdraw_rewards(RuntimeOrigin::signed(2), vault_id.clone(), None));
+
+        // rewards of a previous stake and of accounts that did not opt in are paid out
+        Fee::compound_vault_rewards.mock_safe(|_, _, _| panic!("Should not compound rewards"));
+        Fee::withdraw_vault_rewards.mock_safe(|_, _, _, _| MockResult::Return(Ok(0)));
+        assert_ok!(Fee::withdraw_rewards(
+            RuntimeOrigin::signed(2),
+            vault_id.clone(),
+            Some(0)
+        ));
+        assert_ok!(Fee::withdraw_rewards(RuntimeOrigin::signed(3), vault_id, None));
+    })
+}
+
+#[test]
+fn should_withdraw_rewards_if_compounding_fails() {
+    run_test(|| {
+        let vault_id = VaultId::new(1, Token(DOT), Token(IBTC));
+        assert_ok!(Fee::set_auto_compound(
+            RuntimeOrigin::signed(2),
+            vault_id.clone(),
+            Some(AutoCompound::default())
+        ));
+
+        let error = DispatchError::Other("swap failed");
+        Fee::compound_vault_rewards.mock_safe(move |_, _, _| MockResult::Return(Err(error)));
+        let withdrawn = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = withdrawn.clone();
+        Fee::withdraw_vault_rewards.mock_safe(move |_, nominator_id, _, currency_id| {
+            log.borrow_mut().push((*nominator_id, currency_id));
+            MockResult::Return(Ok(0))
+        });
+
+        assert_ok!(Fee::withdraw_rewards(RuntimeOrigin::signed(2), vault_id.clone(), None));
+        assert_eq!(*withdrawn.borrow(), vec![(2, Token(IBTC)), (2, Token(INTR))]);
+        System::assert_last_event(RuntimeEvent::Fee(Event::AutoCompoundFailed {
+            vault_id,
+            nominator_id: 2,
+            error,
+        }));
+    })
+}
+
+#[test]
+fn should_compound_on_idle() {
+    run_test(|| {
+        let vault_id = VaultId::new(1, Token(DOT), Token(IBTC));
+        for nominator_id in [2, 3, 4] {
+            assert_ok!(Fee::set_auto_compound(
+                RuntimeOrigin::signed(nominator_id),
+                vault_id.clone(),
+                Some(AutoCompound::default())
+            ));
+        }
+
+        let compounded = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = compounded.clone();
+        Fee::compound_vault_rewards.mock_safe(move |_, nominator_id, _| {
+            log.borrow_mut().push(*nominator_id);
+            MockResult::Return(Ok(Amount::new(0, Token(DOT))))
+        });
+
+        // not enough weight to compound a single account
+        assert_eq!(Fee::on_idle(1, Weight::zero()), Weight::zero());
+        assert!(compounded.borrow().is_empty());
+
+        // compound two accounts, then continue with the third and start over
+        let compound_weight = <Test as crate::Config>::WeightInfo::compound_rewards();
+        let remaining_weight = compound_weight.saturating_mul(2);
+        Fee::on_idle(1, remaining_weight);
+        assert_eq!(compounded.borrow().len(), 2);
+        assert!(CompoundCursor::<Test>::get().is_some());
+
+        Fee::on_idle(2, remaining_weight);
+        assert_eq!(compounded.borrow().len(), 3);
+        assert_eq!(CompoundCursor::<Test>::get(), None);
+
+        let mut nominators = compounded.borrow().clone();
+        nominators.sort();
+        assert_eq!(nominators, vec![2, 3, 4]);
+    })
+}
+
+#[test]
+fn should_disable_auto_compound_that_keeps_failing_on_idle() {
+    run_test(|| {
+        let vault_id = VaultId::new(1, Token(DOT), Token(IBTC));
+        assert_ok!(Fee::set_auto_compound(
+            RuntimeOrigin::signed(2),
+            vault_id.clone(),
+            Some(AutoCompound::default())
+        ));
+        Fee::compound_vault_rewards.mock_safe(|_, _, _| MockResult::Return(Err(DispatchError::Other("swap failed"))));
+
+        let remaining_weight = <Test as crate::Config>::WeightInfo::compound_rewards().saturating_mul(2);
+        for failures in 1..MAX_COMPOUND_FAILURES {
+            Fee::on_idle(1, remaining_weight);
+            assert_eq!(CompoundFailures::<Test>::get(&vault_id, 2), failures);
+        }
+        Fee::on_idle(1, remaining_weight);
+        assert_eq!(AutoCompoundSettings::<Test>::get(&vault_id, 2), None);
+        assert_eq!(CompoundFailures::<Test>::get(&vault_id, 2), 0);
+        System::assert_last_event(RuntimeEvent::Fee(Event::AutoCompoundRemoved {
+            vault_id,
+            nominator_id: 2,
+        }));
+    })
+}
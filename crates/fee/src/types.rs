@@ -2,6 +2,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use currency::CurrencyId;
 use primitives::{VaultCurrencyPair, VaultId};
 use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
 
 pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
 
@@ -17,3 +18,12 @@ pub enum Version {
     /// Initial version.
     V0,
 }
+
+/// Auto-compound setting of a participant in a vault's staking pool.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AutoCompound<UnsignedFixedPoint> {
+    /// If set, rewards that are not in the collateral currency are swapped into
+    /// collateral, accepting at most this deviation from the oracle price.
+    /// Otherwise these rewards are paid out as usual.
+    pub max_slippage: Option<UnsignedFixedPoint>,
+}
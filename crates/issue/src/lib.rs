@@ -38,6 +38,7 @@ use currency::Amount;
 use frame_support::{dispatch::DispatchError, ensure, pallet_prelude::Weight, traits::Get, transactional, PalletId};
 use frame_system::{ensure_root, ensure_signed};
 pub use pallet::*;
+use security::Subsystem;
 use sp_core::H256;
 use sp_runtime::traits::{AccountIdConversion, Convert, Saturating};
 use sp_std::vec::Vec;
@@ -306,6 +307,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WaitingForRelayerInitialization
         );
 
+        ext::security::ensure_subsystem_running::<T>(Subsystem::Issue, &vault_id.currencies)?;
+
         let vault = ext::vault_registry::get_active_vault_from_id::<T>(&vault_id)?;
 
         // ensure that the vault is accepting new issues
@@ -319,7 +322,7 @@ impl<T: Config> Pallet<T> {
 
         // calculate griefing collateral based on the total amount of tokens to be issued
         let amount_collateral = amount_requested.convert_to(griefing_currency)?;
-        let griefing_collateral = ext::fee::get_issue_griefing_collateral::<T>(&amount_collateral)?;
+        let griefing_collateral = ext::fee::get_issue_griefing_collateral::<T>(&vault_id, &amount_collateral)?;
         griefing_collateral.lock_on(&requester)?;
 
         // only continue if the payment is above the dust value
@@ -330,7 +333,7 @@ impl<T: Config> Pallet<T> {
 
         ext::vault_registry::try_increase_to_be_issued_tokens::<T>(&vault_id, &amount_requested)?;
 
-        let fee = ext::fee::get_issue_fee::<T>(&amount_requested)?;
+        let fee = ext::fee::get_issue_fee::<T>(&vault_id, &amount_requested)?;
         // calculate the amount of tokens that will be transferred to the user upon execution
         let amount_user = amount_requested.checked_sub(&fee)?;
 
@@ -625,7 +628,7 @@ impl<T: Config> Pallet<T> {
         confiscated_griefing_collateral: Amount<T>,
     ) -> Result<(), DispatchError> {
         // Current vault can handle the surplus; update the issue request
-        issue.fee = ext::fee::get_issue_fee::<T>(&transferred_btc)?.amount();
+        issue.fee = ext::fee::get_issue_fee::<T>(&issue.vault, &transferred_btc)?.amount();
         issue.amount = transferred_btc.checked_sub(&issue.fee())?.amount();
 
         // update storage
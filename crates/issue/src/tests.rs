@@ -110,6 +110,19 @@ fn test_request_issue_banned_fails() {
     })
 }
 
+#[test]
+fn test_request_issue_subsystem_halted_fails() {
+    run_test(|| {
+        assert_ok!(<security::Pallet<Test>>::set_subsystem_status(
+            RuntimeOrigin::root(),
+            security::Subsystem::Issue,
+            security::SubsystemScope::Pair(VAULT.currencies),
+            security::SubsystemStatus::Halted
+        ));
+        assert_noop!(request_issue(USER, 3, VAULT), security::Error::<Test>::SubsystemHalted);
+    })
+}
+
 #[test]
 fn test_request_issue_succeeds() {
     run_test(|| {
@@ -123,10 +136,10 @@ fn test_request_issue_succeeds() {
         ext::vault_registry::get_active_vault_from_id::<Test>
             .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
 
-        ext::fee::get_issue_fee::<Test>.mock_safe(move |_| MockResult::Return(Ok(wrapped(issue_fee))));
+        ext::fee::get_issue_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(issue_fee))));
 
         ext::fee::get_issue_griefing_collateral::<Test>
-            .mock_safe(move |_| MockResult::Return(Ok(griefing(issue_griefing_collateral))));
+            .mock_safe(move |_, _| MockResult::Return(Ok(griefing(issue_griefing_collateral))));
 
         let issue_id = request_issue_ok_with_address(origin, amount, vault.clone(), address.clone());
 
@@ -164,9 +177,9 @@ fn setup_execute(
     ext::vault_registry::issue_tokens::<Test>.mock_safe(|_, _| MockResult::Return(Ok(())));
     ext::vault_registry::is_vault_liquidated::<Test>.mock_safe(|_| MockResult::Return(Ok(false)));
 
-    ext::fee::get_issue_fee::<Test>.mock_safe(move |_| MockResult::Return(Ok(wrapped(issue_fee))));
+    ext::fee::get_issue_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(issue_fee))));
     ext::fee::get_issue_griefing_collateral::<Test>
-        .mock_safe(move |_| MockResult::Return(Ok(griefing(griefing_collateral))));
+        .mock_safe(move |_, _| MockResult::Return(Ok(griefing(griefing_collateral))));
 
     let issue_id = request_issue_ok(USER, issue_amount, VAULT);
     <security::Pallet<Test>>::set_active_block_number(5);
@@ -333,7 +346,7 @@ fn test_cancel_issue_not_expired_and_requester_succeeds() {
             .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
         ext::vault_registry::decrease_to_be_issued_tokens::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(())));
         ext::vault_registry::is_vault_liquidated::<Test>.mock_safe(move |_| MockResult::Return(Ok(false)));
-        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(griefing(100))));
 
         let issue_id = request_issue_ok(USER, 300, VAULT);
 
@@ -369,7 +382,7 @@ fn test_cancel_issue_expired_succeeds() {
             .mock_safe(|_| MockResult::Return(Ok(init_zero_vault(VAULT))));
         ext::vault_registry::decrease_to_be_issued_tokens::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(())));
         ext::vault_registry::is_vault_liquidated::<Test>.mock_safe(move |_| MockResult::Return(Ok(false)));
-        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(100))));
+        ext::fee::get_issue_griefing_collateral::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(griefing(100))));
         ext::btc_relay::has_request_expired::<Test>.mock_safe(move |_, _, _| MockResult::Return(Ok(true)));
 
         let issue_id = request_issue_ok(USER, 300, VAULT);
@@ -18,15 +18,29 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use primitives::{
+    loans::{EarningsReport, LiquidationCandidates},
+    CurrencyId, Liquidity, Rate, Ratio, Shortfall,
+};
 use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-    pub trait LoansApi<AccountId, Balance> where
+    pub trait LoansApi<AccountId, Balance, RateModel> where
         AccountId: Codec,
-        Balance: Codec {
+        Balance: Codec,
+        RateModel: Codec {
         fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall), DispatchError>;
         fn get_market_status(asset_id: CurrencyId) -> Result<(Rate, Rate, Rate, Ratio, Balance, Balance, FixedU128), DispatchError>;
         fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall), DispatchError>;
+        fn get_bad_debt(asset_id: CurrencyId) -> Balance;
+        fn get_rate_model_preview(asset_id: CurrencyId, rate_model: RateModel) -> Result<(Rate, Rate), DispatchError>;
+        fn get_liquidation_candidates(
+            max_health_factor: FixedU128,
+            start_key: Option<(CurrencyId, AccountId)>,
+            limit: u32,
+        ) -> Result<LiquidationCandidates<AccountId, CurrencyId, Balance, FixedU128>, DispatchError>;
+        fn get_liquidate_incentive(account: AccountId, asset_id: CurrencyId) -> Result<Rate, DispatchError>;
+        fn get_account_earnings(account: AccountId) -> Result<EarningsReport<CurrencyId, Balance>, DispatchError>;
     }
 }
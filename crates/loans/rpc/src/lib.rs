@@ -25,16 +25,20 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorCode, ErrorObject},
 };
-use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use primitives::{
+    loans::{EarningsReport, LiquidationCandidates},
+    CurrencyId, Liquidity, Rate, Ratio, Shortfall,
+};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::{traits::Block as BlockT, FixedU128};
 
 #[rpc(client, server)]
-pub trait LoansApi<BlockHash, AccountId, Balance>
+pub trait LoansApi<BlockHash, AccountId, Balance, RateModel>
 where
     Balance: Codec + Copy + TryFrom<NumberOrHex>,
+    RateModel: Codec,
 {
     #[method(name = "loans_getCollateralLiquidity")]
     fn get_account_liquidity(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<(Liquidity, Shortfall)>;
@@ -50,6 +54,36 @@ where
         account: AccountId,
         at: Option<BlockHash>,
     ) -> RpcResult<(Liquidity, Shortfall)>;
+    #[method(name = "loans_getBadDebt")]
+    fn get_bad_debt(&self, asset_id: CurrencyId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+    #[method(name = "loans_getRateModelPreview")]
+    fn get_rate_model_preview(
+        &self,
+        asset_id: CurrencyId,
+        rate_model: RateModel,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(Rate, Rate)>;
+    #[method(name = "loans_getLiquidationCandidates")]
+    fn get_liquidation_candidates(
+        &self,
+        max_health_factor: FixedU128,
+        start_key: Option<(CurrencyId, AccountId)>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<LiquidationCandidates<AccountId, CurrencyId, Balance, FixedU128>>;
+    #[method(name = "loans_getLiquidateIncentive")]
+    fn get_liquidate_incentive(
+        &self,
+        account: AccountId,
+        asset_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Rate>;
+    #[method(name = "loans_getAccountEarnings")]
+    fn get_account_earnings(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<EarningsReport<CurrencyId, Balance>>;
 }
 
 /// A struct that implements the [`LoansApi`].
@@ -72,6 +106,10 @@ pub enum Error {
     RuntimeError,
     AccountLiquidityError,
     MarketStatusError,
+    RateModelPreviewError,
+    LiquidationCandidatesError,
+    LiquidateIncentiveError,
+    AccountEarningsError,
 }
 
 impl From<Error> for i32 {
@@ -80,20 +118,26 @@ impl From<Error> for i32 {
             Error::RuntimeError => 1,
             Error::AccountLiquidityError => 2,
             Error::MarketStatusError => 3,
+            Error::RateModelPreviewError => 4,
+            Error::LiquidationCandidatesError => 5,
+            Error::LiquidateIncentiveError => 6,
+            Error::AccountEarningsError => 7,
         }
     }
 }
 
 #[async_trait]
-impl<C, Block, AccountId, Balance> LoansApiServer<<Block as BlockT>::Hash, AccountId, Balance> for Loans<C, Block>
+impl<C, Block, AccountId, Balance, RateModel> LoansApiServer<<Block as BlockT>::Hash, AccountId, Balance, RateModel>
+    for Loans<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
-    C::Api: LoansRuntimeApi<Block, AccountId, Balance>,
+    C::Api: LoansRuntimeApi<Block, AccountId, Balance, RateModel>,
     AccountId: Codec,
-    Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display,
+    Balance: Codec + Copy + TryFrom<NumberOrHex> + Into<NumberOrHex> + std::fmt::Display + std::str::FromStr,
+    RateModel: Codec,
 {
     fn get_account_liquidity(
         &self,
@@ -149,6 +193,80 @@ where
             .map_err(runtime_error_into_rpc_error)?
             .map_err(account_liquidity_error_into_rpc_error)
     }
+
+    fn get_bad_debt(&self, asset_id: CurrencyId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        let bad_debt = api.get_bad_debt(at, asset_id).map_err(runtime_error_into_rpc_error)?;
+        try_into_rpc_balance(bad_debt)
+    }
+
+    fn get_rate_model_preview(
+        &self,
+        asset_id: CurrencyId,
+        rate_model: RateModel,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(Rate, Rate)> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_rate_model_preview(at, asset_id, rate_model)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(rate_model_preview_error_into_rpc_error)
+    }
+
+    fn get_liquidation_candidates(
+        &self,
+        max_health_factor: FixedU128,
+        start_key: Option<(CurrencyId, AccountId)>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<LiquidationCandidates<AccountId, CurrencyId, Balance, FixedU128>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_liquidation_candidates(at, max_health_factor, start_key, limit)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(liquidation_candidates_error_into_rpc_error)
+    }
+
+    fn get_liquidate_incentive(
+        &self,
+        account: AccountId,
+        asset_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Rate> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_liquidate_incentive(at, account, asset_id)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(liquidate_incentive_error_into_rpc_error)
+    }
+
+    fn get_account_earnings(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<EarningsReport<CurrencyId, Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        );
+        api.get_account_earnings(at, account)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(account_earnings_error_into_rpc_error)
+    }
 }
 
 /// Converts a runtime trap into an RPC error.
@@ -178,6 +296,42 @@ fn market_status_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeErr
     )))
 }
 
+/// Converts a rate model preview error into an RPC error.
+fn rate_model_preview_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RateModelPreviewError.into(),
+        "Not able to preview rate model",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts a liquidation candidates error into an RPC error.
+fn liquidation_candidates_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::LiquidationCandidatesError.into(),
+        "Not able to get liquidation candidates",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts a liquidation incentive error into an RPC error.
+fn liquidate_incentive_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::LiquidateIncentiveError.into(),
+        "Not able to get liquidation incentive",
+        Some(format!("{:?}", err)),
+    )))
+}
+
+/// Converts an account earnings error into an RPC error.
+fn account_earnings_error_into_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::AccountEarningsError.into(),
+        "Not able to get account earnings",
+        Some(format!("{:?}", err)),
+    )))
+}
+
 fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(value: T) -> RpcResult<NumberOrHex> {
     value.try_into().map_err(|_| {
         JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
@@ -65,6 +65,21 @@ fn pending_market_mock<T: Config>(lend_token_id: CurrencyId) -> Market<BalanceOf
     market
 }
 
+fn emode_category_mock() -> EModeCategory {
+    EModeCategory {
+        collateral_factor: Ratio::from_percent(90),
+        liquidation_threshold: Ratio::from_percent(95),
+    }
+}
+
+fn liquidation_auction_config_mock<T: Config>() -> LiquidationAuctionConfig<BlockNumberFor<T>> {
+    LiquidationAuctionConfig {
+        starting_incentive: Rate::from_inner(Rate::DIV / 100 * 102),
+        max_incentive: Rate::from_inner(Rate::DIV / 100 * 115),
+        duration: 100u32.into(),
+    }
+}
+
 fn transfer_initial_balance<T: Config + orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>>(
     caller: T::AccountId,
 ) {
@@ -143,7 +158,8 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 }
 
 #[benchmarks(where
-            T: orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>, T: security::Config, T: oracle::Config)
+            T: orml_tokens::Config<CurrencyId = CurrencyId, Balance = Balance>, T: security::Config, T: oracle::Config,
+            <T as Config>::RuntimeCall: From<frame_system::Call<T>>)
             ]
 pub mod benchmarks {
     use frame_benchmarking::v2::extrinsic_call;
@@ -193,6 +209,22 @@ pub mod benchmarks {
         );
     }
 
+    #[benchmark]
+    pub fn schedule_rate_model_transition() {
+        Loans::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LEND_KSM)).unwrap();
+        let duration: u32 = 100;
+        #[extrinsic_call]
+        Loans::schedule_rate_model_transition(SystemOrigin::Root, KSM, RATE_MODEL_MOCK, duration.into());
+        assert_last_event::<T>(
+            Event::<T>::RateModelTransitionScheduled {
+                underlying_currency_id: KSM,
+                rate_model: RATE_MODEL_MOCK,
+                end_block: frame_system::Pallet::<T>::block_number() + duration.into(),
+            }
+            .into(),
+        );
+    }
+
     #[benchmark]
     pub fn update_market() {
         Loans::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LEND_KSM)).unwrap();
@@ -803,6 +835,512 @@ pub mod benchmarks {
         );
     }
 
+    #[benchmark]
+    pub fn set_flash_loan_fee() {
+        #[extrinsic_call]
+        Loans::set_flash_loan_fee(SystemOrigin::Root, Ratio::from_percent(1));
+        assert_last_event::<T>(
+            Event::<T>::FlashLoanFeeUpdated {
+                fee: Ratio::from_percent(1),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn flash_loan() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        let deposit_amount: u32 = 200_000_000;
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::set_flash_loan_fee(
+            SystemOrigin::Root.into(),
+            Ratio::from_percent(1)
+        ));
+        let amount: u32 = 100_000;
+        // the weight of the nested call is added separately, so benchmark with the cheapest call possible
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        #[extrinsic_call]
+        Loans::flash_loan(
+            SystemOrigin::Signed(caller.clone()),
+            KBTC,
+            amount.into(),
+            Box::new(call),
+        );
+        assert_last_event::<T>(
+            Event::<T>::FlashLoan {
+                account_id: caller,
+                currency_id: KBTC,
+                amount: amount.into(),
+                fee: 1_000u32.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_emode_category() {
+        let category = emode_category_mock();
+        #[extrinsic_call]
+        Loans::set_emode_category(SystemOrigin::Root, 1, Some(category));
+        assert_last_event::<T>(
+            Event::<T>::EModeCategoryUpdated {
+                category_id: 1,
+                category: Some(category),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_asset_emode_category() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::set_emode_category(
+            SystemOrigin::Root.into(),
+            1,
+            Some(emode_category_mock())
+        ));
+        #[extrinsic_call]
+        Loans::set_asset_emode_category(SystemOrigin::Root, KBTC, Some(1));
+        assert_last_event::<T>(
+            Event::<T>::AssetEModeCategoryUpdated {
+                underlying_currency_id: KBTC,
+                category_id: Some(1),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_account_emode() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        transfer_initial_balance::<T>(caller.clone());
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::set_emode_category(
+            SystemOrigin::Root.into(),
+            1,
+            Some(emode_category_mock())
+        ));
+        assert_ok!(Loans::<T>::set_asset_emode_category(
+            SystemOrigin::Root.into(),
+            KBTC,
+            Some(1)
+        ));
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::borrow(
+            SystemOrigin::Signed(caller.clone()).into(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        #[extrinsic_call]
+        Loans::set_account_emode(SystemOrigin::Signed(caller.clone()), Some(1));
+        assert_last_event::<T>(
+            Event::<T>::AccountEModeSet {
+                account_id: caller,
+                category_id: Some(1),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn write_off_bad_debt() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let borrower: T::AccountId = account("borrower", 0, SEED);
+        transfer_initial_balance::<T>(caller.clone());
+        transfer_initial_balance::<T>(borrower.clone());
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        let reserve_amount: u32 = 50_000_000;
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::borrow(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        assert_ok!(Loans::<T>::add_reserves(
+            SystemOrigin::Root.into(),
+            T::Lookup::unlookup(caller.clone()),
+            KBTC,
+            reserve_amount.into()
+        ));
+        // Simulate the borrower losing all of their collateral
+        AccountDeposits::<T>::remove(LEND_KBTC, &borrower);
+        #[extrinsic_call]
+        Loans::write_off_bad_debt(SystemOrigin::Signed(caller), borrower.clone());
+        assert_last_event::<T>(
+            Event::<T>::BadDebtWrittenOff {
+                borrower,
+                currency_id: KBTC,
+                covered_by_reserves: reserve_amount.into(),
+                socialized: 0u32.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn set_liquidation_auction_config() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        let config = liquidation_auction_config_mock::<T>();
+        #[extrinsic_call]
+        Loans::set_liquidation_auction_config(SystemOrigin::Root, KBTC, Some(config));
+        assert_last_event::<T>(
+            Event::<T>::LiquidationAuctionConfigUpdated {
+                underlying_currency_id: KBTC,
+                config: Some(config),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn start_liquidation_auction() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let borrower: T::AccountId = account("borrower", 0, SEED);
+        transfer_initial_balance::<T>(borrower.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 200_000_000;
+        assert_ok!(Oracle::<T>::_set_exchange_rate(DOT, UnsignedFixedPoint::one()));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            DOT,
+            pending_market_mock::<T>(LEND_DOT)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), DOT));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::set_liquidation_auction_config(
+            SystemOrigin::Root.into(),
+            KBTC,
+            Some(liquidation_auction_config_mock::<T>())
+        ));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            DOT,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            DOT
+        ));
+        set_account_borrows::<T>(borrower.clone(), KBTC, borrowed_amount.into());
+        #[extrinsic_call]
+        Loans::start_liquidation_auction(SystemOrigin::Signed(caller), borrower.clone(), KBTC);
+        assert_last_event::<T>(
+            Event::<T>::LiquidationAuctionStarted {
+                borrower,
+                underlying_currency_id: KBTC,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn close_liquidation_auction() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let borrower: T::AccountId = account("borrower", 0, SEED);
+        transfer_initial_balance::<T>(borrower.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        assert_ok!(Oracle::<T>::_set_exchange_rate(DOT, UnsignedFixedPoint::one()));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            DOT,
+            pending_market_mock::<T>(LEND_DOT)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), DOT));
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            DOT,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(borrower.clone()).into(),
+            DOT
+        ));
+        set_account_borrows::<T>(borrower.clone(), KBTC, borrowed_amount.into());
+        // Simulate an auction left behind after the borrower repaid part of their debt
+        LiquidationAuctions::<T>::insert(KBTC, &borrower, frame_system::Pallet::<T>::block_number());
+        #[extrinsic_call]
+        Loans::close_liquidation_auction(SystemOrigin::Signed(caller), borrower.clone(), KBTC);
+        assert_last_event::<T>(
+            Event::<T>::LiquidationAuctionEnded {
+                borrower,
+                underlying_currency_id: KBTC,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn approve_borrow_delegation() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        let allowance: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        #[extrinsic_call]
+        Loans::approve_borrow_delegation(
+            SystemOrigin::Signed(caller.clone()),
+            delegate.clone(),
+            KBTC,
+            allowance.into(),
+        );
+        assert_last_event::<T>(
+            Event::<T>::BorrowAllowanceUpdated {
+                delegator: caller,
+                delegate,
+                currency_id: KBTC,
+                allowance: allowance.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn revoke_borrow_delegation() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        BorrowAllowances::<T>::insert((KBTC, &caller), &delegate, BalanceOf::<T>::from(100_000_000u32));
+        #[extrinsic_call]
+        Loans::revoke_borrow_delegation(SystemOrigin::Signed(caller.clone()), delegate.clone(), KBTC);
+        assert_last_event::<T>(
+            Event::<T>::BorrowAllowanceUpdated {
+                delegator: caller,
+                delegate,
+                currency_id: KBTC,
+                allowance: 0u32.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn delegated_borrow() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let delegator: T::AccountId = account("delegator", 0, SEED);
+        transfer_initial_balance::<T>(caller.clone());
+        transfer_initial_balance::<T>(delegator.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(delegator.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(delegator.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::approve_borrow_delegation(
+            SystemOrigin::Signed(delegator.clone()).into(),
+            caller.clone(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        #[extrinsic_call]
+        Loans::delegated_borrow(
+            SystemOrigin::Signed(caller.clone()),
+            delegator.clone(),
+            KBTC,
+            borrowed_amount.into(),
+        );
+        assert_last_event::<T>(
+            Event::<T>::DelegatedBorrowed {
+                delegator,
+                delegate: caller,
+                currency_id: KBTC,
+                amount: borrowed_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn repay_delegated_borrow() {
+        initialize::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let delegator: T::AccountId = account("delegator", 0, SEED);
+        transfer_initial_balance::<T>(caller.clone());
+        transfer_initial_balance::<T>(delegator.clone());
+        let deposit_amount: u32 = 200_000_000;
+        let borrowed_amount: u32 = 100_000_000;
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KBTC,
+            pending_market_mock::<T>(LEND_KBTC)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KBTC));
+        assert_ok!(Loans::<T>::mint(
+            SystemOrigin::Signed(delegator.clone()).into(),
+            KBTC,
+            deposit_amount.into()
+        ));
+        assert_ok!(Loans::<T>::deposit_all_collateral(
+            SystemOrigin::Signed(delegator.clone()).into(),
+            KBTC
+        ));
+        assert_ok!(Loans::<T>::approve_borrow_delegation(
+            SystemOrigin::Signed(delegator.clone()).into(),
+            caller.clone(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        assert_ok!(Loans::<T>::delegated_borrow(
+            SystemOrigin::Signed(caller.clone()).into(),
+            delegator.clone(),
+            KBTC,
+            borrowed_amount.into()
+        ));
+        let repay_amount: u32 = 100;
+        #[extrinsic_call]
+        Loans::repay_delegated_borrow(
+            SystemOrigin::Signed(caller.clone()),
+            delegator.clone(),
+            KBTC,
+            repay_amount.into(),
+        );
+        assert_last_event::<T>(
+            Event::<T>::RepaidDelegatedBorrow {
+                delegator,
+                delegate: caller,
+                currency_id: KBTC,
+                amount: repay_amount.into(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn deprecate_market() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KSM,
+            pending_market_mock::<T>(LEND_KSM)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+        let collateral_factor_ramp: u32 = 100;
+        let min_borrow_rate = Some(Rate::from_inner(Rate::DIV / 100 * 50));
+        #[extrinsic_call]
+        Loans::deprecate_market(SystemOrigin::Root, KSM, collateral_factor_ramp.into(), min_borrow_rate);
+        assert_last_event::<T>(
+            Event::<T>::MarketDeprecated {
+                underlying_currency_id: KSM,
+                deprecation: MarketDeprecation {
+                    start: frame_system::Pallet::<T>::block_number(),
+                    collateral_factor_ramp: collateral_factor_ramp.into(),
+                    min_borrow_rate,
+                },
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn remove_market() {
+        assert_ok!(Loans::<T>::add_market(
+            SystemOrigin::Root.into(),
+            KSM,
+            pending_market_mock::<T>(LEND_KSM)
+        ));
+        assert_ok!(Loans::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+        assert_ok!(Loans::<T>::deprecate_market(
+            SystemOrigin::Root.into(),
+            KSM,
+            0u32.into(),
+            None
+        ));
+        #[extrinsic_call]
+        Loans::remove_market(SystemOrigin::Root, KSM);
+        assert_last_event::<T>(
+            Event::<T>::MarketRemoved {
+                underlying_currency_id: KSM,
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(Loans, crate::mock::new_test_ext_no_markets(), crate::mock::Test);
 }
 
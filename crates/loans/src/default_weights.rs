@@ -58,6 +58,22 @@ pub trait WeightInfo {
 	fn reduce_incentive_reserves() -> Weight;
 	fn add_reserves() -> Weight;
 	fn reduce_reserves() -> Weight;
+	fn set_flash_loan_fee() -> Weight;
+	fn flash_loan() -> Weight;
+	fn set_emode_category() -> Weight;
+	fn set_asset_emode_category() -> Weight;
+	fn set_account_emode() -> Weight;
+	fn write_off_bad_debt() -> Weight;
+	fn schedule_rate_model_transition() -> Weight;
+	fn set_liquidation_auction_config() -> Weight;
+	fn start_liquidation_auction() -> Weight;
+	fn close_liquidation_auction() -> Weight;
+	fn approve_borrow_delegation() -> Weight;
+	fn revoke_borrow_delegation() -> Weight;
+	fn delegated_borrow() -> Weight;
+	fn repay_delegated_borrow() -> Weight;
+	fn deprecate_market() -> Weight;
+	fn remove_market() -> Weight;
 }
 
 /// Weights for loans using the Substrate node and recommended hardware.
@@ -652,6 +668,359 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans FlashLoanFee (r:0 w:1)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_flash_loan_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_402_000 picoseconds.
+		Weight::from_parts(12_871_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanInProgress (r:1 w:1)
+	/// Proof Skipped: Loans FlashLoanInProgress (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanFee (r:1 w:0)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn flash_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2511`
+		//  Estimated: `25841`
+		// Minimum execution time: 109_377_000 picoseconds.
+		Weight::from_parts(111_240_000, 25841)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans EModeCategories (r:0 w:1)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	fn set_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_115_000 picoseconds.
+		Weight::from_parts(12_630_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans EModeCategories (r:1 w:0)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AssetEModeCategory (r:0 w:1)
+	/// Proof Skipped: Loans AssetEModeCategory (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `6533`
+		// Minimum execution time: 20_348_000 picoseconds.
+		Weight::from_parts(21_002_000, 6533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EModeCategories (r:2 w:0)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:6 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AssetEModeCategory (r:2 w:0)
+	/// Proof Skipped: Loans AssetEModeCategory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:1)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_account_emode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3842`
+		//  Estimated: `91542`
+		// Minimum execution time: 142_917_000 picoseconds.
+		Weight::from_parts(145_628_000, 91542)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:6 w:6)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:6 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BadDebt (r:1 w:1)
+	/// Proof Skipped: Loans BadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4913`
+		//  Estimated: `120466`
+		// Minimum execution time: 248_517_000 picoseconds.
+		Weight::from_parts(253_146_000, 120466)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: Loans RateModelTransitions (r:1 w:1)
+	/// Proof Skipped: Loans RateModelTransitions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	fn schedule_rate_model_transition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `642`
+		//  Estimated: `11906`
+		// Minimum execution time: 27_823_000 picoseconds.
+		Weight::from_parts(28_690_000, 11906)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_liquidation_auction_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `4011`
+		// Minimum execution time: 17_934_000 picoseconds.
+		Weight::from_parts(18_521_000, 4011)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:2 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn start_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3961`
+		//  Estimated: `86335`
+		// Minimum execution time: 139_207_000 picoseconds.
+		Weight::from_parts(142_380_000, 86335)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:2 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn close_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3874`
+		//  Estimated: `76813`
+		// Minimum execution time: 118_602_000 picoseconds.
+		Weight::from_parts(121_449_000, 76813)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	fn approve_borrow_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `7304`
+		// Minimum execution time: 21_387_000 picoseconds.
+		Weight::from_parts(22_145_000, 7304)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	fn revoke_borrow_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_502_000 picoseconds.
+		Weight::from_parts(14_011_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowAllowances (r:1 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:6 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans DelegatedBorrows (r:7 w:1)
+	/// Proof Skipped: Loans DelegatedBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegated_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4637`
+		//  Estimated: `142811`
+		// Minimum execution time: 229_914_000 picoseconds.
+		Weight::from_parts(234_502_000, 142811)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans DelegatedBorrows (r:1 w:1)
+	/// Proof Skipped: Loans DelegatedBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	fn repay_delegated_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2271`
+		//  Estimated: `39207`
+		// Minimum execution time: 93_418_000 picoseconds.
+		Weight::from_parts(95_760_000, 39207)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:1)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketDeprecations (r:0 w:1)
+	/// Proof Skipped: Loans MarketDeprecations (max_values: None, max_size: None, mode: Measured)
+	fn deprecate_market() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `675`
+		//  Estimated: `12081`
+		// Minimum execution time: 31_604_000 picoseconds.
+		Weight::from_parts(32_518_000, 12081)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:1)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketDeprecations (r:0 w:1)
+	/// Proof Skipped: Loans MarketDeprecations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:0 w:1)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowRate (r:0 w:1)
+	/// Proof Skipped: Loans BorrowRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans SupplyRate (r:0 w:1)
+	/// Proof Skipped: Loans SupplyRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UtilizationRatio (r:0 w:1)
+	/// Proof Skipped: Loans UtilizationRatio (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:0 w:1)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:0 w:1)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RateModelTransitions (r:0 w:1)
+	/// Proof Skipped: Loans RateModelTransitions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AssetEModeCategory (r:0 w:1)
+	/// Proof Skipped: Loans AssetEModeCategory (max_values: None, max_size: None, mode: Measured)
+	fn remove_market() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `17430`
+		// Minimum execution time: 55_216_000 picoseconds.
+		Weight::from_parts(56_873_000, 17430)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1245,4 +1614,357 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans FlashLoanFee (r:0 w:1)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_flash_loan_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_402_000 picoseconds.
+		Weight::from_parts(12_871_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanInProgress (r:1 w:1)
+	/// Proof Skipped: Loans FlashLoanInProgress (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoanFee (r:1 w:0)
+	/// Proof Skipped: Loans FlashLoanFee (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn flash_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2511`
+		//  Estimated: `25841`
+		// Minimum execution time: 109_377_000 picoseconds.
+		Weight::from_parts(111_240_000, 25841)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans EModeCategories (r:0 w:1)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	fn set_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_115_000 picoseconds.
+		Weight::from_parts(12_630_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans EModeCategories (r:1 w:0)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AssetEModeCategory (r:0 w:1)
+	/// Proof Skipped: Loans AssetEModeCategory (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `6533`
+		// Minimum execution time: 20_348_000 picoseconds.
+		Weight::from_parts(21_002_000, 6533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EModeCategories (r:2 w:0)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:6 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AssetEModeCategory (r:2 w:0)
+	/// Proof Skipped: Loans AssetEModeCategory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:1)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_account_emode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3842`
+		//  Estimated: `91542`
+		// Minimum execution time: 142_917_000 picoseconds.
+		Weight::from_parts(145_628_000, 91542)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:6 w:6)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:6 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof Skipped: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BadDebt (r:1 w:1)
+	/// Proof Skipped: Loans BadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4913`
+		//  Estimated: `120466`
+		// Minimum execution time: 248_517_000 picoseconds.
+		Weight::from_parts(253_146_000, 120466)
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: Loans RateModelTransitions (r:1 w:1)
+	/// Proof Skipped: Loans RateModelTransitions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	fn schedule_rate_model_transition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `642`
+		//  Estimated: `11906`
+		// Minimum execution time: 27_823_000 picoseconds.
+		Weight::from_parts(28_690_000, 11906)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_liquidation_auction_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `4011`
+		// Minimum execution time: 17_934_000 picoseconds.
+		Weight::from_parts(18_521_000, 4011)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:2 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn start_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3961`
+		//  Estimated: `86335`
+		// Minimum execution time: 139_207_000 picoseconds.
+		Weight::from_parts(142_380_000, 86335)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:2 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn close_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3874`
+		//  Estimated: `76813`
+		// Minimum execution time: 118_602_000 picoseconds.
+		Weight::from_parts(121_449_000, 76813)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	fn approve_borrow_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `7304`
+		// Minimum execution time: 21_387_000 picoseconds.
+		Weight::from_parts(22_145_000, 7304)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	fn revoke_borrow_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_502_000 picoseconds.
+		Weight::from_parts(14_011_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowAllowances (r:1 w:1)
+	/// Proof Skipped: Loans BorrowAllowances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:6 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans DelegatedBorrows (r:7 w:1)
+	/// Proof Skipped: Loans DelegatedBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegated_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4637`
+		//  Estimated: `142811`
+		// Minimum execution time: 229_914_000 picoseconds.
+		Weight::from_parts(234_502_000, 142811)
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans DelegatedBorrows (r:1 w:1)
+	/// Proof Skipped: Loans DelegatedBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	fn repay_delegated_borrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2271`
+		//  Estimated: `39207`
+		// Minimum execution time: 93_418_000 picoseconds.
+		Weight::from_parts(95_760_000, 39207)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:1)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketDeprecations (r:0 w:1)
+	/// Proof Skipped: Loans MarketDeprecations (max_values: None, max_size: None, mode: Measured)
+	fn deprecate_market() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `675`
+		//  Estimated: `12081`
+		// Minimum execution time: 31_604_000 picoseconds.
+		Weight::from_parts(32_518_000, 12081)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:1)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans MarketDeprecations (r:0 w:1)
+	/// Proof Skipped: Loans MarketDeprecations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:0 w:1)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowRate (r:0 w:1)
+	/// Proof Skipped: Loans BorrowRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans SupplyRate (r:0 w:1)
+	/// Proof Skipped: Loans SupplyRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans UtilizationRatio (r:0 w:1)
+	/// Proof Skipped: Loans UtilizationRatio (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:0 w:1)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowSpeed (r:0 w:1)
+	/// Proof Skipped: Loans RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RateModelTransitions (r:0 w:1)
+	/// Proof Skipped: Loans RateModelTransitions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AssetEModeCategory (r:0 w:1)
+	/// Proof Skipped: Loans AssetEModeCategory (max_values: None, max_size: None, mode: Measured)
+	fn remove_market() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `17430`
+		// Minimum execution time: 55_216_000 picoseconds.
+		Weight::from_parts(56_873_000, 17430)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
 }
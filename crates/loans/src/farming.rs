@@ -125,6 +125,26 @@ impl<T: Config> Pallet<T> {
                 *total_reward = total_reward
                     .checked_add(reward_delta)
                     .ok_or(ArithmeticError::Overflow)?;
+                // The lend token balance is about to change, so the interest it earned so far is realized
+                AccountEarnings::<T>::try_mutate(asset_id, supplier, |earnings| -> DispatchResult {
+                    let exchange_rate = Self::exchange_rate(asset_id);
+                    let (interest, loss) =
+                        Self::supply_interest_and_loss(&total_balance, earnings.exchange_rate_prior, exchange_rate)?;
+                    earnings.supply_interest = earnings
+                        .supply_interest
+                        .checked_add(interest)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    earnings.supply_loss = earnings
+                        .supply_loss
+                        .checked_add(loss)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    earnings.rewards_accrued = earnings
+                        .rewards_accrued
+                        .checked_add(reward_delta)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    earnings.exchange_rate_prior = exchange_rate;
+                    Ok(())
+                })?;
                 Self::deposit_event(Event::<T>::DistributedSupplierReward {
                     underlying_currency_id: asset_id,
                     supplier: supplier.clone(),
@@ -154,6 +174,15 @@ impl<T: Config> Pallet<T> {
                 *total_reward = total_reward
                     .checked_add(reward_delta)
                     .ok_or(ArithmeticError::Overflow)?;
+                if !reward_delta.is_zero() {
+                    AccountEarnings::<T>::try_mutate(asset_id, borrower, |earnings| -> DispatchResult {
+                        earnings.rewards_accrued = earnings
+                            .rewards_accrued
+                            .checked_add(reward_delta)
+                            .ok_or(ArithmeticError::Overflow)?;
+                        Ok(())
+                    })?;
+                }
                 Self::deposit_event(Event::<T>::DistributedBorrowerReward {
                     underlying_currency_id: asset_id,
                     borrower: borrower.clone(),
@@ -184,6 +213,10 @@ impl<T: Config> Pallet<T> {
             let amount: Amount<T> = Amount::new(total_reward, reward_asset);
             amount.transfer(&pool_account, user)?;
             RewardAccrued::<T>::remove(user);
+            RewardsClaimed::<T>::try_mutate(user, |claimed| -> DispatchResult {
+                *claimed = claimed.checked_add(total_reward).ok_or(ArithmeticError::Overflow)?;
+                Ok(())
+            })?;
         }
         Self::deposit_event(Event::<T>::RewardPaid {
             receiver: user.clone(),
@@ -34,6 +34,7 @@ impl<T: Config> Pallet<T> {
         if now <= last_accrued_interest_time {
             return Ok(());
         }
+        let delta_time = now.saturating_sub(last_accrued_interest_time);
 
         let (borrow_rate, supply_rate, exchange_rate, util, total_borrows_new, total_reserves_new, borrow_index_new) =
             Self::get_market_status(asset_id)?;
@@ -49,6 +50,7 @@ impl<T: Config> Pallet<T> {
         SupplyRate::<T>::insert(asset_id, supply_rate);
         ExchangeRate::<T>::insert(asset_id, exchange_rate);
         Self::on_exchange_rate_change(&asset_id);
+        Self::accrue_rate_model(asset_id, util, delta_time)?;
 
         Self::deposit_event(Event::<T>::InterestAccrued {
             underlying_currency_id: asset_id,
@@ -76,10 +78,7 @@ impl<T: Config> Pallet<T> {
         let mut borrow_index_new = borrow_index;
 
         let util = Self::calc_utilization_ratio(&total_cash, &total_borrows, &total_reserves)?;
-        let borrow_rate = market
-            .rate_model
-            .get_borrow_rate(util)
-            .ok_or(ArithmeticError::Overflow)?;
+        let borrow_rate = Self::current_borrow_rate(asset_id, &market, util)?;
         let supply_rate = InterestRateModel::get_supply_rate(borrow_rate, util, market.reserve_factor);
 
         let now = T::UnixTime::now().as_secs();
@@ -121,6 +120,73 @@ impl<T: Config> Pallet<T> {
         ))
     }
 
+    /// The borrow rate of a market at `utilization`, following an ongoing rate model transition
+    /// and no lower than the minimum borrow rate of a deprecated market
+    pub(crate) fn current_borrow_rate(
+        asset_id: CurrencyId<T>,
+        market: &Market<BalanceOf<T>>,
+        utilization: Ratio,
+    ) -> Result<Rate, DispatchError> {
+        let borrow_rate = match Self::rate_model_transition(asset_id) {
+            Some(transition) => transition.get_borrow_rate(utilization, frame_system::Pallet::<T>::block_number()),
+            None => market.rate_model.get_borrow_rate(utilization),
+        }
+        .ok_or(ArithmeticError::Overflow)?;
+        match Self::market_deprecation(asset_id).and_then(|deprecation| deprecation.min_borrow_rate) {
+            Some(min_borrow_rate) => Ok(borrow_rate.max(min_borrow_rate)),
+            None => Ok(borrow_rate),
+        }
+    }
+
+    /// Completes a rate model transition once it is due. Otherwise adjusts an adaptive rate
+    /// model to the utilization of the past `delta_time` seconds.
+    fn accrue_rate_model(asset_id: CurrencyId<T>, utilization: Ratio, delta_time: Timestamp) -> DispatchResult {
+        if let Some(transition) = Self::rate_model_transition(asset_id) {
+            if transition.is_complete(frame_system::Pallet::<T>::block_number()) {
+                Self::mutate_market(asset_id, |market| {
+                    market.rate_model = transition.to;
+                    Ok(market.clone())
+                })?;
+                RateModelTransitions::<T>::remove(asset_id);
+                Self::deposit_event(Event::<T>::RateModelTransitionCompleted {
+                    underlying_currency_id: asset_id,
+                });
+            }
+            return Ok(());
+        }
+
+        let market = Self::market(asset_id)?;
+        if let InterestRateModel::Adaptive(_) = market.rate_model {
+            let rate_model = market
+                .rate_model
+                .accrue(utilization, delta_time)
+                .ok_or(ArithmeticError::Overflow)?;
+            Self::mutate_market(asset_id, |market| {
+                market.rate_model = rate_model;
+                Ok(market.clone())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// The borrow and supply rates a market would have at its current utilization if its rate
+    /// model was replaced by `rate_model`
+    pub fn preview_rate_model(
+        asset_id: CurrencyId<T>,
+        rate_model: InterestRateModel,
+    ) -> Result<(Rate, Rate), DispatchError> {
+        ensure!(rate_model.check_model(), Error::<T>::InvalidRateModelParam);
+        let market = Self::market(asset_id)?;
+        let util = Self::calc_utilization_ratio(
+            &Self::get_total_cash(asset_id),
+            &Self::total_borrows(asset_id),
+            &Self::total_reserves(asset_id),
+        )?;
+        let borrow_rate = rate_model.get_borrow_rate(util).ok_or(ArithmeticError::Overflow)?;
+        let supply_rate = InterestRateModel::get_supply_rate(borrow_rate, util, market.reserve_factor);
+        Ok((borrow_rate, supply_rate))
+    }
+
     /// Update the exchange rate according to the totalCash, totalBorrows and totalSupply.
     /// This function does not accrue interest before calculating the exchange rate.
     /// exchangeRate = (totalCash + totalBorrows - totalReserves) / totalSupply
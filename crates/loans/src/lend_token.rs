@@ -55,7 +55,7 @@ impl<T: Config> Pallet<T> {
             Err(_) => return DepositConsequence::UnknownAsset,
         };
 
-        if let Err(res) = Self::ensure_active_market(underlying_id).map_err(|_| DepositConsequence::UnknownAsset) {
+        if let Err(res) = Self::ensure_listed_market(underlying_id).map_err(|_| DepositConsequence::UnknownAsset) {
             return res;
         }
 
@@ -87,7 +87,7 @@ impl<T: Config> Pallet<T> {
             Err(_) => return WithdrawConsequence::UnknownAsset,
         };
 
-        if let Err(res) = Self::ensure_active_market(underlying_id).map_err(|_| WithdrawConsequence::UnknownAsset) {
+        if let Err(res) = Self::ensure_listed_market(underlying_id).map_err(|_| WithdrawConsequence::UnknownAsset) {
             return res;
         }
 
@@ -124,7 +124,7 @@ impl<T: Config> Pallet<T> {
         let voucher_balance = Self::account_deposits(lend_token_id, &who).amount();
 
         let underlying_id = Self::underlying_id(lend_token_id)?;
-        let market = Self::ensure_active_market(underlying_id)?;
+        Self::ensure_listed_market(underlying_id)?;
         let collateral_value = Self::collateral_asset_value(who, underlying_id)?;
 
         // liquidity of all assets
@@ -137,7 +137,8 @@ impl<T: Config> Pallet<T> {
 
         // Formula
         // reducible_underlying_amount = liquidity / collateral_factor / price
-        let reducible_supply_amount = liquidity.checked_div(&market.collateral_factor.into())?;
+        let (collateral_factor, _) = Self::collateral_parameters(who, underlying_id)?;
+        let reducible_supply_amount = liquidity.checked_div(&collateral_factor.into())?;
         let reducible_underlying_amount = reducible_supply_amount.convert_to(underlying_id)?.amount();
 
         let exchange_rate = Self::exchange_rate(underlying_id);
@@ -31,9 +31,11 @@ use crate::types::AccountLiquidity;
 
 use currency::{Amount, Rounding};
 use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
     log,
     pallet_prelude::*,
     require_transactional,
+    storage::with_transaction,
     traits::{
         tokens::{fungibles::Inspect, Fortitude, Preservation},
         UnixTime,
@@ -44,15 +46,21 @@ use frame_system::pallet_prelude::*;
 use num_traits::cast::ToPrimitive;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 pub use pallet::*;
-use primitives::{Balance, Rate, Ratio, Timestamp};
+use primitives::{
+    loans::{
+        EarningsReport, LiquidationCandidate, LiquidationCandidates, LiquidationOpportunity, LoanPosition,
+        MarketEarnings,
+    },
+    Balance, Rate, Ratio, Timestamp,
+};
 use sp_runtime::{
     traits::{
-        AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, One, SaturatedConversion, Saturating, StaticLookup,
-        Zero,
+        AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, Dispatchable, One, SaturatedConversion, Saturating,
+        StaticLookup, Zero,
     },
-    ArithmeticError, FixedPointNumber, FixedU128,
+    ArithmeticError, FixedPointNumber, FixedU128, TransactionOutcome,
 };
-use sp_std::{marker, result::Result};
+use sp_std::{boxed::Box, marker, result::Result, vec::Vec};
 
 use traits::{
     ConvertToBigUint, LoansApi as LoansTrait, LoansMarketDataProvider, MarketInfo, MarketStatus, OnExchangeRateChange,
@@ -60,7 +68,10 @@ use traits::{
 
 pub use default_weights::WeightInfo;
 pub use orml_traits::currency::{OnDeposit, OnSlash, OnTransfer};
-pub use types::{BorrowSnapshot, EarnedSnapshot, Market, MarketState, RewardMarketState};
+pub use types::{
+    BorrowSnapshot, EModeCategory, EModeCategoryId, EarnedSnapshot, EarningsSnapshot, LiquidationAuctionConfig, Market,
+    MarketDeprecation, MarketState, RewardMarketState,
+};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -74,6 +85,7 @@ mod farming;
 mod interest;
 #[cfg(test)]
 mod lend_token;
+pub mod migration;
 mod rate_model;
 mod types;
 
@@ -91,6 +103,7 @@ pub const DEFAULT_MIN_EXCHANGE_RATE: u128 = 20_000_000_000_000_000; // 0.02
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type CurrencyId<T> = <T as orml_tokens::Config>::CurrencyId;
 type BalanceOf<T> = <T as currency::Config>::Balance;
+type CallOf<T> = <T as Config>::RuntimeCall;
 
 /// Lending-specific methods on Amount
 #[cfg_attr(test, mockable)]
@@ -215,6 +228,8 @@ impl<T: Config> OnTransfer<T::AccountId, CurrencyId<T>, BalanceOf<T>> for PostTr
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum Versions {
     V0,
+    /// Tracks the interest and rewards of each account in `AccountEarnings`.
+    V1,
 }
 
 #[frame_support::pallet]
@@ -255,6 +270,11 @@ pub mod pallet {
 
         /// Hook for exchangerate changes.
         type OnExchangeRateChange: OnExchangeRateChange<CurrencyId<Self>>;
+
+        /// The aggregated call type, used for the nested call of a flash loan.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo;
     }
 
     #[pallet::error]
@@ -312,6 +332,36 @@ pub mod pallet {
         TokensAlreadyLocked,
         /// Only free lend tokens are redeemable
         LockedTokensCannotBeRedeemed,
+        /// Flash loans cannot be nested
+        FlashLoanInProgress,
+        /// Efficiency mode category does not exist
+        EModeCategoryDoesNotExist,
+        /// The asset does not belong to the account's efficiency mode category
+        AssetNotInEModeCategory,
+        /// Debt can only be written off once the borrower has no collateral left
+        BorrowerHasCollateral,
+        /// The market is already transitioning to a new rate model
+        RateModelTransitionInProgress,
+        /// Liquidation auction parameters are invalid
+        InvalidLiquidationAuctionConfig,
+        /// Liquidations in the market are not auctioned
+        LiquidationAuctionNotEnabled,
+        /// A liquidation auction is already running for the borrower
+        LiquidationAuctionAlreadyStarted,
+        /// No liquidation auction is running for the borrower
+        LiquidationAuctionNotFound,
+        /// The borrower can still be liquidated
+        BorrowerIsLiquidatable,
+        /// The delegate may not borrow this much against the delegator's collateral
+        InsufficientBorrowAllowance,
+        /// Accounts can not delegate credit to themselves
+        CannotDelegateToSelf,
+        /// The market is deprecated, so no new supply or borrows are accepted
+        MarketDeprecated,
+        /// The market has not been deprecated
+        MarketNotDeprecated,
+        /// The market still has outstanding borrows, supply or reserves
+        MarketNotEmpty,
     }
 
     #[pallet::event]
@@ -437,6 +487,89 @@ pub mod pallet {
             supply_rate: Rate,
             exchange_rate: Rate,
         },
+        /// Event emitted when the flash loan fee is updated
+        FlashLoanFeeUpdated { fee: Ratio },
+        /// Event emitted when a flash loan has been repaid
+        FlashLoan {
+            account_id: T::AccountId,
+            currency_id: CurrencyId<T>,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+        /// Efficiency mode category was added, updated or removed
+        EModeCategoryUpdated {
+            category_id: EModeCategoryId,
+            category: Option<EModeCategory>,
+        },
+        /// Market was added to or removed from an efficiency mode category
+        AssetEModeCategoryUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            category_id: Option<EModeCategoryId>,
+        },
+        /// Account entered or left efficiency mode
+        AccountEModeSet {
+            account_id: T::AccountId,
+            category_id: Option<EModeCategoryId>,
+        },
+        /// Debt of a borrower without collateral was written off. `covered_by_reserves` was
+        /// absorbed by the market reserves and `socialized` by the suppliers of the market.
+        BadDebtWrittenOff {
+            borrower: T::AccountId,
+            currency_id: CurrencyId<T>,
+            covered_by_reserves: BalanceOf<T>,
+            socialized: BalanceOf<T>,
+        },
+        /// A gradual transition to a new rate model was scheduled
+        RateModelTransitionScheduled {
+            underlying_currency_id: CurrencyId<T>,
+            rate_model: InterestRateModel,
+            end_block: BlockNumberFor<T>,
+        },
+        /// The market completed the transition to its new rate model
+        RateModelTransitionCompleted { underlying_currency_id: CurrencyId<T> },
+        /// Liquidation auctions of a market were enabled, updated or disabled
+        LiquidationAuctionConfigUpdated {
+            underlying_currency_id: CurrencyId<T>,
+            config: Option<LiquidationAuctionConfig<BlockNumberFor<T>>>,
+        },
+        /// The liquidation incentive for repaying the borrower's debt started rising
+        LiquidationAuctionStarted {
+            borrower: T::AccountId,
+            underlying_currency_id: CurrencyId<T>,
+        },
+        /// The borrower can no longer be liquidated, so the liquidation auction was closed
+        LiquidationAuctionEnded {
+            borrower: T::AccountId,
+            underlying_currency_id: CurrencyId<T>,
+        },
+        /// The amount the delegate may borrow against the delegator's collateral was set or revoked
+        BorrowAllowanceUpdated {
+            delegator: T::AccountId,
+            delegate: T::AccountId,
+            currency_id: CurrencyId<T>,
+            allowance: BalanceOf<T>,
+        },
+        /// Event emitted when cash is borrowed against the collateral of a delegator
+        DelegatedBorrowed {
+            delegator: T::AccountId,
+            delegate: T::AccountId,
+            currency_id: CurrencyId<T>,
+            amount: BalanceOf<T>,
+        },
+        /// Event emitted when a delegate repays a borrow made against the collateral of a delegator
+        RepaidDelegatedBorrow {
+            delegator: T::AccountId,
+            delegate: T::AccountId,
+            currency_id: CurrencyId<T>,
+            amount: BalanceOf<T>,
+        },
+        /// The market started winding down
+        MarketDeprecated {
+            underlying_currency_id: CurrencyId<T>,
+            deprecation: MarketDeprecation<BlockNumberFor<T>>,
+        },
+        /// A deprecated market without any positions left was removed
+        MarketRemoved { underlying_currency_id: CurrencyId<T> },
     }
 
     /// The timestamp of the last calculation of accrued interest
@@ -566,6 +699,119 @@ pub mod pallet {
     #[pallet::getter(fn min_exchange_rate)]
     pub type MinExchangeRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
 
+    /// Fraction of a flash loan that has to be paid on top of the borrowed amount. The fee is
+    /// added to the reserves of the market.
+    #[pallet::storage]
+    #[pallet::getter(fn flash_loan_fee)]
+    pub type FlashLoanFee<T: Config> = StorageValue<_, Ratio, ValueQuery>;
+
+    /// The currency and amount of the flash loan in progress. The amount still counts as cash
+    /// of the market, so that the loan doesn't move its exchange rate and utilization. Also
+    /// prevents nesting.
+    #[pallet::storage]
+    pub(crate) type FlashLoanInProgress<T: Config> = StorageValue<_, (CurrencyId<T>, BalanceOf<T>), OptionQuery>;
+
+    /// Collateral parameters of each efficiency mode category
+    #[pallet::storage]
+    pub type EModeCategories<T: Config> = StorageMap<_, Blake2_128Concat, EModeCategoryId, EModeCategory>;
+
+    /// Mapping of underlying currency id to the efficiency mode category it belongs to
+    #[pallet::storage]
+    pub type AssetEModeCategory<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, EModeCategoryId>;
+
+    /// The efficiency mode category each account has opted into
+    #[pallet::storage]
+    #[pallet::getter(fn account_emode)]
+    pub type AccountEMode<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, EModeCategoryId>;
+
+    /// Total amount of bad debt written off in each market
+    /// CurrencyId -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn bad_debt)]
+    pub type BadDebt<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId<T>, BalanceOf<T>, ValueQuery>;
+
+    /// Ongoing rate model transitions
+    /// CurrencyId -> RateModelTransition
+    #[pallet::storage]
+    #[pallet::getter(fn rate_model_transition)]
+    pub type RateModelTransitions<T: Config> =
+        StorageMap<_, Blake2_128Concat, CurrencyId<T>, RateModelTransition<BlockNumberFor<T>>>;
+
+    /// Dutch auction parameters of the markets whose liquidations are auctioned
+    /// CurrencyId -> LiquidationAuctionConfig
+    #[pallet::storage]
+    #[pallet::getter(fn liquidation_auction_config)]
+    pub type LiquidationAuctionConfigs<T: Config> =
+        StorageMap<_, Blake2_128Concat, CurrencyId<T>, LiquidationAuctionConfig<BlockNumberFor<T>>>;
+
+    /// The block at which the liquidation auction of each liquidatable borrower started
+    /// CurrencyId -> Owner -> BlockNumber
+    #[pallet::storage]
+    #[pallet::getter(fn liquidation_auction_start)]
+    pub type LiquidationAuctions<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, CurrencyId<T>, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// The last borrow visited by the `on_idle` sweep that starts and ends liquidation auctions,
+    /// which continues after it in the next block.
+    #[pallet::storage]
+    pub(crate) type LiquidationAuctionCursor<T: Config> = StorageValue<_, (CurrencyId<T>, T::AccountId), OptionQuery>;
+
+    /// The remaining amount each delegate may borrow against the collateral of a delegator
+    /// (CurrencyId, Delegator) -> Delegate -> Balance
+    #[pallet::storage]
+    #[pallet::getter(fn borrow_allowance)]
+    pub type BorrowAllowances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (CurrencyId<T>, T::AccountId),
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// The share of the delegator's borrows that each delegate took on and is expected to repay.
+    /// The debt itself is booked in the delegator's `AccountBorrows`, so it is liquidated,
+    /// written off and repaid like any other borrow of the delegator.
+    /// (CurrencyId, Delegator) -> Delegate -> BorrowSnapshot
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_borrows)]
+    pub type DelegatedBorrows<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (CurrencyId<T>, T::AccountId),
+        Blake2_128Concat,
+        T::AccountId,
+        BorrowSnapshot<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Sunset schedules of the deprecated markets
+    /// CurrencyId -> MarketDeprecation
+    #[pallet::storage]
+    #[pallet::getter(fn market_deprecation)]
+    pub type MarketDeprecations<T: Config> =
+        StorageMap<_, Blake2_128Concat, CurrencyId<T>, MarketDeprecation<BlockNumberFor<T>>>;
+
+    /// Interest and incentive rewards accumulated by each account
+    /// CurrencyId -> Owner -> EarningsSnapshot
+    #[pallet::storage]
+    #[pallet::getter(fn account_earnings)]
+    pub type AccountEarnings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CurrencyId<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        EarningsSnapshot<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Total incentive rewards paid out to each account through `claim_reward`
+    #[pallet::storage]
+    #[pallet::getter(fn rewards_claimed)]
+    pub type RewardsClaimed<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
     /// DefaultVersion is using for initialize the StorageVersion
     #[pallet::type_value]
     pub(super) fn DefaultVersion<T: Config>() -> Versions {
@@ -576,6 +822,11 @@ pub mod pallet {
     #[pallet::storage]
     pub(crate) type StorageVersion<T: Config> = StorageValue<_, Versions, ValueQuery, DefaultVersion<T>>;
 
+    /// The last lend token account visited by the `on_idle` migration to `Versions::V1`, which
+    /// continues after it in the next block.
+    #[pallet::storage]
+    pub(crate) type EarningsMigrationCursor<T: Config> = StorageValue<_, (T::AccountId, CurrencyId<T>), OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -589,6 +840,17 @@ pub mod pallet {
         fn build(&self) {
             MaxExchangeRate::<T>::put(&self.max_exchange_rate);
             MinExchangeRate::<T>::put(&self.min_exchange_rate);
+            StorageVersion::<T>::put(Versions::V1);
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let migration_weight = migration::v1::migrate_step::<T>(remaining_weight);
+            migration_weight.saturating_add(Self::sweep_liquidation_auctions(
+                remaining_weight.saturating_sub(migration_weight),
+            ))
         }
     }
 
@@ -673,7 +935,8 @@ pub mod pallet {
 
         /// Activates a market. Returns `Err` if the market does not exist.
         ///
-        /// If the market is already active, does nothing.
+        /// If the market is already active, does nothing. Activating a deprecated
+        /// market cancels its sunset schedule.
         ///
         /// - `asset_id`: Currency to enable lending and borrowing for.
         #[pallet::call_index(1)]
@@ -689,6 +952,7 @@ pub mod pallet {
                 stored_market.state = MarketState::Active;
                 Ok(stored_market.clone())
             })?;
+            MarketDeprecations::<T>::remove(asset_id);
             Self::deposit_event(Event::<T>::ActivatedMarket {
                 underlying_currency_id: asset_id,
             });
@@ -696,7 +960,8 @@ pub mod pallet {
         }
 
         /// Updates the rate model of a stored market. Returns `Err` if the market
-        /// currency does not exist or the rate model is invalid.
+        /// currency does not exist or the rate model is invalid. Cancels any ongoing
+        /// rate model transition of the market.
         ///
         /// - `asset_id`: Market currency
         /// - `rate_model`: The new rate model to set
@@ -714,6 +979,7 @@ pub mod pallet {
                 stored_market.rate_model = rate_model;
                 Ok(stored_market.clone())
             })?;
+            RateModelTransitions::<T>::remove(asset_id);
             Self::deposit_event(Event::<T>::UpdatedMarket {
                 underlying_currency_id: asset_id,
                 market,
@@ -876,7 +1142,7 @@ pub mod pallet {
             borrow_reward_per_block: Option<BalanceOf<T>>,
         ) -> DispatchResultWithPostInfo {
             T::UpdateOrigin::ensure_origin(origin)?;
-            Self::ensure_active_market(asset_id)?;
+            Self::ensure_listed_market(asset_id)?;
 
             let current_supply_speed = RewardSupplySpeed::<T>::get(asset_id);
             let current_borrow_speed = RewardBorrowSpeed::<T>::get(asset_id);
@@ -1054,7 +1320,7 @@ pub mod pallet {
         #[transactional]
         pub fn repay_borrow_all(origin: OriginFor<T>, asset_id: CurrencyId<T>) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::ensure_active_market(asset_id)?;
+            Self::ensure_listed_market(asset_id)?;
             Self::accrue_interest(asset_id)?;
             let account_borrows = Self::current_borrow_balance(&who, asset_id)?;
             ensure!(!account_borrows.is_zero(), Error::<T>::InvalidAmount);
@@ -1163,7 +1429,7 @@ pub mod pallet {
             let amount_to_transfer = Amount::new(add_amount, asset_id);
             T::ReserveOrigin::ensure_origin(origin)?;
             let payer = T::Lookup::lookup(payer)?;
-            Self::ensure_active_market(asset_id)?;
+            Self::ensure_listed_market(asset_id)?;
             Self::accrue_interest(asset_id)?;
 
             ensure!(!amount_to_transfer.is_zero(), Error::<T>::InvalidAmount);
@@ -1200,7 +1466,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             T::ReserveOrigin::ensure_origin(origin)?;
             let receiver = T::Lookup::lookup(receiver)?;
-            Self::ensure_active_market(asset_id)?;
+            Self::ensure_listed_market(asset_id)?;
             Self::accrue_interest(asset_id)?;
 
             let amount_to_transfer = Amount::new(reduce_amount, asset_id);
@@ -1242,7 +1508,7 @@ pub mod pallet {
             ensure!(!redeem_amount.is_zero(), Error::<T>::InvalidAmount);
             let receiver = T::Lookup::lookup(receiver)?;
             let from = Self::incentive_reward_account_id();
-            Self::ensure_active_market(asset_id)?;
+            Self::ensure_listed_market(asset_id)?;
             Self::accrue_interest(asset_id)?;
 
             let underlying = Amount::new(redeem_amount, asset_id);
@@ -1258,127 +1524,797 @@ pub mod pallet {
             });
             Ok(().into())
         }
-    }
-}
-
-impl<T: Config> Pallet<T> {
-    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
-    fn account_deposits(lend_token_id: CurrencyId<T>, supplier: &T::AccountId) -> Amount<T> {
-        Amount::new(AccountDeposits::<T>::get(lend_token_id, supplier), lend_token_id)
-    }
-
-    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
-    fn total_borrows(asset_id: CurrencyId<T>) -> Amount<T> {
-        Amount::new(TotalBorrows::<T>::get(asset_id), asset_id)
-    }
-
-    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
-    fn total_reserves(asset_id: CurrencyId<T>) -> Amount<T> {
-        Amount::new(TotalReserves::<T>::get(asset_id), asset_id)
-    }
-
-    pub fn account_id() -> T::AccountId {
-        T::PalletId::get().into_account_truncating()
-    }
-
-    pub fn get_account_liquidity(account: &T::AccountId) -> Result<AccountLiquidity<T>, DispatchError> {
-        let total_collateral_value = Self::total_collateral_value(account)?;
-        let total_borrow_value = Self::total_borrowed_value(account)?;
-        log::trace!(
-            target: "loans::get_account_liquidity",
-            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
-            account,
-            total_borrow_value.amount(),
-            total_collateral_value.amount(),
-        );
-        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
-    }
-
-    pub fn get_account_liquidation_threshold_liquidity(
-        account: &T::AccountId,
-    ) -> Result<AccountLiquidity<T>, DispatchError> {
-        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
-        let total_borrow_value = Self::total_borrowed_value(account)?;
-        log::trace!(
-            target: "loans::get_account_liquidation_threshold_liquidity",
-            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
-            account,
-            total_borrow_value.amount(),
-            total_collateral_value.amount(),
-        );
-        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
-    }
 
-    fn total_borrowed_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
-        let mut total_borrow_value = Amount::<T>::zero(T::ReferenceAssetId::get());
-        for (asset_id, _) in Self::active_markets() {
-            let borrow = Self::current_borrow_balance(borrower, asset_id)?;
-            if borrow.is_zero() {
-                continue;
-            }
-            let value = Self::get_asset_value(&borrow)?;
-            total_borrow_value.checked_accrue(&value)?;
+        /// Updates the fee charged on flash loans.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `fee`: fraction of the borrowed amount to be paid into the reserves.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_flash_loan_fee())]
+        #[transactional]
+        pub fn set_flash_loan_fee(origin: OriginFor<T>, fee: Ratio) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(fee < Ratio::one(), Error::<T>::InvalidFactor);
+            FlashLoanFee::<T>::put(fee);
+            Self::deposit_event(Event::<T>::FlashLoanFeeUpdated { fee });
+            Ok(().into())
         }
 
-        Ok(total_borrow_value)
-    }
-
-    fn collateral_amount_value(voucher: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        let underlying = voucher.to_underlying()?;
-        let market = Self::market(underlying.currency())?;
-        let effects = underlying.map(|x| market.collateral_factor.mul_ceil(x));
+        /// The caller borrows `amount` of the market's free cash without posting collateral,
+        /// dispatches `call` with their own origin, and has to pay back `amount` plus the flash loan
+        /// fee by the end of the extrinsic. If the repayment fails, the entire extrinsic reverts,
+        /// including the effects of `call`.
+        ///
+        /// - `asset_id`: the asset to be borrowed.
+        /// - `amount`: the amount to be borrowed.
+        /// - `call`: the call to dispatch while the funds are borrowed.
+        #[pallet::call_index(22)]
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (<T as Config>::WeightInfo::flash_loan().saturating_add(dispatch_info.weight), dispatch_info.class)
+        })]
+        #[transactional]
+        pub fn flash_loan(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            call: Box<CallOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin.clone())?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::ensure_active_market(asset_id)?;
+            Self::accrue_interest(asset_id)?;
 
-        Self::get_asset_value(&effects)
-    }
+            let post_info = Self::do_flash_loan(&who, origin, &Amount::new(amount, asset_id), *call)?;
 
-    fn collateral_asset_value(supplier: &T::AccountId, asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
-        let lend_token_id = Self::lend_token_id(asset_id)?;
-        let deposits = Self::account_deposits(lend_token_id, supplier);
-        if deposits.is_zero() {
-            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+            Ok(PostDispatchInfo {
+                actual_weight: post_info
+                    .actual_weight
+                    .map(|weight| <T as Config>::WeightInfo::flash_loan().saturating_add(weight)),
+                pays_fee: post_info.pays_fee,
+            })
         }
-        Self::collateral_amount_value(&deposits)
-    }
 
-    fn liquidation_threshold_asset_value(
-        borrower: &T::AccountId,
-        asset_id: CurrencyId<T>,
-    ) -> Result<Amount<T>, DispatchError> {
-        let lend_token_id = Self::lend_token_id(asset_id)?;
-        if !AccountDeposits::<T>::contains_key(lend_token_id, borrower) {
-            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
-        }
-        let deposits = Self::account_deposits(lend_token_id, borrower);
-        if deposits.is_zero() {
-            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+        /// Adds, updates or removes an efficiency mode category. Accounts in a removed category
+        /// fall back to the collateral parameters of the individual markets.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `category_id`: the category to update.
+        /// - `category`: the new collateral parameters, or `None` to remove the category.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_emode_category())]
+        #[transactional]
+        pub fn set_emode_category(
+            origin: OriginFor<T>,
+            category_id: EModeCategoryId,
+            category: Option<EModeCategory>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if let Some(category) = category {
+                ensure!(category.collateral_factor < Ratio::one(), Error::<T>::InvalidFactor);
+                ensure!(
+                    category.liquidation_threshold < Ratio::one()
+                        && category.liquidation_threshold >= category.collateral_factor,
+                    Error::<T>::InvalidFactor
+                );
+            }
+            EModeCategories::<T>::set(category_id, category);
+            Self::deposit_event(Event::<T>::EModeCategoryUpdated { category_id, category });
+            Ok(().into())
         }
-        let underlying_amount = deposits.to_underlying()?;
-        let market = Self::market(asset_id)?;
-        let effects_amount = underlying_amount.map(|x| market.liquidation_threshold.mul_ceil(x));
 
-        Self::get_asset_value(&effects_amount)
-    }
-
-    fn total_collateral_value(supplier: &T::AccountId) -> Result<Amount<T>, DispatchError> {
-        let mut total_asset_value = Amount::<T>::zero(T::ReferenceAssetId::get());
-        for (asset_id, _market) in Self::active_markets() {
-            total_asset_value = total_asset_value.checked_add(&Self::collateral_asset_value(supplier, asset_id)?)?;
+        /// Adds a market to an efficiency mode category, or removes it from its category.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `category_id`: the category of the market, or `None` to remove it from its category.
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_asset_emode_category())]
+        #[transactional]
+        pub fn set_asset_emode_category(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            category_id: Option<EModeCategoryId>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            if let Some(category_id) = category_id {
+                ensure!(
+                    EModeCategories::<T>::contains_key(category_id),
+                    Error::<T>::EModeCategoryDoesNotExist
+                );
+            }
+            AssetEModeCategory::<T>::set(asset_id, category_id);
+            Self::deposit_event(Event::<T>::AssetEModeCategoryUpdated {
+                underlying_currency_id: asset_id,
+                category_id,
+            });
+            Ok(().into())
         }
 
-        Ok(total_asset_value)
-    }
+        /// The caller opts into an efficiency mode category, or leaves efficiency mode. While in
+        /// a category, the caller can only borrow and deposit collateral in the assets of that category,
+        /// and the collateral parameters of the category apply to all their positions.
+        ///
+        /// Entering a category requires all existing borrows and collateral to belong to it. Leaving
+        /// efficiency mode requires the account to remain sufficiently collateralized under the
+        /// market parameters.
+        ///
+        /// - `category_id`: the category to enter, or `None` to leave efficiency mode.
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_account_emode())]
+        #[transactional]
+        pub fn set_account_emode(
+            origin: OriginFor<T>,
+            category_id: Option<EModeCategoryId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            if let Some(category_id) = category_id {
+                ensure!(
+                    EModeCategories::<T>::contains_key(category_id),
+                    Error::<T>::EModeCategoryDoesNotExist
+                );
+                for (asset_id, market) in Markets::<T>::iter() {
+                    let has_position = !AccountBorrows::<T>::get(asset_id, &who).principal.is_zero()
+                        || !AccountDeposits::<T>::get(market.lend_token_id, &who).is_zero();
+                    if has_position {
+                        Self::ensure_in_emode_category(asset_id, category_id)?;
+                    }
+                }
+            }
+            AccountEMode::<T>::set(&who, category_id);
+            ensure!(
+                Self::get_account_liquidity(&who)?.shortfall().is_zero(),
+                Error::<T>::InsufficientLiquidity
+            );
 
-    fn total_liquidation_threshold_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
-        let mut total_asset_value = Amount::<T>::zero(T::ReferenceAssetId::get());
-        for (asset_id, _market) in Self::active_markets() {
-            total_asset_value =
-                total_asset_value.checked_add(&Self::liquidation_threshold_asset_value(borrower, asset_id)?)?;
+            Self::deposit_event(Event::<T>::AccountEModeSet {
+                account_id: who,
+                category_id,
+            });
+            Ok(().into())
         }
 
-        Ok(total_asset_value)
-    }
+        /// Writes off the outstanding debt of a borrower that has no collateral left. This
+        /// happens automatically when a liquidation seizes the last of the borrower's collateral,
+        /// so this extrinsic only needs to be called if part of the debt could not be written off
+        /// at that point because the exchange rate was too close to `MinExchangeRate`.
+        ///
+        /// - `borrower`: the borrower whose debt to write off.
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as Config>::WeightInfo::write_off_bad_debt())]
+        #[transactional]
+        pub fn write_off_bad_debt(origin: OriginFor<T>, borrower: T::AccountId) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(!Self::has_collateral(&borrower), Error::<T>::BorrowerHasCollateral);
+            Self::do_write_off_bad_debt(&borrower)?;
+            Ok(().into())
+        }
 
-    /// Checks if the redeemer should be allowed to redeem tokens in given market.
+        /// Gradually replaces the rate model of a market. Over the next `duration` blocks, the
+        /// borrow rate is interpolated between the rates of the current and the new model, after
+        /// which the new model becomes the rate model of the market. Adaptive models do not adjust
+        /// while a transition is in progress.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market currency
+        /// - `rate_model`: The new rate model
+        /// - `duration`: The number of blocks over which to interpolate
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_rate_model_transition())]
+        #[transactional]
+        pub fn schedule_rate_model_transition(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            rate_model: InterestRateModel,
+            #[pallet::compact] duration: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(rate_model.check_model(), Error::<T>::InvalidRateModelParam);
+            ensure!(!duration.is_zero(), Error::<T>::InvalidRateModelParam);
+            ensure!(
+                !RateModelTransitions::<T>::contains_key(asset_id),
+                Error::<T>::RateModelTransitionInProgress
+            );
+            let market = Self::market(asset_id)?;
+            // Settle interest at the current rate before the transition starts
+            Self::accrue_interest(asset_id)?;
+
+            let start = frame_system::Pallet::<T>::block_number();
+            let end_block = start.saturating_add(duration);
+            RateModelTransitions::<T>::insert(
+                asset_id,
+                RateModelTransition {
+                    from: market.rate_model,
+                    to: rate_model,
+                    start,
+                    end: end_block,
+                },
+            );
+            Self::deposit_event(Event::<T>::RateModelTransitionScheduled {
+                underlying_currency_id: asset_id,
+                rate_model,
+                end_block,
+            });
+            Ok(().into())
+        }
+
+        /// Enables, updates or disables Dutch auction liquidations in a market. While enabled, the
+        /// incentive for liquidating a borrower's debt in the market rises from the starting to the
+        /// maximum incentive of the auction, instead of being the fixed `liquidate_incentive`. Disabling
+        /// auctions closes all running auctions of the market.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `config`: the auction parameters, or `None` to disable auctions.
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_liquidation_auction_config())]
+        #[transactional]
+        pub fn set_liquidation_auction_config(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            config: Option<LiquidationAuctionConfig<BlockNumberFor<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::market(asset_id)?;
+            match config {
+                Some(config) => {
+                    ensure!(config.is_valid(), Error::<T>::InvalidLiquidationAuctionConfig);
+                }
+                None => {
+                    let _ = LiquidationAuctions::<T>::clear_prefix(asset_id, u32::MAX, None);
+                }
+            }
+            LiquidationAuctionConfigs::<T>::set(asset_id, config);
+            Self::deposit_event(Event::<T>::LiquidationAuctionConfigUpdated {
+                underlying_currency_id: asset_id,
+                config,
+            });
+            Ok(().into())
+        }
+
+        /// Starts the liquidation auction of a borrower that can be liquidated, so that the liquidation
+        /// incentive starts rising. Auctions also start when the borrower's position changes, when
+        /// the `on_idle` sweep finds the shortfall or when the borrower is liquidated.
+        ///
+        /// - `borrower`: the borrower that can be liquidated.
+        /// - `liquidation_asset_id`: the underlying asset borrowed by `borrower`.
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::start_liquidation_auction())]
+        #[transactional]
+        pub fn start_liquidation_auction(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            liquidation_asset_id: CurrencyId<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::ensure_listed_market(liquidation_asset_id)?;
+            ensure!(
+                LiquidationAuctionConfigs::<T>::contains_key(liquidation_asset_id),
+                Error::<T>::LiquidationAuctionNotEnabled
+            );
+            ensure!(
+                !LiquidationAuctions::<T>::contains_key(liquidation_asset_id, &borrower),
+                Error::<T>::LiquidationAuctionAlreadyStarted
+            );
+            Self::accrue_interest(liquidation_asset_id)?;
+            ensure!(
+                !Self::current_borrow_balance(&borrower, liquidation_asset_id)?.is_zero(),
+                Error::<T>::InsufficientShortfall
+            );
+            ensure!(
+                !Self::get_account_liquidation_threshold_liquidity(&borrower)?
+                    .shortfall()
+                    .is_zero(),
+                Error::<T>::InsufficientShortfall
+            );
+            Self::do_start_liquidation_auction(&borrower, liquidation_asset_id);
+            Ok(().into())
+        }
+
+        /// Closes the liquidation auction of a borrower that can no longer be liquidated, for instance
+        /// because the price of their collateral recovered. Auctions also end when the borrower's
+        /// position changes or when the `on_idle` sweep finds that the shortfall was resolved.
+        ///
+        /// - `borrower`: the borrower that is no longer liquidatable.
+        /// - `liquidation_asset_id`: the underlying asset of the auction.
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::close_liquidation_auction())]
+        #[transactional]
+        pub fn close_liquidation_auction(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            liquidation_asset_id: CurrencyId<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(
+                LiquidationAuctions::<T>::contains_key(liquidation_asset_id, &borrower),
+                Error::<T>::LiquidationAuctionNotFound
+            );
+            ensure!(!Self::is_liquidatable(&borrower)?, Error::<T>::BorrowerIsLiquidatable);
+            Self::do_end_liquidation_auction(&borrower, liquidation_asset_id);
+            Ok(().into())
+        }
+
+        /// The caller allows `delegate` to borrow up to `allowance` of `asset_id` against the
+        /// caller's collateral, replacing any previous allowance. The delegate's borrows count
+        /// towards the caller's debt, so they reduce the caller's liquidity and can get the caller
+        /// liquidated. The allowance shrinks as the delegate borrows, and is not restored on repayment.
+        ///
+        /// - `delegate`: the account allowed to borrow.
+        /// - `asset_id`: the asset that may be borrowed.
+        /// - `allowance`: the amount that may be borrowed.
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as Config>::WeightInfo::approve_borrow_delegation())]
+        #[transactional]
+        pub fn approve_borrow_delegation(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] allowance: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who != delegate, Error::<T>::CannotDelegateToSelf);
+            ensure!(!allowance.is_zero(), Error::<T>::InvalidAmount);
+            Self::ensure_active_market(asset_id)?;
+            BorrowAllowances::<T>::insert((asset_id, &who), &delegate, allowance);
+            Self::deposit_event(Event::<T>::BorrowAllowanceUpdated {
+                delegator: who,
+                delegate,
+                currency_id: asset_id,
+                allowance,
+            });
+            Ok(().into())
+        }
+
+        /// Revokes the caller's borrow allowance of `delegate`. Debt the delegate already took on
+        /// keeps counting towards the caller's debt until the delegate repays it.
+        ///
+        /// - `delegate`: the account no longer allowed to borrow.
+        /// - `asset_id`: the asset of the allowance.
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_borrow_delegation())]
+        #[transactional]
+        pub fn revoke_borrow_delegation(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            asset_id: CurrencyId<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            BorrowAllowances::<T>::remove((asset_id, &who), &delegate);
+            Self::deposit_event(Event::<T>::BorrowAllowanceUpdated {
+                delegator: who,
+                delegate,
+                currency_id: asset_id,
+                allowance: Zero::zero(),
+            });
+            Ok(().into())
+        }
+
+        /// The caller borrows `borrow_amount` of `asset_id` against the collateral of `delegator`,
+        /// who must have granted the caller a sufficient allowance. The caller receives the
+        /// borrowed funds and is responsible for repaying them, but the debt is booked against
+        /// `delegator`, who can also repay it and gets liquidated if it becomes undercollateralized.
+        ///
+        /// - `delegator`: the account whose collateral backs the borrow.
+        /// - `asset_id`: the asset to be borrowed.
+        /// - `borrow_amount`: the amount to be borrowed.
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as Config>::WeightInfo::delegated_borrow())]
+        #[transactional]
+        pub fn delegated_borrow(
+            origin: OriginFor<T>,
+            delegator: T::AccountId,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] borrow_amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!borrow_amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_delegated_borrow(&who, &delegator, &Amount::new(borrow_amount, asset_id))?;
+
+            Ok(().into())
+        }
+
+        /// The caller repays some of the debt they borrowed against the collateral of `delegator`.
+        ///
+        /// - `delegator`: the account whose collateral backs the borrow.
+        /// - `asset_id`: the asset to be repaid.
+        /// - `repay_amount`: the amount to be repaid. Amounts above the outstanding debt repay
+        /// the debt in full.
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as Config>::WeightInfo::repay_delegated_borrow())]
+        #[transactional]
+        pub fn repay_delegated_borrow(
+            origin: OriginFor<T>,
+            delegator: T::AccountId,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] repay_amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!repay_amount.is_zero(), Error::<T>::InvalidAmount);
+            Self::do_repay_delegated_borrow(&who, &delegator, &Amount::new(repay_amount, asset_id))?;
+
+            Ok(().into())
+        }
+
+        /// Starts winding down an active market. Supplying and borrowing are disabled from then on,
+        /// while borrows can still be repaid or liquidated and lend tokens redeemed. The collateral
+        /// factor of the market decreases linearly to zero over `collateral_factor_ramp` blocks.
+        /// Activating the market again cancels the deprecation.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        /// - `collateral_factor_ramp`: the number of blocks until the collateral factor reaches zero
+        /// - `min_borrow_rate`: an optional floor of the borrow rate, to encourage repayment
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as Config>::WeightInfo::deprecate_market())]
+        #[transactional]
+        pub fn deprecate_market(
+            origin: OriginFor<T>,
+            asset_id: CurrencyId<T>,
+            #[pallet::compact] collateral_factor_ramp: BlockNumberFor<T>,
+            min_borrow_rate: Option<Rate>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_active_market(asset_id)?;
+            // Settle interest at the current rate before the borrow rate floor applies
+            Self::accrue_interest(asset_id)?;
+
+            Self::mutate_market(asset_id, |stored_market| {
+                stored_market.state = MarketState::Deprecated;
+                Ok(stored_market.clone())
+            })?;
+            let deprecation = MarketDeprecation {
+                start: frame_system::Pallet::<T>::block_number(),
+                collateral_factor_ramp,
+                min_borrow_rate,
+            };
+            MarketDeprecations::<T>::insert(asset_id, deprecation);
+            Self::deposit_event(Event::<T>::MarketDeprecated {
+                underlying_currency_id: asset_id,
+                deprecation,
+            });
+            Ok(().into())
+        }
+
+        /// Removes a deprecated market once all of its borrows have been repaid, all of its lend
+        /// tokens have been redeemed and its reserves have been withdrawn. All state of the market,
+        /// including reward indices and borrow delegations, is cleared. The lend token of the
+        /// market cannot be reused by a new market.
+        ///
+        /// The origin must conform to `UpdateOrigin`.
+        ///
+        /// - `asset_id`: Market related currency
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_market())]
+        #[transactional]
+        pub fn remove_market(origin: OriginFor<T>, asset_id: CurrencyId<T>) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let market = Self::market(asset_id)?;
+            ensure!(market.state == MarketState::Deprecated, Error::<T>::MarketNotDeprecated);
+            Self::accrue_interest(asset_id)?;
+            ensure!(
+                Self::total_borrows(asset_id).is_zero()
+                    && Self::total_supply(asset_id)?.is_zero()
+                    && Self::total_reserves(asset_id).is_zero(),
+                Error::<T>::MarketNotEmpty
+            );
+
+            Markets::<T>::remove(asset_id);
+            MarketDeprecations::<T>::remove(asset_id);
+            LastAccruedInterestTime::<T>::remove(asset_id);
+            TotalBorrows::<T>::remove(asset_id);
+            TotalReserves::<T>::remove(asset_id);
+            BorrowIndex::<T>::remove(asset_id);
+            ExchangeRate::<T>::remove(asset_id);
+            BorrowRate::<T>::remove(asset_id);
+            SupplyRate::<T>::remove(asset_id);
+            UtilizationRatio::<T>::remove(asset_id);
+            RewardSupplySpeed::<T>::remove(asset_id);
+            RewardBorrowSpeed::<T>::remove(asset_id);
+            RateModelTransitions::<T>::remove(asset_id);
+            LiquidationAuctionConfigs::<T>::remove(asset_id);
+            AssetEModeCategory::<T>::remove(asset_id);
+            RewardSupplyState::<T>::remove(asset_id);
+            RewardBorrowState::<T>::remove(asset_id);
+            BadDebt::<T>::remove(asset_id);
+            let _ = RewardSupplierIndex::<T>::clear_prefix(asset_id, u32::MAX, None);
+            let _ = RewardBorrowerIndex::<T>::clear_prefix(asset_id, u32::MAX, None);
+            let _ = LiquidationAuctions::<T>::clear_prefix(asset_id, u32::MAX, None);
+            // delegations are keyed by market and delegator together, so they can't be cleared by prefix
+            let allowances: Vec<_> = BorrowAllowances::<T>::iter_keys()
+                .filter(|((currency_id, _), _)| *currency_id == asset_id)
+                .collect();
+            for (delegator, delegate) in allowances {
+                BorrowAllowances::<T>::remove(delegator, delegate);
+            }
+            let delegated_borrows: Vec<_> = DelegatedBorrows::<T>::iter_keys()
+                .filter(|((currency_id, _), _)| *currency_id == asset_id)
+                .collect();
+            for (delegator, delegate) in delegated_borrows {
+                DelegatedBorrows::<T>::remove(delegator, delegate);
+            }
+
+            Self::deposit_event(Event::<T>::MarketRemoved {
+                underlying_currency_id: asset_id,
+            });
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
+    fn account_deposits(lend_token_id: CurrencyId<T>, supplier: &T::AccountId) -> Amount<T> {
+        Amount::new(AccountDeposits::<T>::get(lend_token_id, supplier), lend_token_id)
+    }
+
+    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
+    fn total_borrows(asset_id: CurrencyId<T>) -> Amount<T> {
+        Amount::new(TotalBorrows::<T>::get(asset_id), asset_id)
+    }
+
+    #[cfg_attr(any(test, feature = "integration-tests"), visibility::make(pub))]
+    fn total_reserves(asset_id: CurrencyId<T>) -> Amount<T> {
+        Amount::new(TotalReserves::<T>::get(asset_id), asset_id)
+    }
+
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    pub fn get_account_liquidity(account: &T::AccountId) -> Result<AccountLiquidity<T>, DispatchError> {
+        let total_collateral_value = Self::total_collateral_value(account)?;
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        log::trace!(
+            target: "loans::get_account_liquidity",
+            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
+            account,
+            total_borrow_value.amount(),
+            total_collateral_value.amount(),
+        );
+        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
+    }
+
+    pub fn get_account_liquidation_threshold_liquidity(
+        account: &T::AccountId,
+    ) -> Result<AccountLiquidity<T>, DispatchError> {
+        let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+        let total_borrow_value = Self::total_borrowed_value(account)?;
+        log::trace!(
+            target: "loans::get_account_liquidation_threshold_liquidity",
+            "account: {:?}, total_borrow_value: {:?}, total_collateral_value: {:?}",
+            account,
+            total_borrow_value.amount(),
+            total_collateral_value.amount(),
+        );
+        AccountLiquidity::from_collateral_and_debt(total_collateral_value, total_borrow_value)
+    }
+
+    /// Lists borrowers whose health factor is below `max_health_factor`, in storage order of
+    /// their borrows. A `max_health_factor` of one lists all accounts that can be liquidated.
+    ///
+    /// - `start_key`: the borrow to continue after, as returned by the previous page.
+    /// - `limit`: the maximum number of borrows to visit, so a page may hold fewer candidates.
+    pub fn get_liquidation_candidates(
+        max_health_factor: FixedU128,
+        start_key: Option<(CurrencyId<T>, T::AccountId)>,
+        limit: u32,
+    ) -> Result<LiquidationCandidates<T::AccountId, CurrencyId<T>, BalanceOf<T>, FixedU128>, DispatchError> {
+        let mut borrows = match start_key {
+            Some((asset_id, borrower)) => {
+                AccountBorrows::<T>::iter_from(AccountBorrows::<T>::hashed_key_for(asset_id, borrower))
+            }
+            None => AccountBorrows::<T>::iter(),
+        };
+
+        let mut candidates = Vec::new();
+        let mut last_key = None;
+        for (asset_id, borrower, snapshot) in borrows.by_ref().take(limit as usize) {
+            last_key = Some((asset_id, borrower.clone()));
+            if snapshot.principal.is_zero() || !Self::is_first_borrow(asset_id, &borrower) {
+                continue;
+            }
+            if let Some(candidate) = Self::liquidation_candidate(borrower, max_health_factor)? {
+                candidates.push(candidate);
+            }
+        }
+        // continue after the last visited borrow, unless all borrows were visited
+        let next_start_key = last_key.filter(|_| borrows.next().is_some());
+
+        Ok(LiquidationCandidates {
+            candidates,
+            next_start_key,
+        })
+    }
+
+    /// Whether the borrow of `borrower` in `asset_id` is the first of their borrows in storage
+    /// order, so that accounts with several borrows are listed once.
+    fn is_first_borrow(asset_id: CurrencyId<T>, borrower: &T::AccountId) -> bool {
+        let key = AccountBorrows::<T>::hashed_key_for(asset_id, borrower);
+        Markets::<T>::iter_keys().all(|other| {
+            other == asset_id
+                || AccountBorrows::<T>::get(other, borrower).principal.is_zero()
+                || AccountBorrows::<T>::hashed_key_for(other, borrower) > key
+        })
+    }
+
+    fn liquidation_candidate(
+        borrower: T::AccountId,
+        max_health_factor: FixedU128,
+    ) -> Result<Option<LiquidationCandidate<T::AccountId, CurrencyId<T>, BalanceOf<T>, FixedU128>>, DispatchError> {
+        let total_borrow_value = Self::total_borrowed_value(&borrower)?;
+        if total_borrow_value.is_zero() {
+            return Ok(None);
+        }
+        let total_collateral_value = Self::total_liquidation_threshold_value(&borrower)?;
+        let health_factor = total_collateral_value.ratio(&total_borrow_value)?;
+        if health_factor >= max_health_factor {
+            return Ok(None);
+        }
+
+        let mut borrows = Vec::new();
+        let mut collateral = Vec::new();
+        for (asset_id, market) in Self::listed_markets() {
+            let borrow = Self::current_borrow_balance(&borrower, asset_id)?;
+            if !borrow.is_zero() {
+                borrows.push(borrow);
+            }
+            let deposits = Self::account_deposits(market.lend_token_id, &borrower);
+            if !deposits.is_zero() {
+                collateral.push(deposits.to_underlying()?);
+            }
+        }
+
+        let mut liquidations = Vec::new();
+        if health_factor < FixedU128::one() {
+            for borrow in borrows.iter() {
+                let market = Self::market(borrow.currency())?;
+                let liquidate_incentive = Self::current_liquidate_incentive(&borrower, borrow.currency())?;
+                let max_repay_value = Self::get_asset_value(borrow)?.map(|x| market.close_factor.mul_floor(x));
+                for deposit in collateral.iter() {
+                    // The seized collateral, including the incentive, can't exceed the deposit
+                    let collateral_value = Self::get_asset_value(deposit)?;
+                    let repay_value = max_repay_value.min(&collateral_value.checked_div(&liquidate_incentive)?)?;
+                    if repay_value.is_zero() {
+                        continue;
+                    }
+                    liquidations.push(LiquidationOpportunity {
+                        liquidation_currency_id: borrow.currency(),
+                        max_repay_amount: repay_value.convert_to(borrow.currency())?.amount(),
+                        collateral_currency_id: deposit.currency(),
+                        seized_collateral_amount: repay_value
+                            .checked_mul(&liquidate_incentive)?
+                            .convert_to(deposit.currency())?
+                            .amount(),
+                    });
+                }
+            }
+        }
+
+        let to_position = |amount: &Amount<T>| LoanPosition {
+            currency_id: amount.currency(),
+            amount: amount.amount(),
+        };
+        Ok(Some(LiquidationCandidate {
+            account_id: borrower,
+            health_factor,
+            borrows: borrows.iter().map(to_position).collect(),
+            collateral: collateral.iter().map(to_position).collect(),
+            liquidations,
+        }))
+    }
+
+    fn total_borrowed_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
+        let mut total_borrow_value = Amount::<T>::zero(T::ReferenceAssetId::get());
+        for (asset_id, _) in Self::listed_markets() {
+            let borrow = Self::current_borrow_balance(borrower, asset_id)?;
+            if borrow.is_zero() {
+                continue;
+            }
+            let value = Self::get_asset_value(&borrow)?;
+            total_borrow_value.checked_accrue(&value)?;
+        }
+
+        Ok(total_borrow_value)
+    }
+
+    /// Returns the collateral factor and liquidation threshold that apply to `account`'s
+    /// positions in the `asset_id` market, taking efficiency mode and the sunset schedule
+    /// of a deprecated market into account.
+    fn collateral_parameters(account: &T::AccountId, asset_id: CurrencyId<T>) -> Result<(Ratio, Ratio), DispatchError> {
+        let emode_category = AccountEMode::<T>::get(account)
+            .filter(|category_id| AssetEModeCategory::<T>::get(asset_id) == Some(*category_id))
+            .and_then(EModeCategories::<T>::get);
+        let (collateral_factor, liquidation_threshold) = match emode_category {
+            Some(category) => (category.collateral_factor, category.liquidation_threshold),
+            None => {
+                let market = Self::market(asset_id)?;
+                (market.collateral_factor, market.liquidation_threshold)
+            }
+        };
+        let collateral_factor = match Self::market_deprecation(asset_id) {
+            Some(deprecation) => {
+                collateral_factor * deprecation.collateral_factor_share(frame_system::Pallet::<T>::block_number())
+            }
+            None => collateral_factor,
+        };
+        Ok((collateral_factor, liquidation_threshold))
+    }
+
+    /// Ensures that `asset_id` belongs to the efficiency mode category `category_id`.
+    fn ensure_in_emode_category(asset_id: CurrencyId<T>, category_id: EModeCategoryId) -> DispatchResult {
+        ensure!(
+            AssetEModeCategory::<T>::get(asset_id) == Some(category_id),
+            Error::<T>::AssetNotInEModeCategory
+        );
+        Ok(())
+    }
+
+    fn collateral_amount_value(supplier: &T::AccountId, voucher: &Amount<T>) -> Result<Amount<T>, DispatchError> {
+        let underlying = voucher.to_underlying()?;
+        let (collateral_factor, _) = Self::collateral_parameters(supplier, underlying.currency())?;
+        let effects = underlying.map(|x| collateral_factor.mul_ceil(x));
+
+        Self::get_asset_value(&effects)
+    }
+
+    fn collateral_asset_value(supplier: &T::AccountId, asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
+        let lend_token_id = Self::lend_token_id(asset_id)?;
+        let deposits = Self::account_deposits(lend_token_id, supplier);
+        if deposits.is_zero() {
+            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+        }
+        Self::collateral_amount_value(supplier, &deposits)
+    }
+
+    fn liquidation_threshold_asset_value(
+        borrower: &T::AccountId,
+        asset_id: CurrencyId<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let lend_token_id = Self::lend_token_id(asset_id)?;
+        if !AccountDeposits::<T>::contains_key(lend_token_id, borrower) {
+            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+        }
+        let deposits = Self::account_deposits(lend_token_id, borrower);
+        if deposits.is_zero() {
+            return Ok(Amount::<T>::zero(T::ReferenceAssetId::get()));
+        }
+        let underlying_amount = deposits.to_underlying()?;
+        let (_, liquidation_threshold) = Self::collateral_parameters(borrower, asset_id)?;
+        let effects_amount = underlying_amount.map(|x| liquidation_threshold.mul_ceil(x));
+
+        Self::get_asset_value(&effects_amount)
+    }
+
+    fn total_collateral_value(supplier: &T::AccountId) -> Result<Amount<T>, DispatchError> {
+        let mut total_asset_value = Amount::<T>::zero(T::ReferenceAssetId::get());
+        for (asset_id, _market) in Self::listed_markets() {
+            total_asset_value = total_asset_value.checked_add(&Self::collateral_asset_value(supplier, asset_id)?)?;
+        }
+
+        Ok(total_asset_value)
+    }
+
+    fn total_liquidation_threshold_value(borrower: &T::AccountId) -> Result<Amount<T>, DispatchError> {
+        let mut total_asset_value = Amount::<T>::zero(T::ReferenceAssetId::get());
+        for (asset_id, _market) in Self::listed_markets() {
+            total_asset_value =
+                total_asset_value.checked_add(&Self::liquidation_threshold_asset_value(borrower, asset_id)?)?;
+        }
+
+        Ok(total_asset_value)
+    }
+
+    /// Checks if the redeemer should be allowed to redeem tokens in given market.
     /// Takes into account both `free` and `locked` (i.e. deposited as collateral) lend_tokens of the redeemer.
     fn redeem_allowed(redeemer: &T::AccountId, voucher: &Amount<T>) -> DispatchResult {
         let asset_id = Self::underlying_id(voucher.currency())?;
@@ -1411,6 +2347,9 @@ impl<T: Config> Pallet<T> {
 
     /// Borrower shouldn't borrow more than their total collateral value allows
     fn borrow_allowed(borrower: &T::AccountId, borrow: &Amount<T>) -> DispatchResult {
+        if let Some(category_id) = Self::account_emode(borrower) {
+            Self::ensure_in_emode_category(borrow.currency(), category_id)?;
+        }
         Self::ensure_under_borrow_cap(borrow)?;
         Self::ensure_enough_cash(borrow)?;
         let borrow_value = Self::get_asset_value(borrow)?;
@@ -1421,6 +2360,7 @@ impl<T: Config> Pallet<T> {
 
     #[require_transactional]
     fn do_repay_borrow_with_amount(
+        payer: &T::AccountId,
         borrower: &T::AccountId,
         asset_id: CurrencyId<T>,
         account_borrows: &Amount<T>,
@@ -1432,13 +2372,14 @@ impl<T: Config> Pallet<T> {
         Self::update_reward_borrow_index(asset_id)?;
         Self::distribute_borrower_reward(asset_id, borrower)?;
 
-        repay_amount.transfer(borrower, &Self::account_id())?;
+        repay_amount.transfer(payer, &Self::account_id())?;
 
         let account_borrows_new = account_borrows.checked_sub(&repay_amount)?;
         let total_borrows = Self::total_borrows(asset_id);
         // Use `saturating_sub` here, because it's intended for `total_borrows` to be rounded down,
         // such that it is less than or equal to the actual borrower debt.
         let total_borrows_new = total_borrows.saturating_sub(&repay_amount)?;
+        Self::record_borrow_interest(borrower, asset_id, Self::account_borrows(asset_id, borrower))?;
         AccountBorrows::<T>::insert(
             asset_id,
             borrower,
@@ -1455,7 +2396,23 @@ impl<T: Config> Pallet<T> {
     // Calculates and returns the most recent amount of borrowed balance of `currency_id`
     // for `who`.
     pub fn current_borrow_balance(who: &T::AccountId, asset_id: CurrencyId<T>) -> Result<Amount<T>, DispatchError> {
-        let snapshot: BorrowSnapshot<BalanceOf<T>> = Self::account_borrows(asset_id, who);
+        Self::borrow_balance_from_snapshot(Self::account_borrows(asset_id, who), asset_id)
+    }
+
+    /// The most recent amount of `asset_id` that `delegate` owes on the borrows made against the
+    /// collateral of `delegator`. This is a share of the delegator's own borrow balance.
+    pub fn current_delegated_borrow_balance(
+        delegator: &T::AccountId,
+        delegate: &T::AccountId,
+        asset_id: CurrencyId<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        Self::borrow_balance_from_snapshot(Self::delegated_borrows((asset_id, delegator), delegate), asset_id)
+    }
+
+    fn borrow_balance_from_snapshot(
+        snapshot: BorrowSnapshot<BalanceOf<T>>,
+        asset_id: CurrencyId<T>,
+    ) -> Result<Amount<T>, DispatchError> {
         if snapshot.principal.is_zero() || snapshot.borrow_index.is_zero() {
             return Ok(Amount::zero(asset_id));
         }
@@ -1469,6 +2426,104 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Adds the interest accrued on `snapshot` since it was taken to the borrow interest of
+    /// `borrower`. Has to be called before every update of the borrow snapshot.
+    pub(crate) fn record_borrow_interest(
+        borrower: &T::AccountId,
+        asset_id: CurrencyId<T>,
+        snapshot: BorrowSnapshot<BalanceOf<T>>,
+    ) -> DispatchResult {
+        let principal = Amount::<T>::new(snapshot.principal, asset_id);
+        let interest = Self::borrow_balance_from_snapshot(snapshot, asset_id)?.saturating_sub(&principal)?;
+        if interest.is_zero() {
+            return Ok(());
+        }
+        AccountEarnings::<T>::try_mutate(asset_id, borrower, |earnings| -> DispatchResult {
+            earnings.borrow_interest = earnings
+                .borrow_interest
+                .checked_add(interest.amount())
+                .ok_or(ArithmeticError::Overflow)?;
+            Ok(())
+        })
+    }
+
+    /// Interest earned and value lost by `lend_tokens` while the exchange rate moved from
+    /// `exchange_rate_prior` to `exchange_rate`. The exchange rate only decreases when bad debt
+    /// is socialized, so recording the loss keeps a later recovery from being counted as
+    /// interest twice.
+    pub(crate) fn supply_interest_and_loss(
+        lend_tokens: &Amount<T>,
+        exchange_rate_prior: Rate,
+        exchange_rate: Rate,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        if exchange_rate_prior.is_zero() {
+            return Ok((Zero::zero(), Zero::zero()));
+        }
+        let value_at = |rate: Rate| {
+            rate.checked_mul_int(lend_tokens.amount())
+                .ok_or(ArithmeticError::Overflow)
+        };
+        let value_prior = value_at(exchange_rate_prior)?;
+        let value = value_at(exchange_rate)?;
+        Ok((value.saturating_sub(value_prior), value_prior.saturating_sub(value)))
+    }
+
+    /// The interest earned and paid by `account` in every market, including the interest accrued
+    /// since the last balance change, up to the current block.
+    pub fn get_account_earnings(
+        account: &T::AccountId,
+    ) -> Result<EarningsReport<CurrencyId<T>, BalanceOf<T>>, DispatchError> {
+        let mut markets = Vec::new();
+        for (asset_id, market) in Self::listed_markets() {
+            let (_, _, exchange_rate, _, _, _, borrow_index) = Self::get_market_status(asset_id)?;
+            let earnings = Self::account_earnings(asset_id, account);
+            let lend_tokens = Self::balance(market.lend_token_id, account);
+            let (pending_supply_interest, pending_supply_loss) =
+                Self::supply_interest_and_loss(&lend_tokens, earnings.exchange_rate_prior, exchange_rate)?;
+            let snapshot = Self::account_borrows(asset_id, account);
+            let principal = Amount::<T>::new(snapshot.principal, asset_id);
+            let pending_borrow_interest = if principal.is_zero() || snapshot.borrow_index.is_zero() {
+                Amount::zero(asset_id)
+            } else {
+                Self::borrow_balance_from_old_and_new_index(
+                    &snapshot.borrow_index,
+                    &borrow_index,
+                    principal.clone(),
+                    Rounding::Up,
+                )?
+                .saturating_sub(&principal)?
+            };
+
+            let market_earnings = MarketEarnings {
+                currency_id: asset_id,
+                supply_interest: earnings
+                    .supply_interest
+                    .checked_add(pending_supply_interest)
+                    .ok_or(ArithmeticError::Overflow)?,
+                supply_loss: earnings
+                    .supply_loss
+                    .checked_add(pending_supply_loss)
+                    .ok_or(ArithmeticError::Overflow)?,
+                borrow_interest: earnings
+                    .borrow_interest
+                    .checked_add(pending_borrow_interest.amount())
+                    .ok_or(ArithmeticError::Overflow)?,
+                rewards_accrued: earnings.rewards_accrued,
+            };
+            if !market_earnings.supply_interest.is_zero()
+                || !market_earnings.supply_loss.is_zero()
+                || !market_earnings.borrow_interest.is_zero()
+                || !market_earnings.rewards_accrued.is_zero()
+            {
+                markets.push(market_earnings);
+            }
+        }
+        Ok(EarningsReport {
+            markets,
+            rewards_claimed: Self::rewards_claimed(account),
+        })
+    }
+
     pub fn borrow_balance_from_old_and_new_index(
         old_index: &FixedU128,
         new_index: &FixedU128,
@@ -1535,16 +2590,24 @@ impl<T: Config> Pallet<T> {
         collateral_asset_id: CurrencyId<T>,
     ) -> DispatchResult {
         let liquidation_asset_id = repayment_underlying.currency();
-        Self::ensure_active_market(liquidation_asset_id)?;
-        Self::ensure_active_market(collateral_asset_id)?;
+        Self::ensure_listed_market(liquidation_asset_id)?;
+        Self::ensure_listed_market(collateral_asset_id)?;
 
-        let market = Self::market(liquidation_asset_id)?;
+        let mut market = Self::market(liquidation_asset_id)?;
 
         if borrower == liquidator {
             return Err(Error::<T>::LiquidatorIsBorrower.into());
         }
         Self::liquidate_borrow_allowed(&borrower, repayment_underlying, &market)?;
 
+        if LiquidationAuctionConfigs::<T>::contains_key(liquidation_asset_id)
+            && !LiquidationAuctions::<T>::contains_key(liquidation_asset_id, &borrower)
+        {
+            Self::do_start_liquidation_auction(&borrower, liquidation_asset_id);
+        }
+        // The seized collateral and the incentive reserves both follow the auction's incentive
+        market.liquidate_incentive = Self::current_liquidate_incentive(&borrower, liquidation_asset_id)?;
+
         let lend_token_id = Self::lend_token_id(collateral_asset_id)?;
         let deposits = Self::account_deposits(lend_token_id, &borrower);
         ensure!(!deposits.is_zero(), Error::<T>::DepositsAreNotCollateral);
@@ -1567,6 +2630,213 @@ impl<T: Config> Pallet<T> {
             &market,
         )?;
 
+        // Whatever debt remains after seizing the last of the collateral can never be recovered
+        if !Self::has_collateral(&borrower) {
+            Self::do_write_off_bad_debt(&borrower)?;
+        }
+
+        Self::update_liquidation_auctions(&borrower)?;
+
+        Ok(())
+    }
+
+    /// The liquidation incentive currently paid for repaying `borrower`'s debt in the market of
+    /// `liquidation_asset_id`. In markets with liquidation auctions this depends on how long the
+    /// auction has been running, or is the starting incentive if no auction has started yet.
+    pub fn current_liquidate_incentive(
+        borrower: &T::AccountId,
+        liquidation_asset_id: CurrencyId<T>,
+    ) -> Result<Rate, DispatchError> {
+        let market = Self::market(liquidation_asset_id)?;
+        let config = match LiquidationAuctionConfigs::<T>::get(liquidation_asset_id) {
+            Some(config) => config,
+            None => return Ok(market.liquidate_incentive),
+        };
+        let elapsed = LiquidationAuctions::<T>::get(liquidation_asset_id, borrower).map_or(Zero::zero(), |start| {
+            frame_system::Pallet::<T>::block_number().saturating_sub(start)
+        });
+        config
+            .incentive_at(elapsed)
+            .ok_or_else(|| ArithmeticError::Overflow.into())
+    }
+
+    fn do_start_liquidation_auction(borrower: &T::AccountId, liquidation_asset_id: CurrencyId<T>) {
+        LiquidationAuctions::<T>::insert(
+            liquidation_asset_id,
+            borrower,
+            frame_system::Pallet::<T>::block_number(),
+        );
+        Self::deposit_event(Event::<T>::LiquidationAuctionStarted {
+            borrower: borrower.clone(),
+            underlying_currency_id: liquidation_asset_id,
+        });
+    }
+
+    fn do_end_liquidation_auction(borrower: &T::AccountId, liquidation_asset_id: CurrencyId<T>) {
+        LiquidationAuctions::<T>::remove(liquidation_asset_id, borrower);
+        Self::deposit_event(Event::<T>::LiquidationAuctionEnded {
+            borrower: borrower.clone(),
+            underlying_currency_id: liquidation_asset_id,
+        });
+    }
+
+    /// Starts an auction for each auctioned debt of `borrower` once they can be liquidated, and
+    /// ends their auctions once they can't, so that every new shortfall is auctioned from the
+    /// starting incentive.
+    fn update_liquidation_auctions(borrower: &T::AccountId) -> DispatchResult {
+        let auctions: Vec<_> = LiquidationAuctionConfigs::<T>::iter_keys()
+            .map(|asset_id| {
+                let borrowed = !Self::account_borrows(asset_id, borrower).principal.is_zero();
+                let running = LiquidationAuctions::<T>::contains_key(asset_id, borrower);
+                (asset_id, borrowed, running)
+            })
+            .filter(|(_, borrowed, running)| *borrowed || *running)
+            .collect();
+        if auctions.is_empty() {
+            return Ok(());
+        }
+
+        let liquidatable = Self::is_liquidatable(borrower)?;
+        for (asset_id, borrowed, running) in auctions {
+            match (liquidatable && borrowed, running) {
+                (true, false) => Self::do_start_liquidation_auction(borrower, asset_id),
+                (false, true) => Self::do_end_liquidation_auction(borrower, asset_id),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the liquidation auctions of as many borrowers as `remaining_weight` allows,
+    /// continuing after the borrow visited last. This picks up shortfalls that start or end
+    /// through price changes, without the borrower's position changing.
+    fn sweep_liquidation_auctions(remaining_weight: Weight) -> Weight {
+        let borrow_weight = <T as Config>::WeightInfo::start_liquidation_auction();
+        let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+        if used_weight.saturating_add(borrow_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+        if LiquidationAuctionConfigs::<T>::iter_keys().next().is_none() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut borrows = match LiquidationAuctionCursor::<T>::get() {
+            Some((asset_id, borrower)) => {
+                AccountBorrows::<T>::iter_from(AccountBorrows::<T>::hashed_key_for(asset_id, borrower))
+            }
+            None => AccountBorrows::<T>::iter(),
+        };
+        let mut batch = Vec::new();
+        let mut exhausted = false;
+        while used_weight.saturating_add(borrow_weight).all_lte(remaining_weight) {
+            match borrows.next() {
+                Some((asset_id, borrower, _)) => {
+                    used_weight = used_weight.saturating_add(borrow_weight);
+                    batch.push((asset_id, borrower));
+                }
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+
+        // start over once all borrows were visited
+        let cursor = if exhausted { None } else { batch.last().cloned() };
+        LiquidationAuctionCursor::<T>::set(cursor);
+
+        for (asset_id, borrower) in batch {
+            if !LiquidationAuctionConfigs::<T>::contains_key(asset_id) {
+                continue;
+            }
+            // a failed update, e.g. on a missing price, is rolled back and retried in a later sweep
+            let _ = with_transaction(|| match Self::update_liquidation_auctions(&borrower) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(err) => TransactionOutcome::Rollback(Err(err)),
+            });
+        }
+
+        used_weight
+    }
+
+    /// Whether `borrower` has a shortfall and collateral left to seize
+    fn is_liquidatable(borrower: &T::AccountId) -> Result<bool, DispatchError> {
+        Ok(Self::has_collateral(borrower)
+            && !Self::get_account_liquidation_threshold_liquidity(borrower)?
+                .shortfall()
+                .is_zero())
+    }
+
+    /// Whether `account` has collateral deposited in any market
+    fn has_collateral(account: &T::AccountId) -> bool {
+        Markets::<T>::iter_values().any(|market| !AccountDeposits::<T>::get(market.lend_token_id, account).is_zero())
+    }
+
+    /// Writes off the debt of `borrower` in every market. The debt is written off against the
+    /// market's reserves first. The remainder is socialized across suppliers by reducing
+    /// `TotalBorrows`, and thereby the exchange rate. The exchange rate is not taken below
+    /// `MinExchangeRate`, any debt that can't be socialized remains on the borrower's account.
+    #[require_transactional]
+    fn do_write_off_bad_debt(borrower: &T::AccountId) -> DispatchResult {
+        for asset_id in Markets::<T>::iter_keys() {
+            Self::accrue_interest(asset_id)?;
+            let debt = Self::current_borrow_balance(borrower, asset_id)?;
+            if debt.is_zero() {
+                continue;
+            }
+
+            let total_reserves = Self::total_reserves(asset_id);
+            let covered_by_reserves = debt.min(&total_reserves)?;
+            let remaining_debt = debt.checked_sub(&covered_by_reserves)?;
+
+            // Value owed to suppliers must stay at or above `MinExchangeRate * totalSupply`
+            let total_borrows = Self::total_borrows(asset_id);
+            let suppliers_value = Self::get_total_cash(asset_id)
+                .checked_add(&total_borrows)?
+                .checked_sub(&total_reserves)?;
+            let min_suppliers_value = Self::min_exchange_rate()
+                .checked_mul_int(Self::total_supply(asset_id)?.amount())
+                .ok_or(ArithmeticError::Overflow)?;
+            let socializable = suppliers_value.saturating_sub(&Amount::new(min_suppliers_value, asset_id))?;
+            let socialized = remaining_debt.min(&socializable)?;
+
+            let written_off = covered_by_reserves.checked_add(&socialized)?;
+            if written_off.is_zero() {
+                continue;
+            }
+
+            Self::update_reward_borrow_index(asset_id)?;
+            Self::distribute_borrower_reward(asset_id, borrower)?;
+
+            Self::record_borrow_interest(borrower, asset_id, Self::account_borrows(asset_id, borrower))?;
+            AccountBorrows::<T>::insert(
+                asset_id,
+                borrower,
+                BorrowSnapshot {
+                    principal: debt.checked_sub(&written_off)?.amount(),
+                    borrow_index: Self::borrow_index(asset_id),
+                },
+            );
+            TotalBorrows::<T>::insert(asset_id, total_borrows.saturating_sub(&written_off)?.amount());
+            TotalReserves::<T>::insert(asset_id, total_reserves.checked_sub(&covered_by_reserves)?.amount());
+            BadDebt::<T>::try_mutate(asset_id, |bad_debt| -> DispatchResult {
+                *bad_debt = bad_debt
+                    .checked_add(&written_off.amount())
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(())
+            })?;
+
+            // Make sure suppliers redeeming in this same block already bear the loss
+            ExchangeRate::<T>::insert(asset_id, Self::exchange_rate_stored(asset_id)?);
+            Self::on_exchange_rate_change(&asset_id);
+
+            Self::deposit_event(Event::<T>::BadDebtWrittenOff {
+                borrower: borrower.clone(),
+                currency_id: asset_id,
+                covered_by_reserves: covered_by_reserves.amount(),
+                socialized: socialized.amount(),
+            });
+        }
         Ok(())
     }
 
@@ -1605,6 +2875,11 @@ impl<T: Config> Pallet<T> {
         let account_borrows_new =
             Self::current_borrow_balance(borrower, liquidation_asset_id)?.checked_sub(&repayment)?;
         let total_borrows_new = Self::total_borrows(liquidation_asset_id).checked_sub(&repayment)?;
+        Self::record_borrow_interest(
+            borrower,
+            liquidation_asset_id,
+            Self::account_borrows(liquidation_asset_id, borrower),
+        )?;
         AccountBorrows::<T>::insert(
             liquidation_asset_id,
             borrower,
@@ -1675,9 +2950,17 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    // Ensures a given `asset_id` is an active market.
+    // Ensures a given `asset_id` is an active market, which accepts new supply and borrows.
     fn ensure_active_market(asset_id: CurrencyId<T>) -> Result<Market<BalanceOf<T>>, DispatchError> {
-        Self::active_markets()
+        let market = Self::ensure_listed_market(asset_id)?;
+        ensure!(market.state == MarketState::Active, Error::<T>::MarketDeprecated);
+        Ok(market)
+    }
+
+    // Ensures a given `asset_id` is an active or deprecated market, whose existing positions
+    // can be managed.
+    fn ensure_listed_market(asset_id: CurrencyId<T>) -> Result<Market<BalanceOf<T>>, DispatchError> {
+        Self::listed_markets()
             .find(|(id, _)| id == &asset_id)
             .map(|(_, market)| market)
             .ok_or_else(|| Error::<T>::MarketNotActivated.into())
@@ -1728,7 +3011,7 @@ impl<T: Config> Pallet<T> {
     /// https://github.com/compound-finance/compound-protocol/blob/a3214f67b73310d547e00fc578e8355911c9d376/contracts/CToken.sol#L1125
     fn ensure_enough_cash(amount: &Amount<T>) -> DispatchResult {
         let reducible_cash =
-            Self::get_total_cash(amount.currency()).checked_sub(&Self::total_reserves(amount.currency()))?;
+            Self::get_available_cash(amount.currency()).checked_sub(&Self::total_reserves(amount.currency()))?;
         if reducible_cash.lt(&amount)? {
             return Err(Error::<T>::InsufficientCash.into());
         }
@@ -1764,8 +3047,18 @@ impl<T: Config> Pallet<T> {
         Err(Error::<T>::InsufficientLiquidity.into())
     }
 
-    /// Transferrable balance in the pallet account (`free - frozen`)
+    /// Cash of the market: the transferrable balance of the pallet account, plus the amount of a
+    /// flash loan in progress, which is due back by the end of the extrinsic
     fn get_total_cash(asset_id: CurrencyId<T>) -> Amount<T> {
+        let available_cash = Self::get_available_cash(asset_id);
+        match FlashLoanInProgress::<T>::get() {
+            Some((currency_id, amount)) if currency_id == asset_id => available_cash.map(|x| x.saturating_add(amount)),
+            _ => available_cash,
+        }
+    }
+
+    /// Transferrable balance in the pallet account (`free - frozen`)
+    fn get_available_cash(asset_id: CurrencyId<T>) -> Amount<T> {
         // for the reducible_balance arguments, see:
         // - https://docs.rs/frame-support/latest/frame_support/traits/tokens/enum.Preservation.html
         // - https://docs.rs/frame-support/latest/frame_support/traits/tokens/enum.Fortitude.html
@@ -1845,9 +3138,9 @@ impl<T: Config> Pallet<T> {
         })
     }
 
-    // All markets that are `MarketStatus::Active`.
-    fn active_markets() -> impl Iterator<Item = (CurrencyId<T>, Market<BalanceOf<T>>)> {
-        Markets::<T>::iter().filter(|(_, market)| market.state == MarketState::Active)
+    // All markets that are `MarketStatus::Active` or `MarketStatus::Deprecated`.
+    fn listed_markets() -> impl Iterator<Item = (CurrencyId<T>, Market<BalanceOf<T>>)> {
+        Markets::<T>::iter().filter(|(_, market)| matches!(market.state, MarketState::Active | MarketState::Deprecated))
     }
 
     // Returns the lend_token_id of the related asset
@@ -1861,10 +3154,57 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// The exchange rate of `lend_token_id` as of the last interest accrual of its market, for
+    /// pricing lend tokens elsewhere, e.g. in the dex. Unlike `recompute_underlying_amount`, it does
+    /// not read the market's cash, which a flash loan or a transfer can move within a single call.
+    /// Returns `None` while a flash loan is in progress.
+    pub fn lend_token_exchange_rate(lend_token_id: CurrencyId<T>) -> Option<Rate> {
+        if FlashLoanInProgress::<T>::exists() {
+            return None;
+        }
+        let underlying_id = Self::underlying_id(lend_token_id).ok()?;
+        Self::ensure_listed_market(underlying_id).ok()?;
+        Some(Self::exchange_rate(underlying_id)).filter(|rate| !rate.is_zero())
+    }
+
     // Returns the incentive reward account
     pub fn incentive_reward_account_id() -> T::AccountId {
         T::PalletId::get().into_sub_account_truncating(INCENTIVE_SUB_ACCOUNT)
     }
+
+    #[require_transactional]
+    fn do_flash_loan(
+        who: &T::AccountId,
+        origin: OriginFor<T>,
+        loan: &Amount<T>,
+        call: CallOf<T>,
+    ) -> Result<PostDispatchInfo, DispatchError> {
+        ensure!(!FlashLoanInProgress::<T>::exists(), Error::<T>::FlashLoanInProgress);
+        Self::ensure_enough_cash(loan)?;
+
+        let fee = loan.map(|x| Self::flash_loan_fee().mul_ceil(x));
+        let repayment = loan.checked_add(&fee)?;
+
+        FlashLoanInProgress::<T>::put((loan.currency(), loan.amount()));
+        loan.transfer(&Self::account_id(), who)?;
+
+        let post_info = call.dispatch(origin).map_err(|err| err.error)?;
+
+        repayment.transfer(who, &Self::account_id())?;
+        FlashLoanInProgress::<T>::kill();
+
+        let total_reserves_new = Self::total_reserves(loan.currency()).checked_add(&fee)?;
+        TotalReserves::<T>::insert(loan.currency(), total_reserves_new.amount());
+
+        Self::deposit_event(Event::<T>::FlashLoan {
+            account_id: who.clone(),
+            currency_id: loan.currency(),
+            amount: loan.amount(),
+            fee: fee.amount(),
+        });
+
+        Ok(post_info)
+    }
 }
 
 impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<T> {
@@ -1909,6 +3249,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         let account_borrows_new = account_borrows.checked_add(borrow)?;
         let total_borrows = Self::total_borrows(asset_id);
         let total_borrows_new = total_borrows.checked_add(&borrow)?;
+        Self::record_borrow_interest(borrower, asset_id, Self::account_borrows(asset_id, borrower))?;
         AccountBorrows::<T>::insert(
             asset_id,
             borrower,
@@ -1920,6 +3261,8 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         TotalBorrows::<T>::insert(asset_id, total_borrows_new.amount());
         borrow.transfer(&Self::account_id(), borrower)?;
 
+        Self::update_liquidation_auctions(borrower)?;
+
         Self::deposit_event(Event::<T>::Borrowed {
             account_id: borrower.clone(),
             currency_id: asset_id,
@@ -1928,10 +3271,109 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         Ok(())
     }
 
+    fn do_delegated_borrow(
+        delegate: &AccountIdOf<T>,
+        delegator: &AccountIdOf<T>,
+        borrow: &Amount<T>,
+    ) -> Result<(), DispatchError> {
+        let asset_id = borrow.currency();
+        Self::ensure_active_market(asset_id)?;
+
+        let allowance = Amount::new(Self::borrow_allowance((asset_id, delegator), delegate), asset_id);
+        ensure!(allowance.ge(borrow)?, Error::<T>::InsufficientBorrowAllowance);
+
+        Self::accrue_interest(asset_id)?;
+        // The borrow has to be covered by the delegator's collateral
+        Self::borrow_allowed(delegator, &borrow)?;
+
+        // The debt is booked against the delegator, who also earns the borrow rewards
+        Self::update_reward_borrow_index(asset_id)?;
+        Self::distribute_borrower_reward(asset_id, delegator)?;
+
+        let account_borrows_new = Self::current_borrow_balance(delegator, asset_id)?.checked_add(borrow)?;
+        let delegated_borrows = Self::current_delegated_borrow_balance(delegator, delegate, asset_id)?;
+        let delegated_borrows_new = delegated_borrows.checked_add(borrow)?;
+        let total_borrows_new = Self::total_borrows(asset_id).checked_add(&borrow)?;
+        Self::record_borrow_interest(delegator, asset_id, Self::account_borrows(asset_id, delegator))?;
+        AccountBorrows::<T>::insert(
+            asset_id,
+            delegator,
+            BorrowSnapshot {
+                principal: account_borrows_new.amount(),
+                borrow_index: Self::borrow_index(asset_id),
+            },
+        );
+        DelegatedBorrows::<T>::insert(
+            (asset_id, delegator),
+            delegate,
+            BorrowSnapshot {
+                principal: delegated_borrows_new.amount(),
+                borrow_index: Self::borrow_index(asset_id),
+            },
+        );
+        TotalBorrows::<T>::insert(asset_id, total_borrows_new.amount());
+        BorrowAllowances::<T>::insert((asset_id, delegator), delegate, allowance.checked_sub(borrow)?.amount());
+        borrow.transfer(&Self::account_id(), delegate)?;
+
+        Self::update_liquidation_auctions(delegator)?;
+
+        Self::deposit_event(Event::<T>::DelegatedBorrowed {
+            delegator: delegator.clone(),
+            delegate: delegate.clone(),
+            currency_id: asset_id,
+            amount: borrow.amount(),
+        });
+        Ok(())
+    }
+
+    fn do_repay_delegated_borrow(
+        delegate: &AccountIdOf<T>,
+        delegator: &AccountIdOf<T>,
+        repay_amount: &Amount<T>,
+    ) -> Result<(), DispatchError> {
+        let asset_id = repay_amount.currency();
+        Self::ensure_listed_market(asset_id)?;
+        Self::accrue_interest(asset_id)?;
+
+        let delegated_borrows = Self::current_delegated_borrow_balance(delegator, delegate, asset_id)?;
+        let account_borrows = Self::current_borrow_balance(delegator, asset_id)?;
+        // The delegator's debt may have been repaid, liquidated or written off in the meantime
+        let repayment = repay_amount.min(&delegated_borrows)?.min(&account_borrows)?;
+        ensure!(!repayment.is_zero(), Error::<T>::InvalidAmount);
+        Self::do_repay_borrow_with_amount(delegate, delegator, asset_id, &account_borrows, &repayment)?;
+
+        let delegated_borrows_new = delegated_borrows.checked_sub(&repayment)?;
+        if delegated_borrows_new.is_zero() || repayment.eq(&account_borrows)? {
+            DelegatedBorrows::<T>::remove((asset_id, delegator), delegate);
+        } else {
+            DelegatedBorrows::<T>::insert(
+                (asset_id, delegator),
+                delegate,
+                BorrowSnapshot {
+                    principal: delegated_borrows_new.amount(),
+                    borrow_index: Self::borrow_index(asset_id),
+                },
+            );
+        }
+
+        Self::update_liquidation_auctions(delegator)?;
+
+        Self::deposit_event(Event::<T>::RepaidDelegatedBorrow {
+            delegator: delegator.clone(),
+            delegate: delegate.clone(),
+            currency_id: asset_id,
+            amount: repayment.amount(),
+        });
+        Ok(())
+    }
+
     fn do_deposit_collateral(supplier: &AccountIdOf<T>, lend_token_amount: &Amount<T>) -> Result<(), DispatchError> {
         // If the given asset_id is not a valid lend_token, fetching the underlying will fail
         let underlying_id = Self::underlying_id(lend_token_amount.currency())?;
-        Self::ensure_active_market(underlying_id)?;
+        Self::ensure_listed_market(underlying_id)?;
+        if let Some(category_id) = Self::account_emode(supplier) {
+            Self::ensure_in_emode_category(underlying_id, category_id)?;
+        }
 
         // Will fail if supplier has insufficient free tokens
         lend_token_amount.lock_on(supplier)?;
@@ -1941,6 +3383,8 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         let new_deposit = deposit.checked_add(&lend_token_amount)?;
         AccountDeposits::<T>::insert(lend_token_amount.currency(), supplier, new_deposit.amount());
 
+        Self::update_liquidation_auctions(supplier)?;
+
         Self::deposit_event(Event::<T>::DepositCollateral {
             account_id: supplier.clone(),
             currency_id: lend_token_amount.currency(),
@@ -1952,10 +3396,10 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
     fn do_withdraw_collateral(supplier: &AccountIdOf<T>, voucher: &Amount<T>) -> Result<(), DispatchError> {
         // If the given asset_id is not a valid lend_token, fetching the underlying will fail
         let underlying_id = Self::underlying_id(voucher.currency())?;
-        Self::ensure_active_market(underlying_id)?;
+        Self::ensure_listed_market(underlying_id)?;
 
         let total_collateral_value = Self::total_collateral_value(supplier)?;
-        let collateral_amount_value = Self::collateral_amount_value(&voucher)?;
+        let collateral_amount_value = Self::collateral_amount_value(supplier, &voucher)?;
         let total_borrowed_value = Self::total_borrowed_value(supplier)?;
         log::trace!(
             target: "loans::collateral_asset",
@@ -1986,6 +3430,8 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
             Ok(())
         })?;
 
+        Self::update_liquidation_auctions(supplier)?;
+
         Self::deposit_event(Event::<T>::WithdrawCollateral {
             account_id: supplier.clone(),
             currency_id: voucher.currency(),
@@ -1996,10 +3442,12 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
 
     fn do_repay_borrow(borrower: &AccountIdOf<T>, borrow: &Amount<T>) -> Result<(), DispatchError> {
         let asset_id = borrow.currency();
-        Self::ensure_active_market(asset_id)?;
+        Self::ensure_listed_market(asset_id)?;
         Self::accrue_interest(asset_id)?;
         let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
-        Self::do_repay_borrow_with_amount(borrower, asset_id, &account_borrows, &borrow)?;
+        Self::do_repay_borrow_with_amount(borrower, borrower, asset_id, &account_borrows, &borrow)?;
+        Self::update_liquidation_auctions(borrower)?;
+
         Self::deposit_event(Event::<T>::RepaidBorrow {
             account_id: borrower.clone(),
             currency_id: asset_id,
@@ -2019,7 +3467,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
             Self::do_withdraw_collateral(&supplier, &voucher)?;
         }
 
-        Self::ensure_active_market(asset_id)?;
+        Self::ensure_listed_market(asset_id)?;
         Self::accrue_interest(asset_id)?;
 
         Self::redeem_allowed(supplier, &voucher)?;
@@ -2051,7 +3499,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         // possibly not having accrued for a few blocks. This would result in using an
         // outdated exchange rate. Call `accrue_interest` to avoid this.
         let underlying_id = Self::underlying_id(lend_tokens.currency())?;
-        Self::ensure_active_market(underlying_id)?;
+        Self::ensure_listed_market(underlying_id)?;
         Self::accrue_interest(underlying_id)?;
         let exchange_rate = Self::exchange_rate_stored(underlying_id)?;
         Ok(lend_tokens.checked_mul(&exchange_rate)?.set_currency(underlying_id))
@@ -2069,7 +3517,7 @@ impl<T: Config> LoansTrait<CurrencyId<T>, AccountIdOf<T>, Amount<T>> for Pallet<
         // This function could be called externally to this pallet, with interest
         // possibly not having accrued for a few blocks. This would result in using an
         // outdated exchange rate. Call `accrue_interest` to avoid this.
-        Self::ensure_active_market(underlying.currency())?;
+        Self::ensure_listed_market(underlying.currency())?;
         Self::accrue_interest(underlying.currency())?;
         let exchange_rate = Self::exchange_rate_stored(underlying.currency())?;
 
@@ -2107,14 +3555,19 @@ impl<T: Config> LoansMarketDataProvider<CurrencyId<T>, BalanceOf<T>> for Pallet<
     }
 
     fn get_full_interest_rate(asset_id: CurrencyId<T>) -> Option<Rate> {
-        if let Ok(market) = Self::market(asset_id) {
-            let rate = match market.rate_model {
-                InterestRateModel::Jump(jump) => Some(jump.full_rate),
-                _ => None,
-            };
-            return rate;
+        let market = Self::market(asset_id).ok()?;
+        let full_rate = match Self::rate_model_transition(asset_id) {
+            Some(transition) => {
+                let from_rate = transition.from.get_full_rate()?;
+                let to_rate = transition.to.get_full_rate()?;
+                from_rate.max(to_rate)
+            }
+            None => market.rate_model.get_full_rate()?,
+        };
+        match Self::market_deprecation(asset_id).and_then(|deprecation| deprecation.min_borrow_rate) {
+            Some(min_borrow_rate) => Some(full_rate.max(min_borrow_rate)),
+            None => Some(full_rate),
         }
-        None
     }
 }
 
@@ -0,0 +1,142 @@
+use super::*;
+
+/// The log target.
+const TARGET: &'static str = "runtime::loans::migration::v1";
+
+pub mod v1 {
+    use super::*;
+
+    /// Starts tracking the supply interest of existing lend token holders from the current
+    /// exchange rate. Borrow interest needs no initialization, since it is derived from
+    /// `AccountBorrows`.
+    ///
+    /// The number of lend token holders is unbounded, so they are migrated over multiple blocks
+    /// from `on_idle`, continuing after `EarningsMigrationCursor`. The storage version is only
+    /// bumped once every holder was visited. Holders whose lend token balance changes in the
+    /// meantime already track their interest and are left as they are.
+    pub(crate) fn migrate_step<T: Config>(remaining_weight: Weight) -> Weight {
+        let holder_weight = T::DbWeight::get().reads_writes(4, 1);
+        let mut used_weight = T::DbWeight::get().reads_writes(2, 2);
+        if used_weight.saturating_add(holder_weight).any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+        if StorageVersion::<T>::get() != Versions::V0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut accounts = match EarningsMigrationCursor::<T>::get() {
+            Some((holder, currency_id)) => orml_tokens::Accounts::<T>::iter_keys_from(
+                orml_tokens::Accounts::<T>::hashed_key_for(holder, currency_id),
+            ),
+            None => orml_tokens::Accounts::<T>::iter_keys(),
+        };
+        let mut batch = Vec::new();
+        let mut exhausted = false;
+        while used_weight.saturating_add(holder_weight).all_lte(remaining_weight) {
+            match accounts.next() {
+                Some(key) => {
+                    used_weight = used_weight.saturating_add(holder_weight);
+                    batch.push(key);
+                }
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+
+        if exhausted {
+            EarningsMigrationCursor::<T>::kill();
+            StorageVersion::<T>::put(Versions::V1);
+            log::info!(target: TARGET, "all lend token holders migrated");
+        } else {
+            EarningsMigrationCursor::<T>::set(batch.last().cloned());
+        }
+
+        for (holder, currency_id) in batch {
+            if !currency_id.is_lend_token() {
+                continue;
+            }
+            if let Some(asset_id) = UnderlyingAssetId::<T>::get(currency_id) {
+                let exchange_rate = ExchangeRate::<T>::get(asset_id);
+                AccountEarnings::<T>::mutate(asset_id, &holder, |earnings| {
+                    if earnings.exchange_rate_prior.is_zero() {
+                        earnings.exchange_rate_prior = exchange_rate;
+                    }
+                });
+            }
+        }
+
+        used_weight
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::{new_test_ext, unit, Loans, RuntimeOrigin, Test as T, ALICE, BOB};
+    use frame_support::{assert_ok, traits::Hooks};
+    use primitives::{CurrencyId::Token, KSM};
+
+    fn simulate_state_before_upgrade() {
+        let _ = AccountEarnings::<T>::clear(u32::MAX, None);
+        StorageVersion::<T>::put(Versions::V0);
+    }
+
+    #[test]
+    fn migration_initializes_exchange_rate_of_lend_token_holders() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(KSM), unit(100)));
+            simulate_state_before_upgrade();
+
+            Loans::on_idle(1, Weight::MAX);
+
+            assert_eq!(StorageVersion::<T>::get(), Versions::V1);
+            assert_eq!(EarningsMigrationCursor::<T>::get(), None);
+            assert_eq!(
+                AccountEarnings::<T>::get(Token(KSM), ALICE).exchange_rate_prior,
+                ExchangeRate::<T>::get(Token(KSM))
+            );
+            assert!(!AccountEarnings::<T>::contains_key(Token(KSM), BOB));
+        });
+    }
+
+    #[test]
+    fn migration_continues_after_cursor() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(KSM), unit(100)));
+            simulate_state_before_upgrade();
+            let lend_token_id = Loans::lend_token_id(Token(KSM)).unwrap();
+            EarningsMigrationCursor::<T>::put((ALICE, lend_token_id));
+
+            v1::migrate_step::<T>(Weight::MAX);
+
+            // Alice was visited before the cursor was stored
+            assert!(AccountEarnings::<T>::get(Token(KSM), ALICE)
+                .exchange_rate_prior
+                .is_zero());
+            assert_eq!(StorageVersion::<T>::get(), Versions::V1);
+            // No further work once migrated
+            assert_eq!(v1::migrate_step::<T>(Weight::MAX), Weight::zero());
+        });
+    }
+
+    #[test]
+    fn migration_keeps_exchange_rate_of_holders_changed_since_upgrade() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(KSM), unit(100)));
+            simulate_state_before_upgrade();
+            let exchange_rate_prior = Rate::saturating_from_rational(1, 100);
+            AccountEarnings::<T>::mutate(Token(KSM), ALICE, |earnings| {
+                earnings.exchange_rate_prior = exchange_rate_prior;
+            });
+
+            v1::migrate_step::<T>(Weight::MAX);
+
+            assert_eq!(
+                AccountEarnings::<T>::get(Token(KSM), ALICE).exchange_rate_prior,
+                exchange_rate_prior
+            );
+        });
+    }
+}
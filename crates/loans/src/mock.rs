@@ -216,6 +216,7 @@ impl oracle::Config for Test {
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = EnsureRoot<AccountId32>;
 }
 
 parameter_types! {
@@ -236,6 +237,7 @@ impl Config for Test {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = ();
+    type RuntimeCall = RuntimeCall;
 }
 
 pub const LEND_DOT: CurrencyId = LendToken(1);
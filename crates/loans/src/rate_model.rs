@@ -16,9 +16,12 @@
 // limitations under the License.
 
 use codec::MaxEncodedLen;
-use primitives::{Rate, Ratio};
+use primitives::{Rate, Ratio, Timestamp, SECONDS_PER_YEAR};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedSub, Saturating};
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating},
+    PerThing,
+};
 
 use crate::*;
 
@@ -39,6 +42,8 @@ use crate::*;
 pub enum InterestRateModel {
     Jump(JumpModel),
     Curve(CurveModel),
+    MultiKink(MultiKinkModel),
+    Adaptive(AdaptiveModel),
 }
 
 impl Default for InterestRateModel {
@@ -65,6 +70,8 @@ impl InterestRateModel {
         match self {
             Self::Jump(jump) => jump.check_model(),
             Self::Curve(curve) => curve.check_model(),
+            Self::MultiKink(multi_kink) => multi_kink.check_model(),
+            Self::Adaptive(adaptive) => adaptive.check_model(),
         }
     }
 
@@ -73,6 +80,27 @@ impl InterestRateModel {
         match self {
             Self::Jump(jump) => jump.get_borrow_rate(utilization),
             Self::Curve(curve) => curve.get_borrow_rate(utilization),
+            Self::MultiKink(multi_kink) => multi_kink.get_borrow_rate(utilization),
+            Self::Adaptive(adaptive) => adaptive.get_borrow_rate(utilization),
+        }
+    }
+
+    /// The borrow interest rate at 100% utilization, if the model has an upper bound
+    pub fn get_full_rate(&self) -> Option<Rate> {
+        match self {
+            Self::Jump(jump) => Some(jump.full_rate),
+            Self::Curve(_) => None,
+            Self::MultiKink(multi_kink) => Some(multi_kink.full_rate),
+            Self::Adaptive(adaptive) => adaptive.max_rate_at_target.checked_mul(&adaptive.curve_steepness),
+        }
+    }
+
+    /// Returns the model after `delta_time` seconds at the given utilization. Only adaptive
+    /// models change over time.
+    pub fn accrue(&self, utilization: Ratio, delta_time: Timestamp) -> Option<Self> {
+        match self {
+            Self::Adaptive(adaptive) => Some(Self::Adaptive(adaptive.accrue(utilization, delta_time)?)),
+            _ => Some(*self),
         }
     }
 
@@ -207,6 +235,274 @@ impl CurveModel {
     }
 }
 
+/// Linearly interpolates the rate at `utilization` between two points of a rate curve
+fn interpolate(start: (Ratio, Rate), end: (Ratio, Rate), utilization: Ratio) -> Option<Rate> {
+    let (start_utilization, start_rate) = start;
+    let (end_utilization, end_rate) = end;
+    let span = end_utilization.saturating_sub(start_utilization);
+    if span.is_zero() {
+        return Some(end_rate);
+    }
+    let progress = Rate::checked_from_rational(
+        utilization.saturating_sub(start_utilization).deconstruct(),
+        span.deconstruct(),
+    )?;
+    // start_rate + (end_rate - start_rate) * (utilization - start_utilization) / span
+    end_rate
+        .checked_sub(&start_rate)?
+        .checked_mul(&progress)?
+        .checked_add(&start_rate)
+}
+
+/// The maximum number of kinks of a `MultiKinkModel`
+pub const MAX_RATE_MODEL_KINKS: usize = 4;
+
+/// A point on the rate curve of a `MultiKinkModel`
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct Kink {
+    /// The utilization at which the slope of the curve changes
+    pub utilization: Ratio,
+    /// The interest rate at `utilization`
+    pub rate: Rate,
+}
+
+/// Piecewise linear interest rate model
+///
+/// The borrow rate is interpolated linearly between `base_rate` at 0% utilization, each of the
+/// kinks, and `full_rate` at 100% utilization. Models that need fewer kinks repeat the last kink.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct MultiKinkModel {
+    /// The interest rate when utilization is 0%
+    pub base_rate: Rate,
+    /// The kinks of the curve, in order of increasing utilization
+    pub kinks: [Kink; MAX_RATE_MODEL_KINKS],
+    /// The interest rate when utilization is 100%
+    pub full_rate: Rate,
+}
+
+impl MultiKinkModel {
+    pub const MAX_BASE_RATE: Rate = Rate::from_inner(100_000_000_000_000_000); // 10%
+    pub const MAX_FULL_RATE: Rate = Rate::from_inner(2_000_000_000_000_000_000); // 200%
+
+    /// Create a new rate model
+    pub fn new_model(base_rate: Rate, kinks: [Kink; MAX_RATE_MODEL_KINKS], full_rate: Rate) -> MultiKinkModel {
+        Self {
+            base_rate,
+            kinks,
+            full_rate,
+        }
+    }
+
+    fn points(&self) -> impl Iterator<Item = (Ratio, Rate)> + '_ {
+        sp_std::iter::once((Ratio::zero(), self.base_rate))
+            .chain(self.kinks.iter().map(|kink| (kink.utilization, kink.rate)))
+            .chain(sp_std::iter::once((Ratio::one(), self.full_rate)))
+    }
+
+    /// Check the multi-kink model for sanity: both the utilization and the rate must be
+    /// non-decreasing along the curve
+    pub fn check_model(&self) -> bool {
+        if self.base_rate > Self::MAX_BASE_RATE || self.full_rate > Self::MAX_FULL_RATE {
+            return false;
+        }
+        self.points()
+            .zip(self.points().skip(1))
+            .all(|((utilization, rate), (next_utilization, next_rate))| {
+                utilization <= next_utilization && rate <= next_rate
+            })
+    }
+
+    /// Calculates the borrow interest rate of multi-kink model
+    pub fn get_borrow_rate(&self, utilization: Ratio) -> Option<Rate> {
+        let mut previous = (Ratio::zero(), self.base_rate);
+        for point in self.points() {
+            if utilization <= point.0 {
+                return interpolate(previous, point, utilization);
+            }
+            previous = point;
+        }
+        Some(self.full_rate)
+    }
+}
+
+/// Adaptive interest rate model
+///
+/// The borrow rate at `target_utilization` moves over time: up while utilization is above the
+/// target and down while it is below, at a speed proportional to the distance from the target.
+/// Around this moving point, the rate follows a fixed-shape curve that spans from
+/// `rate_at_target / curve_steepness` at 0% utilization to `rate_at_target * curve_steepness` at
+/// 100% utilization.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct AdaptiveModel {
+    /// The utilization the model steers the market towards
+    pub target_utilization: Ratio,
+    /// The current interest rate at `target_utilization`, updated whenever interest accrues
+    pub rate_at_target: Rate,
+    /// Lower bound of `rate_at_target`
+    pub min_rate_at_target: Rate,
+    /// Upper bound of `rate_at_target`
+    pub max_rate_at_target: Rate,
+    /// The relative change of `rate_at_target` per year when utilization is 0% or 100%
+    pub adjustment_speed: Rate,
+    /// The ratio between the interest rate at 100% utilization and `rate_at_target`
+    pub curve_steepness: Rate,
+}
+
+impl AdaptiveModel {
+    pub const MAX_RATE_AT_TARGET: Rate = Rate::from_inner(1_000_000_000_000_000_000); // 100%
+    pub const MAX_ADJUSTMENT_SPEED: Rate = Rate::from_inner(100_000_000_000_000_000_000); // 100x per year
+    pub const MAX_CURVE_STEEPNESS: Rate = Rate::from_inner(10_000_000_000_000_000_000); // 10x
+
+    /// Check the adaptive model for sanity
+    pub fn check_model(&self) -> bool {
+        if self.target_utilization.is_zero() || self.target_utilization == Ratio::one() {
+            return false;
+        }
+        if self.min_rate_at_target > self.rate_at_target
+            || self.rate_at_target > self.max_rate_at_target
+            || self.max_rate_at_target > Self::MAX_RATE_AT_TARGET
+        {
+            return false;
+        }
+        self.adjustment_speed <= Self::MAX_ADJUSTMENT_SPEED
+            && self.curve_steepness >= Rate::one()
+            && self.curve_steepness <= Self::MAX_CURVE_STEEPNESS
+    }
+
+    /// The distance between `utilization` and the target, normalized to `[0, 1]`, and whether
+    /// utilization is above the target
+    fn utilization_error(&self, utilization: Ratio) -> Option<(Rate, bool)> {
+        if utilization > self.target_utilization {
+            let error = Rate::checked_from_rational(
+                utilization.saturating_sub(self.target_utilization).deconstruct(),
+                Ratio::one().saturating_sub(self.target_utilization).deconstruct(),
+            )?;
+            Some((error, true))
+        } else {
+            let error = Rate::checked_from_rational(
+                self.target_utilization.saturating_sub(utilization).deconstruct(),
+                self.target_utilization.deconstruct(),
+            )?;
+            Some((error, false))
+        }
+    }
+
+    /// Calculates the borrow interest rate of adaptive model
+    pub fn get_borrow_rate(&self, utilization: Ratio) -> Option<Rate> {
+        let (error, above_target) = self.utilization_error(utilization)?;
+        if above_target {
+            // rate_at_target + (rate_at_target * curve_steepness - rate_at_target) * error
+            let full_rate = self.rate_at_target.checked_mul(&self.curve_steepness)?;
+            full_rate
+                .checked_sub(&self.rate_at_target)?
+                .checked_mul(&error)?
+                .checked_add(&self.rate_at_target)
+        } else {
+            // rate_at_target - (rate_at_target - rate_at_target / curve_steepness) * error
+            let zero_rate = self.rate_at_target.checked_div(&self.curve_steepness)?;
+            let decrease = self.rate_at_target.checked_sub(&zero_rate)?.checked_mul(&error)?;
+            self.rate_at_target.checked_sub(&decrease)
+        }
+    }
+
+    /// Moves `rate_at_target` towards the target utilization for `delta_time` seconds
+    pub fn accrue(&self, utilization: Ratio, delta_time: Timestamp) -> Option<Self> {
+        let (error, above_target) = self.utilization_error(utilization)?;
+        // rate_at_target * adjustment_speed * error * delta_time / SECONDS_PER_YEAR
+        let elapsed = Rate::checked_from_rational(delta_time, SECONDS_PER_YEAR)?;
+        let change = self
+            .rate_at_target
+            .checked_mul(&self.adjustment_speed)?
+            .checked_mul(&error)?
+            .checked_mul(&elapsed)?;
+        let rate_at_target = if above_target {
+            self.rate_at_target.saturating_add(change)
+        } else {
+            self.rate_at_target.saturating_sub(change)
+        };
+        Some(Self {
+            rate_at_target: rate_at_target.clamp(self.min_rate_at_target, self.max_rate_at_target),
+            ..*self
+        })
+    }
+}
+
+/// A gradual switch from one rate model to another
+///
+/// The borrow rate is interpolated linearly between the rates of both models, from the rate of
+/// `from` at block `start` to the rate of `to` at block `end`.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RateModelTransition<BlockNumber> {
+    /// The rate model at the time the transition was scheduled
+    pub from: InterestRateModel,
+    /// The rate model of the market once the transition completes
+    pub to: InterestRateModel,
+    /// The block at which the transition was scheduled
+    pub start: BlockNumber,
+    /// The block at which the transition completes
+    pub end: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> RateModelTransition<BlockNumber> {
+    /// Whether the transition has completed at block `now`
+    pub fn is_complete(&self, now: BlockNumber) -> bool {
+        now >= self.end
+    }
+
+    /// Calculates the borrow interest rate at block `now`
+    pub fn get_borrow_rate(&self, utilization: Ratio, now: BlockNumber) -> Option<Rate> {
+        let to_rate = self.to.get_borrow_rate(utilization)?;
+        if self.is_complete(now) {
+            return Some(to_rate);
+        }
+        let from_rate = self.from.get_borrow_rate(utilization)?;
+        let progress = Rate::checked_from_rational(
+            now.saturating_sub(self.start).saturated_into::<u128>(),
+            self.end.saturating_sub(self.start).saturated_into::<u128>(),
+        )?;
+        // from_rate * (1 - progress) + to_rate * progress
+        from_rate
+            .checked_mul(&Rate::one().saturating_sub(progress))?
+            .checked_add(&to_rate.checked_mul(&progress)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,4 +584,148 @@ mod tests {
             Rate::from_inner(154217728000000000)
         );
     }
+
+    fn multi_kink_model() -> MultiKinkModel {
+        MultiKinkModel::new_model(
+            Rate::saturating_from_rational(2, 100),
+            [
+                Kink {
+                    utilization: Ratio::from_percent(50),
+                    rate: Rate::saturating_from_rational(6, 100),
+                },
+                Kink {
+                    utilization: Ratio::from_percent(80),
+                    rate: Rate::saturating_from_rational(10, 100),
+                },
+                Kink {
+                    utilization: Ratio::from_percent(90),
+                    rate: Rate::saturating_from_rational(40, 100),
+                },
+                Kink {
+                    utilization: Ratio::from_percent(90),
+                    rate: Rate::saturating_from_rational(40, 100),
+                },
+            ],
+            Rate::saturating_from_rational(150, 100),
+        )
+    }
+
+    #[test]
+    fn multi_kink_model_interpolates_between_kinks() {
+        let model = multi_kink_model();
+        assert!(model.check_model());
+        assert_eq!(
+            model.get_borrow_rate(Ratio::zero()).unwrap(),
+            Rate::saturating_from_rational(2, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::from_percent(25)).unwrap(),
+            Rate::saturating_from_rational(4, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::from_percent(65)).unwrap(),
+            Rate::saturating_from_rational(8, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::from_percent(90)).unwrap(),
+            Rate::saturating_from_rational(40, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::from_percent(95)).unwrap(),
+            Rate::saturating_from_rational(95, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::one()).unwrap(),
+            Rate::saturating_from_rational(150, 100)
+        );
+    }
+
+    #[test]
+    fn multi_kink_model_must_be_increasing() {
+        let mut model = multi_kink_model();
+        model.kinks[1].utilization = Ratio::from_percent(40);
+        assert!(!model.check_model());
+
+        let mut model = multi_kink_model();
+        model.kinks[1].rate = Rate::saturating_from_rational(5, 100);
+        assert!(!model.check_model());
+
+        let mut model = multi_kink_model();
+        model.full_rate = Rate::saturating_from_rational(300, 100);
+        assert!(!model.check_model());
+    }
+
+    fn adaptive_model() -> AdaptiveModel {
+        AdaptiveModel {
+            target_utilization: Ratio::from_percent(80),
+            rate_at_target: Rate::saturating_from_rational(4, 100),
+            min_rate_at_target: Rate::saturating_from_rational(1, 100),
+            max_rate_at_target: Rate::saturating_from_rational(20, 100),
+            adjustment_speed: Rate::saturating_from_integer(10),
+            curve_steepness: Rate::saturating_from_integer(4),
+        }
+    }
+
+    #[test]
+    fn adaptive_model_calculates_borrow_rate_around_target() {
+        let model = adaptive_model();
+        assert!(model.check_model());
+        assert_eq!(
+            model.get_borrow_rate(Ratio::zero()).unwrap(),
+            Rate::saturating_from_rational(1, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::from_percent(80)).unwrap(),
+            Rate::saturating_from_rational(4, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::from_percent(90)).unwrap(),
+            Rate::saturating_from_rational(10, 100)
+        );
+        assert_eq!(
+            model.get_borrow_rate(Ratio::one()).unwrap(),
+            Rate::saturating_from_rational(16, 100)
+        );
+    }
+
+    #[test]
+    fn adaptive_model_moves_towards_target() {
+        let model = adaptive_model();
+        let a_tenth_of_a_year = SECONDS_PER_YEAR / 10;
+
+        // at 100% utilization, the rate doubles in a tenth of a year
+        let raised = model.accrue(Ratio::one(), a_tenth_of_a_year).unwrap();
+        assert_eq!(raised.rate_at_target, Rate::saturating_from_rational(8, 100));
+
+        // at 0% utilization, the rate is reduced to the minimum
+        let lowered = model.accrue(Ratio::zero(), a_tenth_of_a_year).unwrap();
+        assert_eq!(lowered.rate_at_target, model.min_rate_at_target);
+
+        // at target utilization, the rate is stable
+        assert_eq!(model.accrue(Ratio::from_percent(80), a_tenth_of_a_year).unwrap(), model);
+    }
+
+    #[test]
+    fn rate_model_transition_interpolates_between_models() {
+        let transition = RateModelTransition {
+            from: InterestRateModel::new_curve_model(Rate::saturating_from_rational(2, 100)),
+            to: InterestRateModel::new_curve_model(Rate::saturating_from_rational(6, 100)),
+            start: 100u32,
+            end: 200u32,
+        };
+        assert_eq!(
+            transition.get_borrow_rate(Ratio::zero(), 100).unwrap(),
+            Rate::saturating_from_rational(2, 100)
+        );
+        assert_eq!(
+            transition.get_borrow_rate(Ratio::zero(), 125).unwrap(),
+            Rate::saturating_from_rational(3, 100)
+        );
+        assert!(!transition.is_complete(199));
+        assert!(transition.is_complete(200));
+        assert_eq!(
+            transition.get_borrow_rate(Ratio::zero(), 300).unwrap(),
+            Rate::saturating_from_rational(6, 100)
+        );
+    }
 }
@@ -15,11 +15,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bad_debt;
+mod credit_delegation;
+mod earnings;
 mod edge_cases;
+mod emode;
+mod flash_loan;
 mod interest_rate;
 pub mod lend_tokens;
 mod liquidate_borrow;
+mod liquidation_auction;
 mod market;
+mod market_deprecation;
 
 use currency::Amount;
 use frame_support::{assert_noop, assert_ok};
@@ -0,0 +1,141 @@
+use crate::{
+    mock::{new_test_ext, with_price, CurrencyConvert, Loans, RuntimeOrigin, Test, ALICE, BOB, DAVE, LEND_KBTC},
+    tests::unit,
+    AccountDeposits, AccountEarnings, Error, MinExchangeRate,
+};
+use frame_support::{assert_noop, assert_ok};
+use mocktopus::mocking::Mockable;
+use primitives::{
+    CurrencyId::{self, Token},
+    Rate, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
+};
+use sp_runtime::{traits::One, FixedPointNumber};
+
+const KSM: CurrencyId = Token(KSM_CURRENCY);
+const KBTC: CurrencyId = Token(KBTC_CURRENCY);
+
+fn alice_becomes_insolvent() {
+    // Bob deposits 200 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    // Alice deposits 200 KBTC as collateral and borrows 100 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+    assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+    // Without a liquidation premium, repaying 50 KSM seizes all of Alice's collateral
+    Loans::mutate_market(KSM, |market| {
+        market.liquidate_incentive = Rate::one();
+        market.clone()
+    })
+    .unwrap();
+    // Collateral   Loans
+    // KBTC $200    KSM $400
+    CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 4.into()))));
+}
+
+#[test]
+fn bad_debt_is_written_off_against_reserves_first() {
+    new_test_ext().execute_with(|| {
+        alice_becomes_insolvent();
+        assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), DAVE, KSM, unit(60)));
+
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+
+        assert!(AccountDeposits::<Test>::get(LEND_KBTC, ALICE).is_zero());
+        assert!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().is_zero());
+        assert_eq!(Loans::total_borrows(KSM).amount(), 0);
+        assert_eq!(Loans::total_reserves(KSM).amount(), unit(10));
+        assert_eq!(Loans::bad_debt(KSM), unit(50));
+        // Suppliers are not affected
+        assert_eq!(Loans::exchange_rate(KSM), Rate::saturating_from_rational(2, 100));
+    })
+}
+
+#[test]
+fn bad_debt_exceeding_reserves_is_socialized() {
+    new_test_ext().execute_with(|| {
+        alice_becomes_insolvent();
+        assert_ok!(Loans::add_reserves(RuntimeOrigin::root(), DAVE, KSM, unit(20)));
+        MinExchangeRate::<Test>::put(Rate::saturating_from_rational(1, 100));
+
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+
+        assert!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().is_zero());
+        assert_eq!(Loans::total_reserves(KSM).amount(), 0);
+        assert_eq!(Loans::bad_debt(KSM), unit(50));
+        // 170 KSM of cash are left for 200 KSM worth of lend tokens
+        assert_eq!(Loans::exchange_rate(KSM), Rate::saturating_from_rational(17, 1000));
+    })
+}
+
+#[test]
+fn socialized_bad_debt_is_recorded_as_supply_loss() {
+    new_test_ext().execute_with(|| {
+        alice_becomes_insolvent();
+        MinExchangeRate::<Test>::put(Rate::saturating_from_rational(1, 100));
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+
+        let report = Loans::get_account_earnings(&BOB).unwrap();
+        let ksm_earnings = report.markets.iter().find(|market| market.currency_id == KSM).unwrap();
+        assert_eq!(ksm_earnings.supply_loss, unit(30));
+        assert_eq!(ksm_earnings.supply_interest, 0);
+
+        // The loss is realized once Bob's lend token balance changes
+        assert_ok!(Loans::redeem_all(RuntimeOrigin::signed(BOB), KSM));
+        assert_eq!(AccountEarnings::<Test>::get(KSM, BOB).supply_loss, unit(30));
+        assert_eq!(AccountEarnings::<Test>::get(KSM, BOB).supply_interest, 0);
+    })
+}
+
+#[test]
+fn bad_debt_is_not_socialized_below_min_exchange_rate() {
+    new_test_ext().execute_with(|| {
+        alice_becomes_insolvent();
+
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+
+        // The exchange rate is already at its minimum, so the debt remains
+        assert_eq!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().amount(), unit(50));
+        assert_eq!(Loans::bad_debt(KSM), 0);
+
+        MinExchangeRate::<Test>::put(Rate::saturating_from_rational(1, 100));
+        assert_ok!(Loans::write_off_bad_debt(RuntimeOrigin::signed(DAVE), ALICE));
+        assert!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().is_zero());
+        assert_eq!(Loans::bad_debt(KSM), unit(50));
+        assert_eq!(Loans::exchange_rate(KSM), Rate::saturating_from_rational(15, 1000));
+    })
+}
+
+#[test]
+fn write_off_bad_debt_requires_no_collateral() {
+    new_test_ext().execute_with(|| {
+        alice_becomes_insolvent();
+        assert_noop!(
+            Loans::write_off_bad_debt(RuntimeOrigin::signed(DAVE), ALICE),
+            Error::<Test>::BorrowerHasCollateral
+        );
+    })
+}
@@ -0,0 +1,267 @@
+use super::*;
+use crate::{mock::*, DelegatedBorrows, Error};
+use frame_support::{assert_noop, assert_ok};
+
+const KBTC: CurrencyId = Token(KBTC_CURRENCY);
+
+fn alice_delegates_30_ksm_to_charlie() {
+    // Bob deposits 200 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    // Alice deposits 200 KBTC as collateral, which allows borrowing $100
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+    assert_ok!(Loans::approve_borrow_delegation(
+        RuntimeOrigin::signed(ALICE),
+        CHARLIE,
+        KSM,
+        unit(30)
+    ));
+}
+
+fn alice_liquidity() -> u128 {
+    Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount()
+}
+
+#[test]
+fn approve_borrow_delegation_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::approve_borrow_delegation(RuntimeOrigin::signed(ALICE), ALICE, KSM, unit(30)),
+            Error::<Test>::CannotDelegateToSelf
+        );
+        assert_noop!(
+            Loans::approve_borrow_delegation(RuntimeOrigin::signed(ALICE), CHARLIE, KSM, 0),
+            Error::<Test>::InvalidAmount
+        );
+        assert_ok!(Loans::approve_borrow_delegation(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            KSM,
+            unit(30)
+        ));
+        assert_eq!(Loans::borrow_allowance((KSM, ALICE), CHARLIE), unit(30));
+        // A new approval replaces the previous allowance
+        assert_ok!(Loans::approve_borrow_delegation(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            KSM,
+            unit(10)
+        ));
+        assert_eq!(Loans::borrow_allowance((KSM, ALICE), CHARLIE), unit(10));
+    })
+}
+
+#[test]
+fn delegated_borrow_is_accounted_against_the_delegator() {
+    new_test_ext().execute_with(|| {
+        alice_delegates_30_ksm_to_charlie();
+        assert_eq!(alice_liquidity(), unit(100));
+
+        assert_ok!(Loans::delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(20)
+        ));
+        assert_eq!(Tokens::free_balance(KSM, &CHARLIE), unit(20));
+        assert_eq!(Loans::borrow_allowance((KSM, ALICE), CHARLIE), unit(10));
+        assert_eq!(
+            Loans::current_delegated_borrow_balance(&ALICE, &CHARLIE, KSM).unwrap(),
+            Amount::new(unit(20), KSM)
+        );
+        assert_eq!(Loans::total_borrows(KSM).amount(), unit(20));
+        // The debt is booked against Alice and reduces her liquidity
+        assert_eq!(alice_liquidity(), unit(80));
+        assert_eq!(
+            Loans::current_borrow_balance(&ALICE, KSM).unwrap(),
+            Amount::new(unit(20), KSM)
+        );
+        assert!(Loans::get_account_liquidity(&CHARLIE).unwrap().liquidity().is_zero());
+
+        assert_noop!(
+            Loans::delegated_borrow(RuntimeOrigin::signed(CHARLIE), ALICE, KSM, unit(11)),
+            Error::<Test>::InsufficientBorrowAllowance
+        );
+        assert_noop!(
+            Loans::delegated_borrow(RuntimeOrigin::signed(DAVE), ALICE, KSM, unit(1)),
+            Error::<Test>::InsufficientBorrowAllowance
+        );
+        assert_noop!(
+            Loans::delegated_borrow(RuntimeOrigin::signed(CHARLIE), ALICE, DOT, unit(1)),
+            Error::<Test>::InsufficientBorrowAllowance
+        );
+    })
+}
+
+#[test]
+fn delegated_borrow_is_limited_by_delegator_collateral() {
+    new_test_ext().execute_with(|| {
+        alice_delegates_30_ksm_to_charlie();
+        assert_ok!(Loans::approve_borrow_delegation(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            KSM,
+            unit(200)
+        ));
+        assert_noop!(
+            Loans::delegated_borrow(RuntimeOrigin::signed(CHARLIE), ALICE, KSM, unit(101)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_ok!(Loans::delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(100)
+        ));
+        // Alice can no longer borrow or withdraw collateral herself
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(1)),
+            Error::<Test>::InsufficientLiquidity
+        );
+        assert_noop!(
+            Loans::withdraw_all_collateral(RuntimeOrigin::signed(ALICE), KBTC),
+            Error::<Test>::InsufficientLiquidity
+        );
+        // A price increase of the delegated debt makes Alice liquidatable
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_eq!(
+            Loans::get_account_liquidation_threshold_liquidity(&ALICE)
+                .unwrap()
+                .shortfall()
+                .amount(),
+            unit(90)
+        );
+    })
+}
+
+#[test]
+fn revoked_delegation_keeps_outstanding_debt() {
+    new_test_ext().execute_with(|| {
+        alice_delegates_30_ksm_to_charlie();
+        assert_ok!(Loans::delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(20)
+        ));
+
+        assert_ok!(Loans::revoke_borrow_delegation(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            KSM
+        ));
+        assert_eq!(Loans::borrow_allowance((KSM, ALICE), CHARLIE), 0);
+        assert_noop!(
+            Loans::delegated_borrow(RuntimeOrigin::signed(CHARLIE), ALICE, KSM, unit(1)),
+            Error::<Test>::InsufficientBorrowAllowance
+        );
+        assert_eq!(alice_liquidity(), unit(80));
+    })
+}
+
+#[test]
+fn repay_delegated_borrow_works() {
+    new_test_ext().execute_with(|| {
+        alice_delegates_30_ksm_to_charlie();
+        assert_noop!(
+            Loans::repay_delegated_borrow(RuntimeOrigin::signed(CHARLIE), ALICE, KSM, unit(5)),
+            Error::<Test>::InvalidAmount
+        );
+        assert_ok!(Loans::delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(20)
+        ));
+
+        assert_ok!(Loans::repay_delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(5)
+        ));
+        assert_eq!(Tokens::free_balance(KSM, &CHARLIE), unit(15));
+        assert_eq!(alice_liquidity(), unit(85));
+
+        // Repaying more than the outstanding debt repays it in full
+        assert_ok!(Loans::repay_delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(100)
+        ));
+        assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 0);
+        assert!(!DelegatedBorrows::<Test>::contains_key((KSM, ALICE), CHARLIE));
+        assert_eq!(Loans::total_borrows(KSM).amount(), 0);
+        assert_eq!(alice_liquidity(), unit(100));
+        // The allowance is not restored
+        assert_eq!(Loans::borrow_allowance((KSM, ALICE), CHARLIE), unit(10));
+    })
+}
+
+#[test]
+fn delegator_can_repay_delegated_borrow() {
+    new_test_ext().execute_with(|| {
+        alice_delegates_30_ksm_to_charlie();
+        assert_ok!(Loans::delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(20)
+        ));
+
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+        assert_eq!(alice_liquidity(), unit(100));
+        assert_eq!(Loans::total_borrows(KSM).amount(), 0);
+        // Nothing is left for Charlie to repay
+        assert_noop!(
+            Loans::repay_delegated_borrow(RuntimeOrigin::signed(CHARLIE), ALICE, KSM, unit(5)),
+            Error::<Test>::InvalidAmount
+        );
+    })
+}
+
+#[test]
+fn delegated_borrow_can_be_liquidated() {
+    new_test_ext().execute_with(|| {
+        alice_delegates_30_ksm_to_charlie();
+        assert_ok!(Loans::approve_borrow_delegation(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            KSM,
+            unit(100)
+        ));
+        assert_ok!(Loans::delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(100)
+        ));
+
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+        assert_eq!(
+            Loans::current_borrow_balance(&ALICE, KSM).unwrap(),
+            Amount::new(unit(50), KSM)
+        );
+        assert_eq!(Loans::total_borrows(KSM).amount(), unit(50));
+
+        // Charlie can only repay what is left of Alice's debt
+        assert_ok!(Loans::repay_delegated_borrow(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM,
+            unit(100)
+        ));
+        assert_eq!(Tokens::free_balance(KSM, &CHARLIE), unit(50));
+        assert!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().is_zero());
+        assert!(!DelegatedBorrows::<Test>::contains_key((KSM, ALICE), CHARLIE));
+        assert_eq!(Loans::total_borrows(KSM).amount(), 0);
+    })
+}
@@ -0,0 +1,109 @@
+use crate::{
+    mock::{
+        _run_to_block, almost_equal, new_test_ext, Loans, RuntimeOrigin, System, Test, TimestampPallet, Tokens, ALICE,
+        BOB, DAVE,
+    },
+    tests::unit,
+    AccountEarnings,
+};
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use primitives::{
+    CurrencyId::{self, Token},
+    INTR as INTR_CURRENCY, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
+};
+
+const KSM: CurrencyId = Token(KSM_CURRENCY);
+const KBTC: CurrencyId = Token(KBTC_CURRENCY);
+const INTR: CurrencyId = Token(INTR_CURRENCY);
+
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+fn alice_borrows_50_ksm() {
+    System::set_block_number(1);
+    // Bob deposits 200 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    // Alice deposits 200 KBTC as collateral and borrows 50 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+    assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(50)));
+}
+
+#[test]
+fn borrow_interest_is_recorded_on_repayment() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        TimestampPallet::set_timestamp(6000 + SECONDS_PER_YEAR * 1000);
+
+        // Interest accrued since the borrow is reported before it is realized
+        let report = Loans::get_account_earnings(&ALICE).unwrap();
+        assert_eq!(report.markets.len(), 1);
+        assert_eq!(report.markets[0].currency_id, KSM);
+        let pending_interest = report.markets[0].borrow_interest;
+        assert!(pending_interest > 0);
+        assert_eq!(AccountEarnings::<Test>::get(KSM, ALICE).borrow_interest, 0);
+
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+        assert_eq!(
+            AccountEarnings::<Test>::get(KSM, ALICE).borrow_interest,
+            pending_interest
+        );
+        assert_eq!(Tokens::free_balance(KSM, &ALICE), unit(1000) - pending_interest);
+        // Once repaid, no further interest is reported
+        TimestampPallet::set_timestamp(6000 + 2 * SECONDS_PER_YEAR * 1000);
+        assert_eq!(
+            Loans::get_account_earnings(&ALICE).unwrap().markets[0].borrow_interest,
+            pending_interest
+        );
+    })
+}
+
+#[test]
+fn supply_interest_is_recorded_on_redemption() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        TimestampPallet::set_timestamp(6000 + SECONDS_PER_YEAR * 1000);
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+        let borrow_interest = AccountEarnings::<Test>::get(KSM, ALICE).borrow_interest;
+
+        let pending_interest = Loans::get_account_earnings(&BOB).unwrap().markets[0].supply_interest;
+        assert_ok!(Loans::redeem_all(RuntimeOrigin::signed(BOB), KSM));
+        let supply_interest = AccountEarnings::<Test>::get(KSM, BOB).supply_interest;
+        // The realized interest is based on the exchange rate of the last accrual, which doesn't
+        // reflect the rounding of Alice's repayment
+        assert!(almost_equal(supply_interest, pending_interest));
+        assert!(almost_equal(
+            supply_interest,
+            Tokens::free_balance(KSM, &BOB) - unit(1000)
+        ));
+        // Part of the interest paid by Alice goes to the reserves
+        assert!(supply_interest < borrow_interest);
+    })
+}
+
+#[test]
+fn rewards_are_recorded_per_market_and_when_claimed() {
+    new_test_ext().execute_with(|| {
+        _run_to_block(10);
+        assert_ok!(Loans::update_market_reward_speed(
+            RuntimeOrigin::root(),
+            KSM,
+            Some(unit(1)),
+            Some(0),
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+
+        _run_to_block(20);
+        assert_ok!(Loans::add_reward(RuntimeOrigin::signed(DAVE), unit(100)));
+        assert_ok!(Loans::claim_reward(RuntimeOrigin::signed(ALICE)));
+
+        let claimed = Tokens::free_balance(INTR, &ALICE);
+        assert!(almost_equal(claimed, unit(10)));
+        assert_eq!(Loans::rewards_claimed(ALICE), claimed);
+        let report = Loans::get_account_earnings(&ALICE).unwrap();
+        assert_eq!(report.rewards_claimed, claimed);
+        assert_eq!(report.markets.len(), 1);
+        assert_eq!(report.markets[0].currency_id, KSM);
+        assert_eq!(report.markets[0].rewards_accrued, claimed);
+    })
+}
@@ -0,0 +1,154 @@
+use super::*;
+use crate::{mock::*, EModeCategory, Error};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const CATEGORY: EModeCategory = EModeCategory {
+    collateral_factor: Permill::from_percent(90),
+    liquidation_threshold: Permill::from_percent(95),
+};
+
+fn setup_dot_ksm_category() {
+    assert_ok!(Loans::set_emode_category(RuntimeOrigin::root(), 1, Some(CATEGORY)));
+    assert_ok!(Loans::set_asset_emode_category(RuntimeOrigin::root(), DOT, Some(1)));
+    assert_ok!(Loans::set_asset_emode_category(RuntimeOrigin::root(), KSM, Some(1)));
+}
+
+#[test]
+fn set_emode_category_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_emode_category(RuntimeOrigin::signed(ALICE), 1, Some(CATEGORY)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_emode_category(
+                RuntimeOrigin::root(),
+                1,
+                Some(EModeCategory {
+                    collateral_factor: Permill::one(),
+                    liquidation_threshold: Permill::one(),
+                })
+            ),
+            Error::<Test>::InvalidFactor
+        );
+        assert_noop!(
+            Loans::set_emode_category(
+                RuntimeOrigin::root(),
+                1,
+                Some(EModeCategory {
+                    collateral_factor: Permill::from_percent(90),
+                    liquidation_threshold: Permill::from_percent(80),
+                })
+            ),
+            Error::<Test>::InvalidFactor
+        );
+        assert_noop!(
+            Loans::set_asset_emode_category(RuntimeOrigin::root(), DOT, Some(1)),
+            Error::<Test>::EModeCategoryDoesNotExist
+        );
+
+        setup_dot_ksm_category();
+        assert_eq!(crate::EModeCategories::<Test>::get(1), Some(CATEGORY));
+        assert_eq!(crate::AssetEModeCategory::<Test>::get(DOT), Some(1));
+
+        assert_ok!(Loans::set_asset_emode_category(RuntimeOrigin::root(), DOT, None));
+        assert_eq!(crate::AssetEModeCategory::<Test>::get(DOT), None);
+    })
+}
+
+#[test]
+fn emode_increases_account_liquidity() {
+    new_test_ext().execute_with(|| {
+        setup_dot_ksm_category();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), DOT));
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount(),
+            unit(100)
+        );
+
+        assert_ok!(Loans::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+        assert_eq!(Loans::account_emode(&ALICE), Some(1));
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount(),
+            unit(180)
+        );
+        assert_eq!(
+            Loans::get_account_liquidation_threshold_liquidity(&ALICE)
+                .unwrap()
+                .liquidity()
+                .amount(),
+            unit(190)
+        );
+
+        // Removing the category reverts to the market parameters
+        assert_ok!(Loans::set_emode_category(RuntimeOrigin::root(), 1, None));
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount(),
+            unit(100)
+        );
+    })
+}
+
+#[test]
+fn entering_emode_requires_positions_in_category() {
+    new_test_ext().execute_with(|| {
+        setup_dot_ksm_category();
+        assert_noop!(
+            Loans::set_account_emode(RuntimeOrigin::signed(ALICE), Some(2)),
+            Error::<Test>::EModeCategoryDoesNotExist
+        );
+
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), IBTC, unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), IBTC));
+        assert_noop!(
+            Loans::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)),
+            Error::<Test>::AssetNotInEModeCategory
+        );
+    })
+}
+
+#[test]
+fn emode_restricts_borrows_and_collateral_to_category() {
+    new_test_ext().execute_with(|| {
+        setup_dot_ksm_category();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), IBTC, unit(200)));
+        assert_ok!(Loans::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), DOT));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), IBTC, unit(200)));
+        assert_noop!(
+            Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), IBTC),
+            Error::<Test>::AssetNotInEModeCategory
+        );
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), IBTC, unit(10)),
+            Error::<Test>::AssetNotInEModeCategory
+        );
+    })
+}
+
+#[test]
+fn leaving_emode_requires_sufficient_liquidity() {
+    new_test_ext().execute_with(|| {
+        setup_dot_ksm_category();
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+        assert_ok!(Loans::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), DOT));
+
+        // Only possible thanks to the higher collateral factor of the category
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(150)));
+
+        assert_noop!(
+            Loans::set_account_emode(RuntimeOrigin::signed(ALICE), None),
+            Error::<Test>::InsufficientLiquidity
+        );
+
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+        assert_ok!(Loans::set_account_emode(RuntimeOrigin::signed(ALICE), None));
+        assert_eq!(Loans::account_emode(&ALICE), None);
+    })
+}
@@ -0,0 +1,217 @@
+use super::*;
+use crate::{mock::*, Error};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn remark_call() -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+}
+
+fn transfer_call(dest: AccountId, amount: Balance) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Tokens(orml_tokens::Call::transfer {
+        dest,
+        currency_id: DOT,
+        amount,
+    }))
+}
+
+fn setup_flash_loans() {
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+    assert_ok!(Loans::set_flash_loan_fee(
+        RuntimeOrigin::root(),
+        Permill::from_percent(1)
+    ));
+}
+
+#[test]
+fn set_flash_loan_fee_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_flash_loan_fee(RuntimeOrigin::signed(ALICE), Permill::from_percent(1)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Loans::set_flash_loan_fee(RuntimeOrigin::root(), Permill::one()),
+            Error::<Test>::InvalidFactor
+        );
+        assert_ok!(Loans::set_flash_loan_fee(
+            RuntimeOrigin::root(),
+            Permill::from_percent(1)
+        ));
+        assert_eq!(Loans::flash_loan_fee(), Permill::from_percent(1));
+    })
+}
+
+#[test]
+fn flash_loan_fee_goes_to_reserves() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+        let pool_balance = <Tokens as MultiCurrency<_>>::free_balance(DOT, &Loans::account_id());
+
+        assert_ok!(Loans::flash_loan(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            unit(100),
+            remark_call()
+        ));
+
+        assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(DOT, &BOB), unit(999));
+        assert_eq!(
+            <Tokens as MultiCurrency<_>>::free_balance(DOT, &Loans::account_id()),
+            pool_balance + unit(1)
+        );
+        assert_eq!(Loans::total_reserves(DOT).amount(), unit(1));
+        assert!(crate::FlashLoanInProgress::<Test>::get().is_none());
+    })
+}
+
+#[test]
+fn flash_loan_can_use_borrowed_funds() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+
+        // Bob only holds 1000 DOT, so minting 1100 DOT is only possible with the borrowed funds
+        let call = Box::new(RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: vec![
+                RuntimeCall::Loans(crate::Call::mint {
+                    asset_id: DOT,
+                    mint_amount: unit(1100),
+                }),
+                RuntimeCall::Loans(crate::Call::redeem {
+                    asset_id: DOT,
+                    redeem_amount: unit(1100),
+                }),
+            ],
+        }));
+        assert_ok!(Loans::flash_loan(RuntimeOrigin::signed(BOB), DOT, unit(100), call));
+        assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(DOT, &BOB), unit(999));
+        assert_eq!(Loans::total_reserves(DOT).amount(), unit(1));
+    })
+}
+
+#[test]
+fn flash_loan_does_not_move_exchange_rate() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+        let exchange_rate = Loans::exchange_rate_stored(DOT).unwrap();
+
+        // Bob mints with half of the market's cash lent out. Had the loan been taken out of the
+        // cash, the exchange rate would have halved and Bob received twice the lend tokens.
+        let call = Box::new(RuntimeCall::Loans(crate::Call::mint {
+            asset_id: DOT,
+            mint_amount: unit(100),
+        }));
+        assert_ok!(Loans::flash_loan(RuntimeOrigin::signed(BOB), DOT, unit(100), call));
+        assert_eq!(Loans::exchange_rate_stored(DOT).unwrap(), exchange_rate);
+        assert_eq!(
+            <Tokens as MultiCurrency<_>>::free_balance(LEND_DOT, &BOB),
+            Loans::calc_collateral_amount(unit(100), exchange_rate).unwrap()
+        );
+
+        // redeeming after the loan only returns the minted amount
+        assert_ok!(Loans::redeem_all(RuntimeOrigin::signed(BOB), DOT));
+        assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(DOT, &BOB), unit(999));
+    })
+}
+
+#[test]
+fn lend_token_exchange_rate_cannot_be_moved_in_a_flash_loan() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+        let exchange_rate = Loans::lend_token_exchange_rate(LEND_DOT).unwrap();
+        assert_eq!(exchange_rate, Loans::exchange_rate_stored(DOT).unwrap());
+
+        // Donating the borrowed funds to the market raises the exchange rate derived from its cash
+        assert_ok!(Loans::flash_loan(
+            RuntimeOrigin::signed(BOB),
+            DOT,
+            unit(100),
+            transfer_call(Loans::account_id(), unit(100))
+        ));
+        assert!(Loans::exchange_rate_stored(DOT).unwrap() > exchange_rate);
+        assert_eq!(Loans::lend_token_exchange_rate(LEND_DOT), Some(exchange_rate));
+
+        // No rate is provided while a flash loan is in progress
+        crate::FlashLoanInProgress::<Test>::put((DOT, unit(100)));
+        assert_eq!(Loans::lend_token_exchange_rate(LEND_DOT), None);
+    })
+}
+
+#[test]
+fn flash_loan_reverts_without_repayment() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+
+        // Bob sends away everything, including the borrowed funds, and cannot repay
+        assert_noop!(
+            Loans::flash_loan(
+                RuntimeOrigin::signed(BOB),
+                DOT,
+                unit(100),
+                transfer_call(CHARLIE, unit(1100))
+            ),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+
+        // Bob can repay the principal but not the fee
+        assert_noop!(
+            Loans::flash_loan(
+                RuntimeOrigin::signed(BOB),
+                DOT,
+                unit(100),
+                transfer_call(CHARLIE, unit(1000))
+            ),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+    })
+}
+
+#[test]
+fn flash_loan_fails_with_failing_call() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+
+        assert_noop!(
+            Loans::flash_loan(
+                RuntimeOrigin::signed(BOB),
+                DOT,
+                unit(100),
+                transfer_call(CHARLIE, unit(2000))
+            ),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+    })
+}
+
+#[test]
+fn flash_loans_cannot_be_nested() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+
+        let nested = Box::new(RuntimeCall::Loans(crate::Call::flash_loan {
+            asset_id: DOT,
+            amount: unit(50),
+            call: remark_call(),
+        }));
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(BOB), DOT, unit(100), nested),
+            Error::<Test>::FlashLoanInProgress
+        );
+    })
+}
+
+#[test]
+fn flash_loan_is_limited_by_cash() {
+    new_test_ext().execute_with(|| {
+        setup_flash_loans();
+
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(BOB), DOT, unit(201), remark_call()),
+            Error::<Test>::InsufficientCash
+        );
+        assert_noop!(
+            Loans::flash_loan(RuntimeOrigin::signed(BOB), DOT, 0, remark_call()),
+            Error::<Test>::InvalidAmount
+        );
+    })
+}
@@ -1,6 +1,6 @@
-use crate::{mock::*, tests::Loans, Markets};
+use crate::{mock::*, tests::Loans, AdaptiveModel, Error, InterestRateModel, Markets};
 use currency::Amount;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use mocktopus::mocking::Mockable;
 use primitives::{CurrencyId::Token, Moment, Rate, Ratio, DOT, KSM};
 use sp_runtime::{
@@ -355,3 +355,122 @@ fn a_market_can_only_accrue_interest_once_in_a_block() {
         assert_eq!(Loans::borrow_index(Token(DOT)), Rate::from_inner(1000000003805175038),);
     })
 }
+
+#[test]
+fn rate_model_transition_interpolates_over_blocks() {
+    new_test_ext().execute_with(|| {
+        let new_model = InterestRateModel::new_curve_model(Rate::saturating_from_rational(6, 100));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(100)));
+        System::set_block_number(10);
+        assert_ok!(Loans::schedule_rate_model_transition(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            new_model,
+            100
+        ));
+        assert_noop!(
+            Loans::schedule_rate_model_transition(RuntimeOrigin::root(), Token(DOT), new_model, 100),
+            Error::<Test>::RateModelTransitionInProgress
+        );
+
+        // Without borrows, the old model yields its base rate of 2%
+        let (borrow_rate, ..) = Loans::get_market_status(Token(DOT)).unwrap();
+        assert_eq!(borrow_rate, Rate::saturating_from_rational(2, 100));
+
+        System::set_block_number(60);
+        let (borrow_rate, ..) = Loans::get_market_status(Token(DOT)).unwrap();
+        assert_eq!(borrow_rate, Rate::saturating_from_rational(4, 100));
+
+        System::set_block_number(110);
+        TimestampPallet::set_timestamp(6000 * 110);
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(100)));
+        let (borrow_rate, ..) = Loans::get_market_status(Token(DOT)).unwrap();
+        assert_eq!(borrow_rate, Rate::saturating_from_rational(6, 100));
+        assert_eq!(Loans::market(Token(DOT)).unwrap().rate_model, new_model);
+        assert_eq!(Loans::rate_model_transition(Token(DOT)), None);
+    })
+}
+
+#[test]
+fn update_rate_model_cancels_transition() {
+    new_test_ext().execute_with(|| {
+        let new_model = InterestRateModel::new_curve_model(Rate::saturating_from_rational(6, 100));
+        assert_ok!(Loans::schedule_rate_model_transition(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            new_model,
+            100
+        ));
+        assert_ok!(Loans::update_rate_model(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            InterestRateModel::default()
+        ));
+        assert_eq!(Loans::rate_model_transition(Token(DOT)), None);
+        assert_eq!(
+            Loans::market(Token(DOT)).unwrap().rate_model,
+            InterestRateModel::default()
+        );
+    })
+}
+
+#[test]
+fn adaptive_rate_model_adjusts_when_interest_accrues() {
+    new_test_ext().execute_with(|| {
+        let model = AdaptiveModel {
+            target_utilization: Ratio::from_percent(80),
+            rate_at_target: Rate::saturating_from_rational(4, 100),
+            min_rate_at_target: Rate::saturating_from_rational(1, 100),
+            max_rate_at_target: Rate::saturating_from_rational(20, 100),
+            adjustment_speed: Rate::saturating_from_integer(10),
+            curve_steepness: Rate::saturating_from_integer(4),
+        };
+        assert_ok!(Loans::update_rate_model(
+            RuntimeOrigin::root(),
+            Token(DOT),
+            InterestRateModel::Adaptive(model)
+        ));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+
+        // Utilization is far below the target, so the rate at target goes down
+        TimestampPallet::set_timestamp(12000 * 1000);
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(100)));
+        let rate_at_target = match Loans::market(Token(DOT)).unwrap().rate_model {
+            InterestRateModel::Adaptive(adjusted) => adjusted.rate_at_target,
+            _ => unreachable!(),
+        };
+        assert!(rate_at_target < model.rate_at_target);
+        assert!(rate_at_target > model.min_rate_at_target);
+    })
+}
+
+#[test]
+fn preview_rate_model_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), Token(DOT), unit(200)));
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), Token(DOT)));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), Token(DOT), unit(100)));
+
+        // 50% utilization: 0.5^9 + 2%
+        let model = InterestRateModel::new_curve_model(Rate::saturating_from_rational(2, 100));
+        let (borrow_rate, supply_rate) = Loans::preview_rate_model(Token(DOT), model).unwrap();
+        assert_eq!(borrow_rate, Rate::from_inner(21_953_125_000_000_000));
+        assert_eq!(
+            supply_rate,
+            InterestRateModel::get_supply_rate(borrow_rate, Ratio::from_percent(50), Ratio::from_percent(15))
+        );
+        // The market itself is unaffected
+        assert_eq!(
+            Loans::market(Token(DOT)).unwrap().rate_model,
+            market_mock(LEND_DOT).rate_model
+        );
+
+        assert_noop!(
+            Loans::preview_rate_model(
+                Token(DOT),
+                InterestRateModel::new_curve_model(Rate::saturating_from_rational(20, 100))
+            ),
+            Error::<Test>::InvalidRateModelParam
+        );
+    })
+}
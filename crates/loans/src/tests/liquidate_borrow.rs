@@ -1,7 +1,7 @@
 use crate::{
     mock::{
-        new_test_ext, with_price, CurrencyConvert, Loans, RuntimeOrigin, Test, Tokens, _run_to_block, market_mock,
-        new_test_ext_no_markets, RuntimeCall, ALICE, BOB, DEFAULT_WRAPPED_CURRENCY, LEND_KBTC, LEND_KSM,
+        new_test_ext, with_price, AccountId, CurrencyConvert, Loans, RuntimeOrigin, Test, Tokens, _run_to_block,
+        market_mock, new_test_ext_no_markets, RuntimeCall, ALICE, BOB, DEFAULT_WRAPPED_CURRENCY, LEND_KBTC, LEND_KSM,
     },
     tests::unit,
     Amount, Error, Market, MarketState,
@@ -10,11 +10,12 @@ use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable};
 use mocktopus::mocking::Mockable;
 use orml_traits::MultiCurrency;
 use primitives::{
+    loans::{LiquidationCandidate, LiquidationOpportunity, LoanPosition},
     Balance,
     CurrencyId::{self, Token},
     Rate, Ratio, DOT as DOT_CURRENCY, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
 };
-use sp_runtime::FixedPointNumber;
+use sp_runtime::{FixedPointNumber, FixedU128};
 use traits::{LoansApi, OracleApi};
 
 const DOT: CurrencyId = Token(DOT_CURRENCY);
@@ -494,6 +495,123 @@ fn liquidator_must_not_be_borrower() {
     })
 }
 
+#[test]
+fn liquidation_candidates_lists_accounts_in_shortfall() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        // Healthy borrowers are not listed
+        assert_eq!(candidates(FixedU128::one()), vec![]);
+
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        // Here the balance sheet of Alice is:
+        // Collateral   Loans
+        // KBTC $110    KSM $200
+        let candidates = candidates(FixedU128::one());
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert_eq!(candidate.account_id, ALICE);
+        assert_eq!(candidate.health_factor, FixedU128::from_float(0.55));
+        assert_eq!(
+            candidate.borrows,
+            vec![LoanPosition {
+                currency_id: KSM,
+                amount: unit(100)
+            }]
+        );
+        assert_eq!(
+            candidate.collateral,
+            vec![LoanPosition {
+                currency_id: KBTC,
+                amount: unit(200)
+            }]
+        );
+        // Repaying half of the $200 borrow seizes $110 of KBTC
+        assert_eq!(
+            candidate.liquidations,
+            vec![LiquidationOpportunity {
+                liquidation_currency_id: KSM,
+                max_repay_amount: unit(50),
+                collateral_currency_id: KBTC,
+                seized_collateral_amount: unit(110),
+            }]
+        );
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            candidate.liquidations[0].max_repay_amount,
+            KBTC
+        ));
+    })
+}
+
+#[test]
+fn liquidation_candidates_respects_max_health_factor() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        // Alice's health factor is 110 / 100
+        assert_eq!(candidates(FixedU128::one()), vec![]);
+        assert_eq!(candidates(FixedU128::from_float(1.1)), vec![]);
+
+        let candidates = candidates(FixedU128::from_float(1.2));
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].health_factor, FixedU128::from_float(1.1));
+        // At-risk accounts that can't be liquidated yet have no liquidations
+        assert!(candidates[0].liquidations.is_empty());
+    })
+}
+
+#[test]
+fn liquidation_candidates_are_paginated() {
+    new_test_ext().execute_with(|| {
+        initial_setup();
+        alice_borrows_100_ksm();
+        assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(BOB), KSM));
+        assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(BOB), DOT, unit(50)));
+
+        // Bob has borrows in two markets, but is listed once
+        assert_ok!(Loans::borrow(RuntimeOrigin::signed(BOB), KSM, unit(10)));
+
+        let max_health_factor = FixedU128::from_u32(10);
+        let page = |start_key, limit| Loans::get_liquidation_candidates(max_health_factor, start_key, limit).unwrap();
+        let mut accounts = page(None, 10)
+            .candidates
+            .into_iter()
+            .map(|candidate| candidate.account_id)
+            .collect::<Vec<_>>();
+        accounts.sort();
+        assert_eq!(accounts, vec![ALICE, BOB]);
+        assert_eq!(page(None, 10).next_start_key, None);
+        assert_eq!(page(None, 0).candidates, vec![]);
+
+        // Visiting one borrow per page continues after the borrow visited last
+        let mut paged_accounts = Vec::new();
+        let mut start_key = None;
+        for pages in 1.. {
+            let result = page(start_key, 1);
+            assert!(result.candidates.len() <= 1);
+            paged_accounts.extend(result.candidates.into_iter().map(|candidate| candidate.account_id));
+            start_key = result.next_start_key;
+            if start_key.is_none() {
+                // Alice's and Bob's borrows
+                assert_eq!(pages, 3);
+                break;
+            }
+        }
+        paged_accounts.sort();
+        assert_eq!(paged_accounts, vec![ALICE, BOB]);
+    })
+}
+
+fn candidates(max_health_factor: FixedU128) -> Vec<LiquidationCandidate<AccountId, CurrencyId, Balance, FixedU128>> {
+    Loans::get_liquidation_candidates(max_health_factor, None, 10)
+        .unwrap()
+        .candidates
+}
+
 fn alice_borrows_100_ksm() {
     assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
 }
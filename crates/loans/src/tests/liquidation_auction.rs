@@ -0,0 +1,284 @@
+use crate::{
+    mock::{
+        new_test_ext, with_price, CurrencyConvert, Loans, RuntimeOrigin, System, Test, ALICE, BOB, CHARLIE, LEND_KBTC,
+    },
+    tests::unit,
+    AccountDeposits, Error, LiquidationAuctionConfig, LiquidationAuctions,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use mocktopus::mocking::Mockable;
+use primitives::{
+    CurrencyId::{self, ForeignAsset, Token},
+    Rate, DOT as DOT_CURRENCY, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
+};
+use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+
+const DOT: CurrencyId = Token(DOT_CURRENCY);
+const KSM: CurrencyId = Token(KSM_CURRENCY);
+const KBTC: CurrencyId = Token(KBTC_CURRENCY);
+
+fn auction_config() -> LiquidationAuctionConfig<u64> {
+    LiquidationAuctionConfig {
+        starting_incentive: Rate::saturating_from_rational(102, 100),
+        max_incentive: Rate::saturating_from_rational(110, 100),
+        duration: 100,
+    }
+}
+
+fn alice_borrows_100_ksm_with_auctions() {
+    System::set_block_number(1);
+    assert_ok!(Loans::set_liquidation_auction_config(
+        RuntimeOrigin::root(),
+        KSM,
+        Some(auction_config())
+    ));
+    // Bob deposits 200 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    // Alice deposits 200 KBTC as collateral and borrows 100 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+    assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+}
+
+#[test]
+fn liquidation_auction_config_is_validated() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Loans::set_liquidation_auction_config(RuntimeOrigin::signed(ALICE), KSM, Some(auction_config())),
+            BadOrigin
+        );
+        assert_noop!(
+            Loans::set_liquidation_auction_config(
+                RuntimeOrigin::root(),
+                ForeignAsset(987997280),
+                Some(auction_config())
+            ),
+            Error::<Test>::MarketDoesNotExist
+        );
+        let invalid_configs = [
+            LiquidationAuctionConfig {
+                starting_incentive: Rate::saturating_from_rational(99, 100),
+                ..auction_config()
+            },
+            LiquidationAuctionConfig {
+                max_incentive: Rate::saturating_from_rational(101, 100),
+                ..auction_config()
+            },
+            LiquidationAuctionConfig {
+                duration: 0,
+                ..auction_config()
+            },
+        ];
+        for config in invalid_configs {
+            assert_noop!(
+                Loans::set_liquidation_auction_config(RuntimeOrigin::root(), KSM, Some(config)),
+                Error::<Test>::InvalidLiquidationAuctionConfig
+            );
+        }
+        assert_ok!(Loans::set_liquidation_auction_config(
+            RuntimeOrigin::root(),
+            KSM,
+            Some(auction_config())
+        ));
+        assert_eq!(Loans::liquidation_auction_config(KSM), Some(auction_config()));
+    })
+}
+
+#[test]
+fn liquidation_incentive_rises_while_auction_runs() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        // Markets without auctions pay their fixed incentive
+        assert_eq!(
+            Loans::current_liquidate_incentive(&ALICE, DOT),
+            Ok(Rate::saturating_from_rational(110, 100))
+        );
+        assert_noop!(
+            Loans::start_liquidation_auction(RuntimeOrigin::signed(CHARLIE), ALICE, DOT),
+            Error::<Test>::LiquidationAuctionNotEnabled
+        );
+        assert_noop!(
+            Loans::start_liquidation_auction(RuntimeOrigin::signed(CHARLIE), ALICE, KSM),
+            Error::<Test>::InsufficientShortfall
+        );
+
+        // Collateral   Loans
+        // KBTC $110    KSM $200
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_eq!(
+            Loans::current_liquidate_incentive(&ALICE, KSM),
+            Ok(Rate::saturating_from_rational(102, 100))
+        );
+        assert_ok!(Loans::start_liquidation_auction(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM
+        ));
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), Some(1));
+        assert_noop!(
+            Loans::start_liquidation_auction(RuntimeOrigin::signed(CHARLIE), ALICE, KSM),
+            Error::<Test>::LiquidationAuctionAlreadyStarted
+        );
+
+        System::set_block_number(51);
+        assert_eq!(
+            Loans::current_liquidate_incentive(&ALICE, KSM),
+            Ok(Rate::saturating_from_rational(106, 100))
+        );
+        System::set_block_number(1001);
+        assert_eq!(
+            Loans::current_liquidate_incentive(&ALICE, KSM),
+            Ok(Rate::saturating_from_rational(110, 100))
+        );
+    })
+}
+
+#[test]
+fn liquidation_seizes_collateral_at_the_auction_incentive() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_ok!(Loans::start_liquidation_auction(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM
+        ));
+        System::set_block_number(51);
+
+        // Repaying $100 of debt seizes $106 of collateral
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+        // 1 KBTC is worth 50 lend tokens at the initial exchange rate
+        assert_eq!(AccountDeposits::<Test>::get(LEND_KBTC, ALICE), unit(94 * 50));
+        // Alice is still in shortfall, so the auction continues
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), Some(1));
+    })
+}
+
+#[test]
+fn liquidation_starts_auction_at_starting_incentive() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        // Collateral   Loans
+        // KBTC $110    KSM $120
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, Rate::saturating_from_rational(12, 10)))));
+
+        assert_ok!(Loans::liquidate_borrow(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            KSM,
+            unit(50),
+            KBTC
+        ));
+        // Repaying $60 of debt seized $61.2 of collateral
+        assert_eq!(
+            AccountDeposits::<Test>::get(LEND_KBTC, ALICE),
+            unit(200 * 50) - unit(612 * 5)
+        );
+        // Alice is no longer in shortfall, so the auction has ended
+        assert!(!LiquidationAuctions::<Test>::contains_key(KSM, ALICE));
+    })
+}
+
+#[test]
+fn stale_liquidation_auction_can_be_closed() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        assert_noop!(
+            Loans::close_liquidation_auction(RuntimeOrigin::signed(CHARLIE), ALICE, KSM),
+            Error::<Test>::LiquidationAuctionNotFound
+        );
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_ok!(Loans::start_liquidation_auction(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM
+        ));
+        assert_noop!(
+            Loans::close_liquidation_auction(RuntimeOrigin::signed(CHARLIE), ALICE, KSM),
+            Error::<Test>::BorrowerIsLiquidatable
+        );
+
+        // The price recovers before anyone liquidates Alice
+        CurrencyConvert::convert.mock_safe(with_price(None));
+        assert_ok!(Loans::close_liquidation_auction(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM
+        ));
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), None);
+    })
+}
+
+#[test]
+fn disabling_liquidation_auctions_closes_running_auctions() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_ok!(Loans::start_liquidation_auction(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            KSM
+        ));
+
+        assert_ok!(Loans::set_liquidation_auction_config(RuntimeOrigin::root(), KSM, None));
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), None);
+        assert_eq!(
+            Loans::current_liquidate_incentive(&ALICE, KSM),
+            Ok(Rate::saturating_from_rational(110, 100))
+        );
+    })
+}
+
+#[test]
+fn liquidation_auction_ends_when_shortfall_is_resolved() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        // Alice's position changes while she is in shortfall, which starts the auction
+        assert_ok!(Loans::repay_borrow(RuntimeOrigin::signed(ALICE), KSM, unit(1)));
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), Some(1));
+
+        // Collateral   Loans
+        // KBTC $110    KSM $100
+        System::set_block_number(51);
+        assert_ok!(Loans::repay_borrow(RuntimeOrigin::signed(ALICE), KSM, unit(49)));
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), None);
+    })
+}
+
+#[test]
+fn on_idle_starts_and_resets_liquidation_auctions() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_100_ksm_with_auctions();
+        // Not enough weight to visit a single borrow
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        assert_eq!(Loans::on_idle(1, Weight::zero()), Weight::zero());
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), None);
+
+        // The price drop is picked up without Alice's position changing
+        Loans::on_idle(1, Weight::MAX);
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), Some(1));
+
+        // The price recovers, so the auction ends
+        System::set_block_number(51);
+        CurrencyConvert::convert.mock_safe(with_price(None));
+        Loans::on_idle(51, Weight::MAX);
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), None);
+
+        // A later shortfall is auctioned from the starting incentive again
+        System::set_block_number(1001);
+        CurrencyConvert::convert.mock_safe(with_price(Some((KSM, 2.into()))));
+        Loans::on_idle(1001, Weight::MAX);
+        assert_eq!(Loans::liquidation_auction_start(KSM, ALICE), Some(1001));
+        assert_eq!(
+            Loans::current_liquidate_incentive(&ALICE, KSM),
+            Ok(Rate::saturating_from_rational(102, 100))
+        );
+    })
+}
@@ -0,0 +1,171 @@
+use crate::{
+    mock::{new_test_ext, Loans, RuntimeOrigin, System, Test, ALICE, BOB, LEND_KSM},
+    tests::unit,
+    BadDebt, BorrowAllowances, BorrowSnapshot, DelegatedBorrows, Error, LiquidationAuctions, MarketDeprecations,
+    MarketState, Markets, RewardBorrowState, RewardBorrowerIndex, RewardSupplierIndex, RewardSupplyState,
+    UnderlyingAssetId,
+};
+use frame_support::{assert_noop, assert_ok};
+use primitives::{
+    CurrencyId::{self, Token},
+    Rate, KBTC as KBTC_CURRENCY, KSM as KSM_CURRENCY,
+};
+use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+
+const KSM: CurrencyId = Token(KSM_CURRENCY);
+const KBTC: CurrencyId = Token(KBTC_CURRENCY);
+
+fn alice_borrows_50_ksm() {
+    System::set_block_number(1);
+    // Bob deposits 200 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+    // Alice deposits 200 KBTC as collateral and borrows 50 KSM
+    assert_ok!(Loans::mint(RuntimeOrigin::signed(ALICE), KBTC, unit(200)));
+    assert_ok!(Loans::deposit_all_collateral(RuntimeOrigin::signed(ALICE), KBTC));
+    assert_ok!(Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(50)));
+}
+
+fn alice_liquidity() -> u128 {
+    Loans::get_account_liquidity(&ALICE).unwrap().liquidity().amount()
+}
+
+#[test]
+fn deprecated_market_rejects_new_supply_and_borrows() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        assert_noop!(
+            Loans::deprecate_market(RuntimeOrigin::signed(ALICE), KSM, 100, None),
+            BadOrigin
+        );
+        assert_ok!(Loans::deprecate_market(RuntimeOrigin::root(), KSM, 100, None));
+        assert_eq!(Loans::market(KSM).unwrap().state, MarketState::Deprecated);
+        assert_noop!(
+            Loans::deprecate_market(RuntimeOrigin::root(), KSM, 100, None),
+            Error::<Test>::MarketDeprecated
+        );
+
+        assert_noop!(
+            Loans::mint(RuntimeOrigin::signed(BOB), KSM, unit(1)),
+            Error::<Test>::MarketDeprecated
+        );
+        assert_noop!(
+            Loans::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(1)),
+            Error::<Test>::MarketDeprecated
+        );
+    })
+}
+
+#[test]
+fn positions_can_be_closed_in_deprecated_market() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        assert_ok!(Loans::deprecate_market(RuntimeOrigin::root(), KSM, 100, None));
+
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+        assert!(Loans::current_borrow_balance(&ALICE, KSM).unwrap().is_zero());
+        assert_ok!(Loans::redeem_all(RuntimeOrigin::signed(BOB), KSM));
+        assert!(Loans::total_supply(KSM).unwrap().is_zero());
+    })
+}
+
+#[test]
+fn collateral_factor_of_deprecated_market_ramps_down() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        // 200 KBTC at a collateral factor of 50% allow borrowing $100
+        assert_eq!(alice_liquidity(), unit(50));
+        assert_ok!(Loans::deprecate_market(RuntimeOrigin::root(), KBTC, 100, None));
+
+        System::set_block_number(26);
+        assert_eq!(alice_liquidity(), unit(25));
+        System::set_block_number(51);
+        assert_eq!(alice_liquidity(), 0);
+
+        // The liquidation threshold is not affected, so Alice is not liquidatable yet
+        System::set_block_number(101);
+        assert_eq!(
+            Loans::get_account_liquidity(&ALICE).unwrap().shortfall().amount(),
+            unit(50)
+        );
+        assert_eq!(
+            Loans::get_account_liquidation_threshold_liquidity(&ALICE)
+                .unwrap()
+                .liquidity()
+                .amount(),
+            unit(60)
+        );
+    })
+}
+
+#[test]
+fn deprecated_market_charges_minimum_borrow_rate() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        let min_borrow_rate = Rate::saturating_from_rational(50, 100);
+        assert!(Loans::get_market_status(KSM).unwrap().0 < min_borrow_rate);
+
+        assert_ok!(Loans::deprecate_market(
+            RuntimeOrigin::root(),
+            KSM,
+            100,
+            Some(min_borrow_rate)
+        ));
+        assert_eq!(Loans::get_market_status(KSM).unwrap().0, min_borrow_rate);
+
+        // Reactivating the market cancels the deprecation
+        assert_ok!(Loans::activate_market(RuntimeOrigin::root(), KSM));
+        assert!(!MarketDeprecations::<Test>::contains_key(KSM));
+        assert!(Loans::get_market_status(KSM).unwrap().0 < min_borrow_rate);
+    })
+}
+
+#[test]
+fn empty_deprecated_market_can_be_removed() {
+    new_test_ext().execute_with(|| {
+        alice_borrows_50_ksm();
+        assert_ok!(Loans::approve_borrow_delegation(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            KSM,
+            unit(10)
+        ));
+        assert_noop!(
+            Loans::remove_market(RuntimeOrigin::root(), KSM),
+            Error::<Test>::MarketNotDeprecated
+        );
+        assert_ok!(Loans::deprecate_market(RuntimeOrigin::root(), KSM, 100, None));
+        assert_noop!(
+            Loans::remove_market(RuntimeOrigin::root(), KSM),
+            Error::<Test>::MarketNotEmpty
+        );
+
+        assert_ok!(Loans::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+        assert_ok!(Loans::redeem_all(RuntimeOrigin::signed(BOB), KSM));
+        // No interest has accrued, so there are no reserves to withdraw
+        assert!(Loans::total_reserves(KSM).is_zero());
+
+        // Leftovers of past delegated borrows, auctions and write-offs
+        DelegatedBorrows::<Test>::insert((KSM, ALICE), BOB, BorrowSnapshot::default());
+        LiquidationAuctions::<Test>::insert(KSM, ALICE, 1);
+        BadDebt::<Test>::insert(KSM, unit(1));
+
+        assert_ok!(Loans::remove_market(RuntimeOrigin::root(), KSM));
+        assert!(!Markets::<Test>::contains_key(KSM));
+        assert!(!MarketDeprecations::<Test>::contains_key(KSM));
+        // No state of the market is left behind
+        assert!(!RewardSupplyState::<Test>::contains_key(KSM));
+        assert!(!RewardBorrowState::<Test>::contains_key(KSM));
+        assert!(!BadDebt::<Test>::contains_key(KSM));
+        assert_eq!(RewardSupplierIndex::<Test>::iter_prefix(KSM).count(), 0);
+        assert_eq!(RewardBorrowerIndex::<Test>::iter_prefix(KSM).count(), 0);
+        assert_eq!(LiquidationAuctions::<Test>::iter_prefix(KSM).count(), 0);
+        assert_eq!(BorrowAllowances::<Test>::iter().count(), 0);
+        assert_eq!(DelegatedBorrows::<Test>::iter().count(), 0);
+        // The lend token of the removed market cannot be reused
+        assert_eq!(UnderlyingAssetId::<Test>::get(LEND_KSM), Some(KSM));
+        assert_eq!(
+            Loans::market(KSM).unwrap_err(),
+            Error::<Test>::MarketDoesNotExist.into()
+        );
+    })
+}
@@ -4,6 +4,10 @@ use currency::Amount;
 use frame_support::pallet_prelude::*;
 use primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
 use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedMul, One, Saturating, Zero},
+    FixedPointNumber, PerThing, SaturatedConversion,
+};
 
 // TODO: `cargo doc` crashes on this type, remove the `hidden` macro
 // when upgrading rustc in case that fixes it
@@ -75,6 +79,23 @@ pub struct EarnedSnapshot<Balance> {
     pub exchange_rate_prior: Rate,
 }
 
+/// Container for the interest and incentive rewards an account has accumulated in a market
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+pub struct EarningsSnapshot<Balance> {
+    /// Total interest earned on lend tokens, up to the most recent lend token balance change
+    pub supply_interest: Balance,
+    /// Total value lost by lend tokens through decreases of the exchange rate, up to the most
+    /// recent lend token balance change
+    pub supply_loss: Balance,
+    /// Total interest accrued on borrows, up to the most recent borrow balance change
+    pub borrow_interest: Balance,
+    /// Total incentive rewards distributed for supplying and borrowing
+    pub rewards_accrued: Balance,
+    /// Exchange rate as of the most recent lend token balance change, zero if the account never held
+    /// lend tokens of the market
+    pub exchange_rate_prior: Rate,
+}
+
 /// The current state of a market. For more information, see [Market].
 #[derive(
     serde::Deserialize,
@@ -95,6 +116,9 @@ pub enum MarketState {
     // Unclear why the `Supervision` state is required at all, since it's not used anywhere.
     // Could just reuse the `Pending` state to temporarily halt a market.
     Supervision,
+    /// The market is being wound down: supplying and borrowing are disabled, while
+    /// existing positions can still be repaid, redeemed and liquidated.
+    Deprecated,
 }
 
 /// Market.
@@ -140,6 +164,125 @@ pub struct Market<Balance> {
     pub lend_token_id: CurrencyId,
 }
 
+/// Identifier of an efficiency mode category.
+pub type EModeCategoryId = u8;
+
+/// Efficiency mode category.
+///
+/// Groups correlated assets, such as a currency and the lend token of that currency. Accounts that
+/// opt into the category can only borrow and post collateral in its assets, in exchange for using
+/// the category's collateral parameters instead of those of the individual markets.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct EModeCategory {
+    /// The secure collateral ratio for assets in the category
+    pub collateral_factor: Ratio,
+    /// The collateral ratio when a borrower in the category can be liquidated
+    pub liquidation_threshold: Ratio,
+}
+
+/// Dutch auction liquidation parameters of a market.
+///
+/// Once a borrower becomes liquidatable, the incentive paid for repaying their debt in the market starts
+/// at `starting_incentive` and rises linearly to `max_incentive` over `duration` blocks, replacing the
+/// fixed `liquidate_incentive` of the market.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct LiquidationAuctionConfig<BlockNumber> {
+    /// The liquidation incentive when the auction starts
+    pub starting_incentive: Rate,
+    /// The liquidation incentive once `duration` blocks have passed
+    pub max_incentive: Rate,
+    /// The number of blocks over which the incentive rises
+    pub duration: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> LiquidationAuctionConfig<BlockNumber> {
+    pub fn is_valid(&self) -> bool {
+        self.starting_incentive >= Rate::one()
+            && self.max_incentive >= self.starting_incentive
+            && !self.duration.is_zero()
+    }
+
+    /// Calculates the liquidation incentive of an auction that started `elapsed` blocks ago
+    pub fn incentive_at(&self, elapsed: BlockNumber) -> Option<Rate> {
+        if elapsed >= self.duration {
+            return Some(self.max_incentive);
+        }
+        let progress =
+            Rate::checked_from_rational(elapsed.saturated_into::<u128>(), self.duration.saturated_into::<u128>())?;
+        self.starting_incentive.checked_add(
+            &self
+                .max_incentive
+                .saturating_sub(self.starting_incentive)
+                .checked_mul(&progress)?,
+        )
+    }
+}
+
+/// The sunset schedule of a deprecated market. The collateral factor of the market decreases
+/// linearly to zero over `collateral_factor_ramp` blocks from `start`, and borrowers pay at least
+/// `min_borrow_rate` if it is set.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct MarketDeprecation<BlockNumber> {
+    /// The block at which the market was deprecated
+    pub start: BlockNumber,
+    /// The number of blocks over which the collateral factor decreases to zero
+    pub collateral_factor_ramp: BlockNumber,
+    /// The minimum borrow rate while the market is deprecated
+    pub min_borrow_rate: Option<Rate>,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> MarketDeprecation<BlockNumber> {
+    /// The share of the collateral factor that is left at block `now`
+    pub fn collateral_factor_share(&self, now: BlockNumber) -> Ratio {
+        let elapsed = now.saturating_sub(self.start);
+        if elapsed >= self.collateral_factor_ramp {
+            return Ratio::zero();
+        }
+        Ratio::from_rational(
+            self.collateral_factor_ramp
+                .saturating_sub(elapsed)
+                .saturated_into::<u128>(),
+            self.collateral_factor_ramp.saturated_into::<u128>(),
+        )
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default, MaxEncodedLen)]
 pub struct RewardMarketState<BlockNumber, Balance> {
     pub index: Balance,
@@ -0,0 +1,16 @@
+//! Runtime API definition for the multi transaction payment pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    pub trait MultiTransactionPaymentApi<CurrencyId, Balance> where
+        CurrencyId: Codec,
+        Balance: Codec,
+    {
+        /// The partial fee of `uxt` paid in `currency_id`, which is swapped into the native
+        /// currency along the cheapest route. `None` if the currency can't be swapped.
+        fn query_fee(uxt: Block::Extrinsic, len: u32, currency_id: CurrencyId) -> Option<Balance>;
+    }
+}
@@ -0,0 +1,77 @@
+//! RPC interface for the multi transaction payment pallet.
+
+use codec::{Codec, Decode};
+use jsonrpsee::{
+    core::{Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+pub use multi_transaction_payment_rpc_runtime_api::MultiTransactionPaymentApi as MultiTransactionPaymentRuntimeApi;
+
+#[rpc(client, server)]
+pub trait MultiTransactionPaymentApi<BlockHash, CurrencyId, Balance> {
+    /// The partial fee of the encoded extrinsic when paid in `currency_id`
+    #[method(name = "multiTransactionPayment_queryFee")]
+    fn query_fee(&self, encoded_xt: Bytes, currency_id: CurrencyId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+}
+
+fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        ErrorCode::InternalError.code(),
+        message.to_string(),
+        None::<()>,
+    )))
+}
+
+/// A struct that implements the [`MultiTransactionPaymentApi`].
+pub struct MultiTransactionPayment<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> MultiTransactionPayment<C, B> {
+    /// Create new `MultiTransactionPayment` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        MultiTransactionPayment {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, CurrencyId, Balance> MultiTransactionPaymentApiServer<<Block as BlockT>::Hash, CurrencyId, Balance>
+    for MultiTransactionPayment<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: MultiTransactionPaymentRuntimeApi<Block, CurrencyId, Balance>,
+    CurrencyId: Codec,
+    Balance: Codec + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+    fn query_fee(
+        &self,
+        encoded_xt: Bytes,
+        currency_id: CurrencyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let encoded_len = encoded_xt.len() as u32;
+        let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt)
+            .map_err(|err| internal_err(format!("Unable to decode the extrinsic: {:?}", err)))?;
+
+        api.query_fee(at, uxt, encoded_len, currency_id)
+            .map_err(|err| internal_err(format!("Unable to query the fee: {:?}", err)))?
+            .ok_or_else(|| internal_err("The fee can't be paid in this currency"))?
+            .try_into()
+            .map_err(|_| internal_err("The fee doesn't fit into an rpc balance"))
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+use crate::Pallet as MultiTransactionPayment;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+#[benchmarks]
+pub mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_fee_currency() {
+        let caller: T::AccountId = whitelisted_caller();
+        let preference = FeeCurrencyPreference {
+            currency_id: T::GetRelayChainCurrencyId::get(),
+            max_price: FixedU128::from(2),
+        };
+
+        #[extrinsic_call]
+        set_fee_currency(RawOrigin::Signed(caller.clone()), Some(preference));
+
+        assert_eq!(MultiTransactionPayment::<T>::fee_currency(caller), Some(preference));
+    }
+
+    impl_benchmark_test_suite!(MultiTransactionPayment, crate::mock::new_test_ext(), crate::mock::Test);
+}
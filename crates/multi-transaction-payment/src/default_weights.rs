@@ -0,0 +1,70 @@
+
+//! Autogenerated weights for multi_transaction_payment
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-07-31, STEPS: `10`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: ``, CPU: `Intel(R) Core(TM) i7-6700 CPU @ 3.40GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kintsugi-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/debug/interbtc-parachain
+// benchmark
+// pallet
+// --pallet
+// multi-transaction-payment
+// --extrinsic
+// *
+// --chain
+// kintsugi-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --steps
+// 10
+// --repeat
+// 1
+// --output
+// ./crates/multi-transaction-payment/src/default_weights.rs
+// --template
+// .deploy/default-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for multi_transaction_payment.
+pub trait WeightInfo {
+	fn set_fee_currency() -> Weight;
+}
+
+/// Weights for multi_transaction_payment using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: MultiTransactionPayment FeeCurrency (r:0 w:1)
+	/// Proof Skipped: MultiTransactionPayment FeeCurrency (max_values: None, max_size: None, mode: Measured)
+	fn set_fee_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_431_000 picoseconds.
+		Weight::from_parts(17_012_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: MultiTransactionPayment FeeCurrency (r:0 w:1)
+	/// Proof Skipped: MultiTransactionPayment FeeCurrency (max_values: None, max_size: None, mode: Measured)
+	fn set_fee_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_431_000 picoseconds.
+		Weight::from_parts(17_012_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
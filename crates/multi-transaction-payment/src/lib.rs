@@ -2,24 +2,46 @@
 #![cfg_attr(test, feature(proc_macro_hygiene))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use dex_general::{ExportDexGeneral, WeightInfo};
+use codec::{Decode, Encode, MaxEncodedLen};
+use dex_general::{ExportDexGeneral, WeightInfo as DexGeneralWeightInfo};
 use frame_support::{
+    dispatch::{DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
     ensure,
-    traits::{Currency, Get, IsSubType, OnUnbalanced},
+    storage::with_storage_layer,
+    traits::{Currency, ExistenceRequirement, Get, IsSubType, OnUnbalanced, WithdrawReasons},
+    weights::Weight,
 };
 pub use pallet::*;
 use pallet_transaction_payment::OnChargeTransaction;
+use scale_info::TypeInfo;
 use sp_runtime::{
-    traits::{DispatchInfoOf, PostDispatchInfoOf, Zero},
+    traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, UniqueSaturatedInto, Zero},
     transaction_validity::{InvalidTransaction, TransactionValidityError},
+    FixedPointNumber, FixedU128, RuntimeDebug,
 };
 use sp_std::{boxed::Box, vec::Vec};
+use traits::SwapApi;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod default_weights;
+pub use default_weights::WeightInfo;
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+/// The currency in which an account pays its fees by default, with the highest price it accepts.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeCurrencyPreference<CurrencyId> {
+    pub currency_id: CurrencyId,
+    /// The most of `currency_id` paid per unit of the native currency, in the smallest units
+    /// of both, which bounds the slippage of the fee swap.
+    pub max_price: FixedU128,
+}
+
 type CallOf<T> = <T as Config>::RuntimeCall;
 type SubstrateDefaultPayment<T> =
     pallet_transaction_payment::CurrencyAdapter<<T as Config>::Currency, <T as Config>::OnUnbalanced>;
@@ -28,14 +50,16 @@ type SubstrateDefaultPayment<T> =
 pub mod pallet {
     use super::*;
     use frame_support::{
-        dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+        dispatch::{DispatchInfo, GetDispatchInfo},
         pallet_prelude::*,
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::Dispatchable;
 
     #[pallet::config]
     pub trait Config: frame_system::Config<RuntimeCall = CallOf<Self>> + currency::Config<Balance = u128> {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
         /// The aggregated call type.
         type RuntimeCall: Parameter
             // + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo, Info = DispatchInfo>
@@ -46,6 +70,10 @@ pub mod pallet {
         /// dex api
         type Dex: ExportDexGeneral<Self::AccountId, Self::CurrencyId>;
 
+        /// Swaps fees paid in other currencies along the cheapest route into the native currency,
+        /// searched among the hops set by governance
+        type FeeSwap: SwapApi<Self::AccountId, Self::CurrencyId, <Self as currency::Config>::Balance>;
+
         /// The currency trait.
         type Currency: Currency<Self::AccountId, Balance = u128>;
 
@@ -54,14 +82,43 @@ pub mod pallet {
 
         /// weights of dex operations
         type DexWeightInfo: dex_general::WeightInfo;
+
+        /// Weight information for the extrinsics in this module.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The default fee currency of an account was set, or removed to pay in the native currency.
+        FeeCurrencySet {
+            account_id: T::AccountId,
+            preference: Option<FeeCurrencyPreference<T::CurrencyId>>,
+        },
+        /// The fee could not be swapped from the default fee currency of an account, so it was
+        /// paid in the native currency.
+        FeeSwapFailed {
+            account_id: T::AccountId,
+            currency_id: T::CurrencyId,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        /// Fees are paid in the native currency without setting it as the fee currency.
+        NativeFeeCurrency,
+    }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    /// The currency in which an account pays the fees of its transactions by default. The fees
+    /// are paid in the native currency if they can't be swapped at the accepted price.
+    #[pallet::storage]
+    #[pallet::getter(fn fee_currency)]
+    pub type FeeCurrency<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FeeCurrencyPreference<T::CurrencyId>, OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -78,29 +135,118 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             // Note: no swaps are being done here - they have already been done in the `pre_dispatch`
             // of the SignedExtension, which calls the `OnChargeTransaction::withdraw_fee` implemented below.
+            Self::dispatch_with_swap_weight(
+                origin,
+                call,
+                T::DexWeightInfo::swap_assets_for_exact_assets(_path.len() as u32),
+            )
+        }
 
-            let mut ret = call.dispatch(origin);
-
-            // modify any returned weight overrides. Note that we pass-through the `Pays` unmodified.
-            let modify_weight = |x: &mut PostDispatchInfo| {
-                x.actual_weight = x
-                    .actual_weight
-                    .map(|x| x.saturating_add(T::DexWeightInfo::swap_assets_for_exact_assets(_path.len() as u32)));
-            };
-            match ret {
-                Ok(ref mut info) => {
-                    modify_weight(info);
-                }
-                Err(ref mut err) => {
-                    modify_weight(&mut err.post_info);
-                }
-            };
+        /// Dispatch `call`, paying its fee with at most `_amount_in_max` of `_currency_id`, swapped
+        /// into the native currency along the cheapest route.
+        #[pallet::call_index(1)]
+        #[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::FeeSwap::swap_for_exact_weight().saturating_add(dispatch_info.weight), dispatch_info.class,)
+		})]
+        #[frame_support::transactional]
+        pub fn with_fee_currency(
+            origin: OriginFor<T>,
+            _currency_id: T::CurrencyId,
+            _amount_in_max: <T as currency::Config>::Balance,
+            call: Box<CallOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            // Note: like for `with_fee_swap_path`, the swap was done when withdrawing the fee
+            Self::dispatch_with_swap_weight(origin, call, T::FeeSwap::swap_for_exact_weight())
+        }
+
+        /// Set the currency in which the fees of the caller are paid unless the transaction
+        /// names one, together with the highest price paid for the fees, or remove it to pay in
+        /// the native currency.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_fee_currency())]
+        #[frame_support::transactional]
+        pub fn set_fee_currency(
+            origin: OriginFor<T>,
+            preference: Option<FeeCurrencyPreference<T::CurrencyId>>,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+            ensure!(
+                preference.map(|preference| preference.currency_id) != Some(T::GetNativeCurrencyId::get()),
+                Error::<T>::NativeFeeCurrency
+            );
 
-            ret
+            FeeCurrency::<T>::set(&account_id, preference);
+            Self::deposit_event(Event::FeeCurrencySet { account_id, preference });
+            Ok(())
         }
     }
 }
 
+impl<T: Config> Pallet<T> {
+    fn dispatch_with_swap_weight(
+        origin: T::RuntimeOrigin,
+        call: Box<CallOf<T>>,
+        swap_weight: Weight,
+    ) -> DispatchResultWithPostInfo {
+        let mut ret = call.dispatch(origin);
+
+        // modify any returned weight overrides. Note that we pass-through the `Pays` unmodified.
+        let modify_weight = |x: &mut PostDispatchInfo| {
+            x.actual_weight = x.actual_weight.map(|x| x.saturating_add(swap_weight));
+        };
+        match ret {
+            Ok(ref mut info) => {
+                modify_weight(info);
+            }
+            Err(ref mut err) => {
+                modify_weight(&mut err.post_info);
+            }
+        };
+
+        ret
+    }
+
+    /// Swap exactly `fee` of the native currency out of at most `amount_in_max` of `currency_id`.
+    fn swap_fee(
+        who: &T::AccountId,
+        currency_id: T::CurrencyId,
+        fee: <T as currency::Config>::Balance,
+        amount_in_max: <T as currency::Config>::Balance,
+    ) -> DispatchResult {
+        let native_currency_id = T::GetNativeCurrencyId::get();
+        if currency_id == native_currency_id {
+            return Ok(());
+        }
+        T::FeeSwap::swap_for_exact(who, currency_id, native_currency_id, fee, amount_in_max)?;
+        Ok(())
+    }
+
+    /// The amount of `currency_id` paid for a fee of `fee` in the native currency, if it can be
+    /// swapped.
+    pub fn quote_fee(
+        fee: <T as currency::Config>::Balance,
+        currency_id: T::CurrencyId,
+    ) -> Option<<T as currency::Config>::Balance> {
+        let native_currency_id = T::GetNativeCurrencyId::get();
+        if currency_id == native_currency_id || fee.is_zero() {
+            return Some(fee);
+        }
+        T::FeeSwap::quote_for_exact(currency_id, native_currency_id, fee)
+    }
+}
+
+impl<T> Pallet<T>
+where
+    T: pallet::Config + pallet_transaction_payment::Config,
+{
+    /// The fee for `weight`, adjusted by the current fee multiplier.
+    fn fee_for_weight(weight: Weight) -> <T as currency::Config>::Balance {
+        let fee = pallet_transaction_payment::Pallet::<T>::weight_to_fee(weight).unique_saturated_into();
+        pallet_transaction_payment::Pallet::<T>::next_fee_multiplier().saturating_mul_int(fee)
+    }
+}
+
 impl<T> OnChargeTransaction<T> for Pallet<T>
 where
     T: pallet::Config + pallet_transaction_payment::Config,
@@ -122,6 +268,7 @@ where
             return Ok(None);
         }
 
+        let mut swap_surcharge = Zero::zero();
         match call.is_sub_type() {
             Some(pallet::Call::with_fee_swap_path {
                 path, amount_in_max, ..
@@ -139,10 +286,48 @@ where
                 T::Dex::inner_swap_assets_for_exact_assets(who, fee, *amount_in_max, &path, &who)
                     .map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
             }
-            _ => {}
+            Some(pallet::Call::with_fee_currency {
+                currency_id,
+                amount_in_max,
+                ..
+            }) => {
+                Self::swap_fee(who, *currency_id, fee, *amount_in_max)
+                    .map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+            }
+            _ => {
+                if let Some(preference) = Self::fee_currency(who) {
+                    // The swap isn't part of the weight of the call, so its weight is charged on top.
+                    let surcharge = Self::fee_for_weight(T::FeeSwap::swap_for_exact_weight());
+                    let total_fee = fee.saturating_add(surcharge);
+                    let amount_in_max = preference.max_price.saturating_mul_int(total_fee);
+                    // If the swap fails, e.g. because the liquidity was removed or the price rose
+                    // above the accepted one, the fee is paid in the native currency instead,
+                    // without the surcharge for the swap.
+                    match with_storage_layer(|| Self::swap_fee(who, preference.currency_id, total_fee, amount_in_max)) {
+                        Ok(_) => swap_surcharge = surcharge,
+                        Err(_) => Self::deposit_event(Event::FeeSwapFailed {
+                            account_id: who.clone(),
+                            currency_id: preference.currency_id,
+                        }),
+                    }
+                }
+            }
         }
 
-        <SubstrateDefaultPayment<T> as OnChargeTransaction<T>>::withdraw_fee(who, call, info, fee, tip)
+        let liquidity_info =
+            <SubstrateDefaultPayment<T> as OnChargeTransaction<T>>::withdraw_fee(who, call, info, fee, tip)?;
+        if !swap_surcharge.is_zero() {
+            // not refunded, the swap was done either way
+            let imbalance = T::Currency::withdraw(
+                who,
+                swap_surcharge,
+                WithdrawReasons::TRANSACTION_PAYMENT,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+            T::OnUnbalanced::on_unbalanced(imbalance);
+        }
+        Ok(liquidity_info)
     }
 
     /// Hand the fee and the tip over to the `[OnUnbalanced]` implementation.
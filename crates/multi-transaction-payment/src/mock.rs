@@ -9,7 +9,7 @@ use dex_general::GenerateLpAssetId;
 use frame_support::{
     parameter_types,
     traits::{Contains, Currency as CurrencyTrait, Imbalance, OnUnbalanced},
-    weights::{ConstantMultiplier, IdentityFee},
+    weights::{ConstantMultiplier, IdentityFee, Weight},
     PalletId,
 };
 use orml_tokens::{CurrencyAdapter, NegativeImbalance};
@@ -20,7 +20,7 @@ use sp_arithmetic::{FixedI128, FixedPointNumber, FixedU128, Perquintill};
 use sp_core::{ConstU32, Get, H256};
 use sp_runtime::{
     traits::{AccountIdConversion, BlakeTwo256, Bounded, IdentityLookup},
-    BuildStorage,
+    BuildStorage, DispatchError,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -40,7 +40,7 @@ frame_support::construct_runtime!(
         DexGeneral: dex_general::{Pallet, Call, Storage, Event<T>},
         Currency: currency::{Pallet},
         Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
-        MultiTransactionPayment: multi_transaction_payment::{Call, Pallet, Storage},
+        MultiTransactionPayment: multi_transaction_payment::{Call, Pallet, Storage, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
         Testing: testing_helpers::{Call},
     }
@@ -130,6 +130,52 @@ impl currency::CurrencyConversion<currency::Amount<Test>, CurrencyId> for Curren
     }
 }
 
+/// Swaps through the direct pair of `DexGeneral`.
+pub struct PairFeeSwap;
+impl traits::SwapApi<AccountId, CurrencyId, Balance> for PairFeeSwap {
+    fn swap_exact_for(
+        _who: &AccountId,
+        _currency_in: CurrencyId,
+        _currency_out: CurrencyId,
+        _amount_in: Balance,
+        _amount_out_min: Balance,
+    ) -> Result<Balance, DispatchError> {
+        unimplemented!()
+    }
+
+    fn quote_for_exact(currency_in: CurrencyId, currency_out: CurrencyId, amount_out: Balance) -> Option<Balance> {
+        DexGeneral::get_amount_in_by_path(amount_out, &[currency_in, currency_out])
+            .ok()
+            .and_then(|amounts| amounts.first().copied())
+    }
+
+    fn swap_for_exact(
+        who: &AccountId,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        amount_out: Balance,
+        amount_in_max: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let amount_in =
+            Self::quote_for_exact(currency_in, currency_out, amount_out).ok_or(DispatchError::Other("No pair"))?;
+        DexGeneral::inner_swap_assets_for_exact_assets(
+            who,
+            amount_out,
+            amount_in_max,
+            &[currency_in, currency_out],
+            who,
+        )?;
+        Ok(amount_in)
+    }
+
+    fn swap_for_exact_weight() -> Weight {
+        Weight::from_parts(FEE_SWAP_WEIGHT, 0)
+    }
+}
+
+/// The weight charged for the fee swap, which is also its fee with `IdentityFee`.
+pub const FEE_SWAP_WEIGHT: u64 = 1000;
+
 parameter_types! {
     pub const ExistentialDeposit: u64 = 1;
     pub const BlockHashCount: u64 = 250;
@@ -150,9 +196,12 @@ parameter_types! {
 }
 
 impl crate::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type Currency = NativeCurrency;
     type DexWeightInfo = ();
     type Dex = DexGeneral;
+    type FeeSwap = PairFeeSwap;
+    type WeightInfo = ();
     type OnUnbalanced = DealWithFees<Test, GetNativeCurrencyId, TreasuryAccountId>;
     type RuntimeCall = RuntimeCall;
 }
@@ -242,6 +291,7 @@ impl dex_general::Config for Test {
     type WeightInfo = ();
     type MaxBootstrapRewards = ConstU32<1000>;
     type MaxBootstrapLimits = ConstU32<1000>;
+    type MaxBatchOrders = ConstU32<100>;
     type EnsurePairAsset = ();
 }
 
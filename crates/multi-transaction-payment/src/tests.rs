@@ -1,6 +1,6 @@
-use crate::mock::*;
+use crate::{mock::*, FeeCurrencyPreference};
 use frame_support::{
-    assert_err, assert_ok,
+    assert_err, assert_noop, assert_ok,
     dispatch::{DispatchInfo, GetDispatchInfo},
     pallet_prelude::Pays,
     weights::Weight,
@@ -10,10 +10,18 @@ use orml_traits::MultiCurrency;
 use sp_runtime::{
     traits::{Dispatchable, SignedExtension},
     transaction_validity::{InvalidTransaction, TransactionValidityError},
+    FixedU128,
 };
 
 type Payment = pallet_transaction_payment::ChargeTransactionPayment<Test>;
 
+fn fee_currency_preference(max_price: u128) -> FeeCurrencyPreference<CurrencyId> {
+    FeeCurrencyPreference {
+        currency_id: GetForeignCurrencyId::get(),
+        max_price: FixedU128::from(max_price),
+    }
+}
+
 mod helpers {
     use sp_runtime::transaction_validity::TransactionValidity;
 
@@ -415,3 +423,212 @@ fn failing_swaps_dont_get_included_in_block() {
         );
     });
 }
+
+#[test]
+fn pay_fee_with_fee_currency_succeeds() {
+    new_test_ext().execute_with(|| {
+        let native_currency = GetNativeCurrencyId::get();
+        let fee_currency = GetForeignCurrencyId::get();
+        setup_dex(fee_currency, native_currency);
+
+        let call = RuntimeCall::MultiTransactionPayment(crate::Call::with_fee_currency {
+            currency_id: fee_currency,
+            amount_in_max: u128::MAX,
+            call: Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+        });
+        let cost = dry_run_given_call(1, native_currency, fee_currency, Pays::Yes, call, 0);
+        assert!(cost.fee_cost > 0);
+        assert_eq!(cost.native_cost, 0);
+    });
+}
+
+#[test]
+fn pay_fee_with_fee_currency_fails_above_amount_in_max() {
+    new_test_ext().execute_with(|| {
+        let native_currency = GetNativeCurrencyId::get();
+        let fee_currency = GetForeignCurrencyId::get();
+        setup_dex(fee_currency, native_currency);
+        let who = 1;
+        assert_ok!(Tokens::set_balance(
+            RuntimeOrigin::root(),
+            who,
+            fee_currency,
+            1000000000000,
+            0
+        ));
+
+        let call = RuntimeCall::MultiTransactionPayment(crate::Call::with_fee_currency {
+            currency_id: fee_currency,
+            amount_in_max: 1,
+            call: Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+        });
+        let info = call.get_dispatch_info();
+        let payment_extension = pallet_transaction_payment::ChargeTransactionPayment::<Test>::from(0);
+        assert_err!(
+            payment_extension.validate(&who, &call, &info, 123),
+            TransactionValidityError::Invalid(InvalidTransaction::Payment)
+        );
+    });
+}
+
+#[test]
+fn set_fee_currency_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let who = 1;
+        let native_preference = FeeCurrencyPreference {
+            currency_id: GetNativeCurrencyId::get(),
+            max_price: FixedU128::from(1),
+        };
+
+        assert_noop!(
+            MultiTransactionPayment::set_fee_currency(RuntimeOrigin::signed(who), Some(native_preference)),
+            crate::Error::<Test>::NativeFeeCurrency
+        );
+
+        assert_ok!(MultiTransactionPayment::set_fee_currency(
+            RuntimeOrigin::signed(who),
+            Some(fee_currency_preference(2))
+        ));
+        assert_eq!(
+            MultiTransactionPayment::fee_currency(who),
+            Some(fee_currency_preference(2))
+        );
+        System::assert_last_event(RuntimeEvent::MultiTransactionPayment(crate::Event::FeeCurrencySet {
+            account_id: who,
+            preference: Some(fee_currency_preference(2)),
+        }));
+
+        assert_ok!(MultiTransactionPayment::set_fee_currency(
+            RuntimeOrigin::signed(who),
+            None
+        ));
+        assert_eq!(MultiTransactionPayment::fee_currency(who), None);
+    });
+}
+
+#[test]
+fn pay_fee_in_default_fee_currency_succeeds() {
+    new_test_ext().execute_with(|| {
+        let who = 1;
+        let native_currency = GetNativeCurrencyId::get();
+        let fee_currency = GetForeignCurrencyId::get();
+        setup_dex(fee_currency, native_currency);
+        assert_ok!(MultiTransactionPayment::set_fee_currency(
+            RuntimeOrigin::signed(who),
+            Some(fee_currency_preference(2))
+        ));
+
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let fee = TransactionPayment::compute_fee(1231, &call.get_dispatch_info(), 0);
+        let cost = dry_run_given_call(who, native_currency, fee_currency, Pays::Yes, call, 0);
+        // the weight of the swap is paid on top of the fee
+        assert_eq!(
+            cost.fee_cost as u128,
+            MultiTransactionPayment::quote_fee(fee + FEE_SWAP_WEIGHT as u128, fee_currency).unwrap()
+        );
+        assert_eq!(cost.native_cost, 0);
+    });
+}
+
+#[test]
+fn pay_fee_in_native_currency_above_max_price_of_default_fee_currency() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let who = 1;
+        let native_currency = GetNativeCurrencyId::get();
+        let fee_currency = GetForeignCurrencyId::get();
+        setup_dex(fee_currency, native_currency);
+        // the pair charges a swap fee, so the price is above one
+        assert_ok!(MultiTransactionPayment::set_fee_currency(
+            RuntimeOrigin::signed(who),
+            Some(fee_currency_preference(1))
+        ));
+        let initial = 100000000000000;
+        assert_ok!(Tokens::set_balance(
+            RuntimeOrigin::root(),
+            who,
+            native_currency,
+            initial,
+            0
+        ));
+        assert_ok!(Tokens::set_balance(
+            RuntimeOrigin::root(),
+            who,
+            fee_currency,
+            initial,
+            0
+        ));
+
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let info = call.get_dispatch_info();
+        let payment_extension = pallet_transaction_payment::ChargeTransactionPayment::<Test>::from(0);
+        assert_ok!(payment_extension.pre_dispatch(&who, &call, &info, 123));
+
+        // only the fee is paid, without the surcharge for the failed swap
+        assert_eq!(
+            Tokens::free_balance(native_currency, &who),
+            initial - TransactionPayment::compute_fee(123, &info, 0)
+        );
+        assert_eq!(Tokens::free_balance(fee_currency, &who), initial);
+        System::assert_last_event(RuntimeEvent::MultiTransactionPayment(crate::Event::FeeSwapFailed {
+            account_id: who,
+            currency_id: fee_currency,
+        }));
+    });
+}
+
+#[test]
+fn pay_fee_in_native_currency_if_default_fee_currency_cannot_be_swapped() {
+    new_test_ext().execute_with(|| {
+        let who = 1;
+        let native_currency = GetNativeCurrencyId::get();
+        let fee_currency = GetForeignCurrencyId::get();
+        // no pair to swap through
+        assert_ok!(MultiTransactionPayment::set_fee_currency(
+            RuntimeOrigin::signed(who),
+            Some(fee_currency_preference(2))
+        ));
+        let initial = 100000000000000;
+        assert_ok!(Tokens::set_balance(
+            RuntimeOrigin::root(),
+            who,
+            native_currency,
+            initial,
+            0
+        ));
+        assert_ok!(Tokens::set_balance(
+            RuntimeOrigin::root(),
+            who,
+            fee_currency,
+            initial,
+            0
+        ));
+
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let info = call.get_dispatch_info();
+        let payment_extension = pallet_transaction_payment::ChargeTransactionPayment::<Test>::from(0);
+        assert_ok!(payment_extension.pre_dispatch(&who, &call, &info, 123));
+
+        assert!(Tokens::free_balance(native_currency, &who) < initial);
+        assert_eq!(Tokens::free_balance(fee_currency, &who), initial);
+    });
+}
+
+#[test]
+fn quote_fee_works() {
+    new_test_ext().execute_with(|| {
+        let native_currency = GetNativeCurrencyId::get();
+        let fee_currency = GetForeignCurrencyId::get();
+        let fee = 1000;
+
+        assert_eq!(MultiTransactionPayment::quote_fee(fee, native_currency), Some(fee));
+        assert_eq!(MultiTransactionPayment::quote_fee(fee, fee_currency), None);
+
+        setup_dex(fee_currency, native_currency);
+        assert_eq!(
+            MultiTransactionPayment::quote_fee(fee, fee_currency),
+            Some(DexGeneral::get_amount_in_by_path(fee, &[fee_currency, native_currency]).unwrap()[0])
+        );
+    });
+}
@@ -0,0 +1,24 @@
+//! Runtime API definition for the Nomination Module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use frame_support::dispatch::DispatchError;
+use oracle_rpc_runtime_api::BalanceWrapper;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait NominationApi<VaultId, AccountId, Nonce, Balance, BlockNumber> where
+        VaultId: Codec,
+        AccountId: Codec,
+        Nonce: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Get the pending collateral withdrawals of a nominator as (unbonding id, amount, unlock block)
+        fn get_pending_unbondings(
+            vault_id: VaultId,
+            nominator_id: AccountId
+        ) -> Result<Vec<(Nonce, BalanceWrapper<Balance>, BlockNumber)>, DispatchError>;
+    }
+}
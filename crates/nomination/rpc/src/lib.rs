@@ -0,0 +1,96 @@
+//! RPC interface for the Nomination Module.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use oracle_rpc_runtime_api::BalanceWrapper;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+    DispatchError,
+};
+use std::sync::Arc;
+
+pub use nomination_rpc_runtime_api::NominationApi as NominationRuntimeApi;
+
+#[rpc(client, server)]
+pub trait NominationApi<BlockHash, VaultId, AccountId, Nonce, Balance, BlockNumber>
+where
+    VaultId: Codec,
+    AccountId: Codec,
+    Nonce: Codec,
+    Balance: Codec + MaybeDisplay + MaybeFromStr,
+    BlockNumber: Codec,
+{
+    #[method(name = "nomination_getPendingUnbondings")]
+    fn get_pending_unbondings(
+        &self,
+        vault_id: VaultId,
+        nominator_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Nonce, BalanceWrapper<Balance>, BlockNumber)>>;
+}
+
+fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        ErrorCode::InternalError.code(),
+        message.to_string(),
+        None::<()>,
+    )))
+}
+
+/// A struct that implements the [`NominationApi`].
+pub struct Nomination<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Nomination<C, B> {
+    /// Create new `Nomination` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Nomination {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn handle_response<T, E: std::fmt::Debug>(result: Result<Result<T, DispatchError>, E>, msg: String) -> RpcResult<T> {
+    result
+        .map_err(|err| internal_err(format!("Runtime error: {:?}: {:?}", msg, err)))?
+        .map_err(|err| internal_err(format!("Execution error: {:?}: {:?}", msg, err)))
+}
+
+#[async_trait]
+impl<C, Block, VaultId, AccountId, Nonce, Balance, BlockNumber>
+    NominationApiServer<<Block as BlockT>::Hash, VaultId, AccountId, Nonce, Balance, BlockNumber>
+    for Nomination<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: NominationRuntimeApi<Block, VaultId, AccountId, Nonce, Balance, BlockNumber>,
+    VaultId: Codec,
+    AccountId: Codec,
+    Nonce: Codec,
+    Balance: Codec + MaybeDisplay + MaybeFromStr,
+    BlockNumber: Codec,
+{
+    fn get_pending_unbondings(
+        &self,
+        vault_id: VaultId,
+        nominator_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(Nonce, BalanceWrapper<Balance>, BlockNumber)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_pending_unbondings(at, vault_id, nominator_id),
+            "Unable to get pending unbondings".into(),
+        )
+    }
+}
@@ -17,6 +17,7 @@ use vault_registry::{
 use crate::Pallet as Nomination;
 use fee::Pallet as Fee;
 use oracle::Pallet as Oracle;
+use security::Pallet as Security;
 use vault_registry::Pallet as VaultRegistry;
 
 fn deposit_tokens<T: crate::Config>(currency_id: CurrencyId, account_id: &T::AccountId, amount: BalanceOf<T>) {
@@ -195,6 +196,83 @@ pub mod benchmarks {
         assert_eq!(balance_before - amount, balance_after);
     }
 
+    #[benchmark]
+    pub fn set_unbonding_period() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 100u32.into());
+    }
+
+    #[benchmark]
+    pub fn set_vault_unbonding_period() {
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        <MaxVaultUnbondingPeriod<T>>::set(100u32.into());
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(vault_id.account_id),
+            vault_id.currencies.clone(),
+            100u32.into(),
+        );
+    }
+
+    #[benchmark]
+    pub fn claim_collateral() {
+        setup_exchange_rate::<T>();
+        <NominationEnabled<T>>::set(true);
+        <UnbondingPeriod<T>>::set(100u32.into());
+
+        let vault_id = activate_lending_and_get_vault_id::<T>();
+        register_vault::<T>(vault_id.clone());
+
+        <Vaults<T>>::insert(&vault_id, true);
+
+        Nomination::<T>::set_nomination_limit(
+            RawOrigin::Signed(vault_id.account_id.clone()).into(),
+            vault_id.currencies.clone(),
+            (1u32 << 31).into(),
+        )
+        .unwrap();
+
+        let nominator: T::AccountId = account("Nominator", 0, 0);
+        let collateral_currency = vault_id.collateral_currency();
+        if collateral_currency.is_lend_token() {
+            mint_lend_tokens::<T>(&nominator, collateral_currency);
+        } else {
+            mint_collateral::<T>(&nominator, (1u32 << 31).into());
+        }
+        let amount = 100u32.into();
+
+        assert_ok!(Nomination::<T>::_deposit_collateral(&vault_id, &nominator, amount));
+        assert_ok!(Nomination::<T>::_withdraw_collateral(
+            &vault_id,
+            &nominator,
+            Some(amount),
+            Default::default()
+        ));
+
+        // only two reward currencies supported
+        distribute_rewards::<T>(T::GetWrappedCurrencyId::get());
+        distribute_rewards::<T>(T::GetNativeCurrencyId::get());
+
+        Security::<T>::set_active_block_number(Security::<T>::active_block_number() + 100u32.into());
+        let balance_before = <orml_tokens::Pallet<T>>::reserved_balance(collateral_currency, &vault_id.account_id);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(nominator.clone()),
+            vault_id.clone(),
+            Default::default(),
+        );
+
+        let balance_after = <orml_tokens::Pallet<T>>::reserved_balance(collateral_currency, &vault_id.account_id);
+        assert_eq!(balance_before - amount, balance_after);
+    }
+
+    #[benchmark]
+    pub fn set_max_vault_unbonding_period() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 100u32.into());
+    }
+
     impl_benchmark_test_suite!(
         Nomination,
         crate::mock::ExtBuilder::build_with(Default::default()),
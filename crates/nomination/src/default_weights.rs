@@ -43,6 +43,10 @@ pub trait WeightInfo {
 	fn opt_out_of_nomination() -> Weight;
 	fn deposit_collateral() -> Weight;
 	fn withdraw_collateral() -> Weight;
+	fn set_unbonding_period() -> Weight;
+	fn set_vault_unbonding_period() -> Weight;
+	fn claim_collateral() -> Weight;
+	fn set_max_vault_unbonding_period() -> Weight;
 }
 
 /// Weights for nomination using the Substrate node and recommended hardware.
@@ -373,6 +377,58 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(61_u64))
 			.saturating_add(T::DbWeight::get().writes(34_u64))
 	}
+	/// Storage: Nomination UnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination UnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_unbonding_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_712_000 picoseconds.
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Nomination MaxVaultUnbondingPeriod (r:1 w:0)
+	/// Proof: Nomination MaxVaultUnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nomination VaultUnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination VaultUnbondingPeriod (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	fn set_vault_unbonding_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `1486`
+		// Minimum execution time: 20_418_000 picoseconds.
+		Weight::from_parts(20_763_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Unbondings (r:1 w:1)
+	/// Proof: VaultStaking Unbondings (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	/// Storage: VaultStaking UnbondingStake (r:1 w:1)
+	/// Proof: VaultStaking UnbondingStake (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: VaultStaking UnbondingSlashTally (r:1 w:1)
+	/// Proof: VaultStaking UnbondingSlashTally (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	fn claim_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6032`
+		//  Estimated: `202112`
+		// Minimum execution time: 851_204_000 picoseconds.
+		Weight::from_parts(856_327_000, 202112)
+			.saturating_add(T::DbWeight::get().reads(65_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
+	}
+	/// Storage: Nomination MaxVaultUnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination MaxVaultUnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_max_vault_unbonding_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_904_000 picoseconds.
+		Weight::from_parts(9_317_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -702,4 +758,56 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(61_u64))
 			.saturating_add(RocksDbWeight::get().writes(34_u64))
 	}
+	/// Storage: Nomination UnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination UnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_unbonding_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_712_000 picoseconds.
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Nomination MaxVaultUnbondingPeriod (r:1 w:0)
+	/// Proof: Nomination MaxVaultUnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nomination VaultUnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination VaultUnbondingPeriod (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	fn set_vault_unbonding_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `1486`
+		// Minimum execution time: 20_418_000 picoseconds.
+		Weight::from_parts(20_763_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Unbondings (r:1 w:1)
+	/// Proof: VaultStaking Unbondings (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	/// Storage: VaultStaking UnbondingStake (r:1 w:1)
+	/// Proof: VaultStaking UnbondingStake (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: VaultStaking UnbondingSlashTally (r:1 w:1)
+	/// Proof: VaultStaking UnbondingSlashTally (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	fn claim_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6032`
+		//  Estimated: `202112`
+		// Minimum execution time: 851_204_000 picoseconds.
+		Weight::from_parts(856_327_000, 202112)
+			.saturating_add(RocksDbWeight::get().reads(65_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
+	}
+	/// Storage: Nomination MaxVaultUnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination MaxVaultUnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_max_vault_unbonding_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_904_000 picoseconds.
+		Weight::from_parts(9_317_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
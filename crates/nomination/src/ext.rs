@@ -67,11 +67,22 @@ pub(crate) mod vault_registry {
     }
 }
 
+#[cfg_attr(test, mockable)]
+pub(crate) mod security {
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    pub fn active_block_number<T: crate::Config>() -> BlockNumberFor<T> {
+        <security::Pallet<T>>::active_block_number()
+    }
+}
+
 #[cfg_attr(test, mockable)]
 pub(crate) mod staking {
     use crate::BalanceOf;
     use frame_support::dispatch::DispatchError;
-    use staking::{RewardsApi, StakingApi};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::vec::Vec;
+    use staking::{RewardsApi, StakingApi, UnbondingApi};
     use vault_registry::DefaultVaultId;
 
     pub fn nonce<T: crate::Config>(vault_id: &DefaultVaultId<T>) -> T::Nonce {
@@ -84,4 +95,29 @@ pub(crate) mod staking {
     ) -> Result<BalanceOf<T>, DispatchError> {
         T::VaultStaking::get_stake(&(None, vault_id.clone()), nominator_id)
     }
+
+    pub fn request_unbonding<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        amount: Option<BalanceOf<T>>,
+        unlock_at: BlockNumberFor<T>,
+    ) -> Result<(T::Nonce, BalanceOf<T>), DispatchError> {
+        T::VaultStaking::request_unbonding(vault_id, nominator_id, amount, unlock_at)
+    }
+
+    pub fn take_unbonding<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        unbonding_id: T::Nonce,
+        now: BlockNumberFor<T>,
+    ) -> Result<(T::Nonce, BalanceOf<T>), DispatchError> {
+        T::VaultStaking::take_unbonding(vault_id, nominator_id, unbonding_id, now)
+    }
+
+    pub fn get_unbondings<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+    ) -> Result<Vec<(T::Nonce, BalanceOf<T>, BlockNumberFor<T>)>, DispatchError> {
+        T::VaultStaking::get_unbondings(vault_id, nominator_id)
+    }
 }
@@ -29,9 +29,11 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure, transactional,
 };
-use frame_system::{ensure_root, ensure_signed};
+use frame_system::{ensure_root, ensure_signed, pallet_prelude::BlockNumberFor};
 pub use pallet::*;
 use primitives::VaultId;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec::Vec;
 
 pub(crate) type BalanceOf<T> = <T as currency::Config>::Balance;
 
@@ -74,6 +76,23 @@ pub mod pallet {
             nominator_id: T::AccountId,
             amount: BalanceOf<T>,
         },
+        RequestWithdrawCollateral {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+            unbonding_id: T::Nonce,
+            amount: BalanceOf<T>,
+            unlock_at: BlockNumberFor<T>,
+        },
+        UnbondingPeriodSet {
+            unbonding_period: BlockNumberFor<T>,
+        },
+        VaultUnbondingPeriodSet {
+            vault_id: DefaultVaultId<T>,
+            unbonding_period: BlockNumberFor<T>,
+        },
+        MaxVaultUnbondingPeriodSet {
+            unbonding_period: BlockNumberFor<T>,
+        },
     }
 
     #[pallet::error]
@@ -92,6 +111,8 @@ pub mod pallet {
         NominationExceedsLimit,
         /// Vault cannot withdraw.
         CollateralizationTooLow,
+        /// Unbonding period exceeds the maximum allowed for vaults.
+        UnbondingPeriodTooLong,
     }
 
     #[pallet::hooks]
@@ -111,6 +132,23 @@ pub mod pallet {
     pub(super) type NominationLimit<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, BalanceOf<T>, ValueQuery>;
 
+    /// The minimum number of blocks nominators have to wait before they can
+    /// claim withdrawn collateral, for all vaults.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding_period)]
+    pub type UnbondingPeriod<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// The number of blocks nominators of a vault have to wait before they can
+    /// claim withdrawn collateral, if longer than the global `UnbondingPeriod`.
+    #[pallet::storage]
+    pub(super) type VaultUnbondingPeriod<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, BlockNumberFor<T>, ValueQuery>;
+
+    /// The longest unbonding period a vault may set for its nominators.
+    #[pallet::storage]
+    #[pallet::getter(fn max_vault_unbonding_period)]
+    pub type MaxVaultUnbondingPeriod<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -212,6 +250,74 @@ pub mod pallet {
             NominationLimit::<T>::insert(vault_id, limit);
             Ok(().into())
         }
+
+        /// Set the unbonding period that applies to the nominators of all vaults.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_unbonding_period())]
+        #[transactional]
+        pub fn set_unbonding_period(
+            origin: OriginFor<T>,
+            unbonding_period: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            <UnbondingPeriod<T>>::set(unbonding_period);
+            Self::deposit_event(Event::<T>::UnbondingPeriodSet { unbonding_period });
+            Ok(().into())
+        }
+
+        /// Set the unbonding period for the nominators of this vault, up to the
+        /// `MaxVaultUnbondingPeriod`. The global unbonding period still applies if it is longer.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_vault_unbonding_period())]
+        #[transactional]
+        pub fn set_vault_unbonding_period(
+            origin: OriginFor<T>,
+            currency_pair: DefaultVaultCurrencyPair<T>,
+            unbonding_period: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            let vault_id = VaultId::new(account_id, currency_pair.collateral, currency_pair.wrapped);
+            ensure!(
+                unbonding_period <= Self::max_vault_unbonding_period(),
+                Error::<T>::UnbondingPeriodTooLong
+            );
+
+            VaultUnbondingPeriod::<T>::insert(&vault_id, unbonding_period);
+            Self::deposit_event(Event::<T>::VaultUnbondingPeriodSet {
+                vault_id,
+                unbonding_period,
+            });
+            Ok(().into())
+        }
+
+        /// Claim collateral that was withdrawn by the nominator, once the
+        /// unbonding period has passed.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_collateral())]
+        #[transactional]
+        pub fn claim_collateral(
+            origin: OriginFor<T>,
+            vault_id: DefaultVaultId<T>,
+            unbonding_id: T::Nonce,
+        ) -> DispatchResultWithPostInfo {
+            let nominator_id = ensure_signed(origin)?;
+            Self::_claim_collateral(&vault_id, &nominator_id, unbonding_id)?;
+            Ok(().into())
+        }
+
+        /// Set the longest unbonding period that vaults may set for their nominators.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_max_vault_unbonding_period())]
+        #[transactional]
+        pub fn set_max_vault_unbonding_period(
+            origin: OriginFor<T>,
+            unbonding_period: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            <MaxVaultUnbondingPeriod<T>>::set(unbonding_period);
+            Self::deposit_event(Event::<T>::MaxVaultUnbondingPeriodSet { unbonding_period });
+            Ok(().into())
+        }
     }
 }
 
@@ -227,6 +333,50 @@ impl<T: Config> Pallet<T> {
         let nonce = ext::staking::nonce::<T>(vault_id);
         let index = sp_std::cmp::min(index, nonce);
 
+        let unbonding_period = Self::get_unbonding_period(vault_id);
+        if index == nonce && &vault_id.account_id != nominator_id && !unbonding_period.is_zero() {
+            ensure!(Self::is_nomination_enabled(), Error::<T>::VaultNominationDisabled);
+            ensure!(Self::is_opted_in(vault_id), Error::<T>::VaultNotOptedInToNomination);
+
+            // the stake remains in the pool until it is claimed
+            let unlock_at = ext::security::active_block_number::<T>().saturating_add(unbonding_period);
+            let (unbonding_id, amount) =
+                ext::staking::request_unbonding::<T>(vault_id, nominator_id, maybe_amount, unlock_at)?;
+
+            Self::deposit_event(Event::<T>::RequestWithdrawCollateral {
+                vault_id: vault_id.clone(),
+                nominator_id: nominator_id.clone(),
+                unbonding_id,
+                amount,
+                unlock_at,
+            });
+            return Ok(());
+        }
+
+        Self::withdraw_from_pool(vault_id, nominator_id, maybe_amount, index)
+    }
+
+    pub fn _claim_collateral(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        unbonding_id: T::Nonce,
+    ) -> DispatchResult {
+        let now = ext::security::active_block_number::<T>();
+        let (index, amount) = ext::staking::take_unbonding::<T>(vault_id, nominator_id, unbonding_id, now)?;
+        if amount.is_zero() {
+            // the queued stake was slashed entirely
+            return Ok(());
+        }
+        Self::withdraw_from_pool(vault_id, nominator_id, Some(amount), index)
+    }
+
+    fn withdraw_from_pool(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        maybe_amount: Option<BalanceOf<T>>,
+        index: T::Nonce,
+    ) -> DispatchResult {
+        let nonce = ext::staking::nonce::<T>(vault_id);
         let maybe_amount = maybe_amount.map(|x| Amount::<T>::new(x, vault_id.collateral_currency()));
 
         // nominators are always allowed to withdraw from stale staking pools
@@ -350,6 +500,24 @@ impl<T: Config> Pallet<T> {
         vault_backing_collateral.checked_sub(&vault_actual_collateral)
     }
 
+    /// Get the number of blocks nominators of `vault_id` have to wait to claim withdrawn collateral.
+    pub fn get_unbonding_period(vault_id: &DefaultVaultId<T>) -> BlockNumberFor<T> {
+        // a lowered maximum also applies to periods that vaults set before
+        let vault_unbonding_period = sp_std::cmp::min(
+            VaultUnbondingPeriod::<T>::get(vault_id),
+            Self::max_vault_unbonding_period(),
+        );
+        sp_std::cmp::max(Self::unbonding_period(), vault_unbonding_period)
+    }
+
+    /// Get the pending withdrawals of `nominator_id`, with the amounts after slashing.
+    pub fn get_pending_unbondings(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+    ) -> Result<Vec<(T::Nonce, BalanceOf<T>, BlockNumberFor<T>)>, DispatchError> {
+        ext::staking::get_unbondings::<T>(vault_id, nominator_id)
+    }
+
     pub fn get_nomination_limit(vault_id: &DefaultVaultId<T>) -> Amount<T> {
         let limit = NominationLimit::<T>::get(vault_id);
         Amount::new(limit, vault_id.collateral_currency())
@@ -364,11 +532,19 @@ impl<T: Config> Pallet<T> {
     }
 }
 
-impl<T: Config> traits::NominationApi<DefaultVaultId<T>, Amount<T>> for Pallet<T> {
+impl<T: Config> traits::NominationApi<DefaultVaultId<T>, T::AccountId, Amount<T>> for Pallet<T> {
     fn deposit_vault_collateral(vault_id: &DefaultVaultId<T>, amount: &Amount<T>) -> Result<(), DispatchError> {
         Pallet::<T>::_deposit_collateral(vault_id, &vault_id.account_id, amount.amount())
     }
 
+    fn deposit_nominator_collateral(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        amount: &Amount<T>,
+    ) -> Result<(), DispatchError> {
+        Pallet::<T>::_deposit_collateral(vault_id, nominator_id, amount.amount())
+    }
+
     fn ensure_opted_in_to_nomination(vault_id: &DefaultVaultId<T>) -> DispatchResult {
         ensure!(Self::is_opted_in(vault_id), Error::<T>::VaultNotOptedInToNomination);
         Ok(())
@@ -39,7 +39,7 @@ frame_support::construct_runtime!(
         // Operational
         Security: security::{Pallet, Call, Storage, Event<T>},
         VaultRegistry: vault_registry::{Pallet, Call, Config<T>, Storage, Event<T>},
-        Fee: fee::{Pallet, Call, Config<T>, Storage},
+        Fee: fee::{Pallet, Call, Config<T>, Storage, Event<T>},
         Oracle: oracle::{Pallet, Call, Config<T>, Storage, Event<T>},
         Nomination: nomination::{Pallet, Call, Storage, Config<T>, Event<T>},
         Currency: currency::{Pallet},
@@ -170,6 +170,7 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type Loans = Loans;
 }
 
 pub struct CurrencyConvert;
@@ -202,6 +203,7 @@ impl currency::Config for Test {
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -221,6 +223,7 @@ parameter_types! {
 }
 
 impl fee::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type FeePalletId = FeePalletId;
     type WeightInfo = ();
     type SignedFixedPoint = SignedFixedPoint;
@@ -231,6 +234,8 @@ impl fee::Config for Test {
     type OnSweep = ();
     type MaxExpectedValue = MaxExpectedValue;
     type NominationApi = Nomination;
+    type CapacityUtilization = VaultRegistry;
+    type RewardSwap = ();
 }
 
 impl oracle::Config for Test {
@@ -254,6 +259,7 @@ impl loans::Config for Test {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = ();
+    type RuntimeCall = RuntimeCall;
 }
 
 impl Config for Test {
@@ -1,8 +1,9 @@
 use crate::{ext, mock::*};
 use currency::Amount;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok};
 use mocktopus::mocking::*;
 use sp_arithmetic::FixedI128;
+use sp_runtime::DispatchError::BadOrigin;
 
 #[test]
 fn should_not_deposit_against_invalid_vault() {
@@ -95,3 +96,94 @@ fn should_not_withdraw_collateral() {
         );
     });
 }
+
+#[test]
+fn should_use_longest_unbonding_period() {
+    run_test(|| {
+        assert_ok!(Nomination::set_unbonding_period(RuntimeOrigin::root(), 10));
+        assert_ok!(Nomination::set_max_vault_unbonding_period(RuntimeOrigin::root(), 30));
+        assert_eq!(Nomination::get_unbonding_period(&ALICE), 10);
+
+        assert_ok!(Nomination::set_vault_unbonding_period(
+            RuntimeOrigin::signed(ALICE.account_id),
+            ALICE.currencies,
+            20
+        ));
+        assert_eq!(Nomination::get_unbonding_period(&ALICE), 20);
+        assert_eq!(Nomination::get_unbonding_period(&BOB), 10);
+    })
+}
+
+#[test]
+fn should_cap_vault_unbonding_period() {
+    run_test(|| {
+        assert_noop!(
+            Nomination::set_vault_unbonding_period(RuntimeOrigin::signed(ALICE.account_id), ALICE.currencies, 20),
+            TestError::UnbondingPeriodTooLong
+        );
+        assert_noop!(
+            Nomination::set_max_vault_unbonding_period(RuntimeOrigin::signed(ALICE.account_id), 20),
+            BadOrigin
+        );
+
+        assert_ok!(Nomination::set_max_vault_unbonding_period(RuntimeOrigin::root(), 20));
+        assert_ok!(Nomination::set_vault_unbonding_period(
+            RuntimeOrigin::signed(ALICE.account_id),
+            ALICE.currencies,
+            20
+        ));
+        assert_noop!(
+            Nomination::set_vault_unbonding_period(RuntimeOrigin::signed(ALICE.account_id), ALICE.currencies, 21),
+            TestError::UnbondingPeriodTooLong
+        );
+        assert_eq!(Nomination::get_unbonding_period(&ALICE), 20);
+
+        // lowering the maximum also shortens periods that were set before
+        assert_ok!(Nomination::set_max_vault_unbonding_period(RuntimeOrigin::root(), 5));
+        assert_eq!(Nomination::get_unbonding_period(&ALICE), 5);
+    })
+}
+
+#[test]
+fn should_queue_withdrawal_with_unbonding_period() {
+    run_test(|| {
+        ext::vault_registry::vault_exists::<Test>.mock_safe(|_| MockResult::Return(true));
+        assert_ok!(Nomination::_opt_in_to_nomination(&ALICE));
+        assert_ok!(Nomination::set_unbonding_period(RuntimeOrigin::root(), 10));
+
+        ext::staking::request_unbonding::<Test>.mock_safe(|_, nominator_id, amount, unlock_at| {
+            assert_eq!(nominator_id, &BOB.account_id);
+            assert_eq!(amount, Some(50));
+            assert_eq!(unlock_at, 11);
+            MockResult::Return(Ok((0, 50)))
+        });
+        Nomination::withdraw_from_pool.mock_safe(|_, _, _, _| panic!("Should not withdraw from pool"));
+        assert_ok!(Nomination::_withdraw_collateral(&ALICE, &BOB.account_id, Some(50), 0));
+
+        // the vault itself is not subject to the unbonding period
+        Nomination::withdraw_from_pool.mock_safe(|_, _, _, _| MockResult::Return(Ok(())));
+        assert_ok!(Nomination::_withdraw_collateral(&ALICE, &ALICE.account_id, Some(50), 0));
+    })
+}
+
+#[test]
+fn should_claim_collateral() {
+    run_test(|| {
+        ext::staking::take_unbonding::<Test>.mock_safe(|_, _, unbonding_id, now| {
+            assert_eq!(unbonding_id, 3);
+            assert_eq!(now, 1);
+            MockResult::Return(Ok((0, 50)))
+        });
+        Nomination::withdraw_from_pool.mock_safe(|_, nominator_id, amount, index| {
+            assert_eq!(nominator_id, &BOB.account_id);
+            assert_eq!(amount, Some(50));
+            assert_eq!(index, 0);
+            MockResult::Return(Ok(()))
+        });
+        assert_ok!(Nomination::claim_collateral(
+            RuntimeOrigin::signed(BOB.account_id),
+            ALICE,
+            3
+        ));
+    })
+}
@@ -147,6 +147,7 @@ impl pallet_timestamp::Config for Test {
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 impl staking::Config for Test {
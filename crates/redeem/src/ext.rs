@@ -181,8 +181,11 @@ pub(crate) mod treasury {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod security {
+    use frame_support::dispatch::DispatchResult;
     use frame_system::pallet_prelude::BlockNumberFor;
+    use security::Subsystem;
     use sp_core::H256;
+    use vault_registry::types::DefaultVaultCurrencyPair;
 
     pub fn get_secure_id<T: crate::Config>(id: &T::AccountId) -> H256 {
         <security::Pallet<T>>::get_secure_id(id)
@@ -191,6 +194,13 @@ pub(crate) mod security {
     pub fn active_block_number<T: crate::Config>() -> BlockNumberFor<T> {
         <security::Pallet<T>>::active_block_number()
     }
+
+    pub fn ensure_subsystem_running<T: crate::Config>(
+        subsystem: Subsystem,
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> DispatchResult {
+        <security::Pallet<T>>::ensure_subsystem_running(subsystem, currency_pair)
+    }
 }
 
 #[cfg_attr(test, mockable)]
@@ -206,6 +216,7 @@ pub(crate) mod oracle {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod fee {
+    use crate::DefaultVaultId;
     use currency::Amount;
     use frame_support::dispatch::{DispatchError, DispatchResult};
 
@@ -213,19 +224,28 @@ pub(crate) mod fee {
         <fee::Pallet<T>>::fee_pool_account_id()
     }
 
-    pub fn get_redeem_fee<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        <fee::Pallet<T>>::get_redeem_fee(amount)
+    pub fn get_redeem_fee<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_redeem_fee(&vault_id.currencies, amount)
     }
 
     pub fn distribute_rewards<T: crate::Config>(amount: &Amount<T>) -> DispatchResult {
         <fee::Pallet<T>>::distribute_rewards(amount)
     }
 
-    pub fn get_punishment_fee<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        <fee::Pallet<T>>::get_punishment_fee(amount)
+    pub fn get_punishment_fee<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_punishment_fee(&vault_id.currencies, amount)
     }
 
-    pub fn get_premium_redeem_fee<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        <fee::Pallet<T>>::get_premium_redeem_fee(amount)
+    pub fn get_premium_redeem_fee<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_premium_redeem_fee(&vault_id.currencies, amount)
     }
 }
@@ -41,6 +41,7 @@ use frame_support::{
 };
 use frame_system::{ensure_root, ensure_signed};
 use oracle::OracleKey;
+use security::Subsystem;
 use sp_core::H256;
 use sp_runtime::{ArithmeticError, FixedPointNumber};
 use sp_std::{convert::TryInto, vec::Vec};
@@ -413,7 +414,7 @@ mod self_redeem {
         let fees = if redeemable_tokens.eq(&requested_redeem_amount)? {
             Amount::zero(vault_id.wrapped_currency())
         } else {
-            ext::fee::get_redeem_fee::<T>(&requested_redeem_amount)?
+            ext::fee::get_redeem_fee::<T>(&vault_id, &requested_redeem_amount)?
         };
 
         let consumed_issued_tokens = requested_redeem_amount.checked_sub(&fees)?;
@@ -465,6 +466,8 @@ impl<T: Config> Pallet<T> {
         btc_address: BtcAddress,
         vault_id: DefaultVaultId<T>,
     ) -> Result<H256, DispatchError> {
+        ext::security::ensure_subsystem_running::<T>(Subsystem::Redeem, &vault_id.currencies)?;
+
         let amount_wrapped = Amount::new(amount_wrapped, vault_id.wrapped_currency());
 
         let redeemer_balance = ext::treasury::get_balance::<T>(&redeemer, vault_id.wrapped_currency());
@@ -481,7 +484,7 @@ impl<T: Config> Pallet<T> {
         let fee_wrapped = if redeemer == vault_id.account_id {
             Amount::zero(vault_id.wrapped_currency())
         } else {
-            ext::fee::get_redeem_fee::<T>(&amount_wrapped)?
+            ext::fee::get_redeem_fee::<T>(&vault_id, &amount_wrapped)?
         };
         let inclusion_fee = Self::get_current_inclusion_fee(vault_id.wrapped_currency())?;
 
@@ -513,7 +516,7 @@ impl<T: Config> Pallet<T> {
 
         let premium_collateral = if below_premium_redeem {
             let redeem_amount_wrapped_in_collateral = user_to_be_received_btc.convert_to(currency_id)?;
-            ext::fee::get_premium_redeem_fee::<T>(&redeem_amount_wrapped_in_collateral)?
+            ext::fee::get_premium_redeem_fee::<T>(&vault_id, &redeem_amount_wrapped_in_collateral)?
         } else {
             Amount::zero(currency_id)
         };
@@ -556,6 +559,8 @@ impl<T: Config> Pallet<T> {
         currencies: DefaultVaultCurrencyPair<T>,
         amount_wrapped: BalanceOf<T>,
     ) -> Result<(), DispatchError> {
+        ext::security::ensure_subsystem_running::<T>(Subsystem::Redeem, &currencies)?;
+
         let amount_wrapped = Amount::new(amount_wrapped, currencies.wrapped);
 
         let redeemer_balance = ext::treasury::get_balance::<T>(&redeemer, currencies.wrapped);
@@ -659,7 +664,8 @@ impl<T: Config> Pallet<T> {
             // not liquidated
 
             // calculate the punishment fee (e.g. 10%)
-            let punishment_fee_in_collateral = ext::fee::get_punishment_fee::<T>(&amount_wrapped_in_collateral)?;
+            let punishment_fee_in_collateral =
+                ext::fee::get_punishment_fee::<T>(&vault_id, &amount_wrapped_in_collateral)?;
 
             let amount_to_slash = if reimburse {
                 // 100% + punishment fee on reimburse
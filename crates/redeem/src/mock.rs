@@ -42,7 +42,7 @@ frame_support::construct_runtime!(
         VaultRegistry: vault_registry::{Pallet, Call, Config<T>, Storage, Event<T>},
         Oracle: oracle::{Pallet, Call, Config<T>, Storage, Event<T>},
         Redeem: redeem::{Pallet, Call, Config<T>, Storage, Event<T>},
-        Fee: fee::{Pallet, Call, Config<T>, Storage},
+        Fee: fee::{Pallet, Call, Config<T>, Storage, Event<T>},
         Currency: currency::{Pallet},
         Nomination: nomination::{Pallet, Call, Storage, Config<T>, Event<T>},
         Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>},
@@ -145,6 +145,7 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type Loans = Loans;
 }
 
 impl nomination::Config for Test {
@@ -211,6 +212,7 @@ impl btc_relay::Config for Test {
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -237,6 +239,7 @@ parameter_types! {
 }
 
 impl fee::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type FeePalletId = FeePalletId;
     type WeightInfo = ();
     type SignedFixedPoint = SignedFixedPoint;
@@ -247,6 +250,8 @@ impl fee::Config for Test {
     type OnSweep = ();
     type MaxExpectedValue = MaxExpectedValue;
     type NominationApi = Nomination;
+    type CapacityUtilization = VaultRegistry;
+    type RewardSwap = ();
 }
 
 parameter_types! {
@@ -263,6 +268,7 @@ impl loans::Config for Test {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = ();
+    type RuntimeCall = RuntimeCall;
 }
 
 impl Config for Test {
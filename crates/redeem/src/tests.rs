@@ -183,7 +183,7 @@ fn test_request_redeem_succeeds_with_normal_redeem() {
 
         ext::security::get_secure_id::<Test>.mock_safe(move |_| MockResult::Return(H256([0; 32])));
         ext::vault_registry::is_vault_below_premium_threshold::<Test>.mock_safe(move |_| MockResult::Return(Ok(false)));
-        ext::fee::get_redeem_fee::<Test>.mock_safe(move |_| MockResult::Return(Ok(wrapped(redeem_fee))));
+        ext::fee::get_redeem_fee::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(wrapped(redeem_fee))));
         let btc_fee = Redeem::get_current_inclusion_fee(DEFAULT_WRAPPED_CURRENCY).unwrap();
 
         assert_ok!(Redeem::request_redeem(
@@ -762,7 +762,7 @@ mod spec_based_tests {
                 .mock_safe(move |_vault_id, _amount| MockResult::Return(Ok(())));
             ext::vault_registry::is_vault_below_premium_threshold::<Test>
                 .mock_safe(move |_vault_id| MockResult::Return(Ok(false)));
-            let redeem_fee = Fee::get_redeem_fee(&wrapped(amount_to_redeem)).unwrap();
+            let redeem_fee = Fee::get_redeem_fee(&VAULT.currencies, &wrapped(amount_to_redeem)).unwrap();
             let burned_tokens = wrapped(amount_to_redeem) - redeem_fee;
 
             ext::vault_registry::decrease_to_be_replaced_tokens::<Test>.mock_safe(move |vault_id, tokens| {
@@ -150,8 +150,11 @@ pub(crate) mod vault_registry {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod security {
+    use frame_support::dispatch::DispatchResult;
     use frame_system::pallet_prelude::BlockNumberFor;
+    use security::Subsystem;
     use sp_core::H256;
+    use vault_registry::types::DefaultVaultCurrencyPair;
 
     pub fn get_secure_id<T: crate::Config>(id: &T::AccountId) -> H256 {
         <security::Pallet<T>>::get_secure_id(id)
@@ -160,15 +163,26 @@ pub(crate) mod security {
     pub fn active_block_number<T: crate::Config>() -> BlockNumberFor<T> {
         <security::Pallet<T>>::active_block_number()
     }
+
+    pub fn ensure_subsystem_running<T: crate::Config>(
+        subsystem: Subsystem,
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> DispatchResult {
+        <security::Pallet<T>>::ensure_subsystem_running(subsystem, currency_pair)
+    }
 }
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod fee {
+    use crate::DefaultVaultId;
     use currency::Amount;
     use frame_support::dispatch::DispatchError;
 
-    pub fn get_replace_griefing_collateral<T: crate::Config>(amount: &Amount<T>) -> Result<Amount<T>, DispatchError> {
-        <fee::Pallet<T>>::get_replace_griefing_collateral(amount)
+    pub fn get_replace_griefing_collateral<T: crate::Config>(
+        vault_id: &DefaultVaultId<T>,
+        amount: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        <fee::Pallet<T>>::get_replace_griefing_collateral(&vault_id.currencies, amount)
     }
 }
 
@@ -39,6 +39,7 @@ use frame_support::{
     transactional,
 };
 use frame_system::{ensure_root, ensure_signed};
+use security::Subsystem;
 use sp_core::H256;
 use sp_std::vec::Vec;
 use types::DefaultVaultId;
@@ -336,6 +337,8 @@ pub mod pallet {
 #[cfg_attr(test, mockable)]
 impl<T: Config> Pallet<T> {
     fn _request_replace(vault_id: DefaultVaultId<T>, amount_btc: BalanceOf<T>) -> DispatchResult {
+        ext::security::ensure_subsystem_running::<T>(Subsystem::Replace, &vault_id.currencies)?;
+
         // check vault is not banned
         ext::vault_registry::ensure_not_banned::<T>(&vault_id)?;
 
@@ -363,6 +366,7 @@ impl<T: Config> Pallet<T> {
 
         // get the griefing collateral increase
         let griefing_collateral = ext::fee::get_replace_griefing_collateral::<T>(
+            &vault_id,
             &to_be_replaced_increase.convert_to(T::GetGriefingCollateralCurrencyId::get())?,
         )?;
 
@@ -429,6 +433,9 @@ impl<T: Config> Pallet<T> {
         // don't allow vaults to replace themselves
         ensure!(old_vault_id != new_vault_id, Error::<T>::ReplaceSelfNotAllowed);
 
+        ext::security::ensure_subsystem_running::<T>(Subsystem::Replace, &old_vault_id.currencies)?;
+        ext::security::ensure_subsystem_running::<T>(Subsystem::Replace, &new_vault_id.currencies)?;
+
         // probably this check is not strictly required, but it's better to give an
         // explicit error rather than insufficient balance
         ensure!(
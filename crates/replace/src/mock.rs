@@ -41,7 +41,7 @@ frame_support::construct_runtime!(
         VaultRegistry: vault_registry::{Pallet, Call, Config<T>, Storage, Event<T>},
         Oracle: oracle::{Pallet, Call, Config<T>, Storage, Event<T>},
         Replace: replace::{Pallet, Call, Config<T>, Storage, Event<T>},
-        Fee: fee::{Pallet, Call, Config<T>, Storage},
+        Fee: fee::{Pallet, Call, Config<T>, Storage, Event<T>},
         Nomination: nomination::{Pallet, Call, Storage, Config<T>, Event<T>},
         Currency: currency::{Pallet},
     }
@@ -192,6 +192,7 @@ impl vault_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type Loans = ();
 }
 
 impl nomination::Config for Test {
@@ -212,6 +213,7 @@ impl btc_relay::Config for Test {
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -238,6 +240,7 @@ parameter_types! {
 }
 
 impl fee::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type FeePalletId = FeePalletId;
     type WeightInfo = ();
     type SignedFixedPoint = SignedFixedPoint;
@@ -248,6 +251,8 @@ impl fee::Config for Test {
     type OnSweep = ();
     type MaxExpectedValue = MaxExpectedValue;
     type NominationApi = Nomination;
+    type CapacityUtilization = VaultRegistry;
+    type RewardSwap = ();
 }
 
 impl Config for Test {
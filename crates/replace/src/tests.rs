@@ -69,7 +69,7 @@ mod request_replace_tests {
             .mock_safe(move |_| MockResult::Return(Ok(wrapped(1000000))));
         ext::vault_registry::try_increase_to_be_replaced_tokens::<Test>
             .mock_safe(|_, _| MockResult::Return(Ok(wrapped(2))));
-        ext::fee::get_replace_griefing_collateral::<Test>.mock_safe(move |_| MockResult::Return(Ok(griefing(20))));
+        ext::fee::get_replace_griefing_collateral::<Test>.mock_safe(move |_, _| MockResult::Return(Ok(griefing(20))));
         ext::vault_registry::transfer_funds::<Test>.mock_safe(|_, _, _| MockResult::Return(Ok(())));
     }
 
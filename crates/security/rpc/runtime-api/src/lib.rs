@@ -0,0 +1,20 @@
+//! Runtime API definition for the Security Module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait SecurityApi<Subsystem, SubsystemScope, SubsystemStatus> where
+        Subsystem: Codec,
+        SubsystemScope: Codec,
+        SubsystemStatus: Codec,
+    {
+        /// Get the effective status of a subsystem in a scope, taking enclosing scopes into account
+        fn get_subsystem_status(subsystem: Subsystem, scope: SubsystemScope) -> SubsystemStatus;
+
+        /// Get every scope in which a subsystem is currently not running
+        fn get_subsystem_statuses() -> Vec<(Subsystem, SubsystemScope, SubsystemStatus)>;
+    }
+}
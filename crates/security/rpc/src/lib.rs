@@ -0,0 +1,106 @@
+//! RPC interface for the Security Module.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+pub use security_rpc_runtime_api::SecurityApi as SecurityRuntimeApi;
+
+#[rpc(client, server)]
+pub trait SecurityApi<BlockHash, Subsystem, SubsystemScope, SubsystemStatus>
+where
+    Subsystem: Codec,
+    SubsystemScope: Codec,
+    SubsystemStatus: Codec,
+{
+    /// status of a subsystem in a scope, halted if any enclosing scope is halted
+    #[method(name = "security_getSubsystemStatus")]
+    fn get_subsystem_status(
+        &self,
+        subsystem: Subsystem,
+        scope: SubsystemScope,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SubsystemStatus>;
+
+    /// all scopes in which a subsystem is halted
+    #[method(name = "security_getSubsystemStatuses")]
+    fn get_subsystem_statuses(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Subsystem, SubsystemScope, SubsystemStatus)>>;
+}
+
+fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        ErrorCode::InternalError.code(),
+        message.to_string(),
+        None::<()>,
+    )))
+}
+
+/// A struct that implements the [`SecurityApi`].
+pub struct Security<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Security<C, B> {
+    /// Create new `Security` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Security {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn handle_response<T>(result: Result<T, ApiError>, msg: String) -> RpcResult<T> {
+    result.map_err(|err| internal_err(format!("Runtime error: {:?}: {:?}", msg, err)))
+}
+
+#[async_trait]
+impl<C, Block, Subsystem, SubsystemScope, SubsystemStatus>
+    SecurityApiServer<<Block as BlockT>::Hash, Subsystem, SubsystemScope, SubsystemStatus> for Security<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: SecurityRuntimeApi<Block, Subsystem, SubsystemScope, SubsystemStatus>,
+    Subsystem: Codec,
+    SubsystemScope: Codec,
+    SubsystemStatus: Codec,
+{
+    fn get_subsystem_status(
+        &self,
+        subsystem: Subsystem,
+        scope: SubsystemScope,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SubsystemStatus> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_subsystem_status(at, subsystem, scope),
+            "Unable to get subsystem status".into(),
+        )
+    }
+
+    fn get_subsystem_statuses(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(Subsystem, SubsystemScope, SubsystemStatus)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        handle_response(
+            api.get_subsystem_statuses(at),
+            "Unable to get subsystem statuses".into(),
+        )
+    }
+}
@@ -29,5 +29,24 @@ pub mod benchmarks {
         activate_counter(RawOrigin::Root, true);
     }
 
+    #[benchmark]
+    fn set_subsystem_status() {
+        let currency_pair = VaultCurrencyPair {
+            collateral: CurrencyId::ForeignAsset(1),
+            wrapped: CurrencyId::ForeignAsset(2),
+        };
+        #[extrinsic_call]
+        set_subsystem_status(
+            RawOrigin::Root,
+            Subsystem::Issue,
+            SubsystemScope::Pair(currency_pair.clone()),
+            SubsystemStatus::Halted,
+        );
+        assert_eq!(
+            Pallet::<T>::get_subsystem_status(Subsystem::Issue, SubsystemScope::Pair(currency_pair)),
+            SubsystemStatus::Halted
+        );
+    }
+
     impl_benchmark_test_suite!(Security, crate::mock::ExtBuilder::build(), crate::mock::Test);
 }
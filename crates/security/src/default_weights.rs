@@ -39,6 +39,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn on_initialize() -> Weight;
 	fn activate_counter() -> Weight;
+	fn set_subsystem_status() -> Weight;
 }
 
 /// Weights for security using the Substrate node and recommended hardware.
@@ -67,6 +68,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(7_814_000, 711)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Security SubsystemStatuses (r:0 w:1)
+	/// Proof Skipped: Security SubsystemStatuses (max_values: None, max_size: None, mode: Measured)
+	fn set_subsystem_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `711`
+		//  Estimated: `711`
+		// Minimum execution time: 9_126_000 picoseconds.
+		Weight::from_parts(9_531_000, 711)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -94,4 +105,14 @@ impl WeightInfo for () {
 		Weight::from_parts(7_814_000, 711)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Security SubsystemStatuses (r:0 w:1)
+	/// Proof Skipped: Security SubsystemStatuses (max_values: None, max_size: None, mode: Measured)
+	fn set_subsystem_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `711`
+		//  Estimated: `711`
+		// Minimum execution time: 9_126_000 picoseconds.
+		Weight::from_parts(9_531_000, 711)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
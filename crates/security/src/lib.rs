@@ -30,9 +30,11 @@ use codec::Encode;
 use frame_support::{dispatch::DispatchError, weights::Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
+pub use primitives::security::{Subsystem, SubsystemScope, SubsystemStatus};
+use primitives::{CurrencyId, VaultCurrencyPair};
 use sha2::{Digest, Sha256};
 use sp_core::{H256, U256};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -49,18 +51,31 @@ pub mod pallet {
 
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
+
+        /// Origin that is allowed to halt and resume individual subsystems.
+        type SubsystemStatusOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        UpdateActiveBlock { block_number: BlockNumberFor<T> },
+        UpdateActiveBlock {
+            block_number: BlockNumberFor<T>,
+        },
         Activated,
         Deactivated,
+        SubsystemStatusUpdated {
+            subsystem: Subsystem,
+            scope: SubsystemScope<CurrencyId>,
+            status: SubsystemStatus,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        /// The subsystem is halted globally, for one of the currencies or for the currency pair.
+        SubsystemHalted,
+    }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
@@ -87,6 +102,19 @@ pub mod pallet {
     #[pallet::storage]
     pub type IsDeactivated<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// Operational status of each subsystem per scope. A subsystem is halted for a currency pair
+    /// if any scope enclosing the pair is halted. Only non-running entries are stored.
+    #[pallet::storage]
+    pub type SubsystemStatuses<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Subsystem,
+        Blake2_128Concat,
+        SubsystemScope<CurrencyId>,
+        SubsystemStatus,
+        ValueQuery,
+    >;
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -109,6 +137,31 @@ pub mod pallet {
             }
             Ok(())
         }
+
+        /// Halt or resume a subsystem, either globally, for all pairs with a given collateral or
+        /// wrapped currency, or for a single vault currency pair.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_subsystem_status())]
+        pub fn set_subsystem_status(
+            origin: OriginFor<T>,
+            subsystem: Subsystem,
+            scope: SubsystemScope<CurrencyId>,
+            status: SubsystemStatus,
+        ) -> DispatchResult {
+            T::SubsystemStatusOrigin::ensure_origin(origin)?;
+
+            match status {
+                SubsystemStatus::Running => SubsystemStatuses::<T>::remove(subsystem, &scope),
+                SubsystemStatus::Halted => SubsystemStatuses::<T>::insert(subsystem, &scope, status),
+            }
+
+            Self::deposit_event(Event::SubsystemStatusUpdated {
+                subsystem,
+                scope,
+                status,
+            });
+            Ok(())
+        }
     }
 }
 // "Internal" functions, callable by code.
@@ -161,6 +214,37 @@ impl<T: Config> Pallet<T> {
         H256(result)
     }
 
+    /// Returns the effective status of `subsystem` in `scope`: a subsystem is halted in a scope if
+    /// it is halted in any enclosing scope, e.g. a pair is halted if the subsystem is halted
+    /// globally, for its collateral, for its wrapped currency or for the pair itself.
+    pub fn get_subsystem_status(subsystem: Subsystem, scope: SubsystemScope<CurrencyId>) -> SubsystemStatus {
+        let halted = scope
+            .enclosing_scopes()
+            .iter()
+            .any(|scope| SubsystemStatuses::<T>::get(subsystem, scope) == SubsystemStatus::Halted);
+        if halted {
+            SubsystemStatus::Halted
+        } else {
+            SubsystemStatus::Running
+        }
+    }
+
+    /// Returns an error if `subsystem` is halted in any scope enclosing `currency_pair`.
+    pub fn ensure_subsystem_running(
+        subsystem: Subsystem,
+        currency_pair: &VaultCurrencyPair<CurrencyId>,
+    ) -> Result<(), DispatchError> {
+        match Self::get_subsystem_status(subsystem, SubsystemScope::Pair(currency_pair.clone())) {
+            SubsystemStatus::Running => Ok(()),
+            SubsystemStatus::Halted => Err(Error::<T>::SubsystemHalted.into()),
+        }
+    }
+
+    /// Returns all scopes that are currently not running.
+    pub fn get_subsystem_statuses() -> Vec<(Subsystem, SubsystemScope<CurrencyId>, SubsystemStatus)> {
+        SubsystemStatuses::<T>::iter().collect()
+    }
+
     /// for testing purposes only!
     pub fn set_active_block_number(n: BlockNumberFor<T>) {
         ActiveBlockCount::<T>::set(n);
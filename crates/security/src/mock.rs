@@ -56,6 +56,7 @@ impl frame_system::Config for Test {
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 pub struct ExtBuilder;
@@ -1,7 +1,9 @@
-use crate::mock::*;
-use frame_support::assert_ok;
+use crate::{mock::*, Subsystem, SubsystemScope, SubsystemStatus};
+use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
+use primitives::{CurrencyId, TokenSymbol, VaultCurrencyPair};
 use sp_core::H256;
+use sp_runtime::DispatchError;
 
 #[test]
 fn test_get_secure_id() {
@@ -42,6 +44,130 @@ fn test_get_active_block_not_incremented_if_inactive() {
     })
 }
 
+fn currency_pair(collateral: TokenSymbol) -> VaultCurrencyPair<CurrencyId> {
+    VaultCurrencyPair {
+        collateral: CurrencyId::Token(collateral),
+        wrapped: CurrencyId::Token(TokenSymbol::KBTC),
+    }
+}
+
+#[test]
+fn test_set_subsystem_status_requires_origin() {
+    run_test(|| {
+        assert_noop!(
+            Security::set_subsystem_status(
+                RuntimeOrigin::signed(1),
+                Subsystem::Issue,
+                SubsystemScope::Global,
+                SubsystemStatus::Halted
+            ),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn test_subsystem_halted_for_single_currency_pair() {
+    run_test(|| {
+        let ksm_pair = currency_pair(TokenSymbol::KSM);
+        let kint_pair = currency_pair(TokenSymbol::KINT);
+
+        assert_ok!(Security::set_subsystem_status(
+            RawOrigin::Root.into(),
+            Subsystem::Issue,
+            SubsystemScope::Pair(ksm_pair.clone()),
+            SubsystemStatus::Halted
+        ));
+
+        assert_noop!(
+            Security::ensure_subsystem_running(Subsystem::Issue, &ksm_pair),
+            crate::Error::<Test>::SubsystemHalted
+        );
+        assert_ok!(Security::ensure_subsystem_running(Subsystem::Issue, &kint_pair));
+        assert_ok!(Security::ensure_subsystem_running(Subsystem::Redeem, &ksm_pair));
+        assert_eq!(
+            Security::get_subsystem_status(Subsystem::Issue, SubsystemScope::Global),
+            SubsystemStatus::Running
+        );
+
+        assert_ok!(Security::set_subsystem_status(
+            RawOrigin::Root.into(),
+            Subsystem::Issue,
+            SubsystemScope::Pair(ksm_pair.clone()),
+            SubsystemStatus::Running
+        ));
+        assert_ok!(Security::ensure_subsystem_running(Subsystem::Issue, &ksm_pair));
+        assert!(Security::get_subsystem_statuses().is_empty());
+    })
+}
+
+#[test]
+fn test_subsystem_halted_globally() {
+    run_test(|| {
+        assert_ok!(Security::set_subsystem_status(
+            RawOrigin::Root.into(),
+            Subsystem::Redeem,
+            SubsystemScope::Global,
+            SubsystemStatus::Halted
+        ));
+
+        for collateral in [TokenSymbol::KSM, TokenSymbol::KINT] {
+            assert_noop!(
+                Security::ensure_subsystem_running(Subsystem::Redeem, &currency_pair(collateral)),
+                crate::Error::<Test>::SubsystemHalted
+            );
+        }
+        assert_eq!(
+            Security::get_subsystem_statuses(),
+            vec![(Subsystem::Redeem, SubsystemScope::Global, SubsystemStatus::Halted)]
+        );
+    })
+}
+
+#[test]
+fn test_subsystem_halted_for_single_currency() {
+    run_test(|| {
+        let ksm_pair = currency_pair(TokenSymbol::KSM);
+        let kint_pair = currency_pair(TokenSymbol::KINT);
+
+        assert_ok!(Security::set_subsystem_status(
+            RawOrigin::Root.into(),
+            Subsystem::Issue,
+            SubsystemScope::Collateral(ksm_pair.collateral),
+            SubsystemStatus::Halted
+        ));
+        assert_noop!(
+            Security::ensure_subsystem_running(Subsystem::Issue, &ksm_pair),
+            crate::Error::<Test>::SubsystemHalted
+        );
+        assert_ok!(Security::ensure_subsystem_running(Subsystem::Issue, &kint_pair));
+        assert_eq!(
+            Security::get_subsystem_status(Subsystem::Issue, SubsystemScope::Wrapped(ksm_pair.wrapped)),
+            SubsystemStatus::Running
+        );
+
+        // halting the wrapped currency halts every pair that issues it
+        assert_ok!(Security::set_subsystem_status(
+            RawOrigin::Root.into(),
+            Subsystem::Issue,
+            SubsystemScope::Wrapped(ksm_pair.wrapped),
+            SubsystemStatus::Halted
+        ));
+        assert_noop!(
+            Security::ensure_subsystem_running(Subsystem::Issue, &kint_pair),
+            crate::Error::<Test>::SubsystemHalted
+        );
+        assert_eq!(
+            Security::get_subsystem_status(Subsystem::Issue, SubsystemScope::Collateral(kint_pair.collateral)),
+            SubsystemStatus::Running
+        );
+        assert_eq!(
+            Security::get_subsystem_status(Subsystem::Issue, SubsystemScope::Global),
+            SubsystemStatus::Running
+        );
+    })
+}
+
 mod spec_based_tests {
     use super::*;
     use sp_core::U256;
@@ -59,19 +59,20 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use codec::{Decode, Encode, EncodeLike};
+use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     traits::Get,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use primitives::{BalanceToFixedPoint, TruncateFixedPointToInt, VaultCurrencyPair, VaultId};
 use scale_info::TypeInfo;
 use sp_arithmetic::{FixedPointNumber, FixedPointOperand};
 use sp_runtime::{
     traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, MaybeSerializeDeserialize, One, Saturating, Zero},
-    ArithmeticError,
+    ArithmeticError, RuntimeDebug,
 };
-use sp_std::{cmp, convert::TryInto};
+use sp_std::{cmp, convert::TryInto, vec::Vec};
 
 pub(crate) type SignedFixedPoint<T> = <T as Config>::SignedFixedPoint;
 
@@ -81,6 +82,21 @@ pub use reward::RewardsApi;
 pub type DefaultVaultId<T> = VaultId<<T as frame_system::Config>::AccountId, <T as Config>::CurrencyId>;
 pub type DefaultVaultCurrencyPair<T> = VaultCurrencyPair<<T as Config>::CurrencyId>;
 pub type NominatorId<T> = <T as frame_system::Config>::AccountId;
+pub type DefaultUnbonding<T> = Unbonding<<T as frame_system::Config>::Nonce, BlockNumberFor<T>, SignedFixedPoint<T>>;
+
+/// A queued withdrawal of stake. The stake remains in the pool, and is thus
+/// slashed like any other stake, until it is claimed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Unbonding<Nonce, BlockNumber, SignedFixedPoint> {
+    /// The nonce of the staking pool the stake is withdrawn from.
+    pub pool_nonce: Nonce,
+    /// The requested amount of stake, before slashing.
+    pub amount: SignedFixedPoint,
+    /// The `slash_per_token` of the pool at the time of the request.
+    pub slash_per_token: SignedFixedPoint,
+    /// The block from which the stake can be claimed.
+    pub unlock_at: BlockNumber,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -146,6 +162,13 @@ pub mod pallet {
             vault_id: DefaultVaultId<T>,
             new_nonce: T::Nonce,
         },
+        RequestUnbonding {
+            vault_id: DefaultVaultId<T>,
+            nominator_id: T::AccountId,
+            unbonding_id: T::Nonce,
+            amount: T::SignedFixedPoint,
+            unlock_at: BlockNumberFor<T>,
+        },
     }
 
     #[pallet::error]
@@ -156,9 +179,14 @@ pub mod pallet {
         InsufficientFunds,
         /// Cannot slash zero total stake.
         SlashZeroTotalStake,
+        /// There is no stake left to unbond.
+        NothingToUnbond,
+        /// No unbonding request with the given id.
+        UnbondingNotFound,
+        /// The unbonding period has not passed yet.
+        UnbondingNotMatured,
     }
 
-    use frame_system::pallet_prelude::BlockNumberFor;
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
@@ -269,6 +297,47 @@ pub mod pallet {
     #[pallet::storage]
     pub type Nonce<T: Config> = StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, T::Nonce, ValueQuery>;
 
+    /// The nonce used to identify unbonding requests in a vault's staking pools.
+    /// This is a strictly increasing value, independent of the pool `Nonce`.
+    #[pallet::storage]
+    pub type UnbondingNonce<T: Config> = StorageMap<_, Blake2_128Concat, DefaultVaultId<T>, T::Nonce, ValueQuery>;
+
+    /// Pending unbonding requests of a participant.
+    #[pallet::storage]
+    pub type Unbondings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (DefaultVaultId<T>, T::AccountId),
+        Blake2_128Concat,
+        T::Nonce,
+        DefaultUnbonding<T>,
+        OptionQuery,
+    >;
+
+    /// The total stake of a participant that is queued for withdrawal, before slashing.
+    #[pallet::storage]
+    pub type UnbondingStake<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Nonce,
+        Blake2_128Concat,
+        (DefaultVaultId<T>, T::AccountId),
+        SignedFixedPoint<T>,
+        ValueQuery,
+    >;
+
+    /// Accounts for slashes of the stake that is queued for withdrawal.
+    #[pallet::storage]
+    pub type UnbondingSlashTally<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Nonce,
+        Blake2_128Concat,
+        (DefaultVaultId<T>, T::AccountId),
+        SignedFixedPoint<T>,
+        ValueQuery,
+    >;
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -765,6 +834,149 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Compute the stake of `nominator_id` that is queued for withdrawal from the pool
+    /// at `nonce`, after slashing.
+    fn compute_unbonding_stake_at_index(
+        nonce: T::Nonce,
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+    ) -> Result<SignedFixedPoint<T>, DispatchError> {
+        let stake = <UnbondingStake<T>>::get(nonce, (vault_id, nominator_id));
+        let slash_per_token = Self::slash_per_token_at_index(nonce, vault_id);
+        let slash_tally = <UnbondingSlashTally<T>>::get(nonce, (vault_id, nominator_id));
+        let to_slash = Self::compute_amount_to_slash(stake, slash_per_token, slash_tally)?;
+
+        let stake_sub_to_slash = stake.checked_sub(&to_slash).ok_or(ArithmeticError::Underflow)?;
+        Ok(cmp::max(Zero::zero(), stake_sub_to_slash))
+    }
+
+    /// Compute the stake that can be withdrawn for the `unbonding` request, after slashing.
+    fn compute_unbonding_amount(
+        vault_id: &DefaultVaultId<T>,
+        unbonding: &DefaultUnbonding<T>,
+    ) -> Result<SignedFixedPoint<T>, DispatchError> {
+        let slash_per_token = Self::slash_per_token_at_index(unbonding.pool_nonce, vault_id);
+        let slash_tally = unbonding
+            .amount
+            .checked_mul(&unbonding.slash_per_token)
+            .ok_or(ArithmeticError::Overflow)?;
+        let to_slash = Self::compute_amount_to_slash(unbonding.amount, slash_per_token, slash_tally)?;
+
+        let amount_sub_to_slash = unbonding
+            .amount
+            .checked_sub(&to_slash)
+            .ok_or(ArithmeticError::Underflow)?;
+        Ok(cmp::max(Zero::zero(), amount_sub_to_slash))
+    }
+
+    /// Queue a withdrawal of `amount` of stake from the current pool of `vault_id`,
+    /// or of all stake that is not queued yet if `None`. Returns the id of the request
+    /// and the queued amount.
+    pub fn request_unbonding(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        maybe_amount: Option<SignedFixedPoint<T>>,
+        unlock_at: BlockNumberFor<T>,
+    ) -> Result<(T::Nonce, SignedFixedPoint<T>), DispatchError> {
+        let nonce = Self::nonce(vault_id);
+        let stake = Self::compute_precise_stake_at_index(nonce, vault_id, nominator_id)?;
+        let queued = Self::compute_unbonding_stake_at_index(nonce, vault_id, nominator_id)?;
+        let available = stake.saturating_sub(queued);
+
+        let amount = maybe_amount.unwrap_or(available);
+        if amount.is_zero() {
+            return Err(Error::<T>::NothingToUnbond.into());
+        } else if amount > available {
+            return Err(Error::<T>::InsufficientFunds.into());
+        }
+
+        let slash_per_token = Self::slash_per_token_at_index(nonce, vault_id);
+        let slash_per_token_mul_amount = slash_per_token.checked_mul(&amount).ok_or(ArithmeticError::Overflow)?;
+        checked_add_mut!(UnbondingStake<T>, nonce, (vault_id, nominator_id), &amount);
+        checked_add_mut!(
+            UnbondingSlashTally<T>,
+            nonce,
+            (vault_id, nominator_id),
+            &slash_per_token_mul_amount
+        );
+
+        let unbonding_id = <UnbondingNonce<T>>::try_mutate(vault_id, |unbonding_nonce| {
+            let unbonding_id = *unbonding_nonce;
+            *unbonding_nonce = unbonding_nonce
+                .checked_add(&T::Nonce::one())
+                .ok_or(ArithmeticError::Overflow)?;
+            Ok::<_, DispatchError>(unbonding_id)
+        })?;
+        <Unbondings<T>>::insert(
+            (vault_id.clone(), nominator_id.clone()),
+            unbonding_id,
+            Unbonding {
+                pool_nonce: nonce,
+                amount,
+                slash_per_token,
+                unlock_at,
+            },
+        );
+
+        Self::deposit_event(Event::<T>::RequestUnbonding {
+            vault_id: vault_id.clone(),
+            nominator_id: nominator_id.clone(),
+            unbonding_id,
+            amount,
+            unlock_at,
+        });
+        Ok((unbonding_id, amount))
+    }
+
+    /// Remove the unbonding request `unbonding_id` once it has matured. Requests in stale
+    /// pools (i.e. after a force refund) can be removed immediately. Returns the nonce of
+    /// the pool and the amount of stake to withdraw from it.
+    pub fn take_unbonding(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        unbonding_id: T::Nonce,
+        now: BlockNumberFor<T>,
+    ) -> Result<(T::Nonce, SignedFixedPoint<T>), DispatchError> {
+        let key = (vault_id.clone(), nominator_id.clone());
+        let unbonding = <Unbondings<T>>::get(&key, unbonding_id).ok_or(Error::<T>::UnbondingNotFound)?;
+        let nonce = unbonding.pool_nonce;
+        if now < unbonding.unlock_at && nonce == Self::nonce(vault_id) {
+            return Err(Error::<T>::UnbondingNotMatured.into());
+        }
+
+        // the stake may have been withdrawn by other means in the meantime
+        let stake = Self::compute_precise_stake_at_index(nonce, vault_id, nominator_id)?;
+        let amount = cmp::min(Self::compute_unbonding_amount(vault_id, &unbonding)?, stake);
+
+        let slash_tally = unbonding
+            .amount
+            .checked_mul(&unbonding.slash_per_token)
+            .ok_or(ArithmeticError::Overflow)?;
+        let remaining = checked_sub_mut!(UnbondingStake<T>, nonce, (vault_id, nominator_id), &unbonding.amount);
+        checked_sub_mut!(UnbondingSlashTally<T>, nonce, (vault_id, nominator_id), &slash_tally);
+        if remaining.is_zero() {
+            // cleanup any rounding errors
+            <UnbondingStake<T>>::remove(nonce, (vault_id, nominator_id));
+            <UnbondingSlashTally<T>>::remove(nonce, (vault_id, nominator_id));
+        }
+        <Unbondings<T>>::remove(&key, unbonding_id);
+
+        Ok((nonce, amount))
+    }
+
+    /// Get the pending unbonding requests of `nominator_id` with the amounts after slashing.
+    pub fn get_unbondings(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+    ) -> Result<Vec<(T::Nonce, SignedFixedPoint<T>, BlockNumberFor<T>)>, DispatchError> {
+        <Unbondings<T>>::iter_prefix((vault_id.clone(), nominator_id.clone()))
+            .map(|(unbonding_id, unbonding)| {
+                let amount = Self::compute_unbonding_amount(vault_id, &unbonding)?;
+                Ok((unbonding_id, amount, unbonding.unlock_at))
+            })
+            .collect()
+    }
+
     #[cfg(feature = "integration-tests")]
     pub fn get_total_rewards(currency_id: T::CurrencyId) -> <SignedFixedPoint<T> as FixedPointNumber>::Inner {
         TotalRewards::<T>::iter()
@@ -916,6 +1128,85 @@ where
     }
 }
 
+pub trait UnbondingApi<PoolId, AccountId, Index, BlockNumber, Balance> {
+    /// Queue a withdrawal of `amount` of stake from the `pool_id`, or of all stake that
+    /// is not queued yet.
+    fn request_unbonding(
+        pool_id: &PoolId,
+        nominator_id: &AccountId,
+        amount: Option<Balance>,
+        unlock_at: BlockNumber,
+    ) -> Result<(Index, Balance), DispatchError>;
+
+    /// Remove a matured unbonding request, returning the nonce of the pool and the
+    /// amount of stake to withdraw from it.
+    fn take_unbonding(
+        pool_id: &PoolId,
+        nominator_id: &AccountId,
+        unbonding_id: Index,
+        now: BlockNumber,
+    ) -> Result<(Index, Balance), DispatchError>;
+
+    /// Get the pending unbonding requests of the nominator.
+    fn get_unbondings(
+        pool_id: &PoolId,
+        nominator_id: &AccountId,
+    ) -> Result<Vec<(Index, Balance, BlockNumber)>, DispatchError>;
+}
+
+impl<T, Balance> UnbondingApi<DefaultVaultId<T>, T::AccountId, T::Nonce, BlockNumberFor<T>, Balance> for Pallet<T>
+where
+    T: Config,
+    Balance: BalanceToFixedPoint<SignedFixedPoint<T>>,
+    <T::SignedFixedPoint as FixedPointNumber>::Inner: TryInto<Balance>,
+{
+    fn request_unbonding(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        amount: Option<Balance>,
+        unlock_at: BlockNumberFor<T>,
+    ) -> Result<(T::Nonce, Balance), DispatchError> {
+        let amount = amount
+            .map(|amount| amount.to_fixed().ok_or(Error::<T>::TryIntoIntError))
+            .transpose()?;
+        let (unbonding_id, amount) = Pallet::<T>::request_unbonding(vault_id, nominator_id, amount, unlock_at)?;
+        Ok((unbonding_id, truncate_to_balance::<T, Balance>(amount)?))
+    }
+
+    fn take_unbonding(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+        unbonding_id: T::Nonce,
+        now: BlockNumberFor<T>,
+    ) -> Result<(T::Nonce, Balance), DispatchError> {
+        let (nonce, amount) = Pallet::<T>::take_unbonding(vault_id, nominator_id, unbonding_id, now)?;
+        Ok((nonce, truncate_to_balance::<T, Balance>(amount)?))
+    }
+
+    fn get_unbondings(
+        vault_id: &DefaultVaultId<T>,
+        nominator_id: &T::AccountId,
+    ) -> Result<Vec<(T::Nonce, Balance, BlockNumberFor<T>)>, DispatchError> {
+        Pallet::<T>::get_unbondings(vault_id, nominator_id)?
+            .into_iter()
+            .map(|(unbonding_id, amount, unlock_at)| {
+                Ok((unbonding_id, truncate_to_balance::<T, Balance>(amount)?, unlock_at))
+            })
+            .collect()
+    }
+}
+
+fn truncate_to_balance<T: Config, Balance>(amount: SignedFixedPoint<T>) -> Result<Balance, DispatchError>
+where
+    <T::SignedFixedPoint as FixedPointNumber>::Inner: TryInto<Balance>,
+{
+    amount
+        .truncate_to_inner()
+        .ok_or(Error::<T>::TryIntoIntError)?
+        .try_into()
+        .map_err(|_| Error::<T>::TryIntoIntError.into())
+}
+
 pub mod migration {
     use super::*;
     use frame_support::transactional;
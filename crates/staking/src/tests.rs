@@ -306,3 +306,94 @@ fn should_compute_stake_after_adjustments() {
         assert_ok!(Staking::compute_stake(&VAULT, &VAULT.account_id), 1_000_000);
     })
 }
+
+#[test]
+fn should_request_and_take_unbonding() {
+    run_test(|| {
+        assert_ok!(Staking::deposit_stake(&VAULT, &ALICE.account_id, fixed!(100)));
+        assert_ok!(
+            Staking::request_unbonding(&VAULT, &ALICE.account_id, Some(fixed!(40)), 10),
+            (0, fixed!(40))
+        );
+        // the remaining stake can be queued without specifying the amount
+        assert_ok!(
+            Staking::request_unbonding(&VAULT, &ALICE.account_id, None, 20),
+            (1, fixed!(60))
+        );
+        assert_err!(
+            Staking::request_unbonding(&VAULT, &ALICE.account_id, None, 20),
+            TestError::NothingToUnbond
+        );
+
+        // queued stake is still stake
+        assert_ok!(Staking::compute_stake(&VAULT, &ALICE.account_id), 100);
+        assert_ok!(
+            Staking::get_unbondings(&VAULT, &ALICE.account_id),
+            vec![(0, fixed!(40), 10), (1, fixed!(60), 20)]
+        );
+
+        assert_err!(
+            Staking::take_unbonding(&VAULT, &ALICE.account_id, 0, 9),
+            TestError::UnbondingNotMatured
+        );
+        assert_ok!(
+            Staking::take_unbonding(&VAULT, &ALICE.account_id, 0, 10),
+            (0, fixed!(40))
+        );
+        assert_err!(
+            Staking::take_unbonding(&VAULT, &ALICE.account_id, 0, 10),
+            TestError::UnbondingNotFound
+        );
+        assert_ok!(
+            Staking::get_unbondings(&VAULT, &ALICE.account_id),
+            vec![(1, fixed!(60), 20)]
+        );
+    })
+}
+
+#[test]
+fn should_slash_unbonding_stake() {
+    run_test(|| {
+        assert_ok!(Staking::deposit_stake(&VAULT, &ALICE.account_id, fixed!(100)));
+        assert_ok!(Staking::deposit_stake(&VAULT, &BOB.account_id, fixed!(100)));
+        assert_ok!(Staking::request_unbonding(
+            &VAULT,
+            &ALICE.account_id,
+            Some(fixed!(50)),
+            10
+        ));
+
+        assert_ok!(Staking::slash_stake(&VAULT, fixed!(100)));
+        assert_ok!(Staking::compute_stake(&VAULT, &ALICE.account_id), 50);
+        assert_ok!(
+            Staking::get_unbondings(&VAULT, &ALICE.account_id),
+            vec![(0, fixed!(25), 10)]
+        );
+
+        // only the stake that is not queued can be requested
+        assert_err!(
+            Staking::request_unbonding(&VAULT, &ALICE.account_id, Some(fixed!(30)), 10),
+            TestError::InsufficientFunds
+        );
+        assert_ok!(
+            Staking::take_unbonding(&VAULT, &ALICE.account_id, 0, 10),
+            (0, fixed!(25))
+        );
+    })
+}
+
+#[test]
+fn should_take_unbonding_after_force_refund() {
+    run_test(|| {
+        assert_ok!(Staking::deposit_stake(&VAULT, &VAULT.account_id, fixed!(100)));
+        assert_ok!(Staking::deposit_stake(&VAULT, &ALICE.account_id, fixed!(100)));
+        assert_ok!(Staking::request_unbonding(&VAULT, &ALICE.account_id, None, 10));
+        assert_ok!(Staking::force_refund(&VAULT));
+
+        // the stake is no longer locked in the current pool
+        assert_ok!(
+            Staking::take_unbonding(&VAULT, &ALICE.account_id, 0, 1),
+            (0, fixed!(100))
+        );
+    })
+}
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, weights::Weight};
 use num_bigint::{BigUint, ToBigUint};
 
 pub mod loans;
@@ -20,14 +20,83 @@ pub trait OracleApi<Amount, CurrencyId> {
     fn convert(amount: &Amount, to: CurrencyId) -> Result<Amount, DispatchError>;
 }
 
-pub trait NominationApi<VaultId, Amount> {
+pub trait NominationApi<VaultId, AccountId, Amount> {
     fn deposit_vault_collateral(vault_id: &VaultId, amount: &Amount) -> Result<(), DispatchError>;
+    fn deposit_nominator_collateral(
+        vault_id: &VaultId,
+        nominator_id: &AccountId,
+        amount: &Amount,
+    ) -> Result<(), DispatchError>;
     fn ensure_opted_in_to_nomination(vault_id: &VaultId) -> Result<(), DispatchError>;
 
     #[cfg(any(feature = "runtime-benchmarks", test))]
     fn opt_in_to_nomination(vault_id: &VaultId);
 }
 
+pub trait CapacityUtilizationApi<CurrencyPair, UnsignedFixedPoint> {
+    /// Fraction of the issuance capacity of the currency pair that is currently in use.
+    fn get_capacity_utilization(currency_pair: &CurrencyPair) -> Result<UnsignedFixedPoint, DispatchError>;
+}
+
+pub trait SwapApi<AccountId, CurrencyId, Balance> {
+    /// Swap exactly `amount_in` of `currency_in` held by `who` for at least
+    /// `amount_out_min` of `currency_out`, returning the received amount.
+    fn swap_exact_for(
+        who: &AccountId,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        amount_in: Balance,
+        amount_out_min: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// The amount of `currency_in` required to receive exactly `amount_out` of
+    /// `currency_out` along the cheapest route, if there is one.
+    fn quote_for_exact(currency_in: CurrencyId, currency_out: CurrencyId, amount_out: Balance) -> Option<Balance>;
+
+    /// Swap at most `amount_in_max` of `currency_in` held by `who` for exactly `amount_out`
+    /// of `currency_out` along the cheapest route, returning the spent amount.
+    fn swap_for_exact(
+        who: &AccountId,
+        currency_in: CurrencyId,
+        currency_out: CurrencyId,
+        amount_out: Balance,
+        amount_in_max: Balance,
+    ) -> Result<Balance, DispatchError>;
+
+    /// Upper bound for the weight of `swap_for_exact`, including the search for the route.
+    fn swap_for_exact_weight() -> Weight;
+}
+
+impl<AccountId, CurrencyId, Balance> SwapApi<AccountId, CurrencyId, Balance> for () {
+    fn swap_exact_for(
+        _who: &AccountId,
+        _currency_in: CurrencyId,
+        _currency_out: CurrencyId,
+        _amount_in: Balance,
+        _amount_out_min: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Other("Swaps are not supported"))
+    }
+
+    fn quote_for_exact(_currency_in: CurrencyId, _currency_out: CurrencyId, _amount_out: Balance) -> Option<Balance> {
+        None
+    }
+
+    fn swap_for_exact(
+        _who: &AccountId,
+        _currency_in: CurrencyId,
+        _currency_out: CurrencyId,
+        _amount_out: Balance,
+        _amount_in_max: Balance,
+    ) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Other("Swaps are not supported"))
+    }
+
+    fn swap_for_exact_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 pub trait OnExchangeRateChange<CurrencyId> {
     fn on_exchange_rate_change(currency_id: &CurrencyId);
 }
@@ -34,6 +34,36 @@ pub trait LoansApi<CurrencyId, AccountId, Amount> {
     fn recompute_collateral_amount(underlying: &Amount) -> Result<Amount, DispatchError>;
 }
 
+impl<CurrencyId, AccountId, Amount> LoansApi<CurrencyId, AccountId, Amount> for () {
+    fn do_mint(_supplier: &AccountId, _amount: &Amount) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn do_borrow(_borrower: &AccountId, _borrow: &Amount) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn do_deposit_collateral(_supplier: &AccountId, _lend_tokens: &Amount) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn do_withdraw_collateral(_supplier: &AccountId, _voucher: &Amount) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn do_repay_borrow(_borrower: &AccountId, _borrow: &Amount) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn do_redeem(_supplier: &AccountId, _amount: &Amount, _voucher: &Amount) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn recompute_underlying_amount(_lend_tokens: &Amount) -> Result<Amount, DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn underlying_id(_lend_token_id: CurrencyId) -> Result<CurrencyId, DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+    fn recompute_collateral_amount(_underlying: &Amount) -> Result<Amount, DispatchError> {
+        Err(DispatchError::Other("Lending is not supported"))
+    }
+}
+
 pub trait LoansMarketDataProvider<CurrencyId, Balance> {
     fn get_market_info(asset_id: CurrencyId) -> Result<MarketInfo, DispatchError>;
     fn get_market_status(asset_id: CurrencyId) -> Result<MarketStatus<Balance>, DispatchError>;
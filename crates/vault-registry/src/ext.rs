@@ -17,11 +17,21 @@ pub(crate) mod currency {
 
 #[cfg_attr(test, mockable)]
 pub(crate) mod security {
+    use crate::types::DefaultVaultCurrencyPair;
+    use frame_support::dispatch::DispatchResult;
     use frame_system::pallet_prelude::BlockNumberFor;
+    use security::Subsystem;
 
     pub fn active_block_number<T: crate::Config>() -> BlockNumberFor<T> {
         <security::Pallet<T>>::active_block_number()
     }
+
+    pub fn ensure_subsystem_running<T: crate::Config>(
+        subsystem: Subsystem,
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> DispatchResult {
+        <security::Pallet<T>>::ensure_subsystem_running(subsystem, currency_pair)
+    }
 }
 
 #[cfg_attr(test, mockable)]
@@ -45,6 +45,7 @@ use currency::Rounding;
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
+    storage::with_storage_layer,
     traits::Get,
     transactional, PalletId,
 };
@@ -52,6 +53,7 @@ use frame_system::{
     ensure_signed,
     offchain::{SendTransactionTypes, SubmitTransaction},
 };
+use security::Subsystem;
 use sp_core::{H256, U256};
 use sp_runtime::{
     traits::*,
@@ -59,7 +61,7 @@ use sp_runtime::{
     ArithmeticError,
 };
 use sp_std::{convert::TryInto, vec::Vec};
-use traits::NominationApi;
+use traits::{CapacityUtilizationApi, LoansApi, NominationApi};
 
 // value taken from https://github.com/substrate-developer-hub/recipes/blob/master/pallets/ocw-demo/src/lib.rs
 pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
@@ -101,6 +103,9 @@ pub mod pallet {
         /// Currency used for griefing collateral, e.g. DOT.
         #[pallet::constant]
         type GetGriefingCollateralCurrencyId: Get<CurrencyId<Self>>;
+
+        /// Lending markets, used to redeem liquidated lend token collateral.
+        type Loans: LoansApi<CurrencyId<Self>, Self::AccountId, Amount<Self>>;
     }
 
     #[pallet::hooks]
@@ -111,7 +116,7 @@ pub mod pallet {
         }
 
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
-            crate::types::v1::migrate_v1_to_v6::<T>()
+            crate::types::v1::migrate_v1_to_v6::<T>().saturating_add(crate::types::v6::migrate_v6_to_v7::<T>())
         }
     }
 
@@ -207,6 +212,9 @@ pub mod pallet {
             accept_new_issues: bool,
         ) -> DispatchResultWithPostInfo {
             let account_id = ensure_signed(origin)?;
+            if accept_new_issues {
+                ext::security::ensure_subsystem_running::<T>(Subsystem::VaultRegistry, &currency_pair)?;
+            }
             let vault_id = VaultId::new(account_id, currency_pair.collateral, currency_pair.wrapped);
             let mut vault = Self::get_active_rich_vault_from_id(&vault_id)?;
             vault.set_accept_new_issues(accept_new_issues)?;
@@ -632,6 +640,11 @@ pub mod pallet {
     pub(super) type TotalUserVaultCollateral<T: Config> =
         StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, BalanceOf<T>, ValueQuery>;
 
+    /// Total tokens issued by active vaults, excluding the liquidation vault
+    #[pallet::storage]
+    pub(super) type TotalUserVaultIssuedTokens<T: Config> =
+        StorageMap<_, Blake2_128Concat, DefaultVaultCurrencyPair<T>, BalanceOf<T>, ValueQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForStorageVersion() -> Version {
         Version::V6
@@ -695,6 +708,7 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn _register_vault(vault_id: DefaultVaultId<T>, collateral: BalanceOf<T>) -> DispatchResult {
+        ext::security::ensure_subsystem_running::<T>(Subsystem::VaultRegistry, &vault_id.currencies)?;
         ensure!(
             SecureCollateralThreshold::<T>::contains_key(&vault_id.currencies),
             Error::<T>::ThresholdNotSet
@@ -1233,7 +1247,8 @@ impl<T: Config> Pallet<T> {
 
     /// Handles redeem requests which are executed against the LiquidationVault.
     /// Reduces the issued token of the LiquidationVault and slashes the
-    /// corresponding amount of collateral.
+    /// corresponding amount of collateral. Lend token collateral is redeemed
+    /// in the lending market so that the redeemer receives the underlying.
     ///
     /// # Arguments
     /// * `currency_id` - the currency being redeemed
@@ -1269,11 +1284,16 @@ impl<T: Config> Pallet<T> {
             &liquidation_vault.to_be_backed_tokens()?,
         )?;
 
-        Self::transfer_funds(
-            source_liquidation_vault,
-            CurrencySource::FreeBalance(redeemer_id.clone()),
-            &to_transfer,
-        )?;
+        let transferred_collateral = if currency_id.is_lend_token() {
+            Self::transfer_liquidated_lend_tokens(source_liquidation_vault, redeemer_id, &to_transfer)?
+        } else {
+            Self::transfer_funds(
+                source_liquidation_vault,
+                CurrencySource::FreeBalance(redeemer_id.clone()),
+                &to_transfer,
+            )?;
+            to_transfer
+        };
 
         // need to requery since the liquidation vault gets modified in `transfer_funds`
         let mut liquidation_vault = Self::get_rich_liquidation_vault(&currency_pair);
@@ -1282,12 +1302,43 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::<T>::RedeemTokensLiquidation {
             redeemer_id: redeemer_id.clone(),
             burned_tokens: amount_wrapped.amount(),
-            transferred_collateral: to_transfer.amount(),
+            transferred_collateral: transferred_collateral.amount(),
         });
 
         Ok(())
     }
 
+    /// Pays out lend tokens held by the liquidation vault in their underlying currency by
+    /// redeeming them in the lending market. If the market cannot be redeemed from, e.g.
+    /// because all of its cash is borrowed, the lend tokens themselves are paid out instead.
+    ///
+    /// # Arguments
+    /// * `source` - the liquidation vault holding the lend tokens
+    /// * `redeemer_id` - the account receiving the collateral
+    /// * `lend_tokens` - the amount of lend tokens to pay out
+    fn transfer_liquidated_lend_tokens(
+        source: CurrencySource<T>,
+        redeemer_id: &T::AccountId,
+        lend_tokens: &Amount<T>,
+    ) -> Result<Amount<T>, DispatchError> {
+        let liquidation_vault_account_id = source.account_id();
+        Self::transfer_funds(
+            source,
+            CurrencySource::FreeBalance(liquidation_vault_account_id.clone()),
+            lend_tokens,
+        )?;
+
+        let payout = with_storage_layer(|| -> Result<Amount<T>, DispatchError> {
+            let underlying = T::Loans::recompute_underlying_amount(lend_tokens)?;
+            T::Loans::do_redeem(&liquidation_vault_account_id, &underlying, lend_tokens)?;
+            Ok(underlying)
+        })
+        .unwrap_or_else(|_| lend_tokens.clone());
+        payout.transfer(&liquidation_vault_account_id, redeemer_id)?;
+
+        Ok(payout)
+    }
+
     /// Replaces the old vault by the new vault by transferring tokens
     /// from the old vault to the new one
     ///
@@ -1764,6 +1815,32 @@ impl<T: Config> Pallet<T> {
         Ok(Amount::new(amount, vault_id.currencies.collateral))
     }
 
+    /// Get the fraction of the issuance capacity of the currency pair that is in use,
+    /// i.e. the tokens issued by active vaults divided by the amount of tokens their
+    /// collateral, up to the system collateral ceiling, can back at the global secure
+    /// threshold. Saturates at one.
+    pub fn get_capacity_utilization(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        let issued_tokens = Self::get_total_user_vault_issued_tokens(currency_pair);
+        if issued_tokens.is_zero() {
+            return Ok(UnsignedFixedPoint::<T>::zero());
+        }
+
+        let threshold = Self::secure_collateral_threshold(currency_pair).ok_or(Error::<T>::ThresholdNotSet)?;
+        let mut collateral = Self::get_total_user_vault_collateral(currency_pair)?;
+        if let Some(ceiling) = SystemCollateralCeiling::<T>::get(currency_pair) {
+            collateral = collateral.min(&Amount::new(ceiling, currency_pair.collateral))?;
+        }
+        let capacity =
+            Self::calculate_max_wrapped_from_collateral_for_threshold(&collateral, currency_pair.wrapped, threshold)?;
+        if capacity.le(&issued_tokens)? {
+            return Ok(UnsignedFixedPoint::<T>::one());
+        }
+
+        issued_tokens.ratio(&capacity)
+    }
+
     pub fn compute_capacity(vault_id: &DefaultVaultId<T>) -> Result<Amount<T>, DispatchError> {
         let vault = Self::get_rich_vault_from_id(&vault_id)?;
         let amount = vault.get_vault_collateral()?;
@@ -1788,6 +1865,31 @@ impl<T: Config> Pallet<T> {
         ))
     }
 
+    fn get_total_user_vault_issued_tokens(currency_pair: &DefaultVaultCurrencyPair<T>) -> Amount<T> {
+        Amount::new(
+            TotalUserVaultIssuedTokens::<T>::get(currency_pair),
+            currency_pair.wrapped,
+        )
+    }
+
+    pub(crate) fn increase_total_user_vault_issued_tokens(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> DispatchResult {
+        let new = Self::get_total_user_vault_issued_tokens(currency_pair).checked_add(amount)?;
+        TotalUserVaultIssuedTokens::<T>::insert(currency_pair, new.amount());
+        Ok(())
+    }
+
+    pub(crate) fn decrease_total_user_vault_issued_tokens(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+        amount: &Amount<T>,
+    ) -> DispatchResult {
+        let new = Self::get_total_user_vault_issued_tokens(currency_pair).checked_sub(amount)?;
+        TotalUserVaultIssuedTokens::<T>::insert(currency_pair, new.amount());
+        Ok(())
+    }
+
     #[cfg(feature = "integration-tests")]
     pub fn get_free_collateral(vault_id: &DefaultVaultId<T>) -> Result<Amount<T>, DispatchError> {
         let rich_vault = Self::get_rich_vault_from_id(vault_id)?;
@@ -1964,3 +2066,11 @@ impl<T: Config> Pallet<T> {
         }
     }
 }
+
+impl<T: Config> CapacityUtilizationApi<DefaultVaultCurrencyPair<T>, UnsignedFixedPoint<T>> for Pallet<T> {
+    fn get_capacity_utilization(
+        currency_pair: &DefaultVaultCurrencyPair<T>,
+    ) -> Result<UnsignedFixedPoint<T>, DispatchError> {
+        Pallet::<T>::get_capacity_utilization(currency_pair)
+    }
+}
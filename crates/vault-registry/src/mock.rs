@@ -40,7 +40,7 @@ frame_support::construct_runtime!(
         Security: security::{Pallet, Call, Storage, Event<T>},
         VaultRegistry: vault_registry::{Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned},
         Oracle: oracle::{Pallet, Call, Config<T>, Storage, Event<T>},
-        Fee: fee::{Pallet, Call, Config<T>, Storage},
+        Fee: fee::{Pallet, Call, Config<T>, Storage, Event<T>},
         Currency: currency::{Pallet},
         Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>},
     }
@@ -204,6 +204,7 @@ impl loans::Config for Test {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = ();
+    type RuntimeCall = RuntimeCall;
 }
 
 #[cfg_attr(test, mockable)]
@@ -231,6 +232,7 @@ parameter_types! {
 }
 
 impl fee::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
     type FeePalletId = FeePalletId;
     type WeightInfo = ();
     type SignedFixedPoint = SignedFixedPoint;
@@ -241,6 +243,8 @@ impl fee::Config for Test {
     type OnSweep = ();
     type MaxExpectedValue = MaxExpectedValue;
     type NominationApi = MockDeposit;
+    type CapacityUtilization = VaultRegistry;
+    type RewardSwap = ();
 }
 
 parameter_types! {
@@ -249,7 +253,7 @@ parameter_types! {
 
 pub struct MockDeposit;
 
-impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test>> for MockDeposit {
+impl traits::NominationApi<VaultId<AccountId, CurrencyId>, AccountId, currency::Amount<Test>> for MockDeposit {
     fn deposit_vault_collateral(
         vault_id: &VaultId<AccountId, CurrencyId>,
         amount: &currency::Amount<Test>,
@@ -264,6 +268,18 @@ impl traits::NominationApi<VaultId<AccountId, CurrencyId>, currency::Amount<Test
 
         Ok(())
     }
+    fn deposit_nominator_collateral(
+        vault_id: &VaultId<AccountId, CurrencyId>,
+        nominator_id: &AccountId,
+        amount: &currency::Amount<Test>,
+    ) -> Result<(), DispatchError> {
+        amount.transfer(nominator_id, &vault_id.account_id)?;
+        <vault_registry::PoolManager<Test>>::deposit_collateral(vault_id, nominator_id, amount)?;
+        amount.lock_on(&vault_id.account_id)?;
+        VaultRegistry::try_increase_total_backing_collateral(&vault_id.currencies, &amount)?;
+
+        Ok(())
+    }
     fn ensure_opted_in_to_nomination(_vault_id: &VaultId<AccountId, CurrencyId>) -> Result<(), DispatchError> {
         Ok(())
     }
@@ -276,6 +292,7 @@ impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type Loans = Loans;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
@@ -289,6 +306,7 @@ where
 impl security::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type SubsystemStatusOrigin = EnsureRoot<AccountId>;
 }
 
 pub type TestEvent = RuntimeEvent;
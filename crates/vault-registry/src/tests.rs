@@ -9,7 +9,10 @@ use frame_support::{assert_err, assert_noop, assert_ok};
 use mocktopus::mocking::*;
 use pretty_assertions::assert_eq;
 use security::Pallet as Security;
-use sp_arithmetic::{traits::One, FixedPointNumber, FixedU128};
+use sp_arithmetic::{
+    traits::{One, Zero},
+    FixedPointNumber, FixedU128,
+};
 use sp_core::U256;
 use sp_runtime::{
     offchain::{testing::TestTransactionPoolExt, TransactionPoolExt},
@@ -585,6 +588,74 @@ fn redeem_tokens_liquidation_fails_with_insufficient_tokens() {
     });
 }
 
+fn liquidate_lend_token_collateral(lend_tokens: &Amount<Test>, issued_tokens: &Amount<Test>) {
+    let supplier = 6;
+    crate::benchmarking::activate_lending_and_mint::<Test>(
+        DEFAULT_COLLATERAL_CURRENCY,
+        WORST_CASE_COLLATERAL_CURRENCY,
+        &supplier,
+    );
+    assert_ok!(VaultRegistry::transfer_funds(
+        CurrencySource::FreeBalance(supplier),
+        CurrencySource::LiquidationVault(WORST_CASE_CURRENCY_PAIR),
+        lend_tokens,
+    ));
+    let mut liquidation_vault = VaultRegistry::get_rich_liquidation_vault(&WORST_CASE_CURRENCY_PAIR);
+    assert_ok!(liquidation_vault.increase_to_be_issued(issued_tokens));
+    assert_ok!(liquidation_vault.increase_issued(issued_tokens));
+}
+
+#[test]
+fn redeem_tokens_liquidation_redeems_lend_tokens() {
+    run_test(|| {
+        let user_id = 7;
+        // 1 DOT is worth 50 lend tokens at the initial exchange rate
+        liquidate_lend_token_collateral(&Amount::new(50_000, WORST_CASE_COLLATERAL_CURRENCY), &wrapped(50));
+
+        assert_ok!(VaultRegistry::redeem_tokens_liquidation(
+            WORST_CASE_COLLATERAL_CURRENCY,
+            &user_id,
+            &wrapped(10)
+        ));
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &user_id),
+            amount(100)
+        );
+        assert!(crate::ext::currency::get_free_balance::<Test>(WORST_CASE_COLLATERAL_CURRENCY, &user_id).is_zero());
+        let liquidation_vault = VaultRegistry::get_liquidation_vault(&WORST_CASE_CURRENCY_PAIR);
+        assert_eq!(liquidation_vault.collateral, 45_000);
+        assert_eq!(liquidation_vault.issued_tokens, 40);
+        assert_emitted!(Event::RedeemTokensLiquidation {
+            redeemer_id: user_id,
+            burned_tokens: 10,
+            transferred_collateral: 100
+        });
+    });
+}
+
+#[test]
+fn redeem_tokens_liquidation_pays_out_lend_tokens_without_market_cash() {
+    run_test(|| {
+        let user_id = 7;
+        liquidate_lend_token_collateral(&Amount::new(50_000, WORST_CASE_COLLATERAL_CURRENCY), &wrapped(50));
+        // all cash in the lending market is gone, e.g. because it has been borrowed
+        let loans_account_id = loans::Pallet::<Test>::account_id();
+        let cash = crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &loans_account_id);
+        assert_ok!(cash.transfer(&loans_account_id, &OTHER_ID.account_id));
+
+        assert_ok!(VaultRegistry::redeem_tokens_liquidation(
+            WORST_CASE_COLLATERAL_CURRENCY,
+            &user_id,
+            &wrapped(10)
+        ));
+        assert!(crate::ext::currency::get_free_balance::<Test>(DEFAULT_COLLATERAL_CURRENCY, &user_id).is_zero());
+        assert_eq!(
+            crate::ext::currency::get_free_balance::<Test>(WORST_CASE_COLLATERAL_CURRENCY, &user_id),
+            Amount::new(5_000, WORST_CASE_COLLATERAL_CURRENCY)
+        );
+    });
+}
+
 #[test]
 fn replace_tokens_liquidation_succeeds() {
     run_test(|| {
@@ -948,6 +1019,55 @@ fn get_collateralization_from_vault_succeeds() {
     })
 }
 
+#[test]
+fn get_capacity_utilization_succeeds() {
+    run_test(|| {
+        assert_eq!(
+            VaultRegistry::get_capacity_utilization(&DEFAULT_CURRENCY_PAIR),
+            Ok(FixedU128::zero())
+        );
+
+        // collateral of 100_000 backs 10_000 tokens, or 5_000 at the 200% secure threshold
+        let id = create_sample_vault_and_issue_tokens(2_500);
+        assert_eq!(
+            VaultRegistry::get_capacity_utilization(&DEFAULT_CURRENCY_PAIR),
+            Ok(FixedU128::checked_from_rational(50, 100).unwrap())
+        );
+
+        // issued tokens of liquidated vaults no longer count towards the utilization
+        assert_ok!(VaultRegistry::liquidate_vault(&id));
+        assert_eq!(
+            VaultRegistry::get_capacity_utilization(&DEFAULT_CURRENCY_PAIR),
+            Ok(FixedU128::zero())
+        );
+    })
+}
+
+#[test]
+fn get_capacity_utilization_is_capped_by_collateral_ceiling() {
+    run_test(|| {
+        create_sample_vault_and_issue_tokens(2_500);
+        // only half of the collateral counts towards the capacity, which is then fully used
+        VaultRegistry::_set_system_collateral_ceiling(DEFAULT_CURRENCY_PAIR, DEFAULT_COLLATERAL / 2);
+        assert_eq!(
+            VaultRegistry::get_capacity_utilization(&DEFAULT_CURRENCY_PAIR),
+            Ok(FixedU128::one())
+        );
+    })
+}
+
+#[test]
+fn get_capacity_utilization_fails_without_exchange_rate() {
+    run_test(|| {
+        create_sample_vault_and_issue_tokens(2_500);
+        oracle::Aggregate::<Test>::remove(oracle::OracleKey::ExchangeRate(DEFAULT_COLLATERAL_CURRENCY));
+        assert_err!(
+            VaultRegistry::get_capacity_utilization(&DEFAULT_CURRENCY_PAIR),
+            oracle::Error::<Test>::MissingExchangeRate
+        );
+    })
+}
+
 #[test]
 fn get_unsettled_collateralization_from_vault_succeeds() {
     run_test(|| {
@@ -37,6 +37,8 @@ pub enum Version {
     V5,
     /// Removed wallet
     V6,
+    /// Added total issued tokens per currency pair
+    V7,
 }
 
 #[derive(Debug, PartialEq)]
@@ -125,6 +127,35 @@ pub mod v1 {
     }
 }
 
+pub mod v6 {
+    use super::*;
+    use sp_runtime::traits::Saturating;
+
+    /// Initializes `TotalUserVaultIssuedTokens` from the issued tokens of all vaults
+    /// that have not been liquidated.
+    pub fn migrate_v6_to_v7<T: Config>() -> frame_support::weights::Weight {
+        if !matches!(crate::StorageVersion::<T>::get(), Version::V6) {
+            log::info!("Not running vault storage migration");
+            return T::DbWeight::get().reads(1); // already upgraded; don't run migration
+        }
+        let mut reads = 1;
+        let mut writes = 0;
+        for vault in crate::Vaults::<T>::iter_values() {
+            reads += 1;
+            if vault.is_liquidated() || vault.issued_tokens.is_zero() {
+                continue;
+            }
+            crate::TotalUserVaultIssuedTokens::<T>::mutate(&vault.id.currencies, |total| {
+                *total = total.saturating_add(vault.issued_tokens)
+            });
+            reads += 1;
+            writes += 1;
+        }
+        crate::StorageVersion::<T>::put(Version::V7);
+        T::DbWeight::get().reads_writes(reads, writes + 1)
+    }
+}
+
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub enum VaultStatus {
     /// Vault is active - bool=true indicates that the vault accepts new issue requests
@@ -257,6 +288,7 @@ impl<T: Config> UpdatableVault<T> for RichVault<T> {
             Pallet::<T>::get_rich_liquidation_vault(&self.data.id.currencies).increase_issued(tokens)
         } else {
             let new_value = self.issued_tokens().checked_add(&tokens)?.amount();
+            Pallet::<T>::increase_total_user_vault_issued_tokens(&self.data.id.currencies, tokens)?;
             self.update(|v| {
                 v.issued_tokens = new_value;
                 Ok(())
@@ -291,6 +323,7 @@ impl<T: Config> UpdatableVault<T> for RichVault<T> {
             Pallet::<T>::get_rich_liquidation_vault(&self.data.id.currencies).decrease_issued(tokens)
         } else {
             let new_value = self.issued_tokens().checked_sub(&tokens)?.amount();
+            Pallet::<T>::decrease_total_user_vault_issued_tokens(&self.data.id.currencies, tokens)?;
             self.update(|v| {
                 v.issued_tokens = new_value;
                 Ok(())
@@ -610,6 +643,7 @@ impl<T: Config> RichVault<T> {
         liquidation_vault.increase_issued(&self.issued_tokens())?;
         liquidation_vault.increase_to_be_issued(&self.to_be_issued_tokens())?;
         liquidation_vault.increase_to_be_redeemed(&self.to_be_redeemed_tokens())?;
+        Pallet::<T>::decrease_total_user_vault_issued_tokens(&self.data.id.currencies, &self.issued_tokens())?;
         // todo: clear replace collateral?
 
         // withdraw stake from the reward pool
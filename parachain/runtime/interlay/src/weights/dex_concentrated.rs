@@ -0,0 +1,160 @@
+
+//! Autogenerated weights for dex_concentrated
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-08-07, STEPS: `50`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `interlay-rust-runner-2mz2v-jrrg4`, CPU: `AMD EPYC 7502P 32-Core Processor`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("interlay-dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/interbtc-parachain
+// benchmark
+// pallet
+// --pallet
+// *
+// --extrinsic
+// *
+// --chain
+// interlay-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --steps
+// 50
+// --repeat
+// 10
+// --output
+// parachain/runtime/interlay/src/weights/
+// --template
+// .deploy/runtime-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for dex_concentrated using the Substrate node and recommended hardware.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> dex_concentrated::WeightInfo for WeightInfo<T> {
+	/// Storage: DexConcentrated NextPoolId (r:1 w:1)
+	/// Proof: DexConcentrated NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Pools (r:0 w:1)
+	/// Proof: DexConcentrated Pools (max_values: None, max_size: Some(230), added: 2705, mode: MaxEncodedLen)
+	fn create_pool	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3593`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_208_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: DexConcentrated Pools (r:1 w:1)
+	/// Proof: DexConcentrated Pools (max_values: None, max_size: Some(230), added: 2705, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated NextPositionId (r:1 w:1)
+	/// Proof: DexConcentrated NextPositionId (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Ticks (r:2 w:2)
+	/// Proof: DexConcentrated Ticks (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated InitializedTicks (r:1 w:1)
+	/// Proof: DexConcentrated InitializedTicks (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Positions (r:0 w:1)
+	/// Proof: DexConcentrated Positions (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	fn create_position	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1421`
+		//  Estimated: `13940`
+		// Minimum execution time: 141_503_000 picoseconds.
+		Weight::from_parts(143_116_000, 13940)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: DexConcentrated Positions (r:1 w:1)
+	/// Proof: DexConcentrated Positions (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Pools (r:1 w:1)
+	/// Proof: DexConcentrated Pools (max_values: None, max_size: Some(230), added: 2705, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Ticks (r:2 w:2)
+	/// Proof: DexConcentrated Ticks (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn add_liquidity	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1987`
+		//  Estimated: `11350`
+		// Minimum execution time: 128_331_000 picoseconds.
+		Weight::from_parts(130_072_000, 11350)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: DexConcentrated Positions (r:1 w:1)
+	/// Proof: DexConcentrated Positions (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Pools (r:1 w:1)
+	/// Proof: DexConcentrated Pools (max_values: None, max_size: Some(230), added: 2705, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Ticks (r:2 w:2)
+	/// Proof: DexConcentrated Ticks (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated InitializedTicks (r:1 w:1)
+	/// Proof: DexConcentrated InitializedTicks (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	fn remove_liquidity	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2054`
+		//  Estimated: `7603`
+		// Minimum execution time: 84_117_000 picoseconds.
+		Weight::from_parts(85_430_000, 7603)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: DexConcentrated Positions (r:1 w:1)
+	/// Proof: DexConcentrated Positions (max_values: None, max_size: Some(200), added: 2675, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Pools (r:1 w:0)
+	/// Proof: DexConcentrated Pools (max_values: None, max_size: Some(230), added: 2705, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Ticks (r:2 w:0)
+	/// Proof: DexConcentrated Ticks (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn collect	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1873`
+		//  Estimated: `11350`
+		// Minimum execution time: 112_689_000 picoseconds.
+		Weight::from_parts(114_205_000, 11350)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DexConcentrated Pools (r:1 w:1)
+	/// Proof: DexConcentrated Pools (max_values: None, max_size: Some(230), added: 2705, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated InitializedTicks (r:1 w:0)
+	/// Proof: DexConcentrated InitializedTicks (max_values: None, max_size: Some(4138), added: 6613, mode: MaxEncodedLen)
+	/// Storage: DexConcentrated Ticks (r:32 w:32)
+	/// Proof: DexConcentrated Ticks (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 32]`.
+	fn swap	(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1742 + c * (97 ±0)`
+		//  Estimated: `11535 + c * (2603 ±0)`
+		// Minimum execution time: 163_108_000 picoseconds.
+		Weight::from_parts(149_871_335, 11535)
+			// Standard Error: 21_482
+			.saturating_add(Weight::from_parts(14_637_209, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+}
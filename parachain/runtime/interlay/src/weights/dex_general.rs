@@ -249,6 +249,8 @@ impl<T: frame_system::Config> dex_general::WeightInfo for WeightInfo<T> {
 	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
 	/// Storage: System Account (r:9 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:9 w:0)
+	/// Proof: DexGeneral BatchOnlyPairs (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
 	/// The range of component `a` is `[2, 10]`.
 	fn swap_exact_assets_for_assets	(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -259,7 +261,7 @@ impl<T: frame_system::Config> dex_general::WeightInfo for WeightInfo<T> {
 			// Standard Error: 109_224
 			.saturating_add(Weight::from_parts(63_887_910, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 5180).saturating_mul(a.into()))
 	}
@@ -269,6 +271,8 @@ impl<T: frame_system::Config> dex_general::WeightInfo for WeightInfo<T> {
 	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
 	/// Storage: System Account (r:9 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:9 w:0)
+	/// Proof: DexGeneral BatchOnlyPairs (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
 	/// The range of component `a` is `[2, 10]`.
 	fn swap_assets_for_exact_assets	(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -279,7 +283,7 @@ impl<T: frame_system::Config> dex_general::WeightInfo for WeightInfo<T> {
 			// Standard Error: 93_262
 			.saturating_add(Weight::from_parts(63_943_649, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 5180).saturating_mul(a.into()))
 	}
@@ -315,4 +319,85 @@ impl<T: frame_system::Config> dex_general::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
+	/// Storage: DexGeneral DynamicFees (r:0 w:1)
+	/// Proof: DexGeneral DynamicFees (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: DexGeneral FeeVolatilities (r:0 w:1)
+	/// Proof: DexGeneral FeeVolatilities (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_dynamic_fee	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `175`
+		//  Estimated: `3628`
+		// Minimum execution time: 20_518_000 picoseconds.
+		Weight::from_parts(21_304_000, 3628)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOrders (r:1 w:1)
+	/// Proof: DexGeneral BatchOrders (max_values: None, max_size: Some(11021), added: 13496, mode: MaxEncodedLen)
+	fn submit_batch_swap	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9147`
+		//  Estimated: `14486`
+		// Minimum execution time: 61_204_000 picoseconds.
+		Weight::from_parts(63_118_000, 14486)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: DexGeneral BatchOrders (r:2 w:1)
+	/// Proof: DexGeneral BatchOrders (max_values: None, max_size: Some(11021), added: 13496, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: DexGeneral DynamicFees (r:1 w:0)
+	/// Proof: DexGeneral DynamicFees (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PriceCumulatives (r:1 w:1)
+	/// Proof: DexGeneral PriceCumulatives (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 100]`.
+	fn clear_batch	(b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `913`
+		//  Estimated: `14486`
+		// Minimum execution time: 118_430_000 picoseconds.
+		Weight::from_parts(104_902_113, 14486)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(31_874_552, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: DexGeneral PairStatuses (r:1 w:0)
+	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(163), added: 2638, mode: MaxEncodedLen)
+	/// Storage: DexGeneral BatchOnlyPairs (r:0 w:1)
+	/// Proof: DexGeneral BatchOnlyPairs (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
+	fn set_batch_only	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1152`
+		//  Estimated: `4617`
+		// Minimum execution time: 18_377_000 picoseconds.
+		Weight::from_parts(19_052_000, 4617)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `b` is `[1, 100]`.
+	fn batch_clearing_round	(b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 19_433_000 picoseconds.
+		Weight::from_parts(19_108_416, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(187_305, 0).saturating_mul(b.into()))
+	}
 }
\ No newline at end of file
@@ -166,4 +166,63 @@ impl<T: frame_system::Config> fee::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Fee CurrencyPairFees (r:0 w:1)
+	/// Proof: Fee CurrencyPairFees (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn set_currency_pair_fee	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_248_000 picoseconds.
+		Weight::from_parts(9_419_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Fee IssueFeeModel (r:0 w:1)
+	/// Proof: Fee IssueFeeModel (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn set_issue_fee_model	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_512_000 picoseconds.
+		Weight::from_parts(9_703_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Fee RedeemFeeModel (r:0 w:1)
+	/// Proof: Fee RedeemFeeModel (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn set_redeem_fee_model	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_487_000 picoseconds.
+		Weight::from_parts(9_655_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Fee AutoCompoundSettings (r:0 w:1)
+	/// Proof: Fee AutoCompoundSettings (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	fn set_auto_compound	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_947_000 picoseconds.
+		Weight::from_parts(11_223_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Fee AutoCompoundSettings (r:1 w:0)
+	/// Proof: Fee AutoCompoundSettings (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	/// Storage: DexGeneral PairStatuses (r:1 w:1)
+	/// Proof: DexGeneral PairStatuses (max_values: None, max_size: Some(1156), added: 3631, mode: MaxEncodedLen)
+	/// Storage: Nomination NominationEnabled (r:1 w:0)
+	/// Proof: Nomination NominationEnabled (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Nomination Vaults (r:1 w:0)
+	/// Proof: Nomination Vaults (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:8 w:8)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	fn compound_rewards	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6418`
+		//  Estimated: `23714`
+		// Minimum execution time: 1_612_908_000 picoseconds.
+		Weight::from_parts(1_624_331_000, 23714)
+			.saturating_add(T::DbWeight::get().reads(97_u64))
+			.saturating_add(T::DbWeight::get().writes(61_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+
+//! Autogenerated weights for multi_transaction_payment
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-08-07, STEPS: `50`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `interlay-rust-runner-2mz2v-jrrg4`, CPU: `AMD EPYC 7502P 32-Core Processor`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("interlay-dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/interbtc-parachain
+// benchmark
+// pallet
+// --pallet
+// *
+// --extrinsic
+// *
+// --chain
+// interlay-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --steps
+// 50
+// --repeat
+// 10
+// --output
+// parachain/runtime/interlay/src/weights/
+// --template
+// .deploy/runtime-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for multi_transaction_payment using the Substrate node and recommended hardware.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> multi_transaction_payment::WeightInfo for WeightInfo<T> {
+
+	/// Storage: MultiTransactionPayment FeeCurrency (r:0 w:1)
+	/// Proof: MultiTransactionPayment FeeCurrency (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	fn set_fee_currency	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_952_000 picoseconds.
+		Weight::from_parts(17_503_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -63,4 +63,14 @@ impl<T: frame_system::Config> security::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(15_060_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Security SubsystemStatuses (r:0 w:1)
+	/// Proof: Security SubsystemStatuses (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	fn set_subsystem_status	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_213_000 picoseconds.
+		Weight::from_parts(16_702_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
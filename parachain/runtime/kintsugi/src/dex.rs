@@ -1,18 +1,31 @@
 use super::{
-    parameter_types, weights, Balance, CurrencyId, DexGeneral, DexStable, PalletId, Runtime, RuntimeEvent,
-    StablePoolId, Timestamp, Tokens,
+    deposit, parameter_types, weights, Balance, ConcentratedPoolId, CurrencyId, DexConcentrated, DexGeneral, DexStable,
+    DexWeighted, GetNativeCurrencyId, Loans, PalletId, Permill, Runtime, RuntimeEvent, StablePoolId, Timestamp, Tokens,
+    WeightedPoolId,
 };
 
 pub use dex_general::{AssetBalance, GenerateLpAssetId, PairInfo, ValidateAsset};
-pub use dex_stable::traits::{StablePoolLpCurrencyIdGenerate, ValidateCurrency};
+pub use dex_stable::traits::{RateProvider, StablePoolLpCurrencyIdGenerate, ValidateCurrency};
+pub use dex_weighted::traits::WeightedPoolLpCurrencyIdGenerate;
+
+use dex_stable::RATE_PRECISION;
+use sp_runtime::FixedPointNumber;
 
 parameter_types! {
     pub const DexGeneralPalletId: PalletId = PalletId(*b"dex/genr");
     pub const DexStablePalletId: PalletId = PalletId(*b"dex/stbl");
+    pub const DexSwapRouterPalletId: PalletId = PalletId(*b"dex/rout");
+    pub const DexWeightedPalletId: PalletId = PalletId(*b"dex/wght");
+    pub const DexConcentratedPalletId: PalletId = PalletId(*b"dex/conc");
+    pub const MaxTicks: u32 = 1024;
+    // a position and the two ticks bounding it
+    pub const ConcentratedPositionDeposit: Balance = deposit(3, 456);
+    pub const KeeperRewardRate: Permill = Permill::from_parts(500); // 0.05%
     pub const CurrencyLimit: u32 = 10;
     pub const StringLimit: u32 = 50;
     pub const MaxBootstrapRewards: u32 = 1000;
     pub const MaxBootstrapLimits:u32 = 1000;
+    pub const MaxBatchOrders: u32 = 100;
 }
 
 pub struct PairLpIdentity;
@@ -39,6 +52,7 @@ impl dex_general::Config for Runtime {
     type WeightInfo = weights::dex_general::WeightInfo<Runtime>;
     type MaxBootstrapRewards = MaxBootstrapRewards;
     type MaxBootstrapLimits = MaxBootstrapLimits;
+    type MaxBatchOrders = MaxBatchOrders;
 }
 
 pub struct PoolLpGenerate;
@@ -62,12 +76,24 @@ impl ValidateCurrency<CurrencyId> for DexStableVerifyPoolAsset {
     }
 }
 
+/// Values lend tokens in their underlying currency, with which they share their decimals. The
+/// exchange rate is the one stored on the last interest accrual, so the pool's rate can't be moved
+/// within a call, and no rate is provided while a flash loan is in progress.
+pub struct LendTokenExchangeRate;
+impl RateProvider<CurrencyId> for LendTokenExchangeRate {
+    fn get_rate(lend_token_id: &CurrencyId) -> Option<Balance> {
+        Loans::lend_token_exchange_rate(*lend_token_id)?.checked_mul_int(RATE_PRECISION)
+    }
+}
+
 impl dex_stable::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type CurrencyId = CurrencyId;
     type MultiCurrency = Tokens;
     type PoolId = StablePoolId;
     type TimeProvider = Timestamp;
+    type RateSource = CurrencyId;
+    type RateProvider = LendTokenExchangeRate;
     type EnsurePoolAsset = DexStableVerifyPoolAsset;
     type LpGenerate = PoolLpGenerate;
     type PoolCurrencyLimit = CurrencyLimit;
@@ -76,6 +102,35 @@ impl dex_stable::Config for Runtime {
     type WeightInfo = weights::dex_stable::WeightInfo<Runtime>;
 }
 
+pub struct WeightedPoolLpGenerate;
+impl WeightedPoolLpCurrencyIdGenerate<CurrencyId, WeightedPoolId> for WeightedPoolLpGenerate {
+    fn generate_by_pool_id(pool_id: WeightedPoolId) -> CurrencyId {
+        CurrencyId::WeightedLpToken(pool_id)
+    }
+}
+
+impl dex_weighted::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CurrencyId = CurrencyId;
+    type MultiCurrency = Tokens;
+    type PoolId = WeightedPoolId;
+    type LpGenerate = WeightedPoolLpGenerate;
+    type PalletId = DexWeightedPalletId;
+    type WeightInfo = weights::dex_weighted::WeightInfo<Runtime>;
+}
+
+impl dex_concentrated::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CurrencyId = CurrencyId;
+    type MultiCurrency = Tokens;
+    type PoolId = ConcentratedPoolId;
+    type NativeCurrencyId = GetNativeCurrencyId;
+    type PositionDeposit = ConcentratedPositionDeposit;
+    type MaxTicks = MaxTicks;
+    type PalletId = DexConcentratedPalletId;
+    type WeightInfo = weights::dex_concentrated::WeightInfo<Runtime>;
+}
+
 impl dex_swap_router::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type StablePoolId = StablePoolId;
@@ -83,7 +138,14 @@ impl dex_swap_router::Config for Runtime {
     type CurrencyId = CurrencyId;
     type GeneralAmm = DexGeneral;
     type StableAmm = DexStable;
+    type WeightedAmm = DexWeighted;
+    type ConcentratedAmm = DexConcentrated;
+    type MultiCurrency = Tokens;
+    type PalletId = DexSwapRouterPalletId;
+    type KeeperRewardRate = KeeperRewardRate;
     type GeneralWeightInfo = weights::dex_general::WeightInfo<Runtime>;
     type StableWeightInfo = weights::dex_stable::WeightInfo<Runtime>;
+    type WeightedWeightInfo = weights::dex_weighted::WeightInfo<Runtime>;
+    type ConcentratedWeightInfo = weights::dex_concentrated::WeightInfo<Runtime>;
     type WeightInfo = weights::dex_swap_router::WeightInfo<Runtime>;
 }
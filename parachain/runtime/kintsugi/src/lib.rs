@@ -81,10 +81,10 @@ pub use oracle_rpc_runtime_api::BalanceWrapper;
 pub use orml_asset_registry::AssetMetadata;
 
 pub use primitives::{
-    self, AccountId, Balance, BlockNumber,
+    self, AccountId, Balance, BlockNumber, ConcentratedPoolId,
     CurrencyId::{ForeignAsset, LendToken, Token},
     CurrencyInfo, Hash, Liquidity, Moment, Nonce, Rate, Ratio, Shortfall, Signature, SignedFixedPoint, SignedInner,
-    StablePoolId, UnsignedFixedPoint, UnsignedInner,
+    StablePoolId, UnsignedFixedPoint, UnsignedInner, WeightedPoolId,
 };
 
 // XCM imports
@@ -257,11 +257,14 @@ impl frame_system::Config for Runtime {
 }
 
 impl multi_transaction_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type DexWeightInfo = weights::dex_general::WeightInfo<Runtime>;
     type RuntimeCall = RuntimeCall;
     type Currency = NativeCurrency;
     type OnUnbalanced = DealWithFees<Runtime, GetNativeCurrencyId>;
     type Dex = DexGeneral;
+    type FeeSwap = DexSwapRouter;
+    type WeightInfo = weights::multi_transaction_payment::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
@@ -676,6 +679,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
         Loans::incentive_reward_account_id(),
         Loans::reward_account_id(),
         FarmingAccount::get(),
+        DexSwapRouter::escrow_account(),
     ]
 }
 
@@ -963,6 +967,7 @@ impl farming::Config for Runtime {
 impl security::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::security::WeightInfo<Runtime>;
+    type SubsystemStatusOrigin = EnsureRootOrAllTechnicalCommittee;
 }
 
 impl currency::Config for Runtime {
@@ -1106,6 +1111,7 @@ impl vault_registry::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = weights::vault_registry::WeightInfo<Runtime>;
     type GetGriefingCollateralCurrencyId = GetNativeCurrencyId;
+    type Loans = Loans;
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
@@ -1130,6 +1136,7 @@ parameter_types! {
 }
 
 impl fee::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type FeePalletId = FeePalletId;
     type WeightInfo = weights::fee::WeightInfo<Runtime>;
     type SignedFixedPoint = SignedFixedPoint;
@@ -1140,6 +1147,8 @@ impl fee::Config for Runtime {
     type OnSweep = currency::SweepFunds<Runtime, FeeAccount>;
     type MaxExpectedValue = MaxExpectedValue;
     type NominationApi = Nomination;
+    type CapacityUtilization = VaultRegistry;
+    type RewardSwap = DexSwapRouter;
 }
 
 pub use issue::IssueRequest;
@@ -1205,6 +1214,7 @@ impl loans::Config for Runtime {
     type RewardAssetId = GetNativeCurrencyId;
     type ReferenceAssetId = GetWrappedCurrencyId;
     type OnExchangeRateChange = vault_registry::PoolManager<Runtime>;
+    type RuntimeCall = RuntimeCall;
 }
 
 construct_runtime! {
@@ -1228,7 +1238,7 @@ construct_runtime! {
         Supply: supply::{Pallet, Storage, Call, Event<T>, Config<T>} = 22,
         Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 23,
         AssetRegistry: orml_asset_registry::{Pallet, Storage, Call, Event<T>, Config<T>} = 24,
-        MultiTransactionPayment: multi_transaction_payment::{Pallet, Call, Storage}  = 25,
+        MultiTransactionPayment: multi_transaction_payment::{Pallet, Call, Storage, Event<T>}  = 25,
 
 
         Escrow: escrow::{Pallet, Call, Storage, Event<T>} = 30,
@@ -1254,7 +1264,7 @@ construct_runtime! {
         Issue: issue::{Pallet, Call, Config<T>, Storage, Event<T>} = 63,
         Redeem: redeem::{Pallet, Call, Config<T>, Storage, Event<T>} = 64,
         Replace: replace::{Pallet, Call, Config<T>, Storage, Event<T>} = 65,
-        Fee: fee::{Pallet, Call, Config<T>, Storage} = 66,
+        Fee: fee::{Pallet, Call, Config<T>, Storage, Event<T>} = 66,
         // Refund: 67
         Nomination: nomination::{Pallet, Call, Storage, Config<T>, Event<T>} = 68,
         ClientsInfo: clients_info::{Pallet, Call, Storage, Event<T>} = 69,
@@ -1286,7 +1296,9 @@ construct_runtime! {
         Loans: loans::{Pallet, Call, Storage, Event<T>, Config<T>} = 100,
         DexGeneral: dex_general::{Pallet, Call, Storage, Event<T>} = 101,
         DexStable: dex_stable::{Pallet, Call, Storage, Event<T>}  = 102,
-        DexSwapRouter: dex_swap_router::{Pallet, Call, Event<T>} = 103,
+        DexSwapRouter: dex_swap_router::{Pallet, Call, Storage, Event<T>} = 103,
+        DexWeighted: dex_weighted::{Pallet, Call, Storage, Event<T>} = 104,
+        DexConcentrated: dex_concentrated::{Pallet, Call, Storage, Event<T>} = 105,
 
         // # Smart contracts
         Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>} = 110,
@@ -1426,11 +1438,14 @@ mod benches {
         [dex_general, DexGeneral]
         [dex_stable, DexStable]
         [dex_swap_router, DexSwapRouter]
+        [dex_weighted, DexWeighted]
+        [dex_concentrated, DexConcentrated]
         [escrow, Escrow]
         [farming, Farming]
         [fee, Fee]
         [issue, Issue]
         [loans, Loans]
+        [multi_transaction_payment, MultiTransactionPayment]
         [nomination, Nomination]
         [oracle, Oracle]
         [redeem, Redeem]
@@ -1846,6 +1861,7 @@ impl_runtime_apis! {
         Block,
         AccountId,
         Balance,
+        loans::InterestRateModel,
     > for Runtime {
         fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall), DispatchError> {
             Loans::get_account_liquidity(&account)
@@ -1860,9 +1876,33 @@ impl_runtime_apis! {
             Loans::get_account_liquidation_threshold_liquidity(&account)
             .and_then(|liquidity| liquidity.to_rpc_tuple())
         }
+
+        fn get_bad_debt(asset_id: CurrencyId) -> Balance {
+            Loans::bad_debt(asset_id)
+        }
+
+        fn get_rate_model_preview(asset_id: CurrencyId, rate_model: loans::InterestRateModel) -> Result<(Rate, Rate), DispatchError> {
+            Loans::preview_rate_model(asset_id, rate_model)
+        }
+
+        fn get_liquidation_candidates(
+            max_health_factor: FixedU128,
+            start_key: Option<(CurrencyId, AccountId)>,
+            limit: u32,
+        ) -> Result<primitives::loans::LiquidationCandidates<AccountId, CurrencyId, Balance, FixedU128>, DispatchError> {
+            Loans::get_liquidation_candidates(max_health_factor, start_key, limit)
+        }
+
+        fn get_liquidate_incentive(account: AccountId, asset_id: CurrencyId) -> Result<Rate, DispatchError> {
+            Loans::current_liquidate_incentive(&account, asset_id)
+        }
+
+        fn get_account_earnings(account: AccountId) -> Result<primitives::loans::EarningsReport<CurrencyId, Balance>, DispatchError> {
+            Loans::get_account_earnings(&account)
+        }
     }
 
-    impl dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId> for Runtime {
+    impl dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId, BlockNumber> for Runtime {
         fn get_pair_by_asset_id(
             asset_0: CurrencyId,
             asset_1: CurrencyId
@@ -1913,6 +1953,29 @@ impl_runtime_apis! {
                 amount,
             )
         }
+
+        fn get_price_observation(
+            asset_0: CurrencyId,
+            asset_1: CurrencyId,
+        ) -> Option<dex_general::PriceObservation<BlockNumber>> {
+            DexGeneral::price_observation(asset_0, asset_1).ok()
+        }
+
+        fn get_twap(
+            asset_0: CurrencyId,
+            asset_1: CurrencyId,
+            start: dex_general::PriceObservation<BlockNumber>,
+            end: dex_general::PriceObservation<BlockNumber>,
+        ) -> Option<FixedU128> {
+            DexGeneral::calculate_twap(asset_0, asset_1, &start, &end).ok()
+        }
+
+        fn get_fee_rate(
+            asset_0: CurrencyId,
+            asset_1: CurrencyId,
+        ) -> Option<dex_general::PairFeeRate> {
+            DexGeneral::get_pair_fee_rate(asset_0, asset_1)
+        }
     }
 
     impl dex_stable_rpc_runtime_api::DexStableApi<Block, CurrencyId, Balance, AccountId, StablePoolId> for Runtime {
@@ -1977,6 +2040,185 @@ impl_runtime_apis! {
         }
     }
 
+    impl dex_concentrated_rpc_runtime_api::DexConcentratedApi<Block, CurrencyId, Balance, AccountId, ConcentratedPoolId> for Runtime {
+        fn get_currencies(pool_id: ConcentratedPoolId) -> Vec<CurrencyId> {
+            DexConcentrated::get_currencies(pool_id)
+        }
+
+        fn get_swap_fee(pool_id: ConcentratedPoolId) -> Permill {
+            DexConcentrated::get_swap_fee(pool_id)
+        }
+
+        fn get_tick(pool_id: ConcentratedPoolId) -> Option<i32> {
+            DexConcentrated::get_tick(pool_id)
+        }
+
+        fn get_liquidity(pool_id: ConcentratedPoolId) -> Balance {
+            DexConcentrated::get_liquidity(pool_id)
+        }
+
+        fn get_spot_price(pool_id: ConcentratedPoolId) -> Option<Balance> {
+            DexConcentrated::get_spot_price(pool_id)
+        }
+
+        fn get_positions(who: AccountId) -> Vec<dex_concentrated::PositionId> {
+            DexConcentrated::get_positions(who)
+        }
+
+        fn calculate_position_amounts(who: AccountId, position_id: dex_concentrated::PositionId) -> Option<Vec<Balance>> {
+            DexConcentrated::calculate_position_amounts(who, position_id)
+        }
+
+        fn calculate_swap(pool_id: ConcentratedPoolId, in_currency: CurrencyId, in_amount: Balance) -> Option<Balance> {
+            DexConcentrated::calculate_swap(pool_id, in_currency, in_amount)
+        }
+
+        fn calculate_swap_for_exact(pool_id: ConcentratedPoolId, in_currency: CurrencyId, out_amount: Balance) -> Option<Balance> {
+            DexConcentrated::calculate_swap_for_exact(pool_id, in_currency, out_amount)
+        }
+
+        fn calculate_add_liquidity(pool_id: ConcentratedPoolId, tick_lower: i32, tick_upper: i32, amount_0: Balance, amount_1: Balance) -> Option<Balance> {
+            DexConcentrated::calculate_add_liquidity(pool_id, tick_lower, tick_upper, amount_0, amount_1)
+        }
+    }
+
+    impl multi_transaction_payment_rpc_runtime_api::MultiTransactionPaymentApi<Block, CurrencyId, Balance> for Runtime {
+        fn query_fee(uxt: <Block as BlockT>::Extrinsic, len: u32, currency_id: CurrencyId) -> Option<Balance> {
+            let fee = TransactionPayment::query_info(uxt, len).partial_fee;
+            MultiTransactionPayment::quote_fee(fee, currency_id)
+        }
+    }
+
+    impl dex_weighted_rpc_runtime_api::DexWeightedApi<Block, CurrencyId, Balance, AccountId, WeightedPoolId> for Runtime {
+        fn get_currencies(pool_id: WeightedPoolId) -> Vec<CurrencyId> {
+            DexWeighted::get_currencies(pool_id)
+        }
+
+        fn get_weights(pool_id: WeightedPoolId) -> Vec<Perbill> {
+            DexWeighted::get_weights(pool_id)
+        }
+
+        fn get_lp_currency(pool_id: WeightedPoolId) -> Option<CurrencyId> {
+            DexWeighted::get_lp_currency(pool_id)
+        }
+
+        fn get_currency_balances(pool_id: WeightedPoolId) -> Vec<Balance> {
+            DexWeighted::get_currency_balances(pool_id)
+        }
+
+        fn get_swap_fee(pool_id: WeightedPoolId) -> Permill {
+            DexWeighted::get_swap_fee(pool_id)
+        }
+
+        fn get_spot_price(pool_id: WeightedPoolId, in_index: u32, out_index: u32) -> Option<Balance> {
+            DexWeighted::get_spot_price(pool_id, in_index, out_index)
+        }
+
+        fn calculate_swap(pool_id: WeightedPoolId, in_index: u32, out_index: u32, in_amount: Balance) -> Option<Balance> {
+            DexWeighted::calculate_swap(pool_id, in_index, out_index, in_amount)
+        }
+
+        fn calculate_swap_for_exact(pool_id: WeightedPoolId, in_index: u32, out_index: u32, out_amount: Balance) -> Option<Balance> {
+            DexWeighted::calculate_swap_for_exact(pool_id, in_index, out_index, out_amount)
+        }
+
+        fn calculate_add_liquidity(pool_id: WeightedPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+            DexWeighted::calculate_add_liquidity(pool_id, amounts)
+        }
+
+        fn calculate_add_liquidity_one_currency(pool_id: WeightedPoolId, index: u32, amount: Balance) -> Option<Balance> {
+            DexWeighted::calculate_add_liquidity_one_currency(pool_id, index, amount)
+        }
+
+        fn calculate_remove_liquidity(pool_id: WeightedPoolId, amount: Balance) -> Option<Vec<Balance>> {
+            DexWeighted::calculate_remove_liquidity(pool_id, amount)
+        }
+
+        fn calculate_remove_liquidity_one_currency(pool_id: WeightedPoolId, amount: Balance, index: u32) -> Option<Balance> {
+            DexWeighted::calculate_remove_liquidity_one_currency(pool_id, amount, index)
+        }
+    }
+
+    impl dex_swap_router_rpc_runtime_api::DexSwapRouterApi<
+        Block,
+        AccountId,
+        StablePoolId,
+        CurrencyId,
+        Balance,
+        BlockNumber,
+    > for Runtime {
+        fn find_best_route(
+            currency_in: CurrencyId,
+            currency_out: CurrencyId,
+            amount_in: Balance,
+            max_hops: u32,
+        ) -> Option<dex_swap_router::RouteQuote<StablePoolId, CurrencyId, Balance>> {
+            DexSwapRouter::find_best_route(currency_in, currency_out, amount_in, max_hops)
+        }
+
+        fn get_open_orders(
+            currency_in: CurrencyId,
+            currency_out: CurrencyId,
+        ) -> Vec<(dex_swap_router::OrderId, dex_swap_router::LimitOrder<AccountId, CurrencyId, Balance, BlockNumber>)> {
+            DexSwapRouter::open_orders(currency_in, currency_out)
+        }
+    }
+
+    impl security_rpc_runtime_api::SecurityApi<
+        Block,
+        primitives::security::Subsystem,
+        primitives::security::SubsystemScope<CurrencyId>,
+        primitives::security::SubsystemStatus,
+    > for Runtime {
+        fn get_subsystem_status(
+            subsystem: primitives::security::Subsystem,
+            scope: primitives::security::SubsystemScope<CurrencyId>,
+        ) -> primitives::security::SubsystemStatus {
+            Security::get_subsystem_status(subsystem, scope)
+        }
+
+        fn get_subsystem_statuses() -> Vec<(
+            primitives::security::Subsystem,
+            primitives::security::SubsystemScope<CurrencyId>,
+            primitives::security::SubsystemStatus,
+        )> {
+            Security::get_subsystem_statuses()
+        }
+    }
+
+    impl nomination_rpc_runtime_api::NominationApi<
+        Block,
+        VaultId,
+        AccountId,
+        Nonce,
+        Balance,
+        BlockNumber,
+    > for Runtime {
+        fn get_pending_unbondings(
+            vault_id: VaultId,
+            nominator_id: AccountId,
+        ) -> Result<Vec<(Nonce, BalanceWrapper<Balance>, BlockNumber)>, DispatchError> {
+            let unbondings = Nomination::get_pending_unbondings(&vault_id, &nominator_id)?;
+            Ok(unbondings
+                .into_iter()
+                .map(|(unbonding_id, amount, unlock_at)| (unbonding_id, BalanceWrapper { amount }, unlock_at))
+                .collect())
+        }
+    }
+
+    impl fee_rpc_runtime_api::FeeApi<
+        Block,
+        CurrencyId,
+        UnsignedFixedPoint,
+    > for Runtime {
+        fn get_fee_rate(
+            currency_pair: primitives::VaultCurrencyPair<CurrencyId>,
+            fee_kind: primitives::fee::FeeKind,
+        ) -> Result<UnsignedFixedPoint, DispatchError> {
+            Fee::get_fee_rate(&currency_pair, fee_kind)
+        }
+    }
+
     #[cfg(feature = "try-runtime")]
     impl frame_try_runtime::TryRuntime<Block> for Runtime {
         fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -403,4 +403,27 @@ impl<T: frame_system::Config> dex_stable::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: DexStable NextPoolId (r:1 w:1)
+	/// Proof: DexStable NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DexStable LpCurrencies (r:1 w:1)
+	/// Proof: DexStable LpCurrencies (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DexStable Pools (r:1 w:1)
+	/// Proof: DexStable Pools (max_values: None, max_size: Some(816), added: 3291, mode: MaxEncodedLen)
+	/// Storage: DexStable RateSources (r:0 w:1)
+	/// Proof: DexStable RateSources (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// The range of component `b` is `[2, 10]`.
+	/// The range of component `s` is `[0, 50]`.
+	fn create_rate_pool	(b: u32, _s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `175`
+		//  Estimated: `4281`
+		// Minimum execution time: 931_402_000 picoseconds.
+		Weight::from_parts(934_811_527, 4281)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(713_092, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
\ No newline at end of file
@@ -627,4 +627,357 @@ impl<T: frame_system::Config> loans::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans FlashLoanFee (r:0 w:1)
+	/// Proof: Loans FlashLoanFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_flash_loan_fee	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_402_000 picoseconds.
+		Weight::from_parts(12_871_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans FlashLoanInProgress (r:1 w:1)
+	/// Proof: Loans FlashLoanInProgress (max_values: Some(1), max_size: Some(27), added: 522, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans FlashLoanFee (r:1 w:0)
+	/// Proof: Loans FlashLoanFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn flash_loan	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2511`
+		//  Estimated: `25841`
+		// Minimum execution time: 109_377_000 picoseconds.
+		Weight::from_parts(111_240_000, 25841)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans EModeCategories (r:0 w:1)
+	/// Proof: Loans EModeCategories (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	fn set_emode_category	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_115_000 picoseconds.
+		Weight::from_parts(12_630_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans EModeCategories (r:1 w:0)
+	/// Proof: Loans EModeCategories (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: Loans AssetEModeCategory (r:0 w:1)
+	/// Proof: Loans AssetEModeCategory (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_asset_emode_category	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `6533`
+		// Minimum execution time: 20_348_000 picoseconds.
+		Weight::from_parts(21_002_000, 6533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EModeCategories (r:2 w:0)
+	/// Proof: Loans EModeCategories (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans AccountBorrows (r:6 w:0)
+	/// Proof: Loans AccountBorrows (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans AssetEModeCategory (r:2 w:0)
+	/// Proof: Loans AssetEModeCategory (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Loans AccountEMode (r:1 w:1)
+	/// Proof: Loans AccountEMode (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_account_emode	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3842`
+		//  Estimated: `91542`
+		// Minimum execution time: 142_917_000 picoseconds.
+		Weight::from_parts(145_628_000, 91542)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:6 w:6)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans AccountBorrows (r:6 w:1)
+	/// Proof: Loans AccountBorrows (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof: Loans BorrowIndex (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MinExchangeRate (r:1 w:0)
+	/// Proof: Loans MinExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans MaxExchangeRate (r:1 w:0)
+	/// Proof: Loans MaxExchangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof: Loans RewardBorrowState (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
+	/// Storage: Loans RewardBorrowSpeed (r:1 w:0)
+	/// Proof: Loans RewardBorrowSpeed (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof: Loans RewardBorrowerIndex (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof: Loans RewardAccrued (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Loans BadDebt (r:1 w:1)
+	/// Proof: Loans BadDebt (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof: Loans ExchangeRate (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	fn write_off_bad_debt	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4913`
+		//  Estimated: `120466`
+		// Minimum execution time: 248_517_000 picoseconds.
+		Weight::from_parts(253_146_000, 120466)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: Loans RateModelTransitions (r:1 w:1)
+	/// Proof: Loans RateModelTransitions (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	fn schedule_rate_model_transition	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `642`
+		//  Estimated: `11906`
+		// Minimum execution time: 27_823_000 picoseconds.
+		Weight::from_parts(28_690_000, 11906)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof: Loans LiquidationAuctionConfigs (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	fn set_liquidation_auction_config	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `4011`
+		// Minimum execution time: 17_934_000 picoseconds.
+		Weight::from_parts(18_521_000, 4011)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof: Loans LiquidationAuctionConfigs (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof: Loans LiquidationAuctions (max_values: None, max_size: Some(79), added: 2554, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof: Loans AccountBorrows (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowIndex (r:2 w:0)
+	/// Proof: Loans BorrowIndex (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof: Loans AccountEMode (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn start_liquidation_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3961`
+		//  Estimated: `86335`
+		// Minimum execution time: 139_207_000 picoseconds.
+		Weight::from_parts(142_380_000, 86335)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof: Loans LiquidationAuctions (max_values: None, max_size: Some(79), added: 2554, mode: MaxEncodedLen)
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof: Loans AccountBorrows (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowIndex (r:2 w:0)
+	/// Proof: Loans BorrowIndex (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof: Loans AccountEMode (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn close_liquidation_auction	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3874`
+		//  Estimated: `76813`
+		// Minimum execution time: 118_602_000 picoseconds.
+		Weight::from_parts(121_449_000, 76813)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof: Loans BorrowAllowances (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn approve_borrow_delegation	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `7304`
+		// Minimum execution time: 21_387_000 picoseconds.
+		Weight::from_parts(22_145_000, 7304)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans BorrowAllowances (r:0 w:1)
+	/// Proof: Loans BorrowAllowances (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	fn revoke_borrow_delegation	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_502_000 picoseconds.
+		Weight::from_parts(14_011_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:7 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowAllowances (r:1 w:1)
+	/// Proof: Loans BorrowAllowances (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans AccountEMode (r:1 w:0)
+	/// Proof: Loans AccountEMode (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:0)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans AccountDeposits (r:6 w:0)
+	/// Proof: Loans AccountDeposits (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Loans AccountBorrows (r:6 w:0)
+	/// Proof: Loans AccountBorrows (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	/// Storage: Loans DelegatedBorrows (r:7 w:1)
+	/// Proof: Loans DelegatedBorrows (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof: Loans BorrowIndex (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Oracle Aggregate (r:2 w:0)
+	/// Proof: Oracle Aggregate (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delegated_borrow	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4637`
+		//  Estimated: `142811`
+		// Minimum execution time: 229_914_000 picoseconds.
+		Weight::from_parts(234_502_000, 142811)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans DelegatedBorrows (r:1 w:1)
+	/// Proof: Loans DelegatedBorrows (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowIndex (r:1 w:0)
+	/// Proof: Loans BorrowIndex (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	fn repay_delegated_borrow	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2271`
+		//  Estimated: `39207`
+		// Minimum execution time: 93_418_000 picoseconds.
+		Weight::from_parts(95_760_000, 39207)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:1)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans MarketDeprecations (r:0 w:1)
+	/// Proof: Loans MarketDeprecations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn deprecate_market	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `675`
+		//  Estimated: `12081`
+		// Minimum execution time: 31_604_000 picoseconds.
+		Weight::from_parts(32_518_000, 12081)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:1)
+	/// Proof: Loans Markets (max_values: None, max_size: Some(160), added: 2635, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof: Loans LastAccruedInterestTime (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof: Loans TotalBorrows (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(35), added: 2510, mode: MaxEncodedLen)
+	/// Storage: Loans TotalReserves (r:1 w:1)
+	/// Proof: Loans TotalReserves (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans MarketDeprecations (r:0 w:1)
+	/// Proof: Loans MarketDeprecations (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowIndex (r:0 w:1)
+	/// Proof: Loans BorrowIndex (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof: Loans ExchangeRate (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans BorrowRate (r:0 w:1)
+	/// Proof: Loans BorrowRate (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans SupplyRate (r:0 w:1)
+	/// Proof: Loans SupplyRate (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans UtilizationRatio (r:0 w:1)
+	/// Proof: Loans UtilizationRatio (max_values: None, max_size: Some(31), added: 2506, mode: MaxEncodedLen)
+	/// Storage: Loans RewardSupplySpeed (r:0 w:1)
+	/// Proof: Loans RewardSupplySpeed (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans RewardBorrowSpeed (r:0 w:1)
+	/// Proof: Loans RewardBorrowSpeed (max_values: None, max_size: Some(43), added: 2518, mode: MaxEncodedLen)
+	/// Storage: Loans RateModelTransitions (r:0 w:1)
+	/// Proof: Loans RateModelTransitions (max_values: None, max_size: Some(261), added: 2736, mode: MaxEncodedLen)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof: Loans LiquidationAuctionConfigs (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	/// Storage: Loans AssetEModeCategory (r:0 w:1)
+	/// Proof: Loans AssetEModeCategory (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn remove_market	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `17430`
+		// Minimum execution time: 55_216_000 picoseconds.
+		Weight::from_parts(56_873_000, 17430)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
 }
\ No newline at end of file
@@ -6,9 +6,11 @@ pub mod clients_info;
 pub mod collator_selection;
 pub mod cumulus_pallet_xcmp_queue;
 pub mod democracy;
+pub mod dex_concentrated;
 pub mod dex_general;
 pub mod dex_stable;
 pub mod dex_swap_router;
+pub mod dex_weighted;
 pub mod escrow;
 pub mod extrinsic_weights;
 pub mod farming;
@@ -16,6 +18,7 @@ pub mod fee;
 pub mod frame_system;
 pub mod issue;
 pub mod loans;
+pub mod multi_transaction_payment;
 pub mod nomination;
 pub mod oracle;
 pub mod orml_asset_registry;
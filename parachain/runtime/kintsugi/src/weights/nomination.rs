@@ -354,4 +354,56 @@ impl<T: frame_system::Config> nomination::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(60_u64))
 			.saturating_add(T::DbWeight::get().writes(34_u64))
 	}
+	/// Storage: Nomination UnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination UnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_unbonding_period	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_381_000 picoseconds.
+		Weight::from_parts(9_727_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ParachainStatus (r:1 w:0)
+	/// Proof: Security ParachainStatus (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Nomination MaxVaultUnbondingPeriod (r:1 w:0)
+	/// Proof: Nomination MaxVaultUnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nomination VaultUnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination VaultUnbondingPeriod (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	fn set_vault_unbonding_period	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `1486`
+		// Minimum execution time: 21_066_000 picoseconds.
+		Weight::from_parts(21_452_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Security ActiveBlockCount (r:1 w:0)
+	/// Proof: Security ActiveBlockCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: VaultStaking Unbondings (r:1 w:1)
+	/// Proof: VaultStaking Unbondings (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	/// Storage: VaultStaking UnbondingStake (r:1 w:1)
+	/// Proof: VaultStaking UnbondingStake (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: VaultStaking UnbondingSlashTally (r:1 w:1)
+	/// Proof: VaultStaking UnbondingSlashTally (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	fn claim_collateral	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6331`
+		//  Estimated: `9017`
+		// Minimum execution time: 948_207_000 picoseconds.
+		Weight::from_parts(955_913_000, 9017)
+			.saturating_add(T::DbWeight::get().reads(64_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
+	}
+	/// Storage: Nomination MaxVaultUnbondingPeriod (r:0 w:1)
+	/// Proof: Nomination MaxVaultUnbondingPeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_max_vault_unbonding_period	() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_904_000 picoseconds.
+		Weight::from_parts(9_317_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
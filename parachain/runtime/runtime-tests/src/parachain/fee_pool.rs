@@ -576,7 +576,8 @@ impl ConcreteAction {
                 let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
                 let amount_without_fee_collateral =
                     redeem.amount_without_fee_as_collateral(vault_id.collateral_currency());
-                let punishment_fee = FeePallet::get_punishment_fee(&amount_without_fee_collateral).unwrap();
+                let punishment_fee =
+                    FeePallet::get_punishment_fee(&vault_id.currencies, &amount_without_fee_collateral).unwrap();
 
                 assert_ok!(RuntimeCall::Redeem(RedeemCall::cancel_redeem {
                     redeem_id: redeem_id,
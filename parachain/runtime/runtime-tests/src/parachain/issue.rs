@@ -352,9 +352,9 @@ mod request_issue_tests {
         });
     }
 
-    fn get_expected_griefing_collateral(amount_btc: Amount<Runtime>) -> Amount<Runtime> {
+    fn get_expected_griefing_collateral(vault_id: &VaultId, amount_btc: Amount<Runtime>) -> Amount<Runtime> {
         let amount_collateral = amount_btc.convert_to(DEFAULT_GRIEFING_CURRENCY).unwrap();
-        FeePallet::get_issue_griefing_collateral(&amount_collateral).unwrap()
+        FeePallet::get_issue_griefing_collateral(&vault_id.currencies, &amount_collateral).unwrap()
     }
 
     /// Request fails if the user can't pay the griefing collateral
@@ -362,7 +362,7 @@ mod request_issue_tests {
     fn integration_test_issue_request_precond_sufficient_funds_for_collateral() {
         test_with_initialized_vault(|vault_id| {
             let amount_btc = vault_id.wrapped(10_000);
-            let expected_griefing_collateral = get_expected_griefing_collateral(amount_btc);
+            let expected_griefing_collateral = get_expected_griefing_collateral(&vault_id, amount_btc);
             let mut user_state = default_user_state();
             user_state.balances.get_mut(&DEFAULT_GRIEFING_CURRENCY).unwrap().free =
                 expected_griefing_collateral - Amount::new(1, DEFAULT_GRIEFING_CURRENCY);
@@ -406,9 +406,9 @@ mod request_issue_tests {
             let expected_btc_address = VaultRegistryPallet::register_deposit_address(&vault_id, issue_id).unwrap();
             let expected_public_key = VaultRegistryPallet::get_bitcoin_public_key(&vault_id.account_id).unwrap();
 
-            let expected_fee = FeePallet::get_issue_fee(&amount_btc).unwrap();
+            let expected_fee = FeePallet::get_issue_fee(&vault_id.currencies, &amount_btc).unwrap();
             let expected_height = BTCRelayPallet::get_best_block_height();
-            let expected_griefing_collateral = get_expected_griefing_collateral(amount_btc);
+            let expected_griefing_collateral = get_expected_griefing_collateral(&vault_id, amount_btc);
 
             let expected_issue = IssueRequest {
                 vault: vault_id,
@@ -469,7 +469,7 @@ fn integration_test_issue_wrapped_execute_succeeds() {
         };
 
         let amount_btc = vault_id.wrapped(1000000);
-        let collateral_vault = required_collateral_for_issue(amount_btc, vault_id.collateral_currency());
+        let collateral_vault = required_collateral_for_issue(amount_btc, &vault_id);
 
         register_vault(&vault_id, collateral_vault);
         register_vault(&vault_id_proof_submitter, collateral_vault);
@@ -538,7 +538,7 @@ fn integration_test_withdraw_after_request_issue() {
             ..vault_id.clone()
         };
         let amount_btc = vault_id.wrapped(1000000);
-        let collateral_vault = required_collateral_for_issue(amount_btc, vault_id.collateral_currency());
+        let collateral_vault = required_collateral_for_issue(amount_btc, &vault_id);
 
         register_vault(&vault_id, collateral_vault);
         register_vault(&vault_id_proof_submitter, collateral_vault);
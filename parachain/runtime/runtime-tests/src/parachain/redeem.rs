@@ -4,6 +4,7 @@ use crate::{
 };
 use currency::Amount;
 use std::str::FromStr;
+use traits::LoansApi;
 
 fn test_with<R>(execute: impl Fn(VaultId) -> R) {
     let test_with = |collateral_id, wrapped_id, extra_vault_currency| {
@@ -44,6 +45,15 @@ fn test_with<R>(execute: impl Fn(VaultId) -> R) {
     test_with(LendToken(1), Token(IBTC), None);
 }
 
+/// liquidated lend token collateral is paid out in the underlying currency
+fn liquidation_payout(collateral: &Amount<Runtime>) -> Amount<Runtime> {
+    if collateral.currency().is_lend_token() {
+        LoansPallet::recompute_underlying_amount(collateral).unwrap()
+    } else {
+        collateral.clone()
+    }
+}
+
 /// to-be-replaced & replace_collateral are decreased in request_redeem
 fn consume_to_be_replaced(vault: &mut CoreVaultData, amount_btc: Amount<Runtime>) {
     let to_be_replaced_decrease = amount_btc.min(&vault.to_be_replaced).unwrap();
@@ -78,10 +88,11 @@ mod spec_based_tests {
             // than `redeemable_tokens`. A first approximation of the limit is redeemable_tokens+fee,
             // however, this slightly underestimates it. Since the actual fee rate is not exposed,
             // use an iterative process to find the maximum redeem request amount.
-            let mut ret = redeemable_tokens + FeePallet::get_redeem_fee(&redeemable_tokens).unwrap();
+            let mut ret =
+                redeemable_tokens + FeePallet::get_redeem_fee(&vault_id.currencies, &redeemable_tokens).unwrap();
 
             loop {
-                let actually_redeemed_tokens = ret - FeePallet::get_redeem_fee(&ret).unwrap();
+                let actually_redeemed_tokens = ret - FeePallet::get_redeem_fee(&vault_id.currencies, &ret).unwrap();
                 if actually_redeemed_tokens > redeemable_tokens {
                     return ret.with_amount(|x| x - 1);
                 }
@@ -203,7 +214,7 @@ mod spec_based_tests {
                 let vault_to_be_redeemed = vault_id.wrapped(1500);
                 let user_to_redeem = vault_id.wrapped(1500);
                 set_redeem_state(vault_to_be_redeemed, user_to_redeem, USER, &vault_id);
-                let redeem_fee = FeePallet::get_redeem_fee(&user_to_redeem).unwrap();
+                let redeem_fee = FeePallet::get_redeem_fee(&vault_id.currencies, &user_to_redeem).unwrap();
                 let burned_tokens = user_to_redeem - redeem_fee;
 
                 CoreVaultData::force_to(
@@ -682,7 +693,8 @@ mod spec_based_tests {
                 let parachain_state_before_cancellation = ParachainState::get(&vault_id);
                 let amount_without_fee_collateral = redeem.amount_without_fee_as_collateral(currency_id);
 
-                let punishment_fee = FeePallet::get_punishment_fee(&amount_without_fee_collateral).unwrap();
+                let punishment_fee =
+                    FeePallet::get_punishment_fee(&vault_id.currencies, &amount_without_fee_collateral).unwrap();
                 assert!(punishment_fee.amount() > 0);
 
                 // alice cancels redeem request and chooses to reimburse
@@ -757,7 +769,8 @@ mod spec_based_tests {
                 let parachain_state_before_cancellation = ParachainState::get(&vault_id);
                 let amount_without_fee_as_collateral = redeem.amount_without_fee_as_collateral(currency_id);
 
-                let punishment_fee = FeePallet::get_punishment_fee(&amount_without_fee_as_collateral).unwrap();
+                let punishment_fee =
+                    FeePallet::get_punishment_fee(&vault_id.currencies, &amount_without_fee_as_collateral).unwrap();
                 assert!(punishment_fee.amount() > 0);
 
                 // alice cancels redeem request and chooses to reimburse
@@ -818,7 +831,8 @@ mod spec_based_tests {
                 let parachain_state_before_cancellation = ParachainState::get(&vault_id);
                 let amount_without_fee_collateral = redeem.amount_without_fee_as_collateral(currency_id);
 
-                let punishment_fee = FeePallet::get_punishment_fee(&amount_without_fee_collateral).unwrap();
+                let punishment_fee =
+                    FeePallet::get_punishment_fee(&vault_id.currencies, &amount_without_fee_collateral).unwrap();
                 assert!(punishment_fee.amount() > 0);
 
                 // alice cancels redeem request and chooses not to reimburse
@@ -1410,7 +1424,8 @@ fn integration_test_redeem_wrapped_liquidation_redeem() {
                 });
 
                 (*user.balances.get_mut(&vault_id.wrapped_currency()).unwrap()).free -= liquidation_redeem_amount;
-                (*user.balances.get_mut(&currency_id).unwrap()).free += reward;
+                let payout = liquidation_payout(&reward);
+                (*user.balances.get_mut(&payout.currency()).unwrap()).free += payout;
 
                 liquidation_vault.issued -= liquidation_redeem_amount;
                 liquidation_vault.collateral -= reward;
@@ -1429,7 +1444,8 @@ fn integration_test_redeem_wrapped_cancel_reimburse_sufficient_collateral_for_wr
         let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
         let amount_without_fee_collateral = redeem.amount_without_fee_as_collateral(currency_id);
 
-        let punishment_fee = FeePallet::get_punishment_fee(&amount_without_fee_collateral).unwrap();
+        let punishment_fee =
+            FeePallet::get_punishment_fee(&vault_id.currencies, &amount_without_fee_collateral).unwrap();
         assert!(punishment_fee.amount() > 0);
 
         // alice cancels redeem request and chooses to reimburse
@@ -1481,7 +1497,8 @@ fn integration_test_redeem_wrapped_cancel_reimburse_insufficient_collateral_for_
         let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
         let amount_without_fee_as_collateral = redeem.amount_without_fee_as_collateral(currency_id);
 
-        let punishment_fee = FeePallet::get_punishment_fee(&amount_without_fee_as_collateral).unwrap();
+        let punishment_fee =
+            FeePallet::get_punishment_fee(&vault_id.currencies, &amount_without_fee_as_collateral).unwrap();
         assert!(punishment_fee.amount() > 0);
 
         // alice cancels redeem request and chooses to reimburse
@@ -1544,8 +1561,11 @@ fn integration_test_redeem_wrapped_cancel_no_reimburse() {
         let redeem_id = setup_cancelable_redeem(USER, &vault_id, amount_btc);
         let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
 
-        let punishment_fee =
-            FeePallet::get_punishment_fee(&redeem.amount_without_fee_as_collateral(currency_id)).unwrap();
+        let punishment_fee = FeePallet::get_punishment_fee(
+            &vault_id.currencies,
+            &redeem.amount_without_fee_as_collateral(currency_id),
+        )
+        .unwrap();
         assert!(punishment_fee.amount() > 0);
 
         // alice cancels redeem request and chooses not to reimburse
@@ -1626,7 +1646,8 @@ fn integration_test_liquidation_redeem_with_cancel_redeem() {
                 liquidation_vault.collateral -= collateral_vault / 2;
 
                 (*user.balances.get_mut(&vault_id.wrapped_currency()).unwrap()).free -= consumed_issued_tokens;
-                (*user.balances.get_mut(&vault_id.collateral_currency()).unwrap()).free += collateral_vault / 2;
+                let payout = liquidation_payout(&(collateral_vault / 2));
+                (*user.balances.get_mut(&payout.currency()).unwrap()).free += payout;
             })
         );
 
@@ -1667,7 +1688,8 @@ fn integration_test_liquidation_redeem_with_cancel_redeem() {
                 liquidation_vault.collateral -= collateral_vault / 2;
 
                 (*user.balances.get_mut(&vault_id.wrapped_currency()).unwrap()).free -= consumed_issued_tokens;
-                (*user.balances.get_mut(&vault_id.collateral_currency()).unwrap()).free += collateral_vault / 2;
+                let payout = liquidation_payout(&(collateral_vault / 2));
+                (*user.balances.get_mut(&payout.currency()).unwrap()).free += payout;
             })
         );
     })
@@ -1883,7 +1905,11 @@ fn setup_cancelable_redeem_with_insufficient_collateral_for_reimburse(vault_id:
     let redeem_id = setup_cancelable_redeem(USER, &vault_id, amount_btc);
     let redeem = RedeemPallet::get_open_redeem_request_from_id(&redeem_id).unwrap();
 
-    let punishment_fee = FeePallet::get_punishment_fee(&redeem.amount_without_fee_as_collateral(currency_id)).unwrap();
+    let punishment_fee = FeePallet::get_punishment_fee(
+        &vault_id.currencies,
+        &redeem.amount_without_fee_as_collateral(currency_id),
+    )
+    .unwrap();
     assert!(punishment_fee.amount() > 0);
 
     redeem_id
@@ -265,10 +265,11 @@ pub fn premium_redeem_request(
     vault_id: &VaultId,
     user: [u8; 32],
 ) -> RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId> {
-    let redeem_fee = FeePallet::get_redeem_fee(&user_to_redeem).unwrap();
+    let redeem_fee = FeePallet::get_redeem_fee(&vault_id.currencies, &user_to_redeem).unwrap();
     let burned_tokens = user_to_redeem - redeem_fee;
     let inclusion_fee = RedeemPallet::get_current_inclusion_fee(vault_id.wrapped_currency()).unwrap();
-    let premium_redeem_fee = FeePallet::get_premium_redeem_fee(&(burned_tokens - inclusion_fee)).unwrap();
+    let premium_redeem_fee =
+        FeePallet::get_premium_redeem_fee(&vault_id.currencies, &(burned_tokens - inclusion_fee)).unwrap();
 
     RedeemRequest {
         premium: premium_redeem_fee.amount(),
@@ -281,7 +282,7 @@ pub fn default_redeem_request(
     vault_id: &VaultId,
     user: [u8; 32],
 ) -> RedeemRequest<AccountId, BlockNumber, Balance, CurrencyId> {
-    let redeem_fee = FeePallet::get_redeem_fee(&user_to_redeem).unwrap();
+    let redeem_fee = FeePallet::get_redeem_fee(&vault_id.currencies, &user_to_redeem).unwrap();
     let burned_tokens = user_to_redeem - redeem_fee;
     let inclusion_fee = RedeemPallet::get_current_inclusion_fee(vault_id.wrapped_currency()).unwrap();
     let redeem_period = RedeemPallet::redeem_period();
@@ -1079,10 +1080,10 @@ pub fn force_issue_tokens(user: [u8; 32], vault: [u8; 32], collateral: Amount<Ru
     assert_ok!(tokens.mint_to(&user.into()));
 }
 
-pub fn required_collateral_for_issue(issued_tokens: Amount<Runtime>, currency_id: CurrencyId) -> Amount<Runtime> {
-    let fee_amount_btc = FeePallet::get_issue_fee(&issued_tokens).unwrap();
+pub fn required_collateral_for_issue(issued_tokens: Amount<Runtime>, vault_id: &VaultId) -> Amount<Runtime> {
+    let fee_amount_btc = FeePallet::get_issue_fee(&vault_id.currencies, &issued_tokens).unwrap();
     let total_amount_btc = issued_tokens + fee_amount_btc;
-    VaultRegistryPallet::get_required_collateral_for_wrapped(&total_amount_btc, currency_id).unwrap()
+    VaultRegistryPallet::get_required_collateral_for_wrapped(&total_amount_btc, vault_id.collateral_currency()).unwrap()
 }
 
 pub fn assert_store_main_chain_header_event(block_height: u32, block_hash: H256Le, relayer_id: AccountId) {
@@ -109,7 +109,7 @@ pub fn set_redeem_state(
     user: [u8; 32],
     vault_id: &VaultId,
 ) -> () {
-    let burned_tokens = user_to_redeem - FeePallet::get_redeem_fee(&user_to_redeem).unwrap();
+    let burned_tokens = user_to_redeem - FeePallet::get_redeem_fee(&vault_id.currencies, &user_to_redeem).unwrap();
     let vault_issued_tokens = vault_to_be_redeemed + burned_tokens;
     CoreVaultData::force_to(
         vault_id,
@@ -86,8 +86,7 @@ where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }
-pub trait RuntimeApiCollection:
-    BaseRuntimeApiCollection
+pub trait RuntimeApiCollection: BaseRuntimeApiCollection
     + EthCompatRuntimeApiCollection
     + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
     + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
@@ -125,9 +124,26 @@ pub trait RuntimeApiCollection:
         Balance,
         BlockNumber,
         UnsignedFixedPoint,
-    > + loans_rpc_runtime_api::LoansApi<Block, AccountId, Balance>
-    + dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId>
+    > + loans_rpc_runtime_api::LoansApi<Block, AccountId, Balance, loans::InterestRateModel>
+    + dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId, BlockNumber>
     + dex_stable_rpc_runtime_api::DexStableApi<Block, CurrencyId, Balance, AccountId, StablePoolId>
+    + dex_swap_router_rpc_runtime_api::DexSwapRouterApi<Block, AccountId, StablePoolId, CurrencyId, Balance, BlockNumber>
+    + fee_rpc_runtime_api::FeeApi<Block, CurrencyId, UnsignedFixedPoint>
+    + nomination_rpc_runtime_api::NominationApi<
+        Block,
+        VaultId<AccountId, CurrencyId>,
+        AccountId,
+        Nonce,
+        Balance,
+        BlockNumber,
+    > + security_rpc_runtime_api::SecurityApi<
+        Block,
+        primitives::security::Subsystem,
+        primitives::security::SubsystemScope<CurrencyId>,
+        primitives::security::SubsystemStatus,
+    > + dex_weighted_rpc_runtime_api::DexWeightedApi<Block, CurrencyId, Balance, AccountId, WeightedPoolId>
+    + multi_transaction_payment_rpc_runtime_api::MultiTransactionPaymentApi<Block, CurrencyId, Balance>
+    + dex_concentrated_rpc_runtime_api::DexConcentratedApi<Block, CurrencyId, Balance, AccountId, ConcentratedPoolId>
 where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -173,9 +189,32 @@ where
             Balance,
             BlockNumber,
             UnsignedFixedPoint,
-        > + loans_rpc_runtime_api::LoansApi<Block, AccountId, Balance>
-        + dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId>
-        + dex_stable_rpc_runtime_api::DexStableApi<Block, CurrencyId, Balance, AccountId, StablePoolId>,
+        > + loans_rpc_runtime_api::LoansApi<Block, AccountId, Balance, loans::InterestRateModel>
+        + dex_general_rpc_runtime_api::DexGeneralApi<Block, AccountId, CurrencyId, BlockNumber>
+        + dex_stable_rpc_runtime_api::DexStableApi<Block, CurrencyId, Balance, AccountId, StablePoolId>
+        + dex_swap_router_rpc_runtime_api::DexSwapRouterApi<
+            Block,
+            AccountId,
+            StablePoolId,
+            CurrencyId,
+            Balance,
+            BlockNumber,
+        > + fee_rpc_runtime_api::FeeApi<Block, CurrencyId, UnsignedFixedPoint>
+        + nomination_rpc_runtime_api::NominationApi<
+            Block,
+            VaultId<AccountId, CurrencyId>,
+            AccountId,
+            Nonce,
+            Balance,
+            BlockNumber,
+        > + security_rpc_runtime_api::SecurityApi<
+            Block,
+            primitives::security::Subsystem,
+            primitives::security::SubsystemScope<CurrencyId>,
+            primitives::security::SubsystemStatus,
+        > + dex_weighted_rpc_runtime_api::DexWeightedApi<Block, CurrencyId, Balance, AccountId, WeightedPoolId>
+        + multi_transaction_payment_rpc_runtime_api::MultiTransactionPaymentApi<Block, CurrencyId, Balance>
+        + dex_concentrated_rpc_runtime_api::DexConcentratedApi<Block, CurrencyId, Balance, AccountId, ConcentratedPoolId>,
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }
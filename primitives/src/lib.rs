@@ -354,6 +354,188 @@ pub mod oracle {
     }
 }
 
+pub mod fee {
+    use super::*;
+
+    /// Fee parameters that can be overridden per vault currency pair.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[serde(rename_all = "camelCase")]
+    pub enum FeeKind {
+        IssueFee,
+        IssueGriefingCollateral,
+        RedeemFee,
+        PremiumRedeemFee,
+        PunishmentFee,
+        ReplaceGriefingCollateral,
+    }
+}
+
+pub mod security {
+    use super::*;
+
+    /// Subsystems that can be halted independently of the rest of the parachain.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[serde(rename_all = "camelCase")]
+    pub enum Subsystem {
+        Issue,
+        Redeem,
+        Replace,
+        VaultRegistry,
+    }
+
+    /// The part of a subsystem that is halted: all of it, everything involving a collateral or
+    /// wrapped currency, or a single currency pair.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    #[serde(rename_all = "camelCase")]
+    pub enum SubsystemScope<CurrencyId: Copy> {
+        Global,
+        Collateral(CurrencyId),
+        Wrapped(CurrencyId),
+        Pair(VaultCurrencyPair<CurrencyId>),
+    }
+
+    impl<CurrencyId: Copy> SubsystemScope<CurrencyId> {
+        /// The scopes that, when halted, also halt this scope, including the scope itself.
+        pub fn enclosing_scopes(&self) -> Vec<Self> {
+            match self {
+                SubsystemScope::Global => vec![SubsystemScope::Global],
+                SubsystemScope::Collateral(_) | SubsystemScope::Wrapped(_) => {
+                    vec![SubsystemScope::Global, self.clone()]
+                }
+                SubsystemScope::Pair(pair) => vec![
+                    SubsystemScope::Global,
+                    SubsystemScope::Collateral(pair.collateral),
+                    SubsystemScope::Wrapped(pair.wrapped),
+                    self.clone(),
+                ],
+            }
+        }
+    }
+
+    /// Operational status of a subsystem in a scope.
+    #[derive(
+        Serialize, Deserialize, Encode, Decode, Clone, Copy, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub enum SubsystemStatus {
+        #[default]
+        Running,
+        Halted,
+    }
+}
+
+pub mod loans {
+    use super::*;
+
+    /// An amount of a single currency held or owed by a loans account.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LoanPosition<CurrencyId, Balance> {
+        pub currency_id: CurrencyId,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        pub amount: Balance,
+    }
+
+    /// A liquidation that can currently be executed against a borrower.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LiquidationOpportunity<CurrencyId, Balance> {
+        /// the borrowed currency to repay
+        pub liquidation_currency_id: CurrencyId,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the largest amount that can be repaid in one liquidation, limited by the market's
+        /// `close_factor` and by the borrower's collateral in `collateral_currency_id`
+        pub max_repay_amount: Balance,
+        /// the underlying currency whose lend tokens are seized
+        pub collateral_currency_id: CurrencyId,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the underlying collateral seized when repaying `max_repay_amount`, including the
+        /// liquidation incentive
+        pub seized_collateral_amount: Balance,
+    }
+
+    /// A borrower whose health factor is below the queried threshold.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LiquidationCandidate<AccountId, CurrencyId, Balance, FixedPoint> {
+        pub account_id: AccountId,
+        /// the collateral value at the liquidation threshold divided by the borrowed value, the
+        /// account can be liquidated once this drops below one
+        pub health_factor: FixedPoint,
+        /// the outstanding borrows, including accrued interest
+        pub borrows: Vec<LoanPosition<CurrencyId, Balance>>,
+        /// the deposited collateral, in underlying currency
+        pub collateral: Vec<LoanPosition<CurrencyId, Balance>>,
+        /// every borrow and collateral pair that can be liquidated right now
+        pub liquidations: Vec<LiquidationOpportunity<CurrencyId, Balance>>,
+    }
+
+    /// A page of liquidation candidates.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LiquidationCandidates<AccountId, CurrencyId, Balance, FixedPoint> {
+        pub candidates: Vec<LiquidationCandidate<AccountId, CurrencyId, Balance, FixedPoint>>,
+        /// the borrow to continue after in the next page, `None` once all borrows were visited
+        pub next_start_key: Option<(CurrencyId, AccountId)>,
+    }
+
+    /// Cumulative interest and incentive rewards of an account in a single market.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct MarketEarnings<CurrencyId, Balance> {
+        /// the underlying currency of the market
+        pub currency_id: CurrencyId,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the interest earned on supplied assets, in underlying currency
+        pub supply_interest: Balance,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the value lost by supplied assets through bad debt socialized across suppliers, in
+        /// underlying currency
+        pub supply_loss: Balance,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the interest accrued on borrows, in underlying currency
+        pub borrow_interest: Balance,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the incentive rewards distributed for supplying to and borrowing from the market
+        pub rewards_accrued: Balance,
+    }
+
+    /// Lending history of an account, across all markets.
+    #[derive(Serialize, Deserialize, Encode, Decode, Clone, Eq, PartialEq, Debug, TypeInfo)]
+    #[serde(rename_all = "camelCase")]
+    pub struct EarningsReport<CurrencyId, Balance> {
+        /// the markets the account has earned or paid interest in
+        pub markets: Vec<MarketEarnings<CurrencyId, Balance>>,
+        #[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+        #[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+        #[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+        #[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+        /// the incentive rewards paid out through `claim_reward`, over all markets
+        pub rewards_claimed: Balance,
+    }
+}
+
 #[cfg(feature = "substrate-compat")]
 pub use runtime::*;
 
@@ -566,11 +748,14 @@ pub enum CurrencyId {
     LendToken(LendTokenId),
     LpToken(LpToken, LpToken),
     StableLpToken(StablePoolId),
+    WeightedLpToken(WeightedPoolId),
 }
 
 pub type ForeignAssetId = u32;
 pub type LendTokenId = u32;
 pub type StablePoolId = u32;
+pub type WeightedPoolId = u32;
+pub type ConcentratedPoolId = u32;
 
 #[derive(scale_info::TypeInfo, Encode, Decode, Clone, Eq, PartialEq, Debug)]
 pub struct CustomMetadata {
@@ -5,7 +5,7 @@
 
 use primitives::{
     issue::IssueRequest, redeem::RedeemRequest, replace::ReplaceRequest, AccountId, Balance, Block, BlockNumber,
-    CurrencyId, H256Le, Hash, Nonce, StablePoolId, VaultId,
+    ConcentratedPoolId, CurrencyId, H256Le, Hash, Nonce, StablePoolId, VaultId, WeightedPoolId,
 };
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
 pub use sc_rpc_api::DenyUnsafe;
@@ -73,6 +73,7 @@ where
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: multi_transaction_payment_rpc::MultiTransactionPaymentRuntimeApi<Block, CurrencyId, Balance>,
     C::Api: btc_relay_rpc::BtcRelayRuntimeApi<Block, H256Le>,
     C::Api: oracle_rpc::OracleRuntimeApi<Block, Balance, CurrencyId>,
     C::Api: vault_registry_rpc::VaultRegistryRuntimeApi<
@@ -107,25 +108,51 @@ where
         BlockNumber,
         FixedU128,
     >,
-    C::Api: loans_rpc::LoansRuntimeApi<Block, AccountId, Balance>,
-    C::Api: dex_general_rpc::DexGeneralRuntimeApi<Block, AccountId, CurrencyId>,
+    C::Api: loans_rpc::LoansRuntimeApi<Block, AccountId, Balance, loans::InterestRateModel>,
+    C::Api: dex_general_rpc::DexGeneralRuntimeApi<Block, AccountId, CurrencyId, BlockNumber>,
     C::Api: dex_stable_rpc::DexStableRuntimeApi<Block, CurrencyId, Balance, AccountId, StablePoolId>,
+    C::Api: dex_concentrated_rpc::DexConcentratedRuntimeApi<Block, CurrencyId, Balance, AccountId, ConcentratedPoolId>,
+    C::Api: dex_weighted_rpc::DexWeightedRuntimeApi<Block, CurrencyId, Balance, AccountId, WeightedPoolId>,
+    C::Api:
+        dex_swap_router_rpc::DexSwapRouterRuntimeApi<Block, AccountId, StablePoolId, CurrencyId, Balance, BlockNumber>,
+    C::Api: security_rpc::SecurityRuntimeApi<
+        Block,
+        primitives::security::Subsystem,
+        primitives::security::SubsystemScope<CurrencyId>,
+        primitives::security::SubsystemStatus,
+    >,
+    C::Api: nomination_rpc::NominationRuntimeApi<
+        Block,
+        VaultId<AccountId, CurrencyId>,
+        AccountId,
+        Nonce,
+        Balance,
+        BlockNumber,
+    >,
+    C::Api: fee_rpc::FeeRuntimeApi<Block, CurrencyId, FixedU128>,
     P: TransactionPool<Block = Block> + 'static,
     BE: Backend<Block> + 'static,
     A: ChainApi<Block = Block> + 'static,
     CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + 'static,
 {
     use btc_relay_rpc::{BtcRelay, BtcRelayApiServer};
+    use dex_concentrated_rpc::{DexConcentrated, DexConcentratedApiServer};
     use dex_general_rpc::{DexGeneral, DexGeneralApiServer};
     use dex_stable_rpc::{DexStable, DexStableApiServer};
+    use dex_swap_router_rpc::{DexSwapRouter, DexSwapRouterApiServer};
+    use dex_weighted_rpc::{DexWeighted, DexWeightedApiServer};
     use escrow_rpc::{Escrow, EscrowApiServer};
+    use fee_rpc::{Fee, FeeApiServer};
     use issue_rpc::{Issue, IssueApiServer};
     use loans_rpc::{Loans, LoansApiServer};
+    use multi_transaction_payment_rpc::{MultiTransactionPayment, MultiTransactionPaymentApiServer};
+    use nomination_rpc::{Nomination, NominationApiServer};
     use oracle_rpc::{Oracle, OracleApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use redeem_rpc::{Redeem, RedeemApiServer};
     use replace_rpc::{Replace, ReplaceApiServer};
     use reward_rpc::{Reward, RewardApiServer};
+    use security_rpc::{Security, SecurityApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
     use vault_registry_rpc::{VaultRegistry, VaultRegistryApiServer};
 
@@ -158,6 +185,8 @@ where
 
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
+    module.merge(MultiTransactionPayment::new(client.clone()).into_rpc())?;
+
     module.merge(BtcRelay::new(client.clone()).into_rpc())?;
 
     module.merge(Oracle::new(client.clone()).into_rpc())?;
@@ -178,7 +207,19 @@ where
 
     module.merge(DexGeneral::new(client.clone()).into_rpc())?;
 
-    module.merge(DexStable::new(client).into_rpc())?;
+    module.merge(DexStable::new(client.clone()).into_rpc())?;
+
+    module.merge(DexConcentrated::new(client.clone()).into_rpc())?;
+
+    module.merge(DexWeighted::new(client.clone()).into_rpc())?;
+
+    module.merge(DexSwapRouter::new(client.clone()).into_rpc())?;
+
+    module.merge(Security::new(client.clone()).into_rpc())?;
+
+    module.merge(Nomination::new(client.clone()).into_rpc())?;
+
+    module.merge(Fee::new(client).into_rpc())?;
 
     Ok(module)
 }